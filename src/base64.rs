@@ -0,0 +1,186 @@
+//! Methods for encoding and decoding base64.
+//!
+//! ## Purpose
+//!
+//! [SASL](crate::sasl) payloads sent with the `AUTHENTICATE` command are encoded using the standard base64
+//! alphabet with padding as per [RFC 4648]. Encoding and decoding are provided here to keep this crate free of
+//! dependencies. Each method returns a [`Base64Buffer`] of `N` bytes.
+//!
+//! [RFC 4648]: <https://datatracker.ietf.org/doc/html/rfc4648#section-4>
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Returns the amount of bytes needed to base64 encode `len` bytes.
+#[must_use]
+pub const fn encoded_len(len: usize) -> usize {
+    len.div_ceil(3) * 4
+}
+
+/// Encodes the `input` as base64 with padding.
+///
+/// # Errors
+///
+/// Will return `Err` if the encoded output is longer than `N` bytes.
+pub const fn encode<const N: usize>(input: &[u8]) -> Result<Base64Buffer<N>, Base64Error> {
+    if encoded_len(input.len()) > N {return Err(Base64Error::BufferFull);}
+    let mut output = Base64Buffer{buffer: [0; N], len: 0};
+    let mut index = 0;
+    while index < input.len() {
+        let remaining = input.len() - index;
+        let first = input[index];
+        let second = if remaining > 1 {input[index + 1]} else {0};
+        let third = if remaining > 2 {input[index + 2]} else {0};
+        output.buffer[output.len] = ALPHABET[(first >> 2) as usize];
+        output.buffer[output.len + 1] = ALPHABET[(((first & 0x03) << 4) | (second >> 4)) as usize];
+        output.buffer[output.len + 2] = if remaining > 1 {
+            ALPHABET[(((second & 0x0F) << 2) | (third >> 6)) as usize]
+        } else {
+            b'='
+        };
+        output.buffer[output.len + 3] = if remaining > 2 {ALPHABET[(third & 0x3F) as usize]} else {b'='};
+        output.len += 4;
+        index += 3;
+    }
+    Ok(output)
+}
+
+/// Decodes the base64 `input` with padding.
+///
+/// # Errors
+///
+/// Will return `Err` if the input length is not a multiple of 4, contains a byte outside the base64 alphabet,
+/// has misplaced padding or the decoded output is longer than `N` bytes.
+pub const fn decode<const N: usize>(input: &[u8]) -> Result<Base64Buffer<N>, Base64Error> {
+    if input.len() % 4 != 0 {return Err(Base64Error::InvalidLength);}
+    let mut output = Base64Buffer{buffer: [0; N], len: 0};
+    let mut index = 0;
+    while index < input.len() {
+        let is_last = index + 4 == input.len();
+        let mut values = [0; 4];
+        let mut padding = 0;
+        let mut offset = 0;
+        while offset < 4 {
+            let byte = input[index + offset];
+            if byte == b'=' {
+                if !is_last || offset < 2 {return Err(Base64Error::InvalidPadding);}
+                padding += 1;
+            } else if padding > 0 {
+                return Err(Base64Error::InvalidPadding);
+            } else {
+                let Some(value) = decode_byte(byte) else {return Err(Base64Error::InvalidByte(byte));};
+                values[offset] = value;
+            }
+            offset += 1;
+        }
+        let decoded = [
+            (values[0] << 2) | (values[1] >> 4),
+            (values[1] << 4) | (values[2] >> 2),
+            (values[2] << 6) | values[3],
+        ];
+        let amount = 3 - padding;
+        if output.len + amount > N {return Err(Base64Error::BufferFull);}
+        let mut byte = 0;
+        while byte < amount {
+            output.buffer[output.len] = decoded[byte];
+            output.len += 1;
+            byte += 1;
+        }
+        index += 4;
+    }
+    Ok(output)
+}
+
+const fn decode_byte(input: u8) -> Option<u8> {
+    match input {
+        b'A'..=b'Z' => Some(input - b'A'),
+        b'a'..=b'z' => Some(input - b'a' + 26),
+        b'0'..=b'9' => Some(input - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// A buffer of `N` bytes holding base64 encoded or decoded output.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Base64Buffer<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> Base64Buffer<N> {
+    /// Returns the output as a slice of bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        let (output, _) = self.buffer.split_at(self.len);
+        output
+    }
+    /// Returns the amount of bytes written.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Check if no bytes were written.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// The possible types of errors when encoding or decoding base64.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Base64Error {
+    /// The buffer is too small.
+    BufferFull,
+    /// The length of the input is not a multiple of 4.
+    InvalidLength,
+    /// Use of a byte outside the base64 alphabet.
+    InvalidByte(u8),
+    /// Padding is not only at the end of the input or more than 2 bytes long.
+    InvalidPadding,
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{Base64Error, decode, encode, encoded_len};
+    use crate::is_identical;
+    #[test]
+    const fn encoding() {
+        let output = encode::<16>(b"");
+        assert!(output.is_ok());
+        if let Ok(output) = output {assert!(output.is_empty());}
+        let output = encode::<16>(b"f");
+        assert!(output.is_ok());
+        if let Ok(output) = output {assert!(is_identical(output.as_bytes(), b"Zg=="));}
+        let output = encode::<16>(b"fo");
+        assert!(output.is_ok());
+        if let Ok(output) = output {assert!(is_identical(output.as_bytes(), b"Zm8="));}
+        let output = encode::<16>(b"foobar");
+        assert!(output.is_ok());
+        if let Ok(output) = output {
+            assert!(is_identical(output.as_bytes(), b"Zm9vYmFy") && output.len() == encoded_len(6));
+        }
+        let output = encode::<32>(b"\0dan\0hunter2");
+        assert!(output.is_ok());
+        if let Ok(output) = output {assert!(is_identical(output.as_bytes(), b"AGRhbgBodW50ZXIy"));}
+        assert!(matches!(encode::<3>(b"f"), Err(Base64Error::BufferFull)));
+    }
+    #[test]
+    const fn decoding() {
+        let output = decode::<16>(b"Zm9vYmE=");
+        assert!(output.is_ok());
+        if let Ok(output) = output {assert!(is_identical(output.as_bytes(), b"fooba"));}
+        let output = decode::<16>(b"Zg==");
+        assert!(output.is_ok());
+        if let Ok(output) = output {assert!(is_identical(output.as_bytes(), b"f"));}
+        let output = decode::<32>(b"AGRhbgBodW50ZXIy");
+        assert!(output.is_ok());
+        if let Ok(output) = output {assert!(is_identical(output.as_bytes(), b"\0dan\0hunter2"));}
+        assert!(matches!(decode::<16>(b"Zg="), Err(Base64Error::InvalidLength)));
+        assert!(matches!(decode::<16>(b"Zg=a"), Err(Base64Error::InvalidPadding)));
+        assert!(matches!(decode::<16>(b"Z==="), Err(Base64Error::InvalidPadding)));
+        assert!(matches!(decode::<16>(b"Zg==Zg=="), Err(Base64Error::InvalidPadding)));
+        assert!(matches!(decode::<16>(b"Zm9*"), Err(Base64Error::InvalidByte(b'*'))));
+        assert!(matches!(decode::<2>(b"Zm9v"), Err(Base64Error::BufferFull)));
+    }
+}
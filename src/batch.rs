@@ -0,0 +1,580 @@
+//! Methods for parsing `BATCH` messages and tracking open batches.
+//!
+//! ## Purpose
+//!
+//! A [batch] groups related messages such as the replies of a netsplit or a chathistory playback.
+//! The IRC server opens a batch with `BATCH +reference type parameters...` and closes it with `BATCH -reference`.
+//! Every message belonging to the batch carries a `batch` tag whose value is the reference.
+//! Batches can be nested by tagging the opening `BATCH` message with the reference of the outer batch.
+//!
+//! A [`BatchMsg`] parses a single `BATCH` message while [`OpenBatches`] keeps track of the references currently
+//! open so the `batch` tag of any other message can be matched against them.
+//!
+//! A [multiline] batch splits a single message over several `PRIVMSG` or `NOTICE` lines.
+//! A [`MultilineAssembler`] joins the lines back together within the [`MultilineLimits`] of the capability.
+//!
+//! [batch]: <https://ircv3.net/specs/extensions/batch>
+//! [multiline]: <https://ircv3.net/specs/extensions/multiline>
+
+use crate::{ContentType, IrcMsg, cap::{Capability, KnownCapability}, command::CommandKind, is_identical, parse_u64,
+    parameters::Parameters};
+
+/// The maximum amount of bytes in a batch reference stored by [`OpenBatches`].
+pub const MAX_BATCH_REFERENCE_LEN: usize = 64;
+
+/// Returns the value of the `batch` tag of an [`IrcMsg`].
+///
+/// Returns `None` if the [`IrcMsg`] doesn't belong to a batch.
+#[must_use]
+pub const fn batch_tag<'msg>(msg: &IrcMsg<'msg>) -> Option<&'msg [u8]> {
+    let Some(tags) = msg.tags() else {return None;};
+    let Some(tag) = tags.find(b"batch") else {return None;};
+    match tag.escaped_value() {
+        Some(value) => Some(value.as_bytes()),
+        None => None,
+    }
+}
+
+/// The type of a batch.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BatchType {
+    /// Quits caused by a netsplit.
+    Netsplit,
+    /// Joins caused by a netjoin after a netsplit.
+    Netjoin,
+    /// History played back with `CHATHISTORY`.
+    ChatHistory,
+    /// The response to a labeled command.
+    LabeledResponse,
+    /// A single message split over several lines.
+    Multiline,
+    /// A type not known by this crate.
+    Other,
+}
+
+impl BatchType {
+    /// Generates a [`BatchType`] from a slice of bytes.
+    ///
+    /// Any type not known by this crate becomes [`BatchType::Other`].
+    #[must_use]
+    pub const fn from_bytes(input: &[u8]) -> Self {
+        match input {
+            b"netsplit" => Self::Netsplit,
+            b"netjoin" => Self::Netjoin,
+            b"chathistory" => Self::ChatHistory,
+            b"labeled-response" => Self::LabeledResponse,
+            b"draft/multiline" => Self::Multiline,
+            _ => Self::Other,
+        }
+    }
+}
+
+/// A `BATCH` message opening or closing a batch.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BatchMsg<'msg> {
+    reference: &'msg [u8],
+    opening: bool,
+    outer: Option<&'msg [u8]>,
+    params: Parameters<'msg>,
+}
+
+impl<'msg> BatchMsg<'msg> {
+    /// Generates a [`BatchMsg`] from an [`IrcMsg`].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the [`IrcMsg`] is not a `BATCH` command, the reference is missing or not prefixed by
+    /// `+` or `-` or an opening `BATCH` is missing its type.
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Result<Self, BatchMsgError> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Batch)) {return Err(BatchMsgError::NotBatch);}
+        let Some(params) = msg.parameters() else {return Err(BatchMsgError::MissingReference);};
+        let (opening, reference) = match params.extract_first().as_bytes() {
+            [b'+', reference @ ..] if !reference.is_empty() => (true, reference),
+            [b'-', reference @ ..] if !reference.is_empty() => (false, reference),
+            [b'+' | b'-'] => return Err(BatchMsgError::MissingReference),
+            _ => return Err(BatchMsgError::InvalidReference),
+        };
+        if opening && params.count() < 2 {return Err(BatchMsgError::MissingType);}
+        Ok(Self{reference, opening, outer: batch_tag(msg), params})
+    }
+    /// Returns the reference of the batch without its `+` or `-` prefix.
+    #[must_use]
+    pub const fn reference(&self) -> &'msg [u8] {
+        self.reference
+    }
+    /// Check whether the [`BatchMsg`] opens a batch.
+    #[must_use]
+    pub const fn is_start(&self) -> bool {
+        self.opening
+    }
+    /// Check whether the [`BatchMsg`] closes a batch.
+    #[must_use]
+    pub const fn is_end(&self) -> bool {
+        !self.opening
+    }
+    /// Returns the reference of the outer batch an opening [`BatchMsg`] is nested in.
+    #[must_use]
+    pub const fn outer_reference(&self) -> Option<&'msg [u8]> {
+        self.outer
+    }
+    /// Returns the [`BatchType`] of an opening [`BatchMsg`].
+    #[must_use]
+    pub const fn batch_type(&self) -> Option<BatchType> {
+        match self.raw_type() {
+            Some(raw) => Some(BatchType::from_bytes(raw.as_bytes())),
+            None => None,
+        }
+    }
+    /// Returns the type of an opening [`BatchMsg`] as it appears in the message.
+    #[must_use]
+    pub const fn raw_type(&self) -> Option<ContentType<'msg>> {
+        if !self.opening {return None;}
+        self.params.extract_specific(1)
+    }
+    /// Returns the amount of parameters following the type of an opening [`BatchMsg`].
+    #[must_use]
+    pub const fn parameter_count(&self) -> usize {
+        if !self.opening {return 0;}
+        self.params.count() - 2
+    }
+    /// Returns the parameter following the type at the specified index.
+    ///
+    /// Index starts at 0. If out of bounds it returns `None`.
+    #[must_use]
+    pub const fn parameter(&self, index: usize) -> Option<ContentType<'msg>> {
+        if index >= self.parameter_count() {return None;}
+        self.params.extract_specific(index + 2)
+    }
+    /// Check whether an [`IrcMsg`] belongs to this batch according to its `batch` tag.
+    #[must_use]
+    pub const fn contains(&self, msg: &IrcMsg) -> bool {
+        match batch_tag(msg) {
+            Some(reference) => is_identical(reference, self.reference),
+            None => false,
+        }
+    }
+}
+
+/// The possible types of errors when parsing a [`BatchMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BatchMsgError {
+    /// The [`IrcMsg`] is not a `BATCH` command.
+    NotBatch,
+    /// The reference is missing.
+    MissingReference,
+    /// The reference is not prefixed by `+` or `-`.
+    InvalidReference,
+    /// An opening `BATCH` is missing its type.
+    MissingType,
+}
+
+/// The references of up to `N` open batches.
+///
+/// References are copied so the [`IrcMsg`] opening a batch doesn't need to outlive it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct OpenBatches<const N: usize> {
+    references: [[u8; MAX_BATCH_REFERENCE_LEN]; N],
+    lens: [usize; N],
+    types: [BatchType; N],
+    count: usize,
+}
+
+impl<const N: usize> OpenBatches<N> {
+    /// Generates an empty [`OpenBatches`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self{references: [[0; MAX_BATCH_REFERENCE_LEN]; N], lens: [0; N], types: [BatchType::Other; N], count: 0}
+    }
+    /// Opens or closes a batch according to the [`BatchMsg`].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if an opened reference is already open or longer than [`MAX_BATCH_REFERENCE_LEN`] bytes,
+    /// `N` batches are already open or a closed reference is not open.
+    pub const fn apply(mut self, batch: &BatchMsg) -> Result<Self, OpenBatchesError> {
+        let reference = batch.reference();
+        let position = self.position(reference);
+        if batch.is_end() {
+            let Some(position) = position else {return Err(OpenBatchesError::NotOpen);};
+            self.count -= 1;
+            self.references[position] = self.references[self.count];
+            self.lens[position] = self.lens[self.count];
+            self.types[position] = self.types[self.count];
+            return Ok(self);
+        }
+        if position.is_some() {return Err(OpenBatchesError::AlreadyOpen);}
+        if reference.len() > MAX_BATCH_REFERENCE_LEN {return Err(OpenBatchesError::ReferenceTooLong);}
+        if self.count == N {return Err(OpenBatchesError::Full);}
+        let mut index = 0;
+        while index < reference.len() {
+            self.references[self.count][index] = reference[index];
+            index += 1;
+        }
+        self.lens[self.count] = reference.len();
+        self.types[self.count] = match batch.batch_type() {
+            Some(batch_type) => batch_type,
+            None => BatchType::Other,
+        };
+        self.count += 1;
+        Ok(self)
+    }
+    /// Returns the amount of open batches.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.count
+    }
+    /// Check if no batches are open.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+    /// Check whether the `reference` is open.
+    #[must_use]
+    pub const fn is_open(&self, reference: &[u8]) -> bool {
+        self.position(reference).is_some()
+    }
+    /// Returns the [`BatchType`] of the open batch an [`IrcMsg`] belongs to according to its `batch` tag.
+    ///
+    /// Returns `None` if the [`IrcMsg`] has no `batch` tag or its reference is not open.
+    #[must_use]
+    pub const fn batch_of(&self, msg: &IrcMsg) -> Option<BatchType> {
+        let Some(reference) = batch_tag(msg) else {return None;};
+        match self.position(reference) {
+            Some(position) => Some(self.types[position]),
+            None => None,
+        }
+    }
+    const fn position(&self, reference: &[u8]) -> Option<usize> {
+        let mut index = 0;
+        while index < self.count {
+            let (open, _) = self.references[index].split_at(self.lens[index]);
+            if is_identical(open, reference) {return Some(index);}
+            index += 1;
+        }
+        None
+    }
+}
+
+impl<const N: usize> Default for OpenBatches<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The possible types of errors when opening or closing a batch in [`OpenBatches`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OpenBatchesError {
+    /// The reference is already open.
+    AlreadyOpen,
+    /// The reference is not open.
+    NotOpen,
+    /// The reference is longer than [`MAX_BATCH_REFERENCE_LEN`] bytes.
+    ReferenceTooLong,
+    /// The maximum amount of open batches has been reached.
+    Full,
+}
+
+/// The limits advertised in the value of the `draft/multiline` capability.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MultilineLimits {
+    max_bytes: usize,
+    max_lines: Option<usize>,
+}
+
+impl MultilineLimits {
+    /// Generates [`MultilineLimits`] from the maximum amount of bytes and optionally lines.
+    #[must_use]
+    pub const fn new(max_bytes: usize, max_lines: Option<usize>) -> Self {
+        Self{max_bytes, max_lines}
+    }
+    /// Generates [`MultilineLimits`] from a `draft/multiline` [`Capability`] such as
+    /// `draft/multiline=max-bytes=4096,max-lines=24`.
+    ///
+    /// Returns `None` if the [`Capability`] is not `draft/multiline` or `max-bytes` is missing or invalid.
+    #[must_use]
+    pub const fn from_capability(capability: &Capability) -> Option<Self> {
+        if !matches!(capability.known(), Some(KnownCapability::Multiline)) {return None;}
+        let (mut max_bytes, mut max_lines) = (None, None);
+        let mut values = capability.values();
+        while let Some((value, rest)) = values.next_item() {
+            match value.as_bytes() {
+                [b'm', b'a', b'x', b'-', b'b', b'y', b't', b'e', b's', b'=', number @ ..] => {
+                    max_bytes = match parse_limit(number) {Some(number) => Some(number), None => return None};
+                },
+                [b'm', b'a', b'x', b'-', b'l', b'i', b'n', b'e', b's', b'=', number @ ..] => {
+                    max_lines = match parse_limit(number) {Some(number) => Some(number), None => return None};
+                },
+                _ => {},
+            }
+            values = rest;
+        }
+        match max_bytes {
+            Some(max_bytes) => Some(Self{max_bytes, max_lines}),
+            None => None,
+        }
+    }
+    /// Returns the maximum amount of bytes of all the lines combined.
+    #[must_use]
+    pub const fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+    /// Returns the maximum amount of lines if limited.
+    #[must_use]
+    pub const fn max_lines(&self) -> Option<usize> {
+        self.max_lines
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+const fn parse_limit(input: &[u8]) -> Option<usize> {
+    match parse_u64(input) {
+        Some(value) if value <= usize::MAX as u64 => Some(value as usize),
+        _ => None,
+    }
+}
+
+/// A buffer of `N` bytes joining the lines of a `draft/multiline` batch into a single message.
+///
+/// Lines are joined by a line feed unless a line carries the `draft/multiline-concat` tag in which case it is
+/// appended directly to the previous line.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MultilineAssembler<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+    lines: usize,
+    content_bytes: usize,
+    limits: MultilineLimits,
+}
+
+impl<const N: usize> MultilineAssembler<N> {
+    /// Generates an empty [`MultilineAssembler`] enforcing the [`MultilineLimits`].
+    #[must_use]
+    pub const fn new(limits: MultilineLimits) -> Self {
+        Self{buffer: [0; N], len: 0, lines: 0, content_bytes: 0, limits}
+    }
+    /// Appends the text of a `PRIVMSG` or `NOTICE` [`IrcMsg`] belonging to the batch.
+    ///
+    /// The line feeds joining lines don't count towards the maximum amount of bytes.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the [`IrcMsg`] is not a `PRIVMSG` or `NOTICE` with text, the `draft/multiline-concat`
+    /// tag is used on the first line or a blank line, the [`MultilineLimits`] are exceeded or the joined message is
+    /// longer than `N` bytes.
+    pub const fn push(mut self, msg: &IrcMsg) -> Result<Self, MultilineError> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Privmsg | CommandKind::Notice)) {
+            return Err(MultilineError::NotMessage);
+        }
+        let Some(params) = msg.parameters() else {return Err(MultilineError::MissingText);};
+        if params.count() < 2 {return Err(MultilineError::MissingText);}
+        let text = params.extract_last().as_bytes();
+        let concat = match msg.tags() {
+            Some(tags) => tags.find(b"draft/multiline-concat").is_some(),
+            None => false,
+        };
+        if concat && (self.lines == 0 || text.is_empty()) {return Err(MultilineError::InvalidConcat);}
+        let lines = if concat {self.lines} else {self.lines + 1};
+        if let Some(max_lines) = self.limits.max_lines {
+            if lines > max_lines {return Err(MultilineError::MaxLinesExceeded);}
+        }
+        if self.content_bytes + text.len() > self.limits.max_bytes {return Err(MultilineError::MaxBytesExceeded);}
+        let separator = !concat && self.lines > 0;
+        if self.len + text.len() + separator as usize > N {return Err(MultilineError::BufferFull);}
+        if separator {
+            self.buffer[self.len] = b'\n';
+            self.len += 1;
+        }
+        let mut index = 0;
+        while index < text.len() {
+            self.buffer[self.len] = text[index];
+            self.len += 1;
+            index += 1;
+        }
+        self.lines = lines;
+        self.content_bytes += text.len();
+        Ok(self)
+    }
+    /// Returns the joined message as a slice of bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        let (output, _) = self.buffer.split_at(self.len);
+        output
+    }
+    /// Returns the amount of bytes written.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Check if no bytes were written.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Returns the amount of lines after joining the concatenated lines.
+    #[must_use]
+    pub const fn line_count(&self) -> usize {
+        self.lines
+    }
+}
+
+/// The possible types of errors when joining the lines of a `draft/multiline` batch.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MultilineError {
+    /// The [`IrcMsg`] is not a `PRIVMSG` or `NOTICE`.
+    NotMessage,
+    /// The [`IrcMsg`] has no text.
+    MissingText,
+    /// The `draft/multiline-concat` tag is used on the first line or a blank line.
+    InvalidConcat,
+    /// The maximum amount of bytes has been exceeded.
+    MaxBytesExceeded,
+    /// The maximum amount of lines has been exceeded.
+    MaxLinesExceeded,
+    /// The buffer is too small.
+    BufferFull,
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{BatchMsg, BatchMsgError, BatchType, MultilineAssembler, MultilineError, MultilineLimits, OpenBatches,
+        OpenBatchesError, batch_tag};
+    use crate::{IrcMsg, cap::Capability, is_identical};
+    #[test]
+    const fn parsing_batch_msg() {
+        let msg = IrcMsg::parse(b":irc.host BATCH +yXNAbvnRHTRBv netsplit irc.hub other.host");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let start = BatchMsg::from_msg(&msg);
+            assert!(start.is_ok());
+            if let Ok(start) = start {
+                assert!(start.is_start() && !start.is_end() && start.outer_reference().is_none());
+                assert!(is_identical(start.reference(), b"yXNAbvnRHTRBv"));
+                assert!(matches!(start.batch_type(), Some(BatchType::Netsplit)));
+                assert!(start.parameter_count() == 2 && start.parameter(2).is_none());
+                let param = start.parameter(1);
+                assert!(param.is_some());
+                if let Some(param) = param {assert!(is_identical(param.as_bytes(), b"other.host"));}
+            }
+        }
+        let msg = IrcMsg::parse(b"@batch=outer :irc.host BATCH +inner draft/multiline #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let nested = BatchMsg::from_msg(&msg);
+            assert!(nested.is_ok());
+            if let Ok(nested) = nested {
+                assert!(matches!(nested.batch_type(), Some(BatchType::Multiline)));
+                let outer = nested.outer_reference();
+                assert!(outer.is_some());
+                if let Some(outer) = outer {assert!(is_identical(outer, b"outer"));}
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com BATCH -yXNAbvnRHTRBv");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let end = BatchMsg::from_msg(&msg);
+            assert!(end.is_ok());
+            if let Ok(end) = end {assert!(end.is_end() && end.batch_type().is_none() && end.parameter_count() == 0);}
+        }
+        let msg = IrcMsg::parse(b"BATCH +abc example.com/custom");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let other = BatchMsg::from_msg(&msg);
+            assert!(other.is_ok());
+            if let Ok(other) = other {assert!(matches!(other.batch_type(), Some(BatchType::Other)));}
+        }
+        let msg = IrcMsg::parse(b"BATCH +abc");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(BatchMsg::from_msg(&msg), Err(BatchMsgError::MissingType)));}
+        let msg = IrcMsg::parse(b"BATCH abc netsplit");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(BatchMsg::from_msg(&msg), Err(BatchMsgError::InvalidReference)));}
+        let msg = IrcMsg::parse(b"BATCH -");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(BatchMsg::from_msg(&msg), Err(BatchMsgError::MissingReference)));}
+        let msg = IrcMsg::parse(b"PRIVMSG #chan :hi");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(BatchMsg::from_msg(&msg), Err(BatchMsgError::NotBatch)));}
+    }
+    #[test]
+    const fn tracking_open_batches() {
+        let open = IrcMsg::parse(b"BATCH +1 chathistory #chan");
+        assert!(open.is_ok());
+        if let Ok(open) = open {
+            let open = BatchMsg::from_msg(&open);
+            assert!(open.is_ok());
+            if let Ok(open) = open {
+                let member = IrcMsg::parse(b"@time=2023-01-01T00:00:00.000Z;batch=1 :nick!u@host PRIVMSG #c :hi");
+                assert!(member.is_ok());
+                if let Ok(member) = member {
+                    assert!(open.contains(&member));
+                    let reference = batch_tag(&member);
+                    assert!(reference.is_some());
+                    if let Some(reference) = reference {assert!(is_identical(reference, b"1"));}
+                    let batches = OpenBatches::<2>::new();
+                    assert!(batches.is_empty() && batches.batch_of(&member).is_none());
+                    let batches = batches.apply(&open);
+                    assert!(batches.is_ok());
+                    if let Ok(batches) = batches {
+                        assert!(batches.is_open(b"1") && batches.count() == 1);
+                        assert!(matches!(batches.batch_of(&member), Some(BatchType::ChatHistory)));
+                        assert!(matches!(batches.apply(&open), Err(OpenBatchesError::AlreadyOpen)));
+                        let close = IrcMsg::parse(b"BATCH -1");
+                        assert!(close.is_ok());
+                        if let Ok(close) = close {
+                            let close = BatchMsg::from_msg(&close);
+                            assert!(close.is_ok());
+                            if let Ok(close) = close {
+                                let batches = batches.apply(&close);
+                                assert!(batches.is_ok());
+                                if let Ok(batches) = batches {
+                                    assert!(batches.is_empty() && batches.batch_of(&member).is_none());
+                                    assert!(matches!(batches.apply(&close), Err(OpenBatchesError::NotOpen)));
+                                }
+                            }
+                        }
+                    }
+                }
+                assert!(matches!(OpenBatches::<0>::new().apply(&open), Err(OpenBatchesError::Full)));
+            }
+        }
+    }
+    const fn pushed<const N: usize>(assembler: Result<MultilineAssembler<N>, MultilineError>) -> MultilineAssembler<N> {
+        assert!(assembler.is_ok());
+        match assembler {Ok(assembler) => assembler, Err(_) => MultilineAssembler::new(MultilineLimits::new(0, None))}
+    }
+    #[test]
+    const fn joining_multiline() {
+        let capability = Capability::parse(b"draft/multiline=max-bytes=12,max-lines=3");
+        let limits = MultilineLimits::from_capability(&capability);
+        assert!(limits.is_some());
+        if let Some(limits) = limits {
+            assert!(limits.max_bytes() == 12 && matches!(limits.max_lines(), Some(3)));
+            assert!(MultilineLimits::from_capability(&Capability::parse(b"draft/multiline=max-lines=3")).is_none());
+            assert!(MultilineLimits::from_capability(&Capability::parse(b"batch")).is_none());
+            let msgs = (
+                IrcMsg::parse(b"@batch=1 :n!u@h PRIVMSG #c :hello"),
+                IrcMsg::parse(b"@batch=1;draft/multiline-concat :n!u@h PRIVMSG #c :world"),
+                IrcMsg::parse(b"@batch=1 :n!u@h PRIVMSG #c :"),
+                IrcMsg::parse(b"@batch=1 :n!u@h JOIN #c"),
+            );
+            assert!(matches!(msgs, (Ok(_), Ok(_), Ok(_), Ok(_))));
+            if let (Ok(first), Ok(concat), Ok(blank), Ok(join)) = msgs {
+                let assembler = MultilineAssembler::<32>::new(limits);
+                assert!(assembler.is_empty());
+                assert!(matches!(assembler.push(&concat), Err(MultilineError::InvalidConcat)));
+                let assembler = pushed(assembler.push(&first));
+                let assembler = pushed(assembler.push(&concat));
+                let assembler = pushed(assembler.push(&blank));
+                assert!(is_identical(assembler.as_bytes(), b"helloworld\n"));
+                assert!(assembler.line_count() == 2);
+                assert!(matches!(assembler.push(&first), Err(MultilineError::MaxBytesExceeded)));
+                let assembler = pushed(assembler.push(&blank));
+                assert!(assembler.len() == 12);
+                assert!(matches!(assembler.push(&blank), Err(MultilineError::MaxLinesExceeded)));
+                assert!(matches!(assembler.push(&join), Err(MultilineError::NotMessage)));
+                let small = MultilineAssembler::<4>::new(MultilineLimits::new(64, None));
+                assert!(matches!(small.push(&first), Err(MultilineError::BufferFull)));
+            }
+        }
+    }
+}
@@ -0,0 +1,571 @@
+//! Methods for reading `CAP` messages used in capability negotiation.
+//!
+//! ## Purpose
+//!
+//! [Capability negotiation] is the first step of every modern IRC connection.
+//! An IRC client sends `CAP LS` to discover the capabilities of an IRC server, requests the desired ones with
+//! `CAP REQ` and ends negotiation with `CAP END`.
+//! The IRC server replies with `CAP ACK` or `CAP NAK` and may later advertise changes with `CAP NEW` and `CAP DEL`.
+//! Messages sent by an IRC server contain the nickname of the client (or `*` before registration) before the
+//! subcommand. A [`CapMsg`] allows reading either direction and a [`CapList`] reads each [`Capability`].
+//!
+//! [Capability negotiation]: <https://ircv3.net/specs/extensions/capability-negotiation.html>
+
+use crate::{ContentType, IrcMsg, command::CommandKind, is_identical, parameters::CommaList};
+
+/// The subcommand of a [`CapMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CapSubcommand {
+    /// List the capabilities supported by the IRC server.
+    Ls,
+    /// List the capabilities enabled for the IRC client.
+    List,
+    /// Request capabilities to be enabled or disabled.
+    Req,
+    /// Acknowledge a request.
+    Ack,
+    /// Reject a request.
+    Nak,
+    /// Advertise newly available capabilities.
+    New,
+    /// Advertise capabilities that are no longer available.
+    Del,
+    /// End capability negotiation.
+    End,
+}
+
+impl CapSubcommand {
+    const fn parse(input: &[u8]) -> Option<Self> {
+        match input {
+            b"LS" => Some(Self::Ls),
+            b"LIST" => Some(Self::List),
+            b"REQ" => Some(Self::Req),
+            b"ACK" => Some(Self::Ack),
+            b"NAK" => Some(Self::Nak),
+            b"NEW" => Some(Self::New),
+            b"DEL" => Some(Self::Del),
+            b"END" => Some(Self::End),
+            _ => None,
+        }
+    }
+}
+
+/// A view over a `CAP` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CapMsg<'msg> {
+    target: Option<ContentType<'msg>>,
+    subcommand: CapSubcommand,
+    continued: bool,
+    argument: Option<ContentType<'msg>>,
+}
+
+impl<'msg> CapMsg<'msg> {
+    /// Generates a [`CapMsg`] from an [`IrcMsg`].
+    ///
+    /// A `CAP` [`IrcMsg`] whose second parameter is a subcommand is treated as sent by an IRC server with the
+    /// first parameter as the target. Otherwise the first parameter must be the subcommand.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the [`IrcMsg`] is not a `CAP` command or the subcommand is missing or unknown.
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Result<Self, CapMsgError> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Cap)) {return Err(CapMsgError::NotCap);}
+        let Some(params) = msg.parameters() else {return Err(CapMsgError::MissingSubcommand);};
+        let second = match params.extract_specific(1) {
+            Some(second) => CapSubcommand::parse(second.as_bytes()),
+            None => None,
+        };
+        let (target, subcommand, start) = match second {
+            Some(subcommand) => (Some(params.extract_first()), subcommand, 2),
+            None => match CapSubcommand::parse(params.extract_first().as_bytes()) {
+                Some(subcommand) => (None, subcommand, 1),
+                None => return Err(CapMsgError::UnknownSubcommand),
+            },
+        };
+        let (continued, argument) = match params.count() - start {
+            0 => (false, None),
+            1 => (false, params.extract_specific(start)),
+            _ => match params.extract_specific(start) {
+                Some(continued) => (is_identical(continued.as_bytes(), b"*"), Some(params.extract_last())),
+                None => (false, None),
+            },
+        };
+        Ok(Self{target, subcommand, continued, argument})
+    }
+    /// Returns the target nickname (or `*` before registration) of a [`CapMsg`] sent by an IRC server.
+    ///
+    /// Returns `None` for a [`CapMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn target(&self) -> Option<ContentType<'msg>> {
+        self.target
+    }
+    /// Check whether the [`CapMsg`] was sent by an IRC server.
+    #[must_use]
+    pub const fn is_from_server(&self) -> bool {
+        self.target.is_some()
+    }
+    /// Returns the [`CapSubcommand`].
+    #[must_use]
+    pub const fn subcommand(&self) -> CapSubcommand {
+        self.subcommand
+    }
+    /// Check whether a `CAP LS` or `CAP LIST` reply continues in a following [`CapMsg`].
+    #[must_use]
+    pub const fn is_continued(&self) -> bool {
+        self.continued
+    }
+    /// Returns the version of capability negotiation from a `CAP LS` sent by an IRC client such as `302`.
+    #[must_use]
+    pub const fn version(&self) -> Option<u16> {
+        if self.is_from_server() || !matches!(self.subcommand, CapSubcommand::Ls) {return None;}
+        let Some(argument) = self.argument else {return None;};
+        let bytes = argument.as_bytes();
+        if bytes.is_empty() || bytes.len() > 4 {return None;}
+        let (mut index, mut version) = (0, 0);
+        while index < bytes.len() {
+            if !bytes[index].is_ascii_digit() {return None;}
+            version = version * 10 + (bytes[index] - b'0') as u16;
+            index += 1;
+        }
+        Some(version)
+    }
+    /// Returns whether the [`Capability`] of this [`CapMsg`] is enabled for the IRC client afterwards.
+    ///
+    /// `CAP ACK` enables a [`Capability`] unless it starts with `-`, `CAP DEL` always disables it and `CAP LIST`
+    /// lists enabled capabilities. Returns `None` for subcommands that don't change which capabilities are enabled.
+    #[must_use]
+    pub const fn is_enabled_after(&self, capability: &Capability) -> Option<bool> {
+        match self.subcommand {
+            CapSubcommand::Ack => Some(!capability.is_disabling()),
+            CapSubcommand::Del => Some(false),
+            CapSubcommand::List if self.is_from_server() => Some(true),
+            _ => None,
+        }
+    }
+    /// Returns a [`CapList`] for reading each [`Capability`].
+    ///
+    /// The [`CapList`] is empty for `CAP END` and for `CAP LS` and `CAP LIST` sent by an IRC client.
+    #[must_use]
+    pub const fn capabilities(&self) -> CapList<'msg> {
+        match (self.argument, self.subcommand, self.is_from_server()) {
+            (None, _, _) | (_, CapSubcommand::End, _) | (_, CapSubcommand::Ls | CapSubcommand::List, false) => {
+                CapList::new(b"")
+            },
+            (Some(argument), _, _) => CapList::new(argument.as_bytes()),
+        }
+    }
+}
+
+/// The possible types of errors when generating a [`CapMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CapMsgError {
+    /// The [`IrcMsg`] is not a `CAP` command.
+    NotCap,
+    /// The [`IrcMsg`] has no parameters.
+    MissingSubcommand,
+    /// The subcommand is not a [`CapSubcommand`].
+    UnknownSubcommand,
+}
+
+/// A cursor for reading each space separated [`Capability`] of a [`CapMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CapList<'msg> {
+    remaining: &'msg [u8],
+}
+
+impl<'msg> CapList<'msg> {
+    /// Generates a [`CapList`] from a space separated list of capabilities.
+    #[must_use]
+    pub const fn new(input: &'msg [u8]) -> Self {
+        Self{remaining: input}
+    }
+    /// Returns the next [`Capability`] and the advanced [`CapList`].
+    ///
+    /// Returns `None` once all capabilities have been read. Repeated spaces are skipped.
+    #[must_use]
+    pub const fn next_capability(self) -> Option<(Capability<'msg>, Self)> {
+        let mut start = 0;
+        while start < self.remaining.len() && self.remaining[start] == b' ' {start += 1;}
+        if start == self.remaining.len() {return None;}
+        let (_, rest) = self.remaining.split_at(start);
+        let mut end = 0;
+        while end < rest.len() && rest[end] != b' ' {end += 1;}
+        let (capability, rest) = rest.split_at(end);
+        Some((Capability::parse(capability), Self{remaining: rest}))
+    }
+    /// Returns the amount of capabilities in the [`CapList`] that have not been read yet.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        let (mut amount, mut cursor) = (0, *self);
+        while let Some((_, next)) = cursor.next_capability() {
+            amount += 1;
+            cursor = next;
+        }
+        amount
+    }
+    /// Check whether all capabilities have been read.
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.next_capability().is_none()
+    }
+}
+
+/// A single capability of a [`CapList`] such as `sasl=PLAIN,EXTERNAL`.
+///
+/// Capabilities advertised by `CAP LS 302` and `CAP NEW` may have a value after an `=`.
+/// Capabilities in `CAP REQ`, `CAP ACK` and `CAP NAK` may start with `-` to disable them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Capability<'msg> {
+    disabling: bool,
+    name: ContentType<'msg>,
+    value: Option<ContentType<'msg>>,
+}
+
+impl<'msg> Capability<'msg> {
+    /// Generates a [`Capability`] from a slice of bytes splitting the name and value at the first `=`.
+    ///
+    /// A leading `-` is not part of the name.
+    #[must_use]
+    pub const fn parse(input: &'msg [u8]) -> Self {
+        let (disabling, input) = match input {
+            [b'-', rest @ ..] => (true, rest),
+            _ => (false, input),
+        };
+        let mut index = 0;
+        while index < input.len() {
+            if input[index] == b'=' {
+                let (name, value) = input.split_at(index);
+                let (_, value) = value.split_at(1);
+                return Self{disabling, name: ContentType::new(name), value: Some(ContentType::new(value))};
+            }
+            index += 1;
+        }
+        Self{disabling, name: ContentType::new(input), value: None}
+    }
+    /// Returns the name of the [`Capability`].
+    #[must_use]
+    pub const fn name(&self) -> ContentType<'msg> {
+        self.name
+    }
+    /// Returns the [`KnownCapability`] matching the name.
+    #[must_use]
+    pub const fn known(&self) -> Option<KnownCapability> {
+        KnownCapability::from_bytes(self.name.as_bytes())
+    }
+    /// Check whether the [`Capability`] starts with `-` indicating it is to be disabled.
+    #[must_use]
+    pub const fn is_disabling(&self) -> bool {
+        self.disabling
+    }
+    /// Returns the value of the [`Capability`] if present.
+    #[must_use]
+    pub const fn value(&self) -> Option<ContentType<'msg>> {
+        self.value
+    }
+    /// Returns a [`CommaList`] for reading each comma separated value such as the mechanisms of `sasl`.
+    ///
+    /// The [`CommaList`] is empty if the [`Capability`] has no value.
+    #[must_use]
+    pub const fn values(&self) -> CommaList<'msg> {
+        match self.value {
+            Some(value) => CommaList::new(value),
+            None => CommaList::new(ContentType::StringSlice("")),
+        }
+    }
+}
+
+/// Capabilities known to this library.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KnownCapability {
+    /// The `account-notify` capability.
+    AccountNotify,
+    /// The `draft/account-registration` capability.
+    AccountRegistration,
+    /// The `account-tag` capability.
+    AccountTag,
+    /// The `away-notify` capability.
+    AwayNotify,
+    /// The `batch` capability.
+    Batch,
+    /// The `cap-notify` capability.
+    CapNotify,
+    /// The `draft/chathistory` capability.
+    ChatHistory,
+    /// The `chghost` capability.
+    Chghost,
+    /// The `echo-message` capability.
+    EchoMessage,
+    /// The `extended-join` capability.
+    ExtendedJoin,
+    /// The `extended-monitor` capability.
+    ExtendedMonitor,
+    /// The `invite-notify` capability.
+    InviteNotify,
+    /// The `labeled-response` capability.
+    LabeledResponse,
+    /// The `message-tags` capability.
+    MessageTags,
+    /// The `draft/metadata-2` capability.
+    Metadata,
+    /// The `multi-prefix` capability.
+    MultiPrefix,
+    /// The `draft/multiline` capability.
+    Multiline,
+    /// The `draft/no-implicit-names` capability.
+    NoImplicitNames,
+    /// The `draft/pre-away` capability.
+    PreAway,
+    /// The `draft/read-marker` capability.
+    ReadMarker,
+    /// The `sasl` capability.
+    Sasl,
+    /// The `server-time` capability.
+    ServerTime,
+    /// The `setname` capability.
+    Setname,
+    /// The `standard-replies` capability.
+    StandardReplies,
+    /// The `sts` capability.
+    Sts,
+    /// The `userhost-in-names` capability.
+    UserhostInNames,
+}
+
+impl KnownCapability {
+    /// Returns the [`KnownCapability`] matching the `input` name.
+    #[must_use]
+    pub const fn from_bytes(input: &[u8]) -> Option<Self> {
+        let mut index = 0;
+        while index < KNOWN_CAPABILITIES.len() {
+            if is_identical(input, KNOWN_CAPABILITIES[index].0.as_bytes()) {return Some(KNOWN_CAPABILITIES[index].1);}
+            index += 1;
+        }
+        None
+    }
+    /// Returns the name of the [`KnownCapability`] as used in capability negotiation.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        let mut index = 0;
+        while index < KNOWN_CAPABILITIES.len() {
+            if KNOWN_CAPABILITIES[index].1 as u8 == *self as u8 {return KNOWN_CAPABILITIES[index].0;}
+            index += 1;
+        }
+        ""
+    }
+}
+
+const KNOWN_CAPABILITIES: [(&str, KnownCapability); 26] = [
+    ("account-notify", KnownCapability::AccountNotify),
+    ("draft/account-registration", KnownCapability::AccountRegistration),
+    ("account-tag", KnownCapability::AccountTag),
+    ("away-notify", KnownCapability::AwayNotify),
+    ("batch", KnownCapability::Batch),
+    ("cap-notify", KnownCapability::CapNotify),
+    ("draft/chathistory", KnownCapability::ChatHistory),
+    ("chghost", KnownCapability::Chghost),
+    ("echo-message", KnownCapability::EchoMessage),
+    ("extended-join", KnownCapability::ExtendedJoin),
+    ("extended-monitor", KnownCapability::ExtendedMonitor),
+    ("invite-notify", KnownCapability::InviteNotify),
+    ("labeled-response", KnownCapability::LabeledResponse),
+    ("message-tags", KnownCapability::MessageTags),
+    ("draft/metadata-2", KnownCapability::Metadata),
+    ("multi-prefix", KnownCapability::MultiPrefix),
+    ("draft/multiline", KnownCapability::Multiline),
+    ("draft/no-implicit-names", KnownCapability::NoImplicitNames),
+    ("draft/pre-away", KnownCapability::PreAway),
+    ("draft/read-marker", KnownCapability::ReadMarker),
+    ("sasl", KnownCapability::Sasl),
+    ("server-time", KnownCapability::ServerTime),
+    ("setname", KnownCapability::Setname),
+    ("standard-replies", KnownCapability::StandardReplies),
+    ("sts", KnownCapability::Sts),
+    ("userhost-in-names", KnownCapability::UserhostInNames),
+];
+
+#[cfg(test)]
+mod const_tests {
+    use super::{CapMsg, CapMsgError, CapSubcommand, Capability, KnownCapability};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_server_cap() {
+        let input = b":irc.example.com CAP * LS * :multi-prefix sasl  server-time";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let cap = CapMsg::from_msg(&msg);
+            assert!(cap.is_ok());
+            if let Ok(cap) = cap {
+                assert!(cap.is_from_server() && cap.is_continued());
+                assert!(matches!(cap.subcommand(), CapSubcommand::Ls));
+                let target = cap.target();
+                assert!(target.is_some());
+                if let Some(target) = target {assert!(is_identical(target.as_bytes(), b"*"));}
+                let caps = cap.capabilities();
+                assert!(caps.count() == 3);
+                let caps = caps.next_capability();
+                assert!(matches!(caps, Some((_, _))));
+                if let Some((first, caps)) = caps {
+                    assert!(is_identical(first.name().as_bytes(), b"multi-prefix"));
+                    let caps = caps.next_capability();
+                    assert!(matches!(caps, Some((_, _))));
+                    if let Some((_, caps)) = caps {
+                        let caps = caps.next_capability();
+                        assert!(matches!(caps, Some((_, _))));
+                        if let Some((third, caps)) = caps {
+                            assert!(is_identical(third.name().as_bytes(), b"server-time"));
+                            assert!(caps.is_finished());
+                        }
+                    }
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com CAP dan ACK :sasl");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let cap = CapMsg::from_msg(&msg);
+            assert!(cap.is_ok());
+            if let Ok(cap) = cap {
+                assert!(matches!(cap.subcommand(), CapSubcommand::Ack) && !cap.is_continued());
+                assert!(cap.capabilities().count() == 1 && cap.version().is_none());
+            }
+        }
+    }
+    #[test]
+    const fn reading_client_cap() {
+        let msg = IrcMsg::parse(b"CAP LS 302");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let cap = CapMsg::from_msg(&msg);
+            assert!(cap.is_ok());
+            if let Ok(cap) = cap {
+                assert!(!cap.is_from_server() && matches!(cap.subcommand(), CapSubcommand::Ls));
+                assert!(matches!(cap.version(), Some(302)));
+                assert!(cap.capabilities().is_finished());
+            }
+        }
+        let msg = IrcMsg::parse(b"CAP REQ :sasl message-tags");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let cap = CapMsg::from_msg(&msg);
+            assert!(cap.is_ok());
+            if let Ok(cap) = cap {
+                assert!(matches!(cap.subcommand(), CapSubcommand::Req) && cap.capabilities().count() == 2);
+            }
+        }
+        let msg = IrcMsg::parse(b"CAP END");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let cap = CapMsg::from_msg(&msg);
+            assert!(cap.is_ok());
+            if let Ok(cap) = cap {
+                assert!(matches!(cap.subcommand(), CapSubcommand::End) && cap.capabilities().is_finished());
+            }
+        }
+        let msg = IrcMsg::parse(b"CAP FOO");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(CapMsg::from_msg(&msg), Err(CapMsgError::UnknownSubcommand)));}
+        let msg = IrcMsg::parse(b"PRIVMSG #rust :CAP LS");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(CapMsg::from_msg(&msg), Err(CapMsgError::NotCap)));}
+    }
+    #[test]
+    const fn reading_capability_values() {
+        let input = b":irc.example.com CAP * LS :sasl=PLAIN,EXTERNAL,SCRAM-SHA-256 draft/multiline=max-bytes=4096 sts=";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let cap = CapMsg::from_msg(&msg);
+            assert!(cap.is_ok());
+            if let Ok(cap) = cap {
+                let caps = cap.capabilities().next_capability();
+                assert!(matches!(caps, Some((_, _))));
+                if let Some((sasl, caps)) = caps {
+                    assert!(is_identical(sasl.name().as_bytes(), b"sasl"));
+                    let mechanisms = sasl.values();
+                    assert!(mechanisms.count() == 3);
+                    let mechanisms = mechanisms.next_item();
+                    assert!(matches!(mechanisms, Some((_, _))));
+                    if let Some((_, mechanisms)) = mechanisms {
+                        let external = mechanisms.next_item();
+                        assert!(matches!(external, Some((_, _))));
+                        if let Some((external, _)) = external {assert!(is_identical(external.as_bytes(), b"EXTERNAL"));}
+                    }
+                    let caps = caps.next_capability();
+                    assert!(matches!(caps, Some((_, _))));
+                    if let Some((multiline, caps)) = caps {
+                        let value = multiline.value();
+                        assert!(value.is_some());
+                        if let Some(value) = value {
+                            assert!(is_identical(value.as_bytes(), b"max-bytes=4096"));
+                            let sts = caps.next_capability();
+                            assert!(matches!(sts, Some((_, _))));
+                            if let Some((sts, _)) = sts {
+                                assert!(matches!(sts.value(), Some(value) if value.as_bytes().is_empty()));
+                                assert!(sts.values().is_finished());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let capability = Capability::parse(b"server-time");
+        assert!(capability.value().is_none() && capability.values().count() == 0);
+    }
+    #[test]
+    const fn reading_disabled_capabilities() {
+        let msg = IrcMsg::parse(b":irc.example.com CAP dan ACK :-away-notify server-time");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let cap = CapMsg::from_msg(&msg);
+            assert!(cap.is_ok());
+            if let Ok(cap) = cap {
+                let caps = cap.capabilities().next_capability();
+                assert!(matches!(caps, Some((_, _))));
+                if let Some((away, caps)) = caps {
+                    assert!(away.is_disabling() && is_identical(away.name().as_bytes(), b"away-notify"));
+                    assert!(matches!(cap.is_enabled_after(&away), Some(false)));
+                    let time = caps.next_capability();
+                    assert!(matches!(time, Some((_, _))));
+                    if let Some((time, _)) = time {
+                        assert!(!time.is_disabling() && matches!(cap.is_enabled_after(&time), Some(true)));
+                    }
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com CAP dan DEL :sasl");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let cap = CapMsg::from_msg(&msg);
+            assert!(cap.is_ok());
+            if let Ok(cap) = cap {
+                let sasl = cap.capabilities().next_capability();
+                assert!(matches!(sasl, Some((_, _))));
+                if let Some((sasl, _)) = sasl {
+                    assert!(!sasl.is_disabling() && matches!(cap.is_enabled_after(&sasl), Some(false)));
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com CAP dan NAK :-sasl");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let cap = CapMsg::from_msg(&msg);
+            assert!(cap.is_ok());
+            if let Ok(cap) = cap {
+                let sasl = cap.capabilities().next_capability();
+                assert!(matches!(sasl, Some((_, _))));
+                if let Some((sasl, _)) = sasl {assert!(sasl.is_disabling() && cap.is_enabled_after(&sasl).is_none());}
+            }
+        }
+    }
+    #[test]
+    const fn known_capabilities() {
+        assert!(matches!(KnownCapability::from_bytes(b"sasl"), Some(KnownCapability::Sasl)));
+        assert!(matches!(KnownCapability::from_bytes(b"draft/multiline"), Some(KnownCapability::Multiline)));
+        assert!(KnownCapability::from_bytes(b"SASL").is_none());
+        assert!(KnownCapability::from_bytes(b"example.org/unknown").is_none());
+        assert!(is_identical(KnownCapability::ServerTime.as_str().as_bytes(), b"server-time"));
+        assert!(is_identical(KnownCapability::UserhostInNames.as_str().as_bytes(), b"userhost-in-names"));
+        assert!(matches!(Capability::parse(b"-echo-message").known(), Some(KnownCapability::EchoMessage)));
+    }
+}
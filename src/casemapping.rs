@@ -1,4 +1,4 @@
-//! Method for checking equivalency of slices using [`IrcCaseMapping`].
+//! Methods for checking equivalency and mask matching of slices using [`IrcCaseMapping`].
 //!
 //! ## Purpose
 //!
@@ -6,6 +6,12 @@
 //! A `CASEMAPPING` [`ISupportToken`](crate::isupport::ISupportToken) will specify which approach the server uses.
 //! The casemapping is performed on client names, server names and channel names.
 //! Enforcing casemapping can prevent confusion.
+//! Ban, exception and invite exception masks are also matched against user prefixes using the casemapping.
+
+use crate::isupport::{ISupportToken, KnownISupport};
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
 
 /// The possible casemapping approaches.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -21,44 +27,316 @@ pub enum IrcCaseMapping {
     Rfc1459,
     /// Same as rfc1459 but excludes `^` and `~`.
     Rfc1459Strict,
+    /// An approximation of the PRECIS `UsernameCaseMapped` profile as per [RFC 8265] (formerly [RFC 7613]) for
+    /// unicode nicknames.
+    ///
+    /// Fullwidth ascii characters are mapped to ascii and letters of the Latin-1 Supplement, Latin Extended-A,
+    /// Greek and Cyrillic blocks are mapped to lowercase. Other scripts are not lowercased and unicode
+    /// normalization is not performed so precomposed and decomposed characters are not equivalent. Slices are
+    /// compared by utf8 character with invalid bytes only equivalent to themselves.
+    ///
+    /// Names an IRC server considers equivalent may therefore compare unequal. As such this is never generated
+    /// from a `CASEMAPPING` value and callers accepting the approximation must opt into it when
+    /// [`IrcCaseMapping::from_value`] returns [`IrcCaseMappingError::Approximated`].
+    ///
+    /// [RFC 8265]: <https://datatracker.ietf.org/doc/html/rfc8265#section-3.3>
+    /// [RFC 7613]: <https://datatracker.ietf.org/doc/html/rfc7613>
+    PrecisApproximation,
 }
 
 impl IrcCaseMapping {
+    /// Generates an [`IrcCaseMapping`] from the value of a `CASEMAPPING` [`ISupportToken`].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the value is not `ascii`, `rfc1459` or `rfc1459-strict`. The `rfc8265` and `rfc7613`
+    /// values return [`IrcCaseMappingError::Approximated`].
+    pub const fn from_value(input: &[u8]) -> Result<Self, IrcCaseMappingError> {
+        match input {
+            b"ascii" => Ok(Self::Ascii),
+            b"rfc1459" => Ok(Self::Rfc1459),
+            b"rfc1459-strict" => Ok(Self::Rfc1459Strict),
+            b"rfc8265" | b"rfc7613" => Err(IrcCaseMappingError::Approximated),
+            _ => Err(IrcCaseMappingError::UnknownValue),
+        }
+    }
+    /// Generates an [`IrcCaseMapping`] from a `CASEMAPPING` [`ISupportToken`].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the [`ISupportToken`] is not a set `CASEMAPPING` with a value of `ascii`, `rfc1459` or
+    /// `rfc1459-strict`.
+    pub const fn from_token(token: &ISupportToken) -> Result<Self, IrcCaseMappingError> {
+        if !matches!(token.known(), Some(KnownISupport::CaseMapping)) || !token.is_set() {
+            return Err(IrcCaseMappingError::NotCaseMapping);
+        }
+        match token.value() {
+            Some(value) => Self::from_value(value.as_bytes()),
+            None => Err(IrcCaseMappingError::MissingValue),
+        }
+    }
     /// Check if both slices are equivalent according to the casemapping aproach.
     #[must_use]
     pub const fn is_equivalent(&self, first: &[u8], second: &[u8]) -> bool {
-        if first.len() != second.len() {return false;}
+        match self.match_at(first, 0, second) {
+            Some(end) => end == first.len(),
+            None => false,
+        }
+    }
+    /// Check if the `input` starts with the `prefix` according to the casemapping approach.
+    #[must_use]
+    pub const fn starts_with(&self, input: &[u8], prefix: &[u8]) -> bool {
+        self.match_at(input, 0, prefix).is_some()
+    }
+    /// Check if the `input` ends with the `suffix` according to the casemapping approach.
+    #[must_use]
+    pub const fn ends_with(&self, input: &[u8], suffix: &[u8]) -> bool {
+        let mut start = 0;
+        while start <= input.len() {
+            if let Some(end) = self.match_at(input, start, suffix) {
+                if end == input.len() {return true;}
+            }
+            start += 1;
+        }
+        false
+    }
+    /// Check if the `input` contains the `needle` according to the casemapping approach.
+    ///
+    /// An empty `needle` is always contained.
+    #[must_use]
+    pub const fn contains(&self, input: &[u8], needle: &[u8]) -> bool {
+        let mut start = 0;
+        while start <= input.len() {
+            if self.match_at(input, start, needle).is_some() {return true;}
+            start += 1;
+        }
+        false
+    }
+    // Returns the index in `input` after the end of the match.
+    const fn match_at(self, input: &[u8], start: usize, other: &[u8]) -> Option<usize> {
+        let (mut input_index, mut other_index) = (start, 0);
+        while other_index < other.len() {
+            if input_index >= input.len() {return None;}
+            let (input_unit, input_next) = self.next_unit(input, input_index);
+            let (other_unit, other_next) = self.next_unit(other, other_index);
+            if input_unit != other_unit {return None;}
+            input_index = input_next;
+            other_index = other_next;
+        }
+        Some(input_index)
+    }
+    /// Returns the 64-bit FNV-1a hash of the canonical lowercase form of the `input`.
+    ///
+    /// Equivalent slices according to the casemapping approach always produce the same hash.
+    #[must_use]
+    pub const fn hash(&self, input: &[u8]) -> u64 {
+        let mut hash = FNV_OFFSET_BASIS;
         let mut index = 0;
-        while index < first.len() {
-            if first[index].is_ascii_alphabetic() && second[index].is_ascii_alphabetic() {
-                if first[index].eq_ignore_ascii_case(&second[index]) {return false;}
-            } else if first[index] != second[index] {
-                match self {
-                    Self::Ascii => return false,
-                    Self::Rfc1459 => if !IrcCaseMapping::rfc1459_is_equivalent(first[index], second[index], false) {
-                        return false;
-                    },
-                    Self::Rfc1459Strict => if !IrcCaseMapping::rfc1459_is_equivalent(first[index], second[index], true) {
-                        return false;
-                    },
-                }
+        while index < input.len() {
+            let (unit, next) = self.next_unit(input, index);
+            let (bytes, len) = encode_unit(unit);
+            let mut byte = 0;
+            while byte < len {
+                hash ^= bytes[byte] as u64;
+                hash = hash.wrapping_mul(FNV_PRIME);
+                byte += 1;
+            }
+            index = next;
+        }
+        hash
+    }
+    /// Writes the canonical lowercase form of the `input` according to the casemapping approach into a
+    /// [`CaseMapped`] buffer of `N` bytes.
+    ///
+    /// Equivalent slices always produce identical [`CaseMapped`] output making it suitable as a key for storing
+    /// nicknames and channel names.
+    /// Returns `None` if the lowercase form is longer than `N` bytes.
+    #[must_use]
+    pub const fn lowercase_into<const N: usize>(&self, input: &[u8]) -> Option<CaseMapped<N>> {
+        let mut buffer = [0; N];
+        let (mut index, mut len) = (0, 0);
+        while index < input.len() {
+            let (unit, next) = self.next_unit(input, index);
+            let (bytes, unit_len) = encode_unit(unit);
+            if len + unit_len > N {return None;}
+            let mut byte = 0;
+            while byte < unit_len {
+                buffer[len] = bytes[byte];
+                len += 1;
+                byte += 1;
             }
-            index += 1;
+            index = next;
+        }
+        Some(CaseMapped{buffer, len})
+    }
+    // Returns the lowercase unit starting at `index` and the index of the following unit.
+    // Units are bytes for the ascii based approaches and characters for `PrecisApproximation`.
+    // Bytes that aren't a valid ascii byte or utf8 character are offset past the last unicode code point.
+    const fn next_unit(self, input: &[u8], index: usize) -> (u32, usize) {
+        let byte = input[index];
+        match self {
+            Self::Ascii | Self::Rfc1459 | Self::Rfc1459Strict if byte.is_ascii() => {
+                (self.lowercase_byte(byte) as u32, index + 1)
+            },
+            Self::Ascii | Self::Rfc1459 | Self::Rfc1459Strict => (INVALID_UNIT_OFFSET + byte as u32, index + 1),
+            Self::PrecisApproximation => match decode_utf8(input, index) {
+                Some((character, next)) => (precis_lowercase(character), next),
+                None => (INVALID_UNIT_OFFSET + byte as u32, index + 1),
+            },
         }
-        true
     }
-    const fn rfc1459_is_equivalent(first: u8, second: u8, strict: bool) -> bool {
-        match (first, second) {
-            (b'{', b'[') | (b'[', b'{') | (b'}', b']') | (b']', b'}') | (b'|', b'\\') | (b'\\', b'|') => true,
-            (b'^', b'~') | (b'~', b'^') if !strict => true,
-            _ => false,
+    const fn lowercase_byte(self, input: u8) -> u8 {
+        match (self, input) {
+            (_, b'A'..=b'Z') => input.to_ascii_lowercase(),
+            (Self::Rfc1459 | Self::Rfc1459Strict, b'[') => b'{',
+            (Self::Rfc1459 | Self::Rfc1459Strict, b']') => b'}',
+            (Self::Rfc1459 | Self::Rfc1459Strict, b'\\') => b'|',
+            (Self::Rfc1459, b'~') => b'^',
+            _ => input,
         }
     }
 }
 
+const INVALID_UNIT_OFFSET: u32 = 0x11_0000;
+
+const fn decode_utf8(input: &[u8], index: usize) -> Option<(u32, usize)> {
+    let first = input[index];
+    let (len, initial) = match first {
+        0x00..=0x7F => return Some((first as u32, index + 1)),
+        0xC2..=0xDF => (2, (first & 0x1F) as u32),
+        0xE0..=0xEF => (3, (first & 0x0F) as u32),
+        0xF0..=0xF4 => (4, (first & 0x07) as u32),
+        _ => return None,
+    };
+    if index + len > input.len() {return None;}
+    let (mut character, mut offset) = (initial, 1);
+    while offset < len {
+        let byte = input[index + offset];
+        if byte & 0xC0 != 0x80 {return None;}
+        character = (character << 6) | (byte & 0x3F) as u32;
+        offset += 1;
+    }
+    match (len, character) {
+        (3, 0..=0x7FF | 0xD800..=0xDFFF) | (4, 0..=0xFFFF | 0x11_0000..) => None,
+        _ => Some((character, index + len)),
+    }
+}
+
+#[allow(clippy::cast_possible_truncation)]
+const fn encode_unit(unit: u32) -> ([u8; 4], usize) {
+    match unit {
+        0..=0x7F => ([unit as u8, 0, 0, 0], 1),
+        0x80..=0x7FF => ([0xC0 | (unit >> 6) as u8, 0x80 | (unit & 0x3F) as u8, 0, 0], 2),
+        0x800..=0xFFFF => {
+            ([0xE0 | (unit >> 12) as u8, 0x80 | ((unit >> 6) & 0x3F) as u8, 0x80 | (unit & 0x3F) as u8, 0], 3)
+        },
+        0x1_0000..=0x10_FFFF => ([0xF0 | (unit >> 18) as u8, 0x80 | ((unit >> 12) & 0x3F) as u8,
+            0x80 | ((unit >> 6) & 0x3F) as u8, 0x80 | (unit & 0x3F) as u8], 4),
+        _ => ([(unit - INVALID_UNIT_OFFSET) as u8, 0, 0, 0], 1),
+    }
+}
+
+// Width mapping of fullwidth ascii followed by lowercase mapping of Latin-1 Supplement, Latin Extended-A,
+// Greek and Cyrillic.
+const fn precis_lowercase(input: u32) -> u32 {
+    let input = if matches!(input, 0xFF01..=0xFF5E) {input - 0xFEE0} else {input};
+    match input {
+        0x41..=0x5A | 0xC0..=0xD6 | 0xD8..=0xDE | 0x391..=0x3A1 | 0x3A3..=0x3AB | 0x410..=0x42F => input + 0x20,
+        0x100..=0x12F | 0x132..=0x137 | 0x14A..=0x177 | 0x460..=0x481 | 0x48A..=0x4BF if input % 2 == 0 => input + 1,
+        0x139..=0x148 | 0x179..=0x17E if input % 2 == 1 => input + 1,
+        0x178 => 0xFF,
+        0x386 => 0x3AC,
+        0x388..=0x38A => input + 0x25,
+        0x38C => 0x3CC,
+        0x38E | 0x38F => input + 0x3F,
+        0x400..=0x40F => input + 0x50,
+        _ => input,
+    }
+}
+
+/// A buffer of `N` bytes holding the canonical lowercase form of a slice according to an [`IrcCaseMapping`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CaseMapped<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> CaseMapped<N> {
+    /// Returns the lowercase form as a slice of bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        let (output, _) = self.buffer.split_at(self.len);
+        output
+    }
+    /// Returns the amount of bytes written.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Check if no bytes were written.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// The possible types of errors when generating an [`IrcCaseMapping`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IrcCaseMappingError {
+    /// The [`ISupportToken`] is not a set `CASEMAPPING`.
+    NotCaseMapping,
+    /// The `CASEMAPPING` [`ISupportToken`] has no value.
+    MissingValue,
+    /// The value is not a supported casemapping approach.
+    UnknownValue,
+    /// The value is `rfc8265` or `rfc7613` which is only approximated by [`IrcCaseMapping::PrecisApproximation`].
+    Approximated,
+}
+
+/// Check if the `target` matches the `mask` according to the casemapping approach.
+///
+/// The `mask` may contain the wildcards `*` (matching zero or more bytes) and `?` (matching exactly one byte or
+/// one character for [`IrcCaseMapping::PrecisApproximation`]).
+/// This is intended for evaluating ban, exception and invite exception masks such as `*!*@*.example.com`
+/// against the `nick!user@host` of a [`Source`](crate::source::Source).
+#[must_use]
+pub const fn mask_matches(mask: &[u8], target: &[u8], casemapping: IrcCaseMapping) -> bool {
+    let (mut mask_index, mut target_index) = (0, 0);
+    let mut last_star: Option<(usize, usize)> = None;
+    while target_index < target.len() {
+        if mask_index < mask.len() && mask[mask_index] == b'*' {
+            last_star = Some((mask_index, target_index));
+            mask_index += 1;
+            continue;
+        }
+        let (target_unit, target_next) = casemapping.next_unit(target, target_index);
+        let mask_next = if mask_index >= mask.len() {None}
+            else if mask[mask_index] == b'?' {Some(mask_index + 1)}
+            else {
+                let (mask_unit, mask_next) = casemapping.next_unit(mask, mask_index);
+                if mask_unit == target_unit {Some(mask_next)} else {None}
+            };
+        if let Some(mask_next) = mask_next {
+            mask_index = mask_next;
+            target_index = target_next;
+        } else if let Some((star_index, star_target_index)) = last_star {
+            let (_, star_target_next) = casemapping.next_unit(target, star_target_index);
+            last_star = Some((star_index, star_target_next));
+            mask_index = star_index + 1;
+            target_index = star_target_next;
+        } else {
+            return false;
+        }
+    }
+    while mask_index < mask.len() && mask[mask_index] == b'*' {mask_index += 1;}
+    mask_index == mask.len()
+}
+
 #[cfg(test)]
 mod const_tests {
-    use crate::casemapping::IrcCaseMapping;
+    use crate::casemapping::{IrcCaseMapping, IrcCaseMappingError, mask_matches};
+    use crate::is_identical;
+    use crate::isupport::ISupportToken;
     #[test]
     const fn is_equal_ascii() {
         let first = b"bob";
@@ -108,4 +386,120 @@ mod const_tests {
         let casemapping = IrcCaseMapping::Rfc1459Strict;
         assert!(!casemapping.is_equivalent(first, second));
     }
+    #[test]
+    const fn matching_masks() {
+        let casemapping = IrcCaseMapping::Rfc1459;
+        assert!(mask_matches(b"*!*@*.example.com", b"dan!d@host.example.com", casemapping));
+        assert!(mask_matches(b"DAN!*@*", b"dan!d@localhost", casemapping));
+        assert!(mask_matches(b"[dan]!?@*", b"{DAN}!d@localhost", casemapping));
+        assert!(mask_matches(b"*", b"", casemapping));
+        assert!(mask_matches(b"d*n*!*", b"dannnnn!d@localhost", casemapping));
+        assert!(!mask_matches(b"[dan]!?@*", b"{DAN}!d@localhost", IrcCaseMapping::Ascii));
+        assert!(!mask_matches(b"*!*@*.example.com", b"dan!d@localhost", casemapping));
+        assert!(!mask_matches(b"dan!??@*", b"dan!d@localhost", casemapping));
+        assert!(!mask_matches(b"", b"dan", casemapping));
+    }
+    #[test]
+    const fn from_isupport() {
+        assert!(matches!(IrcCaseMapping::from_value(b"ascii"), Ok(IrcCaseMapping::Ascii)));
+        assert!(matches!(IrcCaseMapping::from_value(b"rfc1459"), Ok(IrcCaseMapping::Rfc1459)));
+        assert!(matches!(IrcCaseMapping::from_value(b"rfc1459-strict"), Ok(IrcCaseMapping::Rfc1459Strict)));
+        assert!(matches!(IrcCaseMapping::from_value(b"RFC1459"), Err(IrcCaseMappingError::UnknownValue)));
+        let token = ISupportToken::parse(b"CASEMAPPING=rfc1459-strict");
+        assert!(token.is_ok());
+        if let Ok(token) = token {
+            assert!(matches!(IrcCaseMapping::from_token(&token), Ok(IrcCaseMapping::Rfc1459Strict)));
+        }
+        let token = ISupportToken::parse(b"CASEMAPPING=rfc8265");
+        assert!(token.is_ok());
+        if let Ok(token) = token {
+            assert!(matches!(IrcCaseMapping::from_token(&token), Err(IrcCaseMappingError::Approximated)));
+        }
+        let token = ISupportToken::parse(b"CASEMAPPING=unicode");
+        assert!(token.is_ok());
+        if let Ok(token) = token {
+            assert!(matches!(IrcCaseMapping::from_token(&token), Err(IrcCaseMappingError::UnknownValue)));
+        }
+        let token = ISupportToken::parse(b"CASEMAPPING");
+        assert!(token.is_ok());
+        if let Ok(token) = token {
+            assert!(matches!(IrcCaseMapping::from_token(&token), Err(IrcCaseMappingError::MissingValue)));
+        }
+        let token = ISupportToken::parse(b"-CASEMAPPING");
+        assert!(token.is_ok());
+        if let Ok(token) = token {
+            assert!(matches!(IrcCaseMapping::from_token(&token), Err(IrcCaseMappingError::NotCaseMapping)));
+        }
+        let token = ISupportToken::parse(b"NICKLEN=30");
+        assert!(token.is_ok());
+        if let Ok(token) = token {
+            assert!(matches!(IrcCaseMapping::from_token(&token), Err(IrcCaseMappingError::NotCaseMapping)));
+        }
+    }
+    #[test]
+    const fn lowercasing() {
+        let ascii = IrcCaseMapping::Ascii.lowercase_into::<16>(b"[Dan]~");
+        assert!(ascii.is_some());
+        if let Some(ascii) = ascii {assert!(is_identical(ascii.as_bytes(), b"[dan]~"));}
+        let rfc1459 = IrcCaseMapping::Rfc1459.lowercase_into::<16>(b"[Dan]~\\");
+        assert!(rfc1459.is_some());
+        if let Some(rfc1459) = rfc1459 {assert!(is_identical(rfc1459.as_bytes(), b"{dan}^|"));}
+        let strict = IrcCaseMapping::Rfc1459Strict.lowercase_into::<16>(b"[Dan]~");
+        assert!(strict.is_some());
+        if let Some(strict) = strict {assert!(is_identical(strict.as_bytes(), b"{dan}~"));}
+        let first = IrcCaseMapping::Rfc1459.lowercase_into::<16>(b"{DAN}");
+        assert!(first.is_some());
+        if let Some(first) = first {
+            let second = IrcCaseMapping::Rfc1459.lowercase_into::<16>(b"[dan]");
+            assert!(second.is_some());
+            if let Some(second) = second {assert!(is_identical(first.as_bytes(), second.as_bytes()));}
+        }
+        assert!(IrcCaseMapping::Ascii.lowercase_into::<2>(b"dan").is_none());
+        let empty = IrcCaseMapping::Ascii.lowercase_into::<0>(b"");
+        assert!(empty.is_some());
+        if let Some(empty) = empty {assert!(empty.is_empty());}
+    }
+    #[test]
+    const fn partial_matching() {
+        let casemapping = IrcCaseMapping::Rfc1459;
+        assert!(casemapping.starts_with(b"[Dan]|away", b"{dan"));
+        assert!(!casemapping.starts_with(b"dan", b"daniel"));
+        assert!(casemapping.ends_with(b"[Dan]|away", b"\\AWAY"));
+        assert!(!IrcCaseMapping::Ascii.ends_with(b"[Dan]|away", b"\\AWAY"));
+        assert!(casemapping.contains(b"#Rust-Lang", b"rust"));
+        assert!(casemapping.contains(b"#Rust-Lang", b""));
+        assert!(!casemapping.contains(b"#Rust-Lang", b"rusty"));
+        assert!(!casemapping.contains(b"#rs", b"#rust"));
+    }
+    #[test]
+    const fn hashing() {
+        let casemapping = IrcCaseMapping::Rfc1459;
+        assert!(IrcCaseMapping::Ascii.hash(b"") == 0xcbf2_9ce4_8422_2325);
+        assert!(IrcCaseMapping::Ascii.hash(b"a") == 0xaf63_dc4c_8601_ec8c);
+        assert!(IrcCaseMapping::Ascii.hash(b"A") == IrcCaseMapping::Ascii.hash(b"a"));
+        assert!(casemapping.hash(b"[Dan]~") == casemapping.hash(b"{dAN}^"));
+        assert!(IrcCaseMapping::Rfc1459Strict.hash(b"dan~") != IrcCaseMapping::Rfc1459Strict.hash(b"dan^"));
+        assert!(casemapping.hash(b"dan") != casemapping.hash(b"bob"));
+    }
+    #[test]
+    const fn precis() {
+        let casemapping = IrcCaseMapping::PrecisApproximation;
+        assert!(matches!(IrcCaseMapping::from_value(b"rfc8265"), Err(IrcCaseMappingError::Approximated)));
+        assert!(matches!(IrcCaseMapping::from_value(b"rfc7613"), Err(IrcCaseMappingError::Approximated)));
+        assert!(casemapping.is_equivalent("Ünïcödé".as_bytes(), "üNÏCÖDÉ".as_bytes()));
+        assert!(casemapping.is_equivalent("ＤＡＮ".as_bytes(), b"dan"));
+        assert!(casemapping.is_equivalent("Αθήνα".as_bytes(), "αΘΉΝΑ".as_bytes()));
+        assert!(casemapping.is_equivalent("Дмитрий".as_bytes(), "дМИТРИЙ".as_bytes()));
+        assert!(!casemapping.is_equivalent(b"[dan]", b"{dan}"));
+        assert!(!casemapping.is_equivalent(b"dan\xC3", b"dan\xE3"));
+        assert!(casemapping.starts_with("Ŝtefan".as_bytes(), "ŝt".as_bytes()));
+        assert!(casemapping.ends_with("Ŝtefan".as_bytes(), "ＡＮ".as_bytes()));
+        assert!(casemapping.contains("#Ĉambro".as_bytes(), "ĉAM".as_bytes()));
+        assert!(casemapping.hash("ÉLODIE".as_bytes()) == casemapping.hash("élodie".as_bytes()));
+        let lowercase = casemapping.lowercase_into::<16>("ＤÁŃ".as_bytes());
+        assert!(lowercase.is_some());
+        if let Some(lowercase) = lowercase {assert!(is_identical(lowercase.as_bytes(), "dáń".as_bytes()));}
+        assert!(mask_matches("?ÓŹ!*@*".as_bytes(), "bóź!b@localhost".as_bytes(), casemapping));
+        assert!(!mask_matches(b"?!*@*", "bóź!b@localhost".as_bytes(), casemapping));
+    }
 }
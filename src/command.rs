@@ -18,6 +18,8 @@
 //! [IRC Message Protocol]: <https://modern.ircdocs.horse/#command>
 //! [capability negotiation]: <https://ircv3.net/specs/extensions/capability-negotiation.html>
 
+use core::cmp::Ordering;
+
 /// The command of an [`IrcMsg`](crate::IrcMsg).
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum Command<'msg> {
@@ -36,8 +38,40 @@ impl<'msg> Command<'msg> {
     /// is an unsupported `Named`/`Numeric` command or is provided too few parameters.
     /// Please file a bug report if you want to add support for a missing `Named`/`Numeric`
     /// command or the parameters required are too low.
-    #[allow(clippy::too_many_lines)]
     pub const fn parse(input: &'msg [u8], params_amount: usize) -> Result<Self, CommandError<'msg>> {
+        Self::parse_with_unhandled_numerics(input, params_amount, UnhandledNumerics::Reject)
+    }
+    /// Generates a [`Command`] from a slice of bytes and number of [`Parameters`](crate::Parameters)
+    /// using the specified [`UnhandledNumerics`].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the input is empty, contains anything but ascii alphanumeric characters,
+    /// is an unsupported `Named` command, is an unsupported `Numeric` command when using
+    /// [`UnhandledNumerics::Reject`] or is provided too few parameters.
+    pub const fn parse_with_unhandled_numerics(input: &'msg [u8], params_amount: usize, unhandled: UnhandledNumerics)
+    -> Result<Self, CommandError<'msg>> {
+        match Self::parse_token(input, unhandled) {
+            Ok(cmd) => match cmd.validate_param_count(params_amount) {
+                Ok(()) => Ok(cmd),
+                Err(e) => Err(e),
+            },
+            Err(e) => Err(e),
+        }
+    }
+    /// Generates a [`Command`] from a slice of bytes without checking the number of
+    /// [`Parameters`](crate::Parameters).
+    ///
+    /// Use [`Command::validate_param_count`] to check the number of [`Parameters`](crate::Parameters) separately.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the input is empty, contains anything but ascii alphanumeric characters
+    /// or is an unsupported `Named`/`Numeric` command.
+    pub const fn parse_name(input: &'msg [u8]) -> Result<Self, CommandError<'msg>> {
+        Self::parse_token(input, UnhandledNumerics::Reject)
+    }
+    const fn parse_token(input: &'msg [u8], unhandled: UnhandledNumerics) -> Result<Self, CommandError<'msg>> {
         if input.is_empty() {return Err(CommandError::EmptyInput);}
         let mut number_count = 0;
         let mut index = 0;
@@ -48,653 +82,163 @@ impl<'msg> Command<'msg> {
         }
         if let Ok(cmd) = core::str::from_utf8(input) {
             if cmd.len() == 3 && number_count == 3 {
-                let mut unhandled = false;
-                match input {
-                    b"006" | // RPL_MAP (unreal)
-                    b"007" | // RPL_MAPEND/RPL_ENDMAP (unreal)
-                    b"008" | // RPL_SNOMASK/RPL_SNOMASKIS (ircu)
-                    b"009" | // RPL_STATMEMTOT (ircu)
-                    b"014" | // RPL_YOURCOOKIE
-                    b"015" | // RPL_MAP (ircu)
-                    b"016" | // RPL_MAPMORE (ircu)
-                    b"017" | // RPL_MAPEND/RPL_ENDMAP (ircu)
-                    b"030" | // RPL_APASSWARN_SET (ircu)
-                    b"031" | // RPL_APASSWARN_SECRET (ircu)
-                    b"032" | // RPL_APASSWARN_CLEAR (ircu)
-                    b"042" | // RPL_YOURID/RPL_YOURUUID (IRCnet/inspircd)
-                    b"050" | // RPL_ATTEMPTINGJUNC (aircd)
-                    b"051" | // RPL_ATTEMPTINGREROUTE (aircd)
-                    b"210" | // RPL_TRACERECONNECT (RFC2812) (conflicts: RPL_STATS (aircd) RPL_STATSHELP (unreal))
-                    b"217" | // RPL_STATSQLINE (RFC1459) (conflict: RPL_STATSPLINE (ircu))
-                    b"220" | // RPL_STATSPLINE (hybrid) (conflicts: RPL_STATSBLINE (bahamut, unreal) RPL_STATSWLINE (nefarious))
-                    b"222" | // RPL_MODLIST (conflicts: RPL_SQLINE_NICK (unreal) RPL_STATSBLINE (bahamut) RPL_STATSJLINE (ircu) RPL_CODEPAGE (rusnet-ircd))
-                    b"223" | // RPL_STATSELINE (bahamut) (conflicts: RPL_STATSGLINE (unreal) RPL_CHARSET (rusnet-ircd))
-                    b"224" | // RPL_STATSFLINE (hybrid, bahamut) (conflict: RPL_STATSTLINE (unreal))
-                    b"225" | // RPL_STATSDLINE (hybrid) (conflicts: RPL_STATSCLONE (bahamut) RPL_STATSELINE (unreal) (depreciated: RPL_STATSZLINE (bahamut)))
-                    b"226" | // RPL_STATSCOUNT (bahamut) (conflicts: RPL_STATSALINE (hybrid) RPL_STATSNLINE (unreal))
-                    b"227" | // RPL_STATSGLINE (bahamut) (conflicts: RPL_STATSVLINE (unreal) RPL_STATSBLINE (rizon))
-                    b"228" | // RPL_STATSQLINE (ircu) (conflicts: RPL_STATSBANVER (unreal) RPL_STATSCOUNT (oftc-hybrid))
-                    b"229" | // RPL_STATSSPAMF (unreal)
-                    b"230" | // RPL_STATSEXCEPTTKL (unreal)
-                    b"231" | // RPL_SERVICEINFO (RFC1459) depreciated
-                    b"232" | // RPL_ENDOFSERVICES (RFC1459) depreciated (conflict: RPL_RULES (unreal))
-                    b"233" | // RPL_SERVICE (RFC1459) depreciated
-                    b"236" | // RPL_STATSVERBOSE (ircu)
-                    b"237" | // RPL_STATSENGINE (ircu)
-                    b"238" | // RPL_STATSFLINE (ircu)
-                    b"239" | // RPL_STATSIAUTH (IRCnet)
-                    b"240" | // RPL_STATSVLINE (RFC2812) (conflict: RPL_STATSXLINE (austhex))
-                    b"245" | // RPL_STATSSLINE (bahamut) (conflict: RPL_STATSTLINE (hybrid?))
-                    b"246" | // RPL_STATSPING (RFC2812) (conflicts: RPL_STATSSERVICE (hybrid) RPL_STATSTLINE (ircu) RPL_STATSULINE (bahamut))
-                    b"247" | // RPL_STATSBLINE (RFC2812) (conflicts: RPL_STATSXLINE (unreal) RPL_STATSGLINE (ircu))
-                    b"248" | // RPL_STATSULINE (ircu) (conflict: RPL_STATSDEFINE (IRCnet))
-                    b"249" | // RPL_STATSDEBUG (hybrid) (conflict: RPL_STATSULINE)
-                    b"250" | // RPL_STATSDLINE (RFC2812) (conflict: RPL_STATSCONN (ircu))
-                    b"264" | // RPL_USINGSSL (rusnet-ircd)
-                    b"267" | // RPL_START_NETSTAT (aircd)
-                    b"268" | // RPL_NETSTAT (aircd)
-                    b"269" | // RPL_END_NETSTAT (aircd)
-                    b"270" | // RPL_PRIVS (ircu) (conflict & depreciated: RPL_MAPUSERS (inspircd old))
-                    b"273" | // RPL_NOTIFY (aircd)
-                    b"274" | // RPL_ENDNOTIFY (aircd) (conflict: RPL_STATSDELTA (IRCnet))
-                    b"275" | // RPL_STATSDLINE (ircu) (conflict: RPL_USINGSSL (bahamut))
-                    b"277" | // RPL_VCHANLIST (hybrid) depreciated
-                    b"278" | // RPL_VCHANHELP (hybrid) depreciated
-                    b"280" | // RPL_GLIST (ircu)
-                    b"283" | // RPL_ALIST (conflict: RPL_ENDOFJUPELIST (ircu))
-                    b"284" | // RPL_ENDOFALIST (conflict: RPL_FEATURE (ircu))
-                    b"285" | // RPL_GLIST_HASH (conflicts: RPL_CHANINFO_HANDLE (aircd) RPL_NEWHOSTIS (quakenet))
-                    b"286" | // RPL_CHANINFO_USERS (aircd) (conflict: RPL_CHKHEAD (quakenet))
-                    b"287" | // RPL_CHANINFO_CHOPS (aircd) (conflict: RPL_CHANUSER (quakenet))
-                    b"288" | // RPL_CHANINFO_VOICES (aircd) (conflict: RPL_PATCHHEAD (quakenet))
-                    b"289" | // RPL_CHANINFO_AWAY (aircd) (conflict: RPL_PATCHCON (quakenet))
-                    b"290" | // RPL_CHANINFO_OPERS (aircd) (conflicts: RPL_DATASTR (quakenet) RPL_HELPHDR (unreal))
-                    b"291" | // RPL_CHANINFO_BANNED (aircd) (conflicts: RPL_ENDOFCHECK (quakenet) RPL_HELPOP (unreal))
-                    b"292" | // RPL_CHANINFO_BANS (aircd) (conflicts: RPL_HELPTLR (unreal) ERR_SEARCHNOMATCH (nefarious))
-                    b"293" | // RPL_CHANINFO_INVITE (aircd) (conflict: RPL_HELPHLP (unreal))
-                    b"294" | // RPL_CHANINFO_INVITES (aircd) (conflict: RPL_HELPFWD (unreal))
-                    b"295" | // RPL_CHANINFO_KICK (aircd) (conflict: RPL_HELPIGN (unreal))
-                    b"296" | // RPL_CHANINFO_KICKS (aircd)
-                    b"299" | // RPL_END_CHANINFO (aircd)
-                    b"300" | // RPL_NONE (RFC1459)
-                    b"302" | // RPL_USERHOST (RFC1459)
-                    b"303" | // RPL_ISON (RFC1459)
-                    b"308" | // RPL_NOTIFYACTION (aircd) (conflicts: RPL_WHOISADMIN (bahamut) RPL_RULESSTART (unreal)/RPL_RULESTART (inspircd))
-                    b"309" | // RPL_NICKTRACE (aircd) (conflicts: RPL_WHOISSADMIN (bahamut) RPL_ENDOFRULES (unreal)/RPL_RULESEND (inspircd) RPL_WHOISHELPER (austhex) RPL_WHOISSERVICE (oftc-hybrid))
-                    b"310" | // RPL_WHOISSVCMSG (bahamut) (conflicts: RPL_WHOISHELPOP (unreal) RPL_WHOISSERVICE (austhex))
-                    b"316" | // RPL_WHOISPRIVDEAF (nefarious) (conflict & depreciated: RPL_WHOISCHANOP (RFC1459))
-                    b"326" | // RPL_NOCHANPASS
-                    b"327" | // RPL_CHPASSUNKNOWN (conflict: RPL_WHOISHOST (rusnet-ircd))
-                    b"328" | // RPL_CHANNEL_URL (bahamut)/RPL_CHANNELURL (charybdis)
-                    b"334" | // RPL_LISTUSAGE (ircu) (conflicts: RPL_COMMANDSYNTAX (bahamut) RPL_LISTSYNTAX (unreal))
-                    b"339" | // RPL_BADCHANPASS (conflict: RPL_WHOISMARKS (nefarious))
-                    b"343" | // RPL_WHOISKILL (nefarious) (conflict: RPL_WHOISOPERNAME (snircd))
-                    b"344" | // RPL_WHOISCOUNTRY (inspircd) (conflicts: RPL_REOPLIST (IRCnet) RPL_QUIETLIST (oftc-hybrid))
-                    b"345" | // RPL_INVITED (gamesurge)/RPL_ISSUEDINVITE (ircu) (conflicts: RPL_ENDOFREOPLIST (IRCnet) RPL_ENDOFQUIETLIST (oftc-hybrid))
-                    b"355" | // RPL_NAMREPLY_ (quakenet)/RPL_DELNAMREPLY (ircu)
-                    b"357" | // RPL_MAP (austhex)
-                    b"358" | // RPL_MAPMORE (austhex)
-                    b"359" | // RPL_MAPEND/RPL_ENDMAP (austhex)
-                    b"360" | // RPL_WHOWASREAL (charybdis) depreciated
-                    b"361" | // RPL_KILLDONE (RFC1459)
-                    b"362" | // RPL_CLOSING (RFC1459)
-                    b"363" | // RPL_CLOSEEND (RFC1459)
-                    b"373" | // RPL_INFOSTART (RFC1459) depreciated
-                    b"377" | // RPL_KICKEXPIRED (aircd) (conflict & deprecated: RPL_SPAM (austhex))
-                    b"380" | // RPL_BANLINKED (aircd) (conflict: RPL_YOURHELPER (austhex))
-                    b"383" | // RPL_YOURESERVICE (RFC2812)
-                    b"384" | // RPL_MYPORTIS (RFC1459) depreciated
-                    b"385" | // RPL_NOTOPERANYMORE (austhex)
-                    b"386" | // RPL_QLIST (unreal) (conflicts: RPL_IRCOPS (ultimate) RPL_IRCOPSHEADER (nefarious) depreciated: RPL_RSACHALLENGE (hybrid))
-                    b"387" | // RPL_ENDOFQLIST (unreal) (conflicts: RPL_ENDOFIRCOPS (ultimate) RPL_IRCOPS (nefarious))
-                    b"388" | // RPL_ALIST (unreal) (conflict: RPL_ENDOFIRCOPS (nefarious))
-                    b"389" | // RPL_ENDOFALIST (unreal)
-                    b"398" | // RPL_STATSSLINE (snirc)
-                    b"399" | // RPL_USINGSLINE (snirc) (conflict: RPL_CLONES (inspircd))
-                    b"419" | // ERR_LENGTHTRUNCATED (aircd)
-                    b"425" | // ERR_NOOPERMOTD (unreal)
-                    b"429" | // ERR_TOOMANYAWAY (bahamut)
-                    b"430" | // ERR_EVENTNICKCHANGE (austhex)
-                    b"434" | // ERR_SERVICENAMEINUSE (austhex) (conflicts: ERR_NORULES (unreal) ERR_NONICKWHILEBAN (oftc-hybrid))
-                    b"435" | // ERR_SERVICECONFUSED (unreal) (conflict: ERR_BANONCHAN (bahamut)/ERR_BANNICKCHANGE (ratbox) depreciated: ERR_NICKONBAN (oftc-hybrid))
-                    b"438" | // ERR_NICKTOOFAST (ircu)/ERR_NCHANGETOOFAST (unreal) (conflict: ERR_DEAD (IRCnet))
-                    b"439" | // ERR_TARGETTOOFAST (ircu)/ERR_TARGETTOFAST/RPL_INVTOOFAST/RPL_MSGTOOFAST
-                    b"440" | // ERR_SERVICESDOWN (bahamut)/ERR_REG_UNAVAILABLE (ergo)
-                    b"447" | // ERR_NONICKCHANGE (unreal)/ERR_CANTCHANGENICK (inspircd)
-                    b"449" | // ERR_NOTIMPLEMENTED (undernet)
-                    b"452" | // ERR_IDCOLLISION
-                    b"453" | // ERR_NICKLOST
-                    b"455" | // ERR_HOSTILENAME (unreal)
-                    b"459" | // ERR_NOHIDING (unreal)
-                    b"460" | // ERR_NOTFORHALFOPS (unreal)
-                    b"466" | // ERR_YOUWILLBEBANNED (RFC1459) depreciated
-                    b"468" | // ERR_INVALIDUSERNAME (ircu) (conflicts: ERR_ONLYSERVERSCANCHANGE (bahamut) ERR_NOCODEPAGE (rusnet-ircd))
-                    b"469" | // ERR_LINKSET (unreal)
-                    b"470" | // ERR_LINKCHANNEL (unreal) (conflicts: ERR_KICKEDFROMCHAN (aircd) ERR_7BIT (rusnet-ircd))
-                    b"479" | // ERR_BADCHANNAME (hybrid) (conflicts: ERR_LINKFAIL (unreal) ERR_NOCOLOR (rusnet-ircd))
-                    b"480" | // ERR_NOULINE (austhex) (conflicts: ERR_CANNOTKNOCK (unreal) ERR_THROTTLE (ratbox)/ERR_NEEDTOWAIT (bahamut) ERR_NOWALLOP (rusnet-ircd) ERR_SSLONLYCHAN (oftc-hybrid))
-                    b"486" | // ERR_NONONREG (unreal)/ERR_ACCOUNTONLY (conflicts: ERR_RLINED (rusnet-ircd) depreciated: ERR_HTMDISABLED (unreal))
-                    b"487" | // ERR_CHANTOORECENT (IRCnet) (conflicts: ERR_MSGSERVICES (bahamut) ERR_NOTFORUSERS (unreal) ERR_NONONSSL (ChatIRCd))
-                    b"488" | // ERR_TSLESSCHAN (IRCnet) (conflicts: ERR_HTMDISABLED (unreal) ERR_NOSSL (bahamut))
-                    b"489" | // ERR_SECUREONLYCHAN (unreal)/ERR_SSLONLYCHAN (conflict: ERR_VOICENEEDED (undernet))
-                    b"490" | // ERR_ALLMUSTSSL (inspIRCd) (conflicts: ERR_NOSWEAR (unreal) ERR_MAXMSGSENT (bahamut))
-                    b"492" | // ERR_NOSERVICEHOST (RFC1459) depreciated (conflicts: ERR_NOCTCP (hybrid)/ERR_NOCTCPALLOWED (inspIRCd) ERR_CANNOTSENDTOUSER (charybdis))
-                    b"493" | // ERR_NOSHAREDCHAN (bahamut) (conflict: ERR_NOFEATURE (ircu))
-                    b"494" | // ERR_BADFEATVALUE (ircu) (conflict: ERR_OWNMODE (bahamut) ERR_INVITEREMOVED (inspIRCd))
-                    b"495" | // ERR_BADLOGTYPE (ircu) (conflict & depreciated: ERR_DELAYREJOIN (inspIRCd))
-                    b"496" | // ERR_BADLOGSYS (ircu)
-                    b"497" | // ERR_BADLOGVALUE (ircu)
-                    b"498" | // ERR_ISOPERLCHAN (ircu)
-                    b"499" | // ERR_CHANOWNPRIVNEEDED (unreal)
-                    b"500" | // ERR_TOOMANYJOINS (unreal) (conflicts: ERR_NOREHASHPARAM (rusnet-ircd) ERR_CANNOTSETMODDER (inspIRCd))
-                    b"504" | // ERR_USERNOTONSERV (conflict: ERR_LAST_ERR_MSG (bahamut))
-                    b"505" | // ERR_NOTINVITED (inspIRCd) (conflict & depreciated: ERR_LAST_ERR_MSG (oftc-hybrid))
-                    b"512" | // ERR_TOOMANYWATCH (bahamut)/ERR_NOTIFYFULL (aircd) (conflicts: ERR_NOSUCHGLINE (ircu) depreciated: ERR_NEEDPONG (oftc-hybrid))
-                    b"513" | // ERR_BADPING (ircu)/ERR_WRONGPONG (charybdis)/ERR_NEEDPONG (ultimate)
-                    b"514" | // ERR_TOOMANYDCC (bahamut) (conflicts: ERR_NOSUCHJUPE (ircu) depreciated: ERR_INVALID_ERROR (ircu))
-                    b"515" | // ERR_BADEXPIRE (ircu)
-                    b"516" | // ERR_DONTCHEAT (ircu)
-                    b"518" | // ERR_NOINVITE (unreal) (conflict: ERR_LONGMASK (ircu))
-                    b"519" | // ERR_ADMONLY (unreal) (conflict: ERR_TOOMANYUSERS (ircu))
-                    b"520" | // ERR_OPERONLY (unreal)/ERR_OPERONLYCHAN (hybrid)/ERR_CANTJOINOPERSONLY (inspIRCd) (conflicts: ERR_MASKTOOWIDE (ircu) depreciated: ERR_WHOTRUNC (austhex))
-                    b"521" | // ERR_LISTSYNTAX (bahamut) (conflict: ERR_NOSUCHGLINE (nefarious))
-                    b"522" | // ERR_WHOSYNTAX (bahamut)
-                    b"524" | // ERR_QUARANTINED (ircu) (conflicts: ERR_OPERSVERIFY (unreal) ERR_HELPNOTFOUND (hybrid))
-                    b"525" | // ERR_INVALIDKEY (ircu) (conflict & depreciated: ERR_REMOTEPFX)
-                    b"530" | // ERR_BADHOSTMASK (snircd)
-                    b"550" | // ERR_BADHOSTMASK (quakenet)
-                    b"551" | // ERR_HOSTUNAVAIL (quakenet)
-                    b"552" | // ERR_USINGSLINE (quakenet)
-                    b"553" | // ERR_STATSSLINE (quakenet)
-                    b"560" | // ERR_NOTLOWEROPLEVEL (ircu)
-                    b"561" | // ERR_NOTMANAGER (ircu)
-                    b"562" | // ERR_CHANSECURED (ircu)
-                    b"563" | // ERR_UPASSSET (ircu)
-                    b"564" | // ERR_UPASSNOTSET (ircu)
-                    b"565" | // ERR_NOMANAGER_LONG (ircu) depreciated
-                    b"566" | // ERR_NOMANAGER (ircu)
-                    b"567" | // ERR_UPASS_SAME_APASS (ircu)
-                    b"568" | // ERR_LASTERROR (ircu) (conflict: RPL_NOOMOTD (nefarious))
-                    b"573" | // ERR_CANNOTSENDRP (ergo)
-                    b"597" | // RPL_REAWAY (unreal)
-                    b"603" | // RPL_WATCHSTAT (unreal)
-                    b"606" | // RPL_WATCHLIST (unreal)
-                    b"607" | // RPL_ENDOFWATCHLIST (unreal)
-                    b"608" | // RPL_WATCHCLEAR (ultimate)/RPL_CLEARWATCH (unreal)
-                    b"610" | // RPL_MAPMORE (unreal) (conflict: RPL_ISOPER (ultimate))
-                    b"611" | // RPL_ISLOCOP (ultimate)
-                    b"612" | // RPL_ISNOTOPER (ultimate)
-                    b"613" | // RPL_ENDOFISOPER (ultimate)
-                    b"615" | // RPL_MAPMORE (ptlink) (conflict: RPL_WHOISMODES (ultimate))
-                    b"616" | // RPL_WHOISHOST (ultimate)
-                    b"617" | // RPL_WHOISSSLFP (nefarious) (conflicts: RPL_DCCSTATUS (bahamut) RPL_WHOISBOT (ultimate))
-                    b"618" | // RPL_DCCLIST (bahamut)
-                    b"619" | // RPL_ENDOFDCCLIST (bahamut) (conflict: RPL_WHOWASHOST (ultimate))
-                    b"620" | // RPL_DCCINFO (bahamut) (conflict: RPL_RULESSTART (ultimate))
-                    b"621" | // RPL_RULES (ultimate)
-                    b"622" | // RPL_ENDOFRULES (ultimate)
-                    b"623" | // RPL_MAPMORE (ultimate)
-                    b"624" | // RPL_OMOTDSTART (ultimate)
-                    b"625" | // RPL_OMOTD (ultimate)
-                    b"626" | // RPL_ENDOFOMOTD (ultimate)
-                    b"630" | // RPL_SETTINGS (ultimate)
-                    b"631" | // RPL_ENDOFSETTINGS (ultimate)
-                    b"640" | // RPL_DUMPING (unreal) depreciated
-                    b"641" | // RPL_DUMPRPL (unreal) depreciated
-                    b"642" | // RPL_EODUMP (unreal) depreciated
-                    b"687" | // RPL_YOURLANGUAGESARE (ergo)
-                    b"727" | // RPL_TESTMASKGECOS (ratbox) (conflict: RPL_ISCAPTURED (oftc-hybrid))
-                    b"728" | // RPL_QUIETLIST (charybdis) (conflict: RPL_ISUNCAPTURED (ofc-hybrid))
-                    b"744" | // ERR_TOPICLOCK (inspIRCd)
-                    b"762" | // RPL_METADATAEND (IRCv3)
-                    b"771" | // RPL_XINFO (ithildin)
-                    b"773" | // RPL_XINFOSTART (ithildin)
-                    b"774" | // RPL_XINFOEND (ithildin)
-                    b"802" | // RPL_CHECK (inspIRCd)
-                    b"975" | // RPL_LOADEDMODULE (inspIRCd) (conflict: ERR_LASTERROR (nefarious))
-                    b"981" | // ERR_TOOMANYLANGUAGES (ergo)
-                    b"982" | // ERR_NOLANGUAGE (ergo)
-                    b"999"   // ERR_NUMERIC_ERR (bahamut)/ERR_NUMERICERR/ERR_LAST_ERR_MSG (depreciated: RPL_ENDOFDCCALLOWHELP (inspIRCd))
-                    => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));},
-                    b"001" | // RPL_WELCOME
-                    b"002" | // RPL_YOURHOST/RPL_YOURHOSTIS
-                    b"003" | // RPL_CREATED/RPL_SERVERCREATED
-                    b"005" | // RPL_ISUPPORT/RPL_PROTOCTL (depreciated: RPL_BOUNCE moved to 010)
-                    b"018" | // RPL_MAPUSERS (inspircd)
-                    b"020" | // RPL_HELLO (rusnet-ircd)
-                    b"105" | // RPL_REMOTEISUPPORT (unreal)
-                    b"203" | // RPL_TRACEUNKNOWN (RFC1459)
-                    b"221" | // RPL_UMODEIS (RFC1459)
-                    b"242" | // RPL_STATSUPTIME (RFC1459)
-                    b"251" | // RPL_LUSERCLIENT (RFC1459)
-                    b"255" | // RPL_LUSERME (RFC1459)
-                    b"256" | // RPL_ADMINME (RFC1459)
-                    b"257" | // RPL_ADMINLOC1 (RFC1459)
-                    b"258" | // RPL_ADMINLOC2 (RFC1459)
-                    b"259" | // RPL_ADMINEMAIL (RFC1459)
-                    b"265" | // RPL_LOCALUSERS/RPL_CURRENT_LOCAL (bahamut)
-                    b"266" | // RPL_GLOBALUSERS/RPL_CURRENT_GLOBAL (bahamut)
-                    b"271" | // RPL_SILELIST (ircu)
-                    b"272" | // RPL_ENDOFSILELIST (ircu)
-                    b"281" | // RPL_ACCEPTLIST (conflict: RPL_ENDOFGLIST (ircu))
-                    b"282" | // RPL_ENDOFACCEPT (conflict: RPL_JUPELIST (ircu))
-                    b"304" | // RPL_TEXT (unreal)
-                    b"305" | // RPL_UNAWAY (RFC1459)
-                    b"306" | // RPL_NOWAWAY (RFC1459)
-                    b"321" | // RPL_LISTSTART (RFC1459) depreciated
-                    b"323" | // RPL_LISTEND (RFC1459)
-                    b"336" | // RPL_INVITELIST (hybrid not 346) (conflict: RPL_WHOISBOT (nefarious))
-                    b"337" | // RPL_ENDOFINVITELIST (hybrid not 347) (conflict: RPL_WHOISTEXT (older hybrid?))
-                    b"340" | // RPL_USERIP (ircu)
-                    b"354" | // RPL_WHOSPCRPL (ircu)/RPL_RWHOREPLY (bahamut)
-                    b"371" | // RPL_INFO (RFC1459)
-                    b"372" | // RPL_MOTD (RFC1459)
-                    b"374" | // RPL_ENDOFINFO (RFC1459)
-                    b"375" | // RPL_MOTDSTART (RFC1459)
-                    b"376" | // RPL_ENDOFMOTD (RFC1459)
-                    b"381" | // RPL_YOUREOPER (RFC1459)/RPL_YOUAREOPER (inspircd)
-                    b"392" | // RPL_USERSSTART (RFC1459)
-                    b"393" | // RPL_USERS (RFC1459)
-                    b"394" | // RPL_ENDOFUSERS (RFC1459)
-                    b"395" | // RPL_NOUSERS (RFC1459)
-                    b"406" | // ERR_WASNOSUCHNICK (RFC1459)
-                    b"409" | // ERR_NOORIGIN (RFC1459)
-                    b"410" | // ERR_INVALIDCAPCMD (undernet?)/ERR_INVALIDCAPSUBCOMMAND (inspircd)/ERR_UNKNOWNCAPCMD (ircu)
-                    b"411" | // ERR_NORECIPIENT (RFC1459)
-                    b"412" | // ERR_NOTEXTTOSEND (RFC1459)
-                    b"417" | // ERR_INPUTTOOLONG (ircu)
-                    b"420" | // ERR_AMBIGUOUSCOMMAND (inspircd)
-                    b"422" | // ERR_NOMOTD (RFC1459)
-                    b"424" | // ERR_FILEERROR (RFC1459)
-                    b"431" | // ERR_NONICKNAMEGIVEN (RFC1459)
-                    b"436" | // ERR_ERR_NICKCOLLISION (RFC1459)
-                    b"445" | // ERR_SUMMONDISABLED (RFC1459)
-                    b"446" | // ERR_USERSDISABLED (RFC1459)
-                    b"448" | // ERR_FORBIDDENCHANNEL (unreal)
-                    b"451" | // ERR_NOTREGISTERED (RFC1459)
-                    b"456" | // ERR_ACCEPTFULL
-                    b"462" | // ERR_ALREADYREGISTERED (RFC1459)/ERR_ALREADYREGISTRED
-                    b"463" | // ERR_NOPERMFORHOST (RFC1459)
-                    b"464" | // ERR_PASSWDMISMATCH (RFC1459)
-                    b"465" | // ERR_YOUREBANNEDCREEP (RFC1459)
-                    b"481" | // ERR_NOPRIVILEGES (RFC1459)
-                    b"483" | // ERR_CANTKILLSERVER (RFC1459)/ERR_KILLDENY (unreal)
-                    b"484" | // ERR_RESTRICTED (RFC2812) (conflicts: ERR_ISCHANSERVICE (undernet) ERR_DESYNC (bahamut) ERR_ATTACKDENY (unreal))
-                    b"485" | // ERR_UNIQOPRIVSNEEDED (RFC2812) (conflicts: ERR_KILLDENY (unreal) ERR_CANTKICKADMIN (PTlink) ERR_ISREALSERVICE (quakenet) ERR_CHANBANREASON (hybrid) depreciated: ERR_BANNEDNICK (ratbox))
-                    b"491" | // ERR_NOOPERHOST (RFC1459)
-                    b"501" | // ERR_UMODEUNKOWNFLAG (RFC1459) (conflict: ERR_UNKNOWNSNOMASK (inspIRCd))
-                    b"502" | // ERR_USERSDONTMATCH (RFC1459)
-                    b"503" | // ERR_GHOSTEDCLIENT (hybrid) depreciated (conflict & depreciated: ERR_VWORLDWARN (austhex))
-                    b"511" | // ERR_SILELISTFULL (ircu)
-                    b"523" | // ERR_WHOLIMEXCEED (bahamut)
-                    b"526" | // ERR_PFXUNROUTABLE depreciated
-                    b"653" | // RPL_UNINVITED (inspIRCd)
-                    b"670" | // RPL_STARTTLS (IRCv3)
-                    b"672" | // RPL_UNKNOWNMODES (ithildin) (conflict: RPL_WHOISREALIP (rizon)/RPL_WHOISCGI (plexus))
-                    b"673" | // RPL_CANNOTSETMODES (ithildin)
-                    b"674" | // RPL_WHOISYOURID (ChatIRCd)
-                    b"690" | // ERR_REDIRECT (inspIRCd)
-                    b"691" | // ERR_STARTTLS (IRCv3)
-                    b"700" | // RPL_COMMANDS (inspIRCd)
-                    b"701" | // RPL_COMMANDSEND (inspIRCd)
-                    b"702" | // RPL_MODLIST (ratbox) (conflict & depreciated: RPL_COMMANDS (inspIRCd))
-                    b"703" | // RPL_ENDOFMODLIST (ratbox) (conflict & depreciated: RPL_COMMANDSEND (inspIRCd))
-                    b"715" | // ERR_KNOCKDISABLED (ratbox) (conflicts: ERR_TOOMANYINVITE (hybrid) RPL_INVITETHROTTLE (rizon))
-                    b"716" | // RPL_TARGUMODEG (ratbox)/ERR_TARGUMODEG
-                    b"717" | // RPL_TARGNOTIFY (ratbox)
-                    b"720" | // RPL_OMOTDSTART (ratbox)
-                    b"721" | // RPL_OMOTD (ratbox)
-                    b"722" | // RPL_ENDOFOMOTD (ratbox)
-                    b"730" | // RPL_MONONLINE (ratbox)
-                    b"731" | // RPL_MONOFFLINE (ratbox)
-                    b"732" | // RPL_MONLIST (ratbox)
-                    b"733" | // RPL_ENDOFMONLIST (ratbox)
-                    b"740" | // RPL_RSACHALLENGE2 (ratbox)
-                    b"741" | // RPL_ENDOFRSACHALLENGE2 (ratbox)
-                    b"750" | // RPL_SCANMATCHED (ratbox)
-                    b"759" | // RPL_ETRACEEND (irc2.11)
-                    b"764" | // ERR_METADATALIMIT (IRCv3)
-                    b"765" | // ERR_TARGETINVALID (IRCv3)
-                    b"767" | // ERR_KEYINVALID (IRCv3)
-                    b"902" | // ERR_NICKLOCKED (IRCv3)
-                    b"903" | // RPL_SASLSUCCESS (IRCv3)
-                    b"904" | // ERR_SASLFAIL (IRCv3)
-                    b"905" | // ERR_SASLTOOLONG (IRCv3)
-                    b"906" | // ERR_SASLABORTED (IRCv3)
-                    b"907" | // ERR_SASLALREADY (IRCv3)
-                    b"944" | // RPL_IDLETIMESET (inspIRCd)
-                    b"948" | // ERR_INVALIDIDLETIME (inspIRCd)
-                    b"961" | // RPL_PROPLIST (inspIRCd)
-                    b"990" | // RPL_DCCALLOWSTART (inspIRCd)
-                    b"992" | // RPL_DCCALLOWEND (inspIRCd)
-                    b"998"   // ERR_UNKNOWNDCCALLOWCMD (inspIRCd) (depreciated: RPL_DCCALLOWHELP (inspIRCd))
-                    => if params_amount < 2 {return Err(CommandError::MinimumArgsRequired(2, cmd));},
-                    b"043" | // RPL_SAVENICK (IRCnet)
-                    b"200" | // RPL_TRACELINK (RFC1459)
-                    b"201" | // RPL_TRACECONNECTING (RFC1459)
-                    b"202" | // RPL_TRACEHANDSHAKE (RFC1459)
-                    b"204" | // RPL_TRACEOPERATOR (RFC1459)
-                    b"205" | // RPL_TRACEUSER (RFC1459)
-                    b"208" | // RPL_TRACENEWTYPE (RFC1459)
-                    b"209" | // RPL_TRACECLASS (RFC2812)
-                    b"212" | // RPL_STATSCOMMANDS (RFC1459)
-                    b"219" | // RPL_ENDOFSTATS (RFC1459)
-                    b"252" | // RPL_LUSEROP (RFC1459)
-                    b"253" | // RPL_LUSERUNKNOWN (RFC1459)
-                    b"254" | // RPL_LUSERCHANNELS (RFC1459)
-                    b"261" | // RPL_TRACELOG (RFC1459)
-                    b"263" | // RPL_TRYAGAIN/RPL_LOAD2HI/RPL_LOAD_THROTTLED (RFC2812)
-                    b"276" | // RPL_WHOISCERTFP (oftc-hybrid) (conflicts: RPL_STATSRLINE (ircu) depreciated: RPL_VCHANEXIST (hybrid))
-                    b"301" | // RPL_AWAY (RFC1459)
-                    b"307" | // RPL_USERIP (conflicts: RPL_WHOISREGNICK (bahamut) RPL_SUPERHOST (austhex))
-                    b"313" | // RPL_WHOISOPERATOR (RFC1459)
-                    b"315" | // RPL_ENDOFWHO (RFC1459)
-                    b"318" | // RPL_ENDOFWHOIS (RFC1459)
-                    b"319" | // RPL_WHOISCHANNELS (RFC1459)
-                    b"320" | // RPL_WHOISSPECIAL (unreal) (conflicts: RPL_WHOIS_HIDDEN (anothernet) RPL_WHOISVIRT (austhex))
-                    b"324" | // RPL_CHANNELMODEIS (RFC1459)
-                    b"325" | // RPL_UNIQOPIS (RFC2812) (conflicts: RPL_CHANNELPASSIS RPL_WHOISWEBIRC (nefarious) depreciated: RPL_CHANNELMLOCKIS/RPL_CHANNELMLOCK (sorircd))
-                    b"329" | // RPL_CREATIONTIME (bahamut)/RPL_CHANNELCREATED (inspircd)
-                    b"331" | // RPL_NOTOPIC (RFC1459)/RPL_NOTOPICSET (inspircd)
-                    b"332" | // RPL_TOPIC (RFC1459)/RPL_TOPICSET (inspircd)
-                    b"333" | // RPL_TOPICWHOTIME (ircu)/RPL_TOPICTIME (inspircd)
-                    b"335" | // RPL_WHOISBOT (unreal) (conflicts: RPL_WHOISTEXT (hybrid) RPL_WHOISACCOUNTONLY (nefarious))
-                    b"338" | // RPL_WHOISACTUALLY (ircu) (conflict: RPL_CHANPASSOK)
-                    b"341" | // RPL_INVITING (RFC1459)
-                    b"342" | // RPL_SUMMONING (RFC1459) depreciated
-                    b"346" | // RPL_INVITELIST (RFC2812 not 336)/RPL_INVEXLIST (hybrid)
-                    b"347" | // RPL_ENDOFINVITELIST (RFC2812 not 337)/RPL_ENDOFINVEXLIST (hybrid)
-                    b"348" | // RPL_EXCEPTLIST (RFC2812)/RPL_EXLIST (unreal)/RPL_EXEMPTLIST (bahamut)
-                    b"349" | // RPL_ENDOFEXCEPTLIST (RFC2812)/RPL_ENDOFEXLIST (unreal)/RPL_ENDOFEXEMPTLIST (bahamut)
-                    b"365" | // RPL_ENDOFLINKS (RFC1459)
-                    b"366" | // RPL_ENDOFNAMES (RFC1459)
-                    b"367" | // RPL_BANLIST (RFC1459)
-                    b"368" | // RPL_ENDOFBANLIST (RFC1459)
-                    b"369" | // RPL_ENDOFWHOWAS (RFC1459)
-                    b"378" | // RPL_BANEXPIRED (aircd) (conflicts: RPL_WHOISHOST (unreal) depreciated: RPL_MOTD (austhex))
-                    b"379" | // RPL_KICKLINKED (aircd) (conflicts: RPL_WHOISMODES (unreal) depreciated: RPL_WHOWASIP (inspircd))
-                    b"382" | // RPL_REHASHING (RFC1459)
-                    b"391" | // RPL_TIME (RFC1459)
-                    b"396" | // RPL_HOSTHIDDEN (unreal)/RPL_VISIBLEHOST (hybrid)/RPL_YOURDISPLAYEDHOST (inspircd)
-                    b"400" | // ERR_UNKNOWNERROR (ergo) (conflict & depreciated: ERR_FIRSTERROR (ircu))
-                    b"401" | // ERR_NOSUCHNICK (RFC1459)
-                    b"402" | // ERR_NOSUCHSERVER (RFC1459)
-                    b"403" | // ERR_NOSUCHCHANNEL (RFC1459)
-                    b"404" | // ERR_CANNOTSENDTOCHAN (RFC1459)
-                    b"405" | // ERR_TOOMANYCHANNELS (RFC1459)
-                    b"407" | // ERR_TOOMANYTARGETS (RFC1459)
-                    b"408" | // ERR_NOSUCHSERVICE (RFC2812) (conflicts: ERR_NOCOLORSONCHAN (bahamut) ERR_NOCTRLSONCHAN (hybrid) ERR_SEARCHNOMATCH (snircd))
-                    b"413" | // ERR_NOTPLEVEL (RFC1459)
-                    b"414" | // ERR_WILDTOPLEVEL (RFC1459)
-                    b"415" | // ERR_BADMASK (RFC2812) (conflict: ERR_MSGNEEDREGGEDNICK (solanum)/ERR_CANTSENDREGONLY (oftc-hybrid))
-                    b"416" | // ERR_TOOMANYMATCHES (IRCnet)/ERR_QUERYTOOLONG (ircu)
-                    b"421" | // ERR_UNKNOWNCOMMAND (RFC1459)
-                    b"423" | // ERR_NOADMININFO (RFC1459)
-                    b"432" | // ERR_ERRONEUSNICKNAME (RFC1459)
-                    b"433" | // ERR_NICKNAMEINUSE (RFC1459)
-                    b"437" | // ERR_UNAVAILRESOURCE (RFC2812) (conflict: ERR_BANNICKCHANGE (ircu))
-                    b"442" | // ERR_NOTONCHANNEL (RFC1459)
-                    b"444" | // ERR_NOLOGIN (RFC1459)
-                    b"457" | // ERR_ACCEPTEXIST
-                    b"458" | // ERR_ACCEPTNOT
-                    b"461" | // ERR_NEEDMOREPARAMS (RFC1459)
-                    b"467" | // ERR_KEYSET (RFC1459)
-                    b"471" | // ERR_CHANNELISFULL (RFC1459)
-                    b"472" | // ERR_UNKNOWNMODE (RFC1459)
-                    b"473" | // ERR_INVITEONLYCHAN (RFC1459)
-                    b"474" | // ERR_BANNEDFROMCHAN (RFC1459)
-                    b"475" | // ERR_BADCHANNELKEY (RFC1459)
-                    b"476" | // ERR_BADCHANMASK (RFC2812) (conflict: ERR_OPERONLYCHAN (plexus))
-                    b"477" | // ERR_NOCHANMODES (RFC2812)/ERR_MODELESS (conflict: ERR_NEEDREGGEDNICK (bahamut)/ERR_REGONLYCHAN (oftc-hybrid))
-                    b"478" | // ERR_BANLISTFULL (RFC2812)
-                    b"482" | // ERR_CHANOPRIVSNEEDED (RFC1459)
-                    b"517" | // ERR_DISABLED (ircu)
-                    b"531" | // ERR_CANTSENDTOUSER (inspIRCd)/ERR_HOSTUNAVAIL (snircd)
-                    b"650" | // RPL_SYNTAX (inspIRCd)
-                    b"651" | // RPL_CHANNELMSG (inspIRCd)
-                    b"652" | // RPL_WHOWASIP (inspIRCd)
-                    b"659" | // RPL_SPAMCMDFWD (unreal)
-                    b"671" | // RPL_WHOISSECURE (unreal)/RPL_WOISSSL (nefarious)
-                    b"704" | // RPL_HELPSTART (ratbox)
-                    b"705" | // RPL_HELPTXT (ratbox)
-                    b"706" | // RPL_ENDOFHELP (ratbox)
-                    b"707" | // ERR_TARGCHANGE (ratbox)
-                    b"710" | // RPL_KNOCK (ratbox)
-                    b"711" | // RPL_KNOCKDLVR (ratbox)
-                    b"712" | // ERR_TOOMANYKNOCK (ratbox)
-                    b"713" | // ERR_CHANOPEN (ratbox)
-                    b"714" | // ERR_KNOCKONCHAN (ratbox)
-                    b"718" | // RPL_UMODEGMSG (ratbox)
-                    b"723" | // ERR_NOPRIVS (ratbox)
-                    b"726" | // RPL_NOTESTLINE (ratbox)
-                    b"761" | // RPL_KEYVALUE (IRCv3)
-                    b"766" | // ERR_NOMATCHINGKEY (IRCv3)
-                    b"768" | // ERR_KEYNOTSET (IRCv3)
-                    b"769" | // ERR_KEYNOPERMISSION (IRCv3)
-                    b"801" | // RPL_STATSCOUNTRY (inspIRCd)
-                    b"901" | // RPL_LOGGEDOUT (IRCv3)
-                    b"908" | // RPL_SASLMECHS (IRCv3)
-                    b"910" | // RPL_ACCESSLIST (inspIRCd)
-                    b"911" | // RPL_ENDOFACCESSLIST (inspIRCd)
-                    b"926" | // ERR_BADCHANNEL (inspIRCd)
-                    b"937" | // ERR_ALREADYCHANFILTERED (inspIRCd) depreciated
-                    b"938" | // ERR_NOSUCHCHANFILTER (inspIRCd) depreciated
-                    b"939" | // ERR_CHANFILTERFULL (inspIRCd) depreciated
-                    b"940" | // RPL_ENDOFSPAMFILTER (inspIRCd)
-                    b"942" | // ERR_INVALIDWATCHNICK (inspIRCd)
-                    b"945" | // RPL_NICKLOCKOFF (inspIRCd)
-                    b"946" | // ERR_NICKNOTLOCKED (inspIRCd)
-                    b"947" | // RPL_NICKLOCKON (inspIRCd)
-                    b"950" | // RPL_UNSILENCED (inspIRCd)
-                    b"951" | // RPL_SILENCED (inspIRCd)
-                    b"952" | // ERR_SILENCE (inspIRCd)
-                    b"953" | // RPL_ENDOFEXEMPTIONLIST (inspIRCd)
-                    b"960" | // RPL_ENDOFPROPLIST (inspIRCd)
-                    b"972" | // ERR_CANNOTDOCOMMAND (unreal) (conflict: ERR_CANTUNLOADMODULE (inspIRCd))
-                    b"973" | // RPL_UNLOADEDMODULE (inspIRCd)
-                    b"974" | // RPL_CANNOTCHANGECHANMODE (unreal) (conflict: ERR_CANTLOADMODULE (inspIRCd))
-                    b"988" | // RPL_SERVLOCKON (inspIRCd)
-                    b"989" | // RPL_SERVLOCKOFF (inspIRCd)
-                    b"991" | // RPL_DCCALLOWLIST (inspIRCd)
-                    b"993" | // RPL_DCCALLOWTIMED (inspIRCd)
-                    b"994" | // RPL_DCCALLOWPERMANENT (inspIRCd)
-                    b"995" | // RPL_DCCALLOWREMOVED (inspIRCd)
-                    b"996" | // ERR_DCCALLOWINVALID (inspIRCd)
-                    b"997"   // RPL_DCCALLOWEXPIRED (inspIRCd)
-                    => if params_amount < 3 {return Err(CommandError::MinimumArgsRequired(3, cmd));},
-                    b"010" | // RPL_BOUNCE/RPL_REDIR (depreciated & conflict: RPL_STATMEM (ircu))
-                    b"235" | // RPL_SERVLISTEND (RFC2812)
-                    b"262" | // RPL_TRACEEND/RPL_ENDOFTRACE (RFC2812) (conflict: RPL_TRACEPING)
-                    b"312" | // RPL_WHOISSERVER (RFC1459)
-                    b"322" | // RPL_LIST (RFC1459)
-                    b"330" | // RPL_WHOISACCOUNT (ircu)/RPL_WHOISLOGGEDIN (conflict: RPL_WHOWAS_TIME)
-                    b"350" | // RPL_WHOISGATEWAY (inspircd)
-                    b"351" | // RPL_VERSION (RFC1459)
-                    b"353" | // RPL_NAMREPLY (RFC1459)
-                    b"364" | // RPL_LINKS (RFC1459)
-                    b"441" | // ERR_USERNOTINCHANNEL (RFC1459)
-                    b"443" | // ERR_USERONCHANNEL (RFC1459)
-                    b"569" | // RPL_WHOISASN (inspIRCd)
-                    b"729" | // RPL_ENDOFQUIETLIST (charybdis)
-                    b"734" | // ERR_MONLISTFULL (ratbox)
-                    b"742" | // ERR_MLOCKRESTRICTED (charybdis)
-                    b"743" | // ERR_INVALIDBAN (charybdis)
-                    b"760" | // RPL_WHOISKEYVALUE (IRCv3)
-                    b"803" | // RPL_OTHERUMODEIS (inspIRCd)
-                    b"804" | // RPL_OTHERSNOMASKIS (inspIRCd)
-                    b"900" | // RPL_LOGGEDIN (IRCv3)
-                    b"936"   // ERR_WORDFILTERED (inspIRCd) depreciated
-                    => if params_amount < 4 {return Err(CommandError::MinimumArgsRequired(4, cmd));},
-                    b"004" | // RPL_MYINFO/RPL_SERVERVERSION
-                    b"206" | // RPL_TRACESERVER (RFC1459)
-                    b"207" | // RPL_TRACESERVICE (RFC2812) (conflict: RPL_TRACECAPTURED (oftc-hybrid))
-                    b"244" | // RPL_STATSHLINE (RFC1459)
-                    b"317" | // RPL_WHOISIDLE (RFC1459)
-                    b"598" | // RPL_GONEAWAY (unreal)
-                    b"599" | // RPL_NOTAWAY (unreal)
-                    b"600" | // RPL_LOGON (unreal)
-                    b"601" | // RPL_LOGOFF (unreal)
-                    b"602" | // RPL_WATCHOFF (unreal)
-                    b"604" | // RPL_NOWON (unreal)
-                    b"605" | // RPL_NOWOFF (unreal)
-                    b"609" | // RPL_NOWISAWAY (unreal)
-                    b"696" | // ERR_INVALIDMODEPARAM (inspIRCd)
-                    b"697" | // ERR_LISTMODEALREADYSET (inspIRCd)
-                    b"698" | // ERR_LISTMODENOTSET (inspIRCd)
-                    b"724" | // RPL_TESTMASK (ratbox)
-                    b"725" | // RPL_TESTLINE (ratbox)
-                    b"941" | // RPL_SPAMFILTER (inspIRCd)
-                    b"954"   // RPL_EXEMPTIONLIST (inspIRCd)
-                    => if params_amount < 5 {return Err(CommandError::MinimumArgsRequired(5, cmd));},
-                    b"218" | // RPL_STATSYLINE (RFC1459)
-                    b"241" | // RPL_STATSLLINE (RFC1459)
-                    b"243" | // RPL_STATSOLINE (RFC1459)
-                    b"311" | // RPL_WHOISUSER (RFC1459)
-                    b"314"   // RPL_WHOWASUSER (RFC1459)
-                    => if params_amount < 6 {return Err(CommandError::MinimumArgsRequired(6, cmd));},
-                    b"213" | // RPL_STATSCLINE (RFC1459)
-                    b"214" | // RPL_STATSNLINE (RFC1459)/RPL_STATSOLDNLINE (ircu, unreal)
-                    b"215" | // RPL_STATSILINE (RFC1459)
-                    b"216" | // RPL_STATSKLINE (RFC1459)
-                    b"234" | // RPL_SERVLIST (RFC2812)
-                    b"709" | // RPL_ETRACE (ratbox)
-                    b"751"   // RPL_SCANUMODES (ratbox)
-                    => if params_amount < 7 {return Err(CommandError::MinimumArgsRequired(7, cmd));},
-                    b"211" | // RPL_STATSLINKINFO (RFC1459)
-                    b"352" | // RPL_WHOREPLY (RFC1459)
-                    b"708"   // RPL_ETRACEFULL (ratbox)
-                    => if params_amount < 8 {return Err(CommandError::MinimumArgsRequired(8, cmd));},
-                    _ => unhandled = true,
-                }
-                if unhandled {return Err(CommandError::UnhandledNumeric(cmd));}
-                return Ok(Self::Numeric(cmd));
+                return match (numeric_min_params(input), unhandled) {
+                    (Some(_), _) | (None, UnhandledNumerics::Accept) => Ok(Self::Numeric(cmd)),
+                    (None, UnhandledNumerics::Reject) => Err(CommandError::UnhandledNumeric(cmd)),
+                };
             } else if number_count > 0 {return Err(CommandError::NumberInNamedCommand(cmd));}
-            match &command_to_uppercase_bytes(input) {
-                b"INFO00000000" => return Ok(Self::Named("INFO")),
-                b"LUSERS000000" => return Ok(Self::Named("LUSERS")),
-                b"REHASH000000" => return Ok(Self::Named("REHASH")),
-                b"RESTART00000" => return Ok(Self::Named("RESTART")),
-                b"LINKS0000000" => return Ok(Self::Named("LINKS")),
-                b"QUIT00000000" => return Ok(Self::Named("QUIT")),
-                b"MOTD00000000" => return Ok(Self::Named("MOTD")),
-                b"VERSION00000" => return Ok(Self::Named("VERSION")),
-                b"ADMIN0000000" => return Ok(Self::Named("ADMIN")),
-                b"TIME00000000" => return Ok(Self::Named("TIME")),
-                b"HELP00000000" => return Ok(Self::Named("HELP")),
-                b"AWAY00000000" => return Ok(Self::Named("AWAY")),
-                b"LIST00000000" => return Ok(Self::Named("LIST")),
-                b"ACK000000000" => return Ok(Self::Named("ACK")),
-                b"ACCEPT000000" => return Ok(Self::Named("ACCEPT")),
-                b"SILENCE00000" => return Ok(Self::Named("SILENCE")),
-                b"DIE000000000" => return Ok(Self::Named("DIE")),
-                b"TRACE0000000" => return Ok(Self::Named("TRACE")),
-                b"ETRACE000000" => return Ok(Self::Named("ETRACE")),
-                b"SERVLIST0000" => return Ok(Self::Named("SERVLIST")),
-                b"USERS0000000" => return Ok(Self::Named("USERS")),
-                b"MAP000000000" => return Ok(Self::Named("MAP")),
-                b"PASS00000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("PASS"));},
-                b"NICK00000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("NICK"));},
-                b"PING00000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("PING"));},
-                b"ERROR0000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("ERROR"));},
-                b"NAMES0000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("NAMES"));},
-                b"WHO000000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("WHO"));},
-                b"WALLOPS00000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("WALLOPS"));},
-                b"AUTHENTICATE" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("AUTHENTICATE"));},
-                b"ACCOUNT00000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("ACCOUNT"));},
-                b"CAP000000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("CAP"));},
-                b"MODE00000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("MODE"));},
-                b"PONG00000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("PONG"));},
-                b"JOIN00000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("JOIN"));},
-                b"PART00000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("PART"));},
-                b"TOPIC0000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("TOPIC"));},
-                b"STATS0000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("STATS"));},
-                b"WHOIS0000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("WHOIS"));},
-                b"WHOWAS000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("WHOWAS"));},
-                b"CONNECT00000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("CONNECT"));},
-                b"USERHOST0000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("USERHOST"));},
-                b"TAGMSG000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("TAGMSG"));},
-                b"BATCH0000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("BATCH"));},
-                b"SETNAME00000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("SETNAME"));},
-                b"MONITOR00000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("MONITOR"));},
-                b"ISON00000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("ISON"));},
-                b"KNOCK0000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("KNOCK"));},
-                b"SUMMON000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("SUMMON"));},
-                b"USERIP000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("USERIP"));},
-                b"WATCH0000000" => if params_amount < 1 {return Err(CommandError::MinimumArgsRequired(1, cmd));}
-                                   else {return Ok(Self::Named("WATCH"));},
-                b"OPER00000000" => if params_amount < 2 {return Err(CommandError::MinimumArgsRequired(2, cmd));}
-                                   else {return Ok(Self::Named("OPER"));},
-                b"INVITE000000" => if params_amount < 2 {return Err(CommandError::MinimumArgsRequired(2, cmd));}
-                                   else {return Ok(Self::Named("INVITE"));},
-                b"PRIVMSG00000" => if params_amount < 2 {return Err(CommandError::MinimumArgsRequired(2, cmd));}
-                                   else {return Ok(Self::Named("PRIVMSG"));},
-                b"NOTICE000000" => if params_amount < 2 {return Err(CommandError::MinimumArgsRequired(2, cmd));}
-                                   else {return Ok(Self::Named("NOTICE"));},
-                b"KILL00000000" => if params_amount < 2 {return Err(CommandError::MinimumArgsRequired(2, cmd));}
-                                   else {return Ok(Self::Named("KILL"));},
-                b"SQUIT0000000" => if params_amount < 2 {return Err(CommandError::MinimumArgsRequired(2, cmd));}
-                                   else {return Ok(Self::Named("SQUIT"));},
-                b"KICK00000000" => if params_amount < 2 {return Err(CommandError::MinimumArgsRequired(2, cmd));}
-                                   else {return Ok(Self::Named("KICK"));},
-                b"CHGHOST00000" => if params_amount < 2 {return Err(CommandError::MinimumArgsRequired(2, cmd));}
-                                   else {return Ok(Self::Named("CHGHOST"));},
-                b"ENCAP0000000" => if params_amount < 2 {return Err(CommandError::MinimumArgsRequired(2, cmd));}
-                                   else {return Ok(Self::Named("ENCAP"));},
-                b"SQUERY000000" => if params_amount < 2 {return Err(CommandError::MinimumArgsRequired(2, cmd));}
-                                   else {return Ok(Self::Named("SQUERY"));},
-                b"METADATA0000" => if params_amount < 2 {return Err(CommandError::MinimumArgsRequired(2, cmd));}
-                                   else {return Ok(Self::Named("METADATA"));},
-                b"FAIL00000000" => if params_amount < 3 {return Err(CommandError::MinimumArgsRequired(3, cmd));}
-                                   else {return Ok(Self::Named("FAIL"));},
-                b"WARN00000000" => if params_amount < 3 {return Err(CommandError::MinimumArgsRequired(3, cmd));}
-                                   else {return Ok(Self::Named("WARN"));},
-                b"NOTE00000000" => if params_amount < 3 {return Err(CommandError::MinimumArgsRequired(3, cmd));}
-                                   else {return Ok(Self::Named("NOTE"));},
-                b"CPRIVMSG0000" => if params_amount < 3 {return Err(CommandError::MinimumArgsRequired(3, cmd));}
-                                   else {return Ok(Self::Named("CPRIVMSG"));},
-                b"CNOTICE00000" => if params_amount < 3 {return Err(CommandError::MinimumArgsRequired(3, cmd));}
-                                   else {return Ok(Self::Named("CNOTICE"));},
-                b"SERVER000000" => if params_amount < 3 {return Err(CommandError::MinimumArgsRequired(3, cmd));}
-                                   else {return Ok(Self::Named("SERVER"));},
-                b"USER00000000" => if params_amount < 4 {return Err(CommandError::MinimumArgsRequired(4, cmd));}
-                                   else {return Ok(Self::Named("USER"));},
-                b"WEBIRC000000" => if params_amount < 4 {return Err(CommandError::MinimumArgsRequired(4, cmd));}
-                                   else {return Ok(Self::Named("WEBIRC"));},
-                b"SERVICE00000" => if params_amount < 6 {return Err(CommandError::MinimumArgsRequired(6, cmd));}
-                                   else {return Ok(Self::Named("SERVICE"));},
-                _ => return Err(CommandError::UnhandledNamed(cmd)),
-            }
+            else if input.len() > 12 {return Err(CommandError::UnhandledNamed(cmd));}
+            let uppercase = command_to_uppercase_bytes(input);
+            let (uppercase, _) = uppercase.split_at(input.len());
+            return match named_command(uppercase) {
+                Some((name, _, _)) => Ok(Self::Named(name)),
+                None => Err(CommandError::UnhandledNamed(cmd)),
+            };
         }
         unreachable!();
     }
+    /// Checks whether the number of [`Parameters`](crate::Parameters) meets the minimum required by the [`Command`].
+    ///
+    /// A [`Command`] not supported by this parser has no minimum.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `params_amount` is less than [`Command::min_params`].
+    pub const fn validate_param_count(&self, params_amount: usize) -> Result<(), CommandError<'msg>> {
+        match self.min_params() {
+            Some(min) if params_amount < min as usize => match self {
+                Self::Named(cmd) | Self::Numeric(cmd) => Err(CommandError::MinimumArgsRequired(min, cmd)),
+            },
+            _ => Ok(()),
+        }
+    }
+    /// Returns the minimum amount of [`Parameters`](crate::Parameters) required by the [`Command`].
+    ///
+    /// This is the same minimum that [`Command::parse`] enforces.
+    /// Returns `None` for a [`Command`] not supported by this parser.
+    #[must_use]
+    pub const fn min_params(&self) -> Option<u8> {
+        match self {
+            Self::Named(name) => match named_command(name.as_bytes()) {
+                Some((_, _, min)) => Some(min),
+                None => None,
+            },
+            Self::Numeric(numeric) => numeric_min_params(numeric.as_bytes()),
+        }
+    }
+    /// Returns the value of a `Numeric` [`Command`] as a [`u16`].
+    ///
+    /// Returns `None` for a `Named` [`Command`].
+    #[must_use]
+    pub const fn as_numeric_u16(&self) -> Option<u16> {
+        match self {
+            Self::Named(_) => None,
+            Self::Numeric(numeric) => numeric_value(numeric.as_bytes()),
+        }
+    }
+    /// Checks whether the [`Command`] is a `Numeric` indicating an error.
+    ///
+    /// Numerics in the `400`-`599` range are errors with the exception of supported numerics whose
+    /// canonical name is a reply (such as `RPL_NOTAWAY`). Supported numerics outside of that range are
+    /// errors if their canonical name is an error (such as `ERR_SASLFAIL`).
+    #[must_use]
+    pub const fn is_error_numeric(&self) -> bool {
+        match self.as_numeric_u16() {
+            Some(value) => match numeric_name(value) {
+                Some(name) => name.as_bytes()[0] == b'E',
+                None => matches!(value, 400..=599),
+            },
+            None => false,
+        }
+    }
+    /// Checks whether the [`Command`] is a `Numeric` indicating a reply.
+    ///
+    /// This is every `Numeric` that is not an error as determined by [`Command::is_error_numeric`].
+    #[must_use]
+    pub const fn is_reply_numeric(&self) -> bool {
+        self.as_numeric_u16().is_some() && !self.is_error_numeric()
+    }
+    /// Returns the canonical name of a `Numeric` [`Command`] such as `RPL_WELCOME` for `001`.
+    ///
+    /// Where IRC server implementations conflict the name used by the specification or the most widespread
+    /// implementation is returned. Returns `None` for a `Named` [`Command`] or an unsupported `Numeric`.
+    #[must_use]
+    pub const fn numeric_name(&self) -> Option<&'static str> {
+        match self {
+            Self::Named(_) => None,
+            Self::Numeric(_) => match self.as_numeric_u16() {
+                Some(value) => numeric_name(value),
+                None => None,
+            },
+        }
+    }
+    /// Returns the [capability] an IRC client must have negotiated before an IRC server can send it the [`Command`].
+    ///
+    /// Returns `None` if the [`Command`] isn't gated behind a capability.
+    ///
+    /// [capability]: <https://ircv3.net/specs/extensions/capability-negotiation.html>
+    #[must_use]
+    pub const fn required_capability(&self) -> Option<&'static str> {
+        match self.kind() {
+            Some(CommandKind::Tagmsg) => Some("message-tags"),
+            Some(CommandKind::Batch) => Some("batch"),
+            Some(CommandKind::Ack) => Some("labeled-response"),
+            Some(CommandKind::Account) => Some("account-notify"),
+            Some(CommandKind::Away) => Some("away-notify"),
+            Some(CommandKind::Chghost) => Some("chghost"),
+            Some(CommandKind::Setname) => Some("setname"),
+            Some(CommandKind::Authenticate) => Some("sasl"),
+            Some(CommandKind::Metadata) => Some("draft/metadata-2"),
+            _ => None,
+        }
+    }
+    /// Returns the [`CommandCategory`] of the [`Command`].
+    ///
+    /// Returns `None` for a [`Command`] not supported by this parser.
+    #[must_use]
+    pub const fn category(&self) -> Option<CommandCategory> {
+        match self.kind() {
+            Some(kind) => Some(match kind {
+                CommandKind::Cap | CommandKind::Authenticate | CommandKind::Pass | CommandKind::Nick |
+                CommandKind::User | CommandKind::Ping | CommandKind::Pong | CommandKind::Oper | CommandKind::Quit |
+                CommandKind::Error | CommandKind::Webirc | CommandKind::Service
+                => CommandCategory::ConnectionRegistration,
+                CommandKind::Join | CommandKind::Part | CommandKind::Topic | CommandKind::Names | CommandKind::List |
+                CommandKind::Invite | CommandKind::Kick | CommandKind::Knock => CommandCategory::ChannelOps,
+                CommandKind::Privmsg | CommandKind::Notice | CommandKind::Cprivmsg | CommandKind::Cnotice |
+                CommandKind::Squery => CommandCategory::Messaging,
+                CommandKind::Motd | CommandKind::Version | CommandKind::Admin | CommandKind::Connect |
+                CommandKind::Lusers | CommandKind::Time | CommandKind::Stats | CommandKind::Help | CommandKind::Info |
+                CommandKind::Mode | CommandKind::Links | CommandKind::Trace | CommandKind::Etrace |
+                CommandKind::Servlist | CommandKind::Map | CommandKind::Users | CommandKind::Summon
+                => CommandCategory::ServerQueries,
+                CommandKind::Who | CommandKind::Whois | CommandKind::Whowas | CommandKind::Userhost |
+                CommandKind::Ison | CommandKind::Userip | CommandKind::Away | CommandKind::Watch |
+                CommandKind::Accept | CommandKind::Silence => CommandCategory::UserQueries,
+                CommandKind::Kill | CommandKind::Rehash | CommandKind::Restart | CommandKind::Squit |
+                CommandKind::Die | CommandKind::Wallops => CommandCategory::OperatorCommands,
+                CommandKind::Server | CommandKind::Encap => CommandCategory::ServerToServer,
+                CommandKind::Tagmsg | CommandKind::Batch | CommandKind::Account | CommandKind::Chghost |
+                CommandKind::Setname | CommandKind::Monitor | CommandKind::Metadata | CommandKind::Fail |
+                CommandKind::Warn | CommandKind::Note | CommandKind::Ack => CommandCategory::IrcV3,
+                CommandKind::Numeric(_) => CommandCategory::Numeric,
+            }),
+            None => None,
+        }
+    }
+    /// Returns the [`CommandKind`] of the [`Command`].
+    ///
+    /// Returns `None` for a [`Command`] not generated by [`Command::parse`] that this parser doesn't support.
+    #[must_use]
+    pub const fn kind(&self) -> Option<CommandKind> {
+        match self {
+            Self::Named(name) => CommandKind::from_named(name.as_bytes()),
+            Self::Numeric(_) => match self.as_numeric_u16() {
+                Some(value) => Some(CommandKind::Numeric(value)),
+                None => None,
+            },
+        }
+    }
 }
 
 impl core::fmt::Display for Command<'_> {
@@ -703,6 +247,206 @@ impl core::fmt::Display for Command<'_> {
     }
 }
 
+/// How a `Numeric` [`Command`] not supported by this parser is handled.
+///
+/// IRC servers introduce new numerics faster than this parser can support them.
+/// Software such as bouncers must still be able to forward them.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum UnhandledNumerics {
+    /// Return an `Err` for any `Numeric` not supported by this parser.
+    #[default]
+    Reject,
+    /// Accept any 3 digit `Numeric` without enforcing a minimum amount of [`Parameters`](crate::Parameters).
+    Accept,
+}
+
+/// The kind of a [`Command`] for matching without comparing strings.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommandKind {
+    /// The `INFO` command.
+    Info,
+    /// The `LUSERS` command.
+    Lusers,
+    /// The `REHASH` command.
+    Rehash,
+    /// The `RESTART` command.
+    Restart,
+    /// The `LINKS` command.
+    Links,
+    /// The `QUIT` command.
+    Quit,
+    /// The `MOTD` command.
+    Motd,
+    /// The `VERSION` command.
+    Version,
+    /// The `ADMIN` command.
+    Admin,
+    /// The `TIME` command.
+    Time,
+    /// The `HELP` command.
+    Help,
+    /// The `AWAY` command.
+    Away,
+    /// The `LIST` command.
+    List,
+    /// The `ACK` command.
+    Ack,
+    /// The `ACCEPT` command.
+    Accept,
+    /// The `SILENCE` command.
+    Silence,
+    /// The `DIE` command.
+    Die,
+    /// The `TRACE` command.
+    Trace,
+    /// The `ETRACE` command.
+    Etrace,
+    /// The `SERVLIST` command.
+    Servlist,
+    /// The `USERS` command.
+    Users,
+    /// The `MAP` command.
+    Map,
+    /// The `PASS` command.
+    Pass,
+    /// The `NICK` command.
+    Nick,
+    /// The `PING` command.
+    Ping,
+    /// The `ERROR` command.
+    Error,
+    /// The `NAMES` command.
+    Names,
+    /// The `WHO` command.
+    Who,
+    /// The `WALLOPS` command.
+    Wallops,
+    /// The `AUTHENTICATE` command.
+    Authenticate,
+    /// The `ACCOUNT` command.
+    Account,
+    /// The `CAP` command.
+    Cap,
+    /// The `MODE` command.
+    Mode,
+    /// The `PONG` command.
+    Pong,
+    /// The `JOIN` command.
+    Join,
+    /// The `PART` command.
+    Part,
+    /// The `TOPIC` command.
+    Topic,
+    /// The `STATS` command.
+    Stats,
+    /// The `WHOIS` command.
+    Whois,
+    /// The `WHOWAS` command.
+    Whowas,
+    /// The `CONNECT` command.
+    Connect,
+    /// The `USERHOST` command.
+    Userhost,
+    /// The `TAGMSG` command.
+    Tagmsg,
+    /// The `BATCH` command.
+    Batch,
+    /// The `SETNAME` command.
+    Setname,
+    /// The `MONITOR` command.
+    Monitor,
+    /// The `ISON` command.
+    Ison,
+    /// The `KNOCK` command.
+    Knock,
+    /// The `SUMMON` command.
+    Summon,
+    /// The `USERIP` command.
+    Userip,
+    /// The `WATCH` command.
+    Watch,
+    /// The `OPER` command.
+    Oper,
+    /// The `INVITE` command.
+    Invite,
+    /// The `PRIVMSG` command.
+    Privmsg,
+    /// The `NOTICE` command.
+    Notice,
+    /// The `KILL` command.
+    Kill,
+    /// The `SQUIT` command.
+    Squit,
+    /// The `KICK` command.
+    Kick,
+    /// The `CHGHOST` command.
+    Chghost,
+    /// The `ENCAP` command.
+    Encap,
+    /// The `SQUERY` command.
+    Squery,
+    /// The `METADATA` command.
+    Metadata,
+    /// The `FAIL` command.
+    Fail,
+    /// The `WARN` command.
+    Warn,
+    /// The `NOTE` command.
+    Note,
+    /// The `CPRIVMSG` command.
+    Cprivmsg,
+    /// The `CNOTICE` command.
+    Cnotice,
+    /// The `SERVER` command.
+    Server,
+    /// The `USER` command.
+    User,
+    /// The `WEBIRC` command.
+    Webirc,
+    /// The `SERVICE` command.
+    Service,
+    /// A `Numeric` command with its 3 digit value.
+    Numeric(u16),
+}
+
+impl CommandKind {
+    const fn from_named(input: &[u8]) -> Option<Self> {
+        match named_command(input) {
+            Some((_, kind, _)) => Some(kind),
+            None => None,
+        }
+    }
+}
+
+/// The purpose of a [`Command`] for grouping or filtering messages.
+///
+/// The categories follow the sections of the [IRC Client Protocol Specification].
+///
+/// [IRC Client Protocol Specification]: <https://modern.ircdocs.horse/#client-messages>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CommandCategory {
+    /// Establishing, maintaining and closing a connection such as `NICK`, `CAP` or `PING`.
+    ConnectionRegistration,
+    /// Joining and managing channels such as `JOIN`, `TOPIC` or `KICK`.
+    ChannelOps,
+    /// Sending messages to channels and users such as `PRIVMSG` or `NOTICE`.
+    Messaging,
+    /// Querying and configuring servers such as `MOTD`, `LUSERS` or `MODE`.
+    ServerQueries,
+    /// Querying information about users such as `WHO`, `WHOIS` or `AWAY`.
+    UserQueries,
+    /// Commands restricted to IRC operators such as `KILL`, `REHASH` or `WALLOPS`.
+    OperatorCommands,
+    /// Commands used between linked IRC servers such as `SERVER` or `ENCAP`.
+    ServerToServer,
+    /// Commands introduced by [IRCv3] extensions such as `TAGMSG`, `BATCH` or `CHGHOST`.
+    ///
+    /// [IRCv3]: <https://ircv3.net/irc>
+    IrcV3,
+    /// A `Numeric` reply or error.
+    Numeric,
+}
+
 /// The possible types of errors when parsing [`Command`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum CommandError<'msg> {
@@ -720,6 +464,660 @@ pub enum CommandError<'msg> {
     UnhandledNamed(&'msg str),
 }
 
+// the value, minimum amount of parameters and canonical name of every supported numeric sorted by value
+const NUMERICS: [(u16, u8, &str); 506] = [
+    (1, 2, "RPL_WELCOME"),
+    (2, 2, "RPL_YOURHOST"), // RPL_YOURHOST/RPL_YOURHOSTIS
+    (3, 2, "RPL_CREATED"), // RPL_CREATED/RPL_SERVERCREATED
+    (4, 5, "RPL_MYINFO"), // RPL_MYINFO/RPL_SERVERVERSION
+    (5, 2, "RPL_ISUPPORT"), // RPL_ISUPPORT/RPL_PROTOCTL (depreciated: RPL_BOUNCE moved to 010)
+    (6, 1, "RPL_MAP"), // RPL_MAP (unreal)
+    (7, 1, "RPL_MAPEND"), // RPL_MAPEND/RPL_ENDMAP (unreal)
+    (8, 1, "RPL_SNOMASK"), // RPL_SNOMASK/RPL_SNOMASKIS (ircu)
+    (9, 1, "RPL_STATMEMTOT"), // RPL_STATMEMTOT (ircu)
+    (10, 4, "RPL_BOUNCE"), // RPL_BOUNCE/RPL_REDIR (depreciated & conflict: RPL_STATMEM (ircu))
+    (14, 1, "RPL_YOURCOOKIE"),
+    (15, 1, "RPL_MAP"), // RPL_MAP (ircu)
+    (16, 1, "RPL_MAPMORE"), // RPL_MAPMORE (ircu)
+    (17, 1, "RPL_MAPEND"), // RPL_MAPEND/RPL_ENDMAP (ircu)
+    (18, 2, "RPL_MAPUSERS"), // RPL_MAPUSERS (inspircd)
+    (20, 2, "RPL_HELLO"), // RPL_HELLO (rusnet-ircd)
+    (30, 1, "RPL_APASSWARN_SET"), // RPL_APASSWARN_SET (ircu)
+    (31, 1, "RPL_APASSWARN_SECRET"), // RPL_APASSWARN_SECRET (ircu)
+    (32, 1, "RPL_APASSWARN_CLEAR"), // RPL_APASSWARN_CLEAR (ircu)
+    (42, 1, "RPL_YOURID"), // RPL_YOURID/RPL_YOURUUID (IRCnet/inspircd)
+    (43, 3, "RPL_SAVENICK"), // RPL_SAVENICK (IRCnet)
+    (50, 1, "RPL_ATTEMPTINGJUNC"), // RPL_ATTEMPTINGJUNC (aircd)
+    (51, 1, "RPL_ATTEMPTINGREROUTE"), // RPL_ATTEMPTINGREROUTE (aircd)
+    (105, 2, "RPL_REMOTEISUPPORT"), // RPL_REMOTEISUPPORT (unreal)
+    (200, 3, "RPL_TRACELINK"), // RPL_TRACELINK (RFC1459)
+    (201, 3, "RPL_TRACECONNECTING"), // RPL_TRACECONNECTING (RFC1459)
+    (202, 3, "RPL_TRACEHANDSHAKE"), // RPL_TRACEHANDSHAKE (RFC1459)
+    (203, 2, "RPL_TRACEUNKNOWN"), // RPL_TRACEUNKNOWN (RFC1459)
+    (204, 3, "RPL_TRACEOPERATOR"), // RPL_TRACEOPERATOR (RFC1459)
+    (205, 3, "RPL_TRACEUSER"), // RPL_TRACEUSER (RFC1459)
+    (206, 5, "RPL_TRACESERVER"), // RPL_TRACESERVER (RFC1459)
+    (207, 5, "RPL_TRACESERVICE"), // RPL_TRACESERVICE (RFC2812) (conflict: RPL_TRACECAPTURED (oftc-hybrid))
+    (208, 3, "RPL_TRACENEWTYPE"), // RPL_TRACENEWTYPE (RFC1459)
+    (209, 3, "RPL_TRACECLASS"), // RPL_TRACECLASS (RFC2812)
+    (210, 1, "RPL_TRACERECONNECT"), // RPL_TRACERECONNECT (RFC2812) (conflicts: RPL_STATS (aircd) RPL_STATSHELP (unreal))
+    (211, 8, "RPL_STATSLINKINFO"), // RPL_STATSLINKINFO (RFC1459)
+    (212, 3, "RPL_STATSCOMMANDS"), // RPL_STATSCOMMANDS (RFC1459)
+    (213, 7, "RPL_STATSCLINE"), // RPL_STATSCLINE (RFC1459)
+    (214, 7, "RPL_STATSNLINE"), // RPL_STATSNLINE (RFC1459)/RPL_STATSOLDNLINE (ircu, unreal)
+    (215, 7, "RPL_STATSILINE"), // RPL_STATSILINE (RFC1459)
+    (216, 7, "RPL_STATSKLINE"), // RPL_STATSKLINE (RFC1459)
+    (217, 1, "RPL_STATSQLINE"), // RPL_STATSQLINE (RFC1459) (conflict: RPL_STATSPLINE (ircu))
+    (218, 6, "RPL_STATSYLINE"), // RPL_STATSYLINE (RFC1459)
+    (219, 3, "RPL_ENDOFSTATS"), // RPL_ENDOFSTATS (RFC1459)
+    (220, 1, "RPL_STATSPLINE"), // RPL_STATSPLINE (hybrid) (conflicts: RPL_STATSBLINE (bahamut, unreal) RPL_STATSWLINE (nefarious))
+    (221, 2, "RPL_UMODEIS"), // RPL_UMODEIS (RFC1459)
+    (222, 1, "RPL_MODLIST"), // RPL_MODLIST (conflicts: RPL_SQLINE_NICK (unreal) RPL_STATSBLINE (bahamut) RPL_STATSJLINE (ircu) RPL_CODEPAGE (rusnet-ircd))
+    (223, 1, "RPL_STATSELINE"), // RPL_STATSELINE (bahamut) (conflicts: RPL_STATSGLINE (unreal) RPL_CHARSET (rusnet-ircd))
+    (224, 1, "RPL_STATSFLINE"), // RPL_STATSFLINE (hybrid, bahamut) (conflict: RPL_STATSTLINE (unreal))
+    (225, 1, "RPL_STATSDLINE"), // RPL_STATSDLINE (hybrid) (conflicts: RPL_STATSCLONE (bahamut) RPL_STATSELINE (unreal) (depreciated: RPL_STATSZLINE (bahamut)))
+    (226, 1, "RPL_STATSCOUNT"), // RPL_STATSCOUNT (bahamut) (conflicts: RPL_STATSALINE (hybrid) RPL_STATSNLINE (unreal))
+    (227, 1, "RPL_STATSGLINE"), // RPL_STATSGLINE (bahamut) (conflicts: RPL_STATSVLINE (unreal) RPL_STATSBLINE (rizon))
+    (228, 1, "RPL_STATSQLINE"), // RPL_STATSQLINE (ircu) (conflicts: RPL_STATSBANVER (unreal) RPL_STATSCOUNT (oftc-hybrid))
+    (229, 1, "RPL_STATSSPAMF"), // RPL_STATSSPAMF (unreal)
+    (230, 1, "RPL_STATSEXCEPTTKL"), // RPL_STATSEXCEPTTKL (unreal)
+    (231, 1, "RPL_SERVICEINFO"), // RPL_SERVICEINFO (RFC1459) depreciated
+    (232, 1, "RPL_ENDOFSERVICES"), // RPL_ENDOFSERVICES (RFC1459) depreciated (conflict: RPL_RULES (unreal))
+    (233, 1, "RPL_SERVICE"), // RPL_SERVICE (RFC1459) depreciated
+    (234, 7, "RPL_SERVLIST"), // RPL_SERVLIST (RFC2812)
+    (235, 4, "RPL_SERVLISTEND"), // RPL_SERVLISTEND (RFC2812)
+    (236, 1, "RPL_STATSVERBOSE"), // RPL_STATSVERBOSE (ircu)
+    (237, 1, "RPL_STATSENGINE"), // RPL_STATSENGINE (ircu)
+    (238, 1, "RPL_STATSFLINE"), // RPL_STATSFLINE (ircu)
+    (239, 1, "RPL_STATSIAUTH"), // RPL_STATSIAUTH (IRCnet)
+    (240, 1, "RPL_STATSVLINE"), // RPL_STATSVLINE (RFC2812) (conflict: RPL_STATSXLINE (austhex))
+    (241, 6, "RPL_STATSLLINE"), // RPL_STATSLLINE (RFC1459)
+    (242, 2, "RPL_STATSUPTIME"), // RPL_STATSUPTIME (RFC1459)
+    (243, 6, "RPL_STATSOLINE"), // RPL_STATSOLINE (RFC1459)
+    (244, 5, "RPL_STATSHLINE"), // RPL_STATSHLINE (RFC1459)
+    (245, 1, "RPL_STATSSLINE"), // RPL_STATSSLINE (bahamut) (conflict: RPL_STATSTLINE (hybrid?))
+    (246, 1, "RPL_STATSPING"), // RPL_STATSPING (RFC2812) (conflicts: RPL_STATSSERVICE (hybrid) RPL_STATSTLINE (ircu) RPL_STATSULINE (bahamut))
+    (247, 1, "RPL_STATSBLINE"), // RPL_STATSBLINE (RFC2812) (conflicts: RPL_STATSXLINE (unreal) RPL_STATSGLINE (ircu))
+    (248, 1, "RPL_STATSULINE"), // RPL_STATSULINE (ircu) (conflict: RPL_STATSDEFINE (IRCnet))
+    (249, 1, "RPL_STATSDEBUG"), // RPL_STATSDEBUG (hybrid) (conflict: RPL_STATSULINE)
+    (250, 1, "RPL_STATSDLINE"), // RPL_STATSDLINE (RFC2812) (conflict: RPL_STATSCONN (ircu))
+    (251, 2, "RPL_LUSERCLIENT"), // RPL_LUSERCLIENT (RFC1459)
+    (252, 3, "RPL_LUSEROP"), // RPL_LUSEROP (RFC1459)
+    (253, 3, "RPL_LUSERUNKNOWN"), // RPL_LUSERUNKNOWN (RFC1459)
+    (254, 3, "RPL_LUSERCHANNELS"), // RPL_LUSERCHANNELS (RFC1459)
+    (255, 2, "RPL_LUSERME"), // RPL_LUSERME (RFC1459)
+    (256, 2, "RPL_ADMINME"), // RPL_ADMINME (RFC1459)
+    (257, 2, "RPL_ADMINLOC1"), // RPL_ADMINLOC1 (RFC1459)
+    (258, 2, "RPL_ADMINLOC2"), // RPL_ADMINLOC2 (RFC1459)
+    (259, 2, "RPL_ADMINEMAIL"), // RPL_ADMINEMAIL (RFC1459)
+    (261, 3, "RPL_TRACELOG"), // RPL_TRACELOG (RFC1459)
+    (262, 4, "RPL_TRACEEND"), // RPL_TRACEEND/RPL_ENDOFTRACE (RFC2812) (conflict: RPL_TRACEPING)
+    (263, 3, "RPL_TRYAGAIN"), // RPL_TRYAGAIN/RPL_LOAD2HI/RPL_LOAD_THROTTLED (RFC2812)
+    (264, 1, "RPL_USINGSSL"), // RPL_USINGSSL (rusnet-ircd)
+    (265, 2, "RPL_LOCALUSERS"), // RPL_LOCALUSERS/RPL_CURRENT_LOCAL (bahamut)
+    (266, 2, "RPL_GLOBALUSERS"), // RPL_GLOBALUSERS/RPL_CURRENT_GLOBAL (bahamut)
+    (267, 1, "RPL_START_NETSTAT"), // RPL_START_NETSTAT (aircd)
+    (268, 1, "RPL_NETSTAT"), // RPL_NETSTAT (aircd)
+    (269, 1, "RPL_END_NETSTAT"), // RPL_END_NETSTAT (aircd)
+    (270, 1, "RPL_PRIVS"), // RPL_PRIVS (ircu) (conflict & depreciated: RPL_MAPUSERS (inspircd old))
+    (271, 2, "RPL_SILELIST"), // RPL_SILELIST (ircu)
+    (272, 2, "RPL_ENDOFSILELIST"), // RPL_ENDOFSILELIST (ircu)
+    (273, 1, "RPL_NOTIFY"), // RPL_NOTIFY (aircd)
+    (274, 1, "RPL_ENDNOTIFY"), // RPL_ENDNOTIFY (aircd) (conflict: RPL_STATSDELTA (IRCnet))
+    (275, 1, "RPL_STATSDLINE"), // RPL_STATSDLINE (ircu) (conflict: RPL_USINGSSL (bahamut))
+    (276, 3, "RPL_WHOISCERTFP"), // RPL_WHOISCERTFP (oftc-hybrid) (conflicts: RPL_STATSRLINE (ircu) depreciated: RPL_VCHANEXIST (hybrid))
+    (277, 1, "RPL_VCHANLIST"), // RPL_VCHANLIST (hybrid) depreciated
+    (278, 1, "RPL_VCHANHELP"), // RPL_VCHANHELP (hybrid) depreciated
+    (280, 1, "RPL_GLIST"), // RPL_GLIST (ircu)
+    (281, 2, "RPL_ACCEPTLIST"), // RPL_ACCEPTLIST (conflict: RPL_ENDOFGLIST (ircu))
+    (282, 2, "RPL_ENDOFACCEPT"), // RPL_ENDOFACCEPT (conflict: RPL_JUPELIST (ircu))
+    (283, 1, "RPL_ALIST"), // RPL_ALIST (conflict: RPL_ENDOFJUPELIST (ircu))
+    (284, 1, "RPL_ENDOFALIST"), // RPL_ENDOFALIST (conflict: RPL_FEATURE (ircu))
+    (285, 1, "RPL_GLIST_HASH"), // RPL_GLIST_HASH (conflicts: RPL_CHANINFO_HANDLE (aircd) RPL_NEWHOSTIS (quakenet))
+    (286, 1, "RPL_CHANINFO_USERS"), // RPL_CHANINFO_USERS (aircd) (conflict: RPL_CHKHEAD (quakenet))
+    (287, 1, "RPL_CHANINFO_CHOPS"), // RPL_CHANINFO_CHOPS (aircd) (conflict: RPL_CHANUSER (quakenet))
+    (288, 1, "RPL_CHANINFO_VOICES"), // RPL_CHANINFO_VOICES (aircd) (conflict: RPL_PATCHHEAD (quakenet))
+    (289, 1, "RPL_CHANINFO_AWAY"), // RPL_CHANINFO_AWAY (aircd) (conflict: RPL_PATCHCON (quakenet))
+    (290, 1, "RPL_CHANINFO_OPERS"), // RPL_CHANINFO_OPERS (aircd) (conflicts: RPL_DATASTR (quakenet) RPL_HELPHDR (unreal))
+    (291, 1, "RPL_CHANINFO_BANNED"), // RPL_CHANINFO_BANNED (aircd) (conflicts: RPL_ENDOFCHECK (quakenet) RPL_HELPOP (unreal))
+    (292, 1, "RPL_CHANINFO_BANS"), // RPL_CHANINFO_BANS (aircd) (conflicts: RPL_HELPTLR (unreal) ERR_SEARCHNOMATCH (nefarious))
+    (293, 1, "RPL_CHANINFO_INVITE"), // RPL_CHANINFO_INVITE (aircd) (conflict: RPL_HELPHLP (unreal))
+    (294, 1, "RPL_CHANINFO_INVITES"), // RPL_CHANINFO_INVITES (aircd) (conflict: RPL_HELPFWD (unreal))
+    (295, 1, "RPL_CHANINFO_KICK"), // RPL_CHANINFO_KICK (aircd) (conflict: RPL_HELPIGN (unreal))
+    (296, 1, "RPL_CHANINFO_KICKS"), // RPL_CHANINFO_KICKS (aircd)
+    (299, 1, "RPL_END_CHANINFO"), // RPL_END_CHANINFO (aircd)
+    (300, 1, "RPL_NONE"), // RPL_NONE (RFC1459)
+    (301, 3, "RPL_AWAY"), // RPL_AWAY (RFC1459)
+    (302, 1, "RPL_USERHOST"), // RPL_USERHOST (RFC1459)
+    (303, 1, "RPL_ISON"), // RPL_ISON (RFC1459)
+    (304, 2, "RPL_TEXT"), // RPL_TEXT (unreal)
+    (305, 2, "RPL_UNAWAY"), // RPL_UNAWAY (RFC1459)
+    (306, 2, "RPL_NOWAWAY"), // RPL_NOWAWAY (RFC1459)
+    (307, 3, "RPL_USERIP"), // RPL_USERIP (conflicts: RPL_WHOISREGNICK (bahamut) RPL_SUPERHOST (austhex))
+    (308, 1, "RPL_NOTIFYACTION"), // RPL_NOTIFYACTION (aircd) (conflicts: RPL_WHOISADMIN (bahamut) RPL_RULESSTART (unreal)/RPL_RULESTART (inspircd))
+    (309, 1, "RPL_NICKTRACE"), // RPL_NICKTRACE (aircd) (conflicts: RPL_WHOISSADMIN (bahamut) RPL_ENDOFRULES (unreal)/RPL_RULESEND (inspircd) RPL_WHOISHELPER (austhex) RPL_WHOISSERVICE (oftc-hybrid))
+    (310, 1, "RPL_WHOISSVCMSG"), // RPL_WHOISSVCMSG (bahamut) (conflicts: RPL_WHOISHELPOP (unreal) RPL_WHOISSERVICE (austhex))
+    (311, 6, "RPL_WHOISUSER"), // RPL_WHOISUSER (RFC1459)
+    (312, 4, "RPL_WHOISSERVER"), // RPL_WHOISSERVER (RFC1459)
+    (313, 3, "RPL_WHOISOPERATOR"), // RPL_WHOISOPERATOR (RFC1459)
+    (314, 6, "RPL_WHOWASUSER"), // RPL_WHOWASUSER (RFC1459)
+    (315, 3, "RPL_ENDOFWHO"), // RPL_ENDOFWHO (RFC1459)
+    (316, 1, "RPL_WHOISPRIVDEAF"), // RPL_WHOISPRIVDEAF (nefarious) (conflict & depreciated: RPL_WHOISCHANOP (RFC1459))
+    (317, 5, "RPL_WHOISIDLE"), // RPL_WHOISIDLE (RFC1459)
+    (318, 3, "RPL_ENDOFWHOIS"), // RPL_ENDOFWHOIS (RFC1459)
+    (319, 3, "RPL_WHOISCHANNELS"), // RPL_WHOISCHANNELS (RFC1459)
+    (320, 3, "RPL_WHOISSPECIAL"), // RPL_WHOISSPECIAL (unreal) (conflicts: RPL_WHOIS_HIDDEN (anothernet) RPL_WHOISVIRT (austhex))
+    (321, 2, "RPL_LISTSTART"), // RPL_LISTSTART (RFC1459) depreciated
+    (322, 4, "RPL_LIST"), // RPL_LIST (RFC1459)
+    (323, 2, "RPL_LISTEND"), // RPL_LISTEND (RFC1459)
+    (324, 3, "RPL_CHANNELMODEIS"), // RPL_CHANNELMODEIS (RFC1459)
+    (325, 3, "RPL_UNIQOPIS"), // RPL_UNIQOPIS (RFC2812) (conflicts: RPL_CHANNELPASSIS RPL_WHOISWEBIRC (nefarious) depreciated: RPL_CHANNELMLOCKIS/RPL_CHANNELMLOCK (sorircd))
+    (326, 1, "RPL_NOCHANPASS"),
+    (327, 1, "RPL_CHPASSUNKNOWN"), // RPL_CHPASSUNKNOWN (conflict: RPL_WHOISHOST (rusnet-ircd))
+    (328, 1, "RPL_CHANNEL_URL"), // RPL_CHANNEL_URL (bahamut)/RPL_CHANNELURL (charybdis)
+    (329, 3, "RPL_CREATIONTIME"), // RPL_CREATIONTIME (bahamut)/RPL_CHANNELCREATED (inspircd)
+    (330, 4, "RPL_WHOISACCOUNT"), // RPL_WHOISACCOUNT (ircu)/RPL_WHOISLOGGEDIN (conflict: RPL_WHOWAS_TIME)
+    (331, 3, "RPL_NOTOPIC"), // RPL_NOTOPIC (RFC1459)/RPL_NOTOPICSET (inspircd)
+    (332, 3, "RPL_TOPIC"), // RPL_TOPIC (RFC1459)/RPL_TOPICSET (inspircd)
+    (333, 3, "RPL_TOPICWHOTIME"), // RPL_TOPICWHOTIME (ircu)/RPL_TOPICTIME (inspircd)
+    (334, 1, "RPL_LISTUSAGE"), // RPL_LISTUSAGE (ircu) (conflicts: RPL_COMMANDSYNTAX (bahamut) RPL_LISTSYNTAX (unreal))
+    (335, 3, "RPL_WHOISBOT"), // RPL_WHOISBOT (unreal) (conflicts: RPL_WHOISTEXT (hybrid) RPL_WHOISACCOUNTONLY (nefarious))
+    (336, 2, "RPL_INVITELIST"), // RPL_INVITELIST (hybrid not 346) (conflict: RPL_WHOISBOT (nefarious))
+    (337, 2, "RPL_ENDOFINVITELIST"), // RPL_ENDOFINVITELIST (hybrid not 347) (conflict: RPL_WHOISTEXT (older hybrid?))
+    (338, 3, "RPL_WHOISACTUALLY"), // RPL_WHOISACTUALLY (ircu) (conflict: RPL_CHANPASSOK)
+    (339, 1, "RPL_BADCHANPASS"), // RPL_BADCHANPASS (conflict: RPL_WHOISMARKS (nefarious))
+    (340, 2, "RPL_USERIP"), // RPL_USERIP (ircu)
+    (341, 3, "RPL_INVITING"), // RPL_INVITING (RFC1459)
+    (342, 3, "RPL_SUMMONING"), // RPL_SUMMONING (RFC1459) depreciated
+    (343, 1, "RPL_WHOISKILL"), // RPL_WHOISKILL (nefarious) (conflict: RPL_WHOISOPERNAME (snircd))
+    (344, 1, "RPL_WHOISCOUNTRY"), // RPL_WHOISCOUNTRY (inspircd) (conflicts: RPL_REOPLIST (IRCnet) RPL_QUIETLIST (oftc-hybrid))
+    (345, 1, "RPL_INVITED"), // RPL_INVITED (gamesurge)/RPL_ISSUEDINVITE (ircu) (conflicts: RPL_ENDOFREOPLIST (IRCnet) RPL_ENDOFQUIETLIST (oftc-hybrid))
+    (346, 3, "RPL_INVITELIST"), // RPL_INVITELIST (RFC2812 not 336)/RPL_INVEXLIST (hybrid)
+    (347, 3, "RPL_ENDOFINVITELIST"), // RPL_ENDOFINVITELIST (RFC2812 not 337)/RPL_ENDOFINVEXLIST (hybrid)
+    (348, 3, "RPL_EXCEPTLIST"), // RPL_EXCEPTLIST (RFC2812)/RPL_EXLIST (unreal)/RPL_EXEMPTLIST (bahamut)
+    (349, 3, "RPL_ENDOFEXCEPTLIST"), // RPL_ENDOFEXCEPTLIST (RFC2812)/RPL_ENDOFEXLIST (unreal)/RPL_ENDOFEXEMPTLIST (bahamut)
+    (350, 4, "RPL_WHOISGATEWAY"), // RPL_WHOISGATEWAY (inspircd)
+    (351, 4, "RPL_VERSION"), // RPL_VERSION (RFC1459)
+    (352, 8, "RPL_WHOREPLY"), // RPL_WHOREPLY (RFC1459)
+    (353, 4, "RPL_NAMREPLY"), // RPL_NAMREPLY (RFC1459)
+    (354, 2, "RPL_WHOSPCRPL"), // RPL_WHOSPCRPL (ircu)/RPL_RWHOREPLY (bahamut)
+    (355, 1, "RPL_NAMREPLY_"), // RPL_NAMREPLY_ (quakenet)/RPL_DELNAMREPLY (ircu)
+    (357, 1, "RPL_MAP"), // RPL_MAP (austhex)
+    (358, 1, "RPL_MAPMORE"), // RPL_MAPMORE (austhex)
+    (359, 1, "RPL_MAPEND"), // RPL_MAPEND/RPL_ENDMAP (austhex)
+    (360, 1, "RPL_WHOWASREAL"), // RPL_WHOWASREAL (charybdis) depreciated
+    (361, 1, "RPL_KILLDONE"), // RPL_KILLDONE (RFC1459)
+    (362, 1, "RPL_CLOSING"), // RPL_CLOSING (RFC1459)
+    (363, 1, "RPL_CLOSEEND"), // RPL_CLOSEEND (RFC1459)
+    (364, 4, "RPL_LINKS"), // RPL_LINKS (RFC1459)
+    (365, 3, "RPL_ENDOFLINKS"), // RPL_ENDOFLINKS (RFC1459)
+    (366, 3, "RPL_ENDOFNAMES"), // RPL_ENDOFNAMES (RFC1459)
+    (367, 3, "RPL_BANLIST"), // RPL_BANLIST (RFC1459)
+    (368, 3, "RPL_ENDOFBANLIST"), // RPL_ENDOFBANLIST (RFC1459)
+    (369, 3, "RPL_ENDOFWHOWAS"), // RPL_ENDOFWHOWAS (RFC1459)
+    (371, 2, "RPL_INFO"), // RPL_INFO (RFC1459)
+    (372, 2, "RPL_MOTD"), // RPL_MOTD (RFC1459)
+    (373, 1, "RPL_INFOSTART"), // RPL_INFOSTART (RFC1459) depreciated
+    (374, 2, "RPL_ENDOFINFO"), // RPL_ENDOFINFO (RFC1459)
+    (375, 2, "RPL_MOTDSTART"), // RPL_MOTDSTART (RFC1459)
+    (376, 2, "RPL_ENDOFMOTD"), // RPL_ENDOFMOTD (RFC1459)
+    (377, 1, "RPL_KICKEXPIRED"), // RPL_KICKEXPIRED (aircd) (conflict & deprecated: RPL_SPAM (austhex))
+    (378, 3, "RPL_BANEXPIRED"), // RPL_BANEXPIRED (aircd) (conflicts: RPL_WHOISHOST (unreal) depreciated: RPL_MOTD (austhex))
+    (379, 3, "RPL_KICKLINKED"), // RPL_KICKLINKED (aircd) (conflicts: RPL_WHOISMODES (unreal) depreciated: RPL_WHOWASIP (inspircd))
+    (380, 1, "RPL_BANLINKED"), // RPL_BANLINKED (aircd) (conflict: RPL_YOURHELPER (austhex))
+    (381, 2, "RPL_YOUREOPER"), // RPL_YOUREOPER (RFC1459)/RPL_YOUAREOPER (inspircd)
+    (382, 3, "RPL_REHASHING"), // RPL_REHASHING (RFC1459)
+    (383, 1, "RPL_YOURESERVICE"), // RPL_YOURESERVICE (RFC2812)
+    (384, 1, "RPL_MYPORTIS"), // RPL_MYPORTIS (RFC1459) depreciated
+    (385, 1, "RPL_NOTOPERANYMORE"), // RPL_NOTOPERANYMORE (austhex)
+    (386, 1, "RPL_QLIST"), // RPL_QLIST (unreal) (conflicts: RPL_IRCOPS (ultimate) RPL_IRCOPSHEADER (nefarious) depreciated: RPL_RSACHALLENGE (hybrid))
+    (387, 1, "RPL_ENDOFQLIST"), // RPL_ENDOFQLIST (unreal) (conflicts: RPL_ENDOFIRCOPS (ultimate) RPL_IRCOPS (nefarious))
+    (388, 1, "RPL_ALIST"), // RPL_ALIST (unreal) (conflict: RPL_ENDOFIRCOPS (nefarious))
+    (389, 1, "RPL_ENDOFALIST"), // RPL_ENDOFALIST (unreal)
+    (391, 3, "RPL_TIME"), // RPL_TIME (RFC1459)
+    (392, 2, "RPL_USERSSTART"), // RPL_USERSSTART (RFC1459)
+    (393, 2, "RPL_USERS"), // RPL_USERS (RFC1459)
+    (394, 2, "RPL_ENDOFUSERS"), // RPL_ENDOFUSERS (RFC1459)
+    (395, 2, "RPL_NOUSERS"), // RPL_NOUSERS (RFC1459)
+    (396, 3, "RPL_HOSTHIDDEN"), // RPL_HOSTHIDDEN (unreal)/RPL_VISIBLEHOST (hybrid)/RPL_YOURDISPLAYEDHOST (inspircd)
+    (398, 1, "RPL_STATSSLINE"), // RPL_STATSSLINE (snirc)
+    (399, 1, "RPL_USINGSLINE"), // RPL_USINGSLINE (snirc) (conflict: RPL_CLONES (inspircd))
+    (400, 3, "ERR_UNKNOWNERROR"), // ERR_UNKNOWNERROR (ergo) (conflict & depreciated: ERR_FIRSTERROR (ircu))
+    (401, 3, "ERR_NOSUCHNICK"), // ERR_NOSUCHNICK (RFC1459)
+    (402, 3, "ERR_NOSUCHSERVER"), // ERR_NOSUCHSERVER (RFC1459)
+    (403, 3, "ERR_NOSUCHCHANNEL"), // ERR_NOSUCHCHANNEL (RFC1459)
+    (404, 3, "ERR_CANNOTSENDTOCHAN"), // ERR_CANNOTSENDTOCHAN (RFC1459)
+    (405, 3, "ERR_TOOMANYCHANNELS"), // ERR_TOOMANYCHANNELS (RFC1459)
+    (406, 2, "ERR_WASNOSUCHNICK"), // ERR_WASNOSUCHNICK (RFC1459)
+    (407, 3, "ERR_TOOMANYTARGETS"), // ERR_TOOMANYTARGETS (RFC1459)
+    (408, 3, "ERR_NOSUCHSERVICE"), // ERR_NOSUCHSERVICE (RFC2812) (conflicts: ERR_NOCOLORSONCHAN (bahamut) ERR_NOCTRLSONCHAN (hybrid) ERR_SEARCHNOMATCH (snircd))
+    (409, 2, "ERR_NOORIGIN"), // ERR_NOORIGIN (RFC1459)
+    (410, 2, "ERR_INVALIDCAPCMD"), // ERR_INVALIDCAPCMD (undernet?)/ERR_INVALIDCAPSUBCOMMAND (inspircd)/ERR_UNKNOWNCAPCMD (ircu)
+    (411, 2, "ERR_NORECIPIENT"), // ERR_NORECIPIENT (RFC1459)
+    (412, 2, "ERR_NOTEXTTOSEND"), // ERR_NOTEXTTOSEND (RFC1459)
+    (413, 3, "ERR_NOTPLEVEL"), // ERR_NOTPLEVEL (RFC1459)
+    (414, 3, "ERR_WILDTOPLEVEL"), // ERR_WILDTOPLEVEL (RFC1459)
+    (415, 3, "ERR_BADMASK"), // ERR_BADMASK (RFC2812) (conflict: ERR_MSGNEEDREGGEDNICK (solanum)/ERR_CANTSENDREGONLY (oftc-hybrid))
+    (416, 3, "ERR_TOOMANYMATCHES"), // ERR_TOOMANYMATCHES (IRCnet)/ERR_QUERYTOOLONG (ircu)
+    (417, 2, "ERR_INPUTTOOLONG"), // ERR_INPUTTOOLONG (ircu)
+    (419, 1, "ERR_LENGTHTRUNCATED"), // ERR_LENGTHTRUNCATED (aircd)
+    (420, 2, "ERR_AMBIGUOUSCOMMAND"), // ERR_AMBIGUOUSCOMMAND (inspircd)
+    (421, 3, "ERR_UNKNOWNCOMMAND"), // ERR_UNKNOWNCOMMAND (RFC1459)
+    (422, 2, "ERR_NOMOTD"), // ERR_NOMOTD (RFC1459)
+    (423, 3, "ERR_NOADMININFO"), // ERR_NOADMININFO (RFC1459)
+    (424, 2, "ERR_FILEERROR"), // ERR_FILEERROR (RFC1459)
+    (425, 1, "ERR_NOOPERMOTD"), // ERR_NOOPERMOTD (unreal)
+    (429, 1, "ERR_TOOMANYAWAY"), // ERR_TOOMANYAWAY (bahamut)
+    (430, 1, "ERR_EVENTNICKCHANGE"), // ERR_EVENTNICKCHANGE (austhex)
+    (431, 2, "ERR_NONICKNAMEGIVEN"), // ERR_NONICKNAMEGIVEN (RFC1459)
+    (432, 3, "ERR_ERRONEUSNICKNAME"), // ERR_ERRONEUSNICKNAME (RFC1459)
+    (433, 3, "ERR_NICKNAMEINUSE"), // ERR_NICKNAMEINUSE (RFC1459)
+    (434, 1, "ERR_SERVICENAMEINUSE"), // ERR_SERVICENAMEINUSE (austhex) (conflicts: ERR_NORULES (unreal) ERR_NONICKWHILEBAN (oftc-hybrid))
+    (435, 1, "ERR_SERVICECONFUSED"), // ERR_SERVICECONFUSED (unreal) (conflict: ERR_BANONCHAN (bahamut)/ERR_BANNICKCHANGE (ratbox) depreciated: ERR_NICKONBAN (oftc-hybrid))
+    (436, 2, "ERR_NICKCOLLISION"), // ERR_NICKCOLLISION (RFC1459)
+    (437, 3, "ERR_UNAVAILRESOURCE"), // ERR_UNAVAILRESOURCE (RFC2812) (conflict: ERR_BANNICKCHANGE (ircu))
+    (438, 1, "ERR_NICKTOOFAST"), // ERR_NICKTOOFAST (ircu)/ERR_NCHANGETOOFAST (unreal) (conflict: ERR_DEAD (IRCnet))
+    (439, 1, "ERR_TARGETTOOFAST"), // ERR_TARGETTOOFAST (ircu)/ERR_TARGETTOFAST/RPL_INVTOOFAST/RPL_MSGTOOFAST
+    (440, 1, "ERR_SERVICESDOWN"), // ERR_SERVICESDOWN (bahamut)/ERR_REG_UNAVAILABLE (ergo)
+    (441, 4, "ERR_USERNOTINCHANNEL"), // ERR_USERNOTINCHANNEL (RFC1459)
+    (442, 3, "ERR_NOTONCHANNEL"), // ERR_NOTONCHANNEL (RFC1459)
+    (443, 4, "ERR_USERONCHANNEL"), // ERR_USERONCHANNEL (RFC1459)
+    (444, 3, "ERR_NOLOGIN"), // ERR_NOLOGIN (RFC1459)
+    (445, 2, "ERR_SUMMONDISABLED"), // ERR_SUMMONDISABLED (RFC1459)
+    (446, 2, "ERR_USERSDISABLED"), // ERR_USERSDISABLED (RFC1459)
+    (447, 1, "ERR_NONICKCHANGE"), // ERR_NONICKCHANGE (unreal)/ERR_CANTCHANGENICK (inspircd)
+    (448, 2, "ERR_FORBIDDENCHANNEL"), // ERR_FORBIDDENCHANNEL (unreal)
+    (449, 1, "ERR_NOTIMPLEMENTED"), // ERR_NOTIMPLEMENTED (undernet)
+    (451, 2, "ERR_NOTREGISTERED"), // ERR_NOTREGISTERED (RFC1459)
+    (452, 1, "ERR_IDCOLLISION"),
+    (453, 1, "ERR_NICKLOST"),
+    (455, 1, "ERR_HOSTILENAME"), // ERR_HOSTILENAME (unreal)
+    (456, 2, "ERR_ACCEPTFULL"),
+    (457, 3, "ERR_ACCEPTEXIST"),
+    (458, 3, "ERR_ACCEPTNOT"),
+    (459, 1, "ERR_NOHIDING"), // ERR_NOHIDING (unreal)
+    (460, 1, "ERR_NOTFORHALFOPS"), // ERR_NOTFORHALFOPS (unreal)
+    (461, 3, "ERR_NEEDMOREPARAMS"), // ERR_NEEDMOREPARAMS (RFC1459)
+    (462, 2, "ERR_ALREADYREGISTERED"), // ERR_ALREADYREGISTERED (RFC1459)/ERR_ALREADYREGISTRED
+    (463, 2, "ERR_NOPERMFORHOST"), // ERR_NOPERMFORHOST (RFC1459)
+    (464, 2, "ERR_PASSWDMISMATCH"), // ERR_PASSWDMISMATCH (RFC1459)
+    (465, 2, "ERR_YOUREBANNEDCREEP"), // ERR_YOUREBANNEDCREEP (RFC1459)
+    (466, 1, "ERR_YOUWILLBEBANNED"), // ERR_YOUWILLBEBANNED (RFC1459) depreciated
+    (467, 3, "ERR_KEYSET"), // ERR_KEYSET (RFC1459)
+    (468, 1, "ERR_INVALIDUSERNAME"), // ERR_INVALIDUSERNAME (ircu) (conflicts: ERR_ONLYSERVERSCANCHANGE (bahamut) ERR_NOCODEPAGE (rusnet-ircd))
+    (469, 1, "ERR_LINKSET"), // ERR_LINKSET (unreal)
+    (470, 1, "ERR_LINKCHANNEL"), // ERR_LINKCHANNEL (unreal) (conflicts: ERR_KICKEDFROMCHAN (aircd) ERR_7BIT (rusnet-ircd))
+    (471, 3, "ERR_CHANNELISFULL"), // ERR_CHANNELISFULL (RFC1459)
+    (472, 3, "ERR_UNKNOWNMODE"), // ERR_UNKNOWNMODE (RFC1459)
+    (473, 3, "ERR_INVITEONLYCHAN"), // ERR_INVITEONLYCHAN (RFC1459)
+    (474, 3, "ERR_BANNEDFROMCHAN"), // ERR_BANNEDFROMCHAN (RFC1459)
+    (475, 3, "ERR_BADCHANNELKEY"), // ERR_BADCHANNELKEY (RFC1459)
+    (476, 3, "ERR_BADCHANMASK"), // ERR_BADCHANMASK (RFC2812) (conflict: ERR_OPERONLYCHAN (plexus))
+    (477, 3, "ERR_NOCHANMODES"), // ERR_NOCHANMODES (RFC2812)/ERR_MODELESS (conflict: ERR_NEEDREGGEDNICK (bahamut)/ERR_REGONLYCHAN (oftc-hybrid))
+    (478, 3, "ERR_BANLISTFULL"), // ERR_BANLISTFULL (RFC2812)
+    (479, 1, "ERR_BADCHANNAME"), // ERR_BADCHANNAME (hybrid) (conflicts: ERR_LINKFAIL (unreal) ERR_NOCOLOR (rusnet-ircd))
+    (480, 1, "ERR_NOULINE"), // ERR_NOULINE (austhex) (conflicts: ERR_CANNOTKNOCK (unreal) ERR_THROTTLE (ratbox)/ERR_NEEDTOWAIT (bahamut) ERR_NOWALLOP (rusnet-ircd) ERR_SSLONLYCHAN (oftc-hybrid))
+    (481, 2, "ERR_NOPRIVILEGES"), // ERR_NOPRIVILEGES (RFC1459)
+    (482, 3, "ERR_CHANOPRIVSNEEDED"), // ERR_CHANOPRIVSNEEDED (RFC1459)
+    (483, 2, "ERR_CANTKILLSERVER"), // ERR_CANTKILLSERVER (RFC1459)/ERR_KILLDENY (unreal)
+    (484, 2, "ERR_RESTRICTED"), // ERR_RESTRICTED (RFC2812) (conflicts: ERR_ISCHANSERVICE (undernet) ERR_DESYNC (bahamut) ERR_ATTACKDENY (unreal))
+    (485, 2, "ERR_UNIQOPRIVSNEEDED"), // ERR_UNIQOPRIVSNEEDED (RFC2812) (conflicts: ERR_KILLDENY (unreal) ERR_CANTKICKADMIN (PTlink) ERR_ISREALSERVICE (quakenet) ERR_CHANBANREASON (hybrid) depreciated: ERR_BANNEDNICK (ratbox))
+    (486, 1, "ERR_NONONREG"), // ERR_NONONREG (unreal)/ERR_ACCOUNTONLY (conflicts: ERR_RLINED (rusnet-ircd) depreciated: ERR_HTMDISABLED (unreal))
+    (487, 1, "ERR_CHANTOORECENT"), // ERR_CHANTOORECENT (IRCnet) (conflicts: ERR_MSGSERVICES (bahamut) ERR_NOTFORUSERS (unreal) ERR_NONONSSL (ChatIRCd))
+    (488, 1, "ERR_TSLESSCHAN"), // ERR_TSLESSCHAN (IRCnet) (conflicts: ERR_HTMDISABLED (unreal) ERR_NOSSL (bahamut))
+    (489, 1, "ERR_SECUREONLYCHAN"), // ERR_SECUREONLYCHAN (unreal)/ERR_SSLONLYCHAN (conflict: ERR_VOICENEEDED (undernet))
+    (490, 1, "ERR_ALLMUSTSSL"), // ERR_ALLMUSTSSL (inspIRCd) (conflicts: ERR_NOSWEAR (unreal) ERR_MAXMSGSENT (bahamut))
+    (491, 2, "ERR_NOOPERHOST"), // ERR_NOOPERHOST (RFC1459)
+    (492, 1, "ERR_NOSERVICEHOST"), // ERR_NOSERVICEHOST (RFC1459) depreciated (conflicts: ERR_NOCTCP (hybrid)/ERR_NOCTCPALLOWED (inspIRCd) ERR_CANNOTSENDTOUSER (charybdis))
+    (493, 1, "ERR_NOSHAREDCHAN"), // ERR_NOSHAREDCHAN (bahamut) (conflict: ERR_NOFEATURE (ircu))
+    (494, 1, "ERR_BADFEATVALUE"), // ERR_BADFEATVALUE (ircu) (conflict: ERR_OWNMODE (bahamut) ERR_INVITEREMOVED (inspIRCd))
+    (495, 1, "ERR_BADLOGTYPE"), // ERR_BADLOGTYPE (ircu) (conflict & depreciated: ERR_DELAYREJOIN (inspIRCd))
+    (496, 1, "ERR_BADLOGSYS"), // ERR_BADLOGSYS (ircu)
+    (497, 1, "ERR_BADLOGVALUE"), // ERR_BADLOGVALUE (ircu)
+    (498, 1, "ERR_ISOPERLCHAN"), // ERR_ISOPERLCHAN (ircu)
+    (499, 1, "ERR_CHANOWNPRIVNEEDED"), // ERR_CHANOWNPRIVNEEDED (unreal)
+    (500, 1, "ERR_TOOMANYJOINS"), // ERR_TOOMANYJOINS (unreal) (conflicts: ERR_NOREHASHPARAM (rusnet-ircd) ERR_CANNOTSETMODDER (inspIRCd))
+    (501, 2, "ERR_UMODEUNKNOWNFLAG"), // ERR_UMODEUNKNOWNFLAG (RFC1459) (conflict: ERR_UNKNOWNSNOMASK (inspIRCd))
+    (502, 2, "ERR_USERSDONTMATCH"), // ERR_USERSDONTMATCH (RFC1459)
+    (503, 2, "ERR_GHOSTEDCLIENT"), // ERR_GHOSTEDCLIENT (hybrid) depreciated (conflict & depreciated: ERR_VWORLDWARN (austhex))
+    (504, 1, "ERR_USERNOTONSERV"), // ERR_USERNOTONSERV (conflict: ERR_LAST_ERR_MSG (bahamut))
+    (505, 1, "ERR_NOTINVITED"), // ERR_NOTINVITED (inspIRCd) (conflict & depreciated: ERR_LAST_ERR_MSG (oftc-hybrid))
+    (511, 2, "ERR_SILELISTFULL"), // ERR_SILELISTFULL (ircu)
+    (512, 1, "ERR_TOOMANYWATCH"), // ERR_TOOMANYWATCH (bahamut)/ERR_NOTIFYFULL (aircd) (conflicts: ERR_NOSUCHGLINE (ircu) depreciated: ERR_NEEDPONG (oftc-hybrid))
+    (513, 1, "ERR_BADPING"), // ERR_BADPING (ircu)/ERR_WRONGPONG (charybdis)/ERR_NEEDPONG (ultimate)
+    (514, 1, "ERR_TOOMANYDCC"), // ERR_TOOMANYDCC (bahamut) (conflicts: ERR_NOSUCHJUPE (ircu) depreciated: ERR_INVALID_ERROR (ircu))
+    (515, 1, "ERR_BADEXPIRE"), // ERR_BADEXPIRE (ircu)
+    (516, 1, "ERR_DONTCHEAT"), // ERR_DONTCHEAT (ircu)
+    (517, 3, "ERR_DISABLED"), // ERR_DISABLED (ircu)
+    (518, 1, "ERR_NOINVITE"), // ERR_NOINVITE (unreal) (conflict: ERR_LONGMASK (ircu))
+    (519, 1, "ERR_ADMONLY"), // ERR_ADMONLY (unreal) (conflict: ERR_TOOMANYUSERS (ircu))
+    (520, 1, "ERR_OPERONLY"), // ERR_OPERONLY (unreal)/ERR_OPERONLYCHAN (hybrid)/ERR_CANTJOINOPERSONLY (inspIRCd) (conflicts: ERR_MASKTOOWIDE (ircu) depreciated: ERR_WHOTRUNC (austhex))
+    (521, 1, "ERR_LISTSYNTAX"), // ERR_LISTSYNTAX (bahamut) (conflict: ERR_NOSUCHGLINE (nefarious))
+    (522, 1, "ERR_WHOSYNTAX"), // ERR_WHOSYNTAX (bahamut)
+    (523, 2, "ERR_WHOLIMEXCEED"), // ERR_WHOLIMEXCEED (bahamut)
+    (524, 1, "ERR_QUARANTINED"), // ERR_QUARANTINED (ircu) (conflicts: ERR_OPERSVERIFY (unreal) ERR_HELPNOTFOUND (hybrid))
+    (525, 1, "ERR_INVALIDKEY"), // ERR_INVALIDKEY (ircu) (conflict & depreciated: ERR_REMOTEPFX)
+    (526, 2, "ERR_PFXUNROUTABLE"), // ERR_PFXUNROUTABLE depreciated
+    (530, 1, "ERR_BADHOSTMASK"), // ERR_BADHOSTMASK (snircd)
+    (531, 3, "ERR_CANTSENDTOUSER"), // ERR_CANTSENDTOUSER (inspIRCd)/ERR_HOSTUNAVAIL (snircd)
+    (550, 1, "ERR_BADHOSTMASK"), // ERR_BADHOSTMASK (quakenet)
+    (551, 1, "ERR_HOSTUNAVAIL"), // ERR_HOSTUNAVAIL (quakenet)
+    (552, 1, "ERR_USINGSLINE"), // ERR_USINGSLINE (quakenet)
+    (553, 1, "ERR_STATSSLINE"), // ERR_STATSSLINE (quakenet)
+    (560, 1, "ERR_NOTLOWEROPLEVEL"), // ERR_NOTLOWEROPLEVEL (ircu)
+    (561, 1, "ERR_NOTMANAGER"), // ERR_NOTMANAGER (ircu)
+    (562, 1, "ERR_CHANSECURED"), // ERR_CHANSECURED (ircu)
+    (563, 1, "ERR_UPASSSET"), // ERR_UPASSSET (ircu)
+    (564, 1, "ERR_UPASSNOTSET"), // ERR_UPASSNOTSET (ircu)
+    (565, 1, "ERR_NOMANAGER_LONG"), // ERR_NOMANAGER_LONG (ircu) depreciated
+    (566, 1, "ERR_NOMANAGER"), // ERR_NOMANAGER (ircu)
+    (567, 1, "ERR_UPASS_SAME_APASS"), // ERR_UPASS_SAME_APASS (ircu)
+    (568, 1, "ERR_LASTERROR"), // ERR_LASTERROR (ircu) (conflict: RPL_NOOMOTD (nefarious))
+    (569, 4, "RPL_WHOISASN"), // RPL_WHOISASN (inspIRCd)
+    (573, 1, "ERR_CANNOTSENDRP"), // ERR_CANNOTSENDRP (ergo)
+    (597, 1, "RPL_REAWAY"), // RPL_REAWAY (unreal)
+    (598, 5, "RPL_GONEAWAY"), // RPL_GONEAWAY (unreal)
+    (599, 5, "RPL_NOTAWAY"), // RPL_NOTAWAY (unreal)
+    (600, 5, "RPL_LOGON"), // RPL_LOGON (unreal)
+    (601, 5, "RPL_LOGOFF"), // RPL_LOGOFF (unreal)
+    (602, 5, "RPL_WATCHOFF"), // RPL_WATCHOFF (unreal)
+    (603, 1, "RPL_WATCHSTAT"), // RPL_WATCHSTAT (unreal)
+    (604, 5, "RPL_NOWON"), // RPL_NOWON (unreal)
+    (605, 5, "RPL_NOWOFF"), // RPL_NOWOFF (unreal)
+    (606, 1, "RPL_WATCHLIST"), // RPL_WATCHLIST (unreal)
+    (607, 1, "RPL_ENDOFWATCHLIST"), // RPL_ENDOFWATCHLIST (unreal)
+    (608, 1, "RPL_WATCHCLEAR"), // RPL_WATCHCLEAR (ultimate)/RPL_CLEARWATCH (unreal)
+    (609, 5, "RPL_NOWISAWAY"), // RPL_NOWISAWAY (unreal)
+    (610, 1, "RPL_MAPMORE"), // RPL_MAPMORE (unreal) (conflict: RPL_ISOPER (ultimate))
+    (611, 1, "RPL_ISLOCOP"), // RPL_ISLOCOP (ultimate)
+    (612, 1, "RPL_ISNOTOPER"), // RPL_ISNOTOPER (ultimate)
+    (613, 1, "RPL_ENDOFISOPER"), // RPL_ENDOFISOPER (ultimate)
+    (615, 1, "RPL_MAPMORE"), // RPL_MAPMORE (ptlink) (conflict: RPL_WHOISMODES (ultimate))
+    (616, 1, "RPL_WHOISHOST"), // RPL_WHOISHOST (ultimate)
+    (617, 1, "RPL_WHOISSSLFP"), // RPL_WHOISSSLFP (nefarious) (conflicts: RPL_DCCSTATUS (bahamut) RPL_WHOISBOT (ultimate))
+    (618, 1, "RPL_DCCLIST"), // RPL_DCCLIST (bahamut)
+    (619, 1, "RPL_ENDOFDCCLIST"), // RPL_ENDOFDCCLIST (bahamut) (conflict: RPL_WHOWASHOST (ultimate))
+    (620, 1, "RPL_DCCINFO"), // RPL_DCCINFO (bahamut) (conflict: RPL_RULESSTART (ultimate))
+    (621, 1, "RPL_RULES"), // RPL_RULES (ultimate)
+    (622, 1, "RPL_ENDOFRULES"), // RPL_ENDOFRULES (ultimate)
+    (623, 1, "RPL_MAPMORE"), // RPL_MAPMORE (ultimate)
+    (624, 1, "RPL_OMOTDSTART"), // RPL_OMOTDSTART (ultimate)
+    (625, 1, "RPL_OMOTD"), // RPL_OMOTD (ultimate)
+    (626, 1, "RPL_ENDOFOMOTD"), // RPL_ENDOFOMOTD (ultimate)
+    (630, 1, "RPL_SETTINGS"), // RPL_SETTINGS (ultimate)
+    (631, 1, "RPL_ENDOFSETTINGS"), // RPL_ENDOFSETTINGS (ultimate)
+    (640, 1, "RPL_DUMPING"), // RPL_DUMPING (unreal) depreciated
+    (641, 1, "RPL_DUMPRPL"), // RPL_DUMPRPL (unreal) depreciated
+    (642, 1, "RPL_EODUMP"), // RPL_EODUMP (unreal) depreciated
+    (650, 3, "RPL_SYNTAX"), // RPL_SYNTAX (inspIRCd)
+    (651, 3, "RPL_CHANNELMSG"), // RPL_CHANNELMSG (inspIRCd)
+    (652, 3, "RPL_WHOWASIP"), // RPL_WHOWASIP (inspIRCd)
+    (653, 2, "RPL_UNINVITED"), // RPL_UNINVITED (inspIRCd)
+    (659, 3, "RPL_SPAMCMDFWD"), // RPL_SPAMCMDFWD (unreal)
+    (670, 2, "RPL_STARTTLS"), // RPL_STARTTLS (IRCv3)
+    (671, 3, "RPL_WHOISSECURE"), // RPL_WHOISSECURE (unreal)/RPL_WHOISSSL (nefarious)
+    (672, 2, "RPL_UNKNOWNMODES"), // RPL_UNKNOWNMODES (ithildin) (conflict: RPL_WHOISREALIP (rizon)/RPL_WHOISCGI (plexus))
+    (673, 2, "RPL_CANNOTSETMODES"), // RPL_CANNOTSETMODES (ithildin)
+    (674, 2, "RPL_WHOISYOURID"), // RPL_WHOISYOURID (ChatIRCd)
+    (687, 1, "RPL_YOURLANGUAGESARE"), // RPL_YOURLANGUAGESARE (ergo)
+    (690, 2, "ERR_REDIRECT"), // ERR_REDIRECT (inspIRCd)
+    (691, 2, "ERR_STARTTLS"), // ERR_STARTTLS (IRCv3)
+    (696, 5, "ERR_INVALIDMODEPARAM"), // ERR_INVALIDMODEPARAM (inspIRCd)
+    (697, 5, "ERR_LISTMODEALREADYSET"), // ERR_LISTMODEALREADYSET (inspIRCd)
+    (698, 5, "ERR_LISTMODENOTSET"), // ERR_LISTMODENOTSET (inspIRCd)
+    (700, 2, "RPL_COMMANDS"), // RPL_COMMANDS (inspIRCd)
+    (701, 2, "RPL_COMMANDSEND"), // RPL_COMMANDSEND (inspIRCd)
+    (702, 2, "RPL_MODLIST"), // RPL_MODLIST (ratbox) (conflict & depreciated: RPL_COMMANDS (inspIRCd))
+    (703, 2, "RPL_ENDOFMODLIST"), // RPL_ENDOFMODLIST (ratbox) (conflict & depreciated: RPL_COMMANDSEND (inspIRCd))
+    (704, 3, "RPL_HELPSTART"), // RPL_HELPSTART (ratbox)
+    (705, 3, "RPL_HELPTXT"), // RPL_HELPTXT (ratbox)
+    (706, 3, "RPL_ENDOFHELP"), // RPL_ENDOFHELP (ratbox)
+    (707, 3, "ERR_TARGCHANGE"), // ERR_TARGCHANGE (ratbox)
+    (708, 8, "RPL_ETRACEFULL"), // RPL_ETRACEFULL (ratbox)
+    (709, 7, "RPL_ETRACE"), // RPL_ETRACE (ratbox)
+    (710, 3, "RPL_KNOCK"), // RPL_KNOCK (ratbox)
+    (711, 3, "RPL_KNOCKDLVR"), // RPL_KNOCKDLVR (ratbox)
+    (712, 3, "ERR_TOOMANYKNOCK"), // ERR_TOOMANYKNOCK (ratbox)
+    (713, 3, "ERR_CHANOPEN"), // ERR_CHANOPEN (ratbox)
+    (714, 3, "ERR_KNOCKONCHAN"), // ERR_KNOCKONCHAN (ratbox)
+    (715, 2, "ERR_KNOCKDISABLED"), // ERR_KNOCKDISABLED (ratbox) (conflicts: ERR_TOOMANYINVITE (hybrid) RPL_INVITETHROTTLE (rizon))
+    (716, 2, "RPL_TARGUMODEG"), // RPL_TARGUMODEG (ratbox)/ERR_TARGUMODEG
+    (717, 2, "RPL_TARGNOTIFY"), // RPL_TARGNOTIFY (ratbox)
+    (718, 3, "RPL_UMODEGMSG"), // RPL_UMODEGMSG (ratbox)
+    (720, 2, "RPL_OMOTDSTART"), // RPL_OMOTDSTART (ratbox)
+    (721, 2, "RPL_OMOTD"), // RPL_OMOTD (ratbox)
+    (722, 2, "RPL_ENDOFOMOTD"), // RPL_ENDOFOMOTD (ratbox)
+    (723, 3, "ERR_NOPRIVS"), // ERR_NOPRIVS (ratbox)
+    (724, 5, "RPL_TESTMASK"), // RPL_TESTMASK (ratbox)
+    (725, 5, "RPL_TESTLINE"), // RPL_TESTLINE (ratbox)
+    (726, 3, "RPL_NOTESTLINE"), // RPL_NOTESTLINE (ratbox)
+    (727, 1, "RPL_TESTMASKGECOS"), // RPL_TESTMASKGECOS (ratbox) (conflict: RPL_ISCAPTURED (oftc-hybrid))
+    (728, 1, "RPL_QUIETLIST"), // RPL_QUIETLIST (charybdis) (conflict: RPL_ISUNCAPTURED (ofc-hybrid))
+    (729, 4, "RPL_ENDOFQUIETLIST"), // RPL_ENDOFQUIETLIST (charybdis)
+    (730, 2, "RPL_MONONLINE"), // RPL_MONONLINE (ratbox)
+    (731, 2, "RPL_MONOFFLINE"), // RPL_MONOFFLINE (ratbox)
+    (732, 2, "RPL_MONLIST"), // RPL_MONLIST (ratbox)
+    (733, 2, "RPL_ENDOFMONLIST"), // RPL_ENDOFMONLIST (ratbox)
+    (734, 4, "ERR_MONLISTFULL"), // ERR_MONLISTFULL (ratbox)
+    (740, 2, "RPL_RSACHALLENGE2"), // RPL_RSACHALLENGE2 (ratbox)
+    (741, 2, "RPL_ENDOFRSACHALLENGE2"), // RPL_ENDOFRSACHALLENGE2 (ratbox)
+    (742, 4, "ERR_MLOCKRESTRICTED"), // ERR_MLOCKRESTRICTED (charybdis)
+    (743, 4, "ERR_INVALIDBAN"), // ERR_INVALIDBAN (charybdis)
+    (744, 1, "ERR_TOPICLOCK"), // ERR_TOPICLOCK (inspIRCd)
+    (750, 2, "RPL_SCANMATCHED"), // RPL_SCANMATCHED (ratbox)
+    (751, 7, "RPL_SCANUMODES"), // RPL_SCANUMODES (ratbox)
+    (759, 2, "RPL_ETRACEEND"), // RPL_ETRACEEND (irc2.11)
+    (760, 4, "RPL_WHOISKEYVALUE"), // RPL_WHOISKEYVALUE (IRCv3)
+    (761, 3, "RPL_KEYVALUE"), // RPL_KEYVALUE (IRCv3)
+    (762, 1, "RPL_METADATAEND"), // RPL_METADATAEND (IRCv3)
+    (764, 2, "ERR_METADATALIMIT"), // ERR_METADATALIMIT (IRCv3)
+    (765, 2, "ERR_TARGETINVALID"), // ERR_TARGETINVALID (IRCv3)
+    (766, 3, "ERR_NOMATCHINGKEY"), // ERR_NOMATCHINGKEY (IRCv3)
+    (767, 2, "ERR_KEYINVALID"), // ERR_KEYINVALID (IRCv3)
+    (768, 3, "ERR_KEYNOTSET"), // ERR_KEYNOTSET (IRCv3)
+    (769, 3, "ERR_KEYNOPERMISSION"), // ERR_KEYNOPERMISSION (IRCv3)
+    (771, 1, "RPL_XINFO"), // RPL_XINFO (ithildin)
+    (773, 1, "RPL_XINFOSTART"), // RPL_XINFOSTART (ithildin)
+    (774, 1, "RPL_XINFOEND"), // RPL_XINFOEND (ithildin)
+    (801, 3, "RPL_STATSCOUNTRY"), // RPL_STATSCOUNTRY (inspIRCd)
+    (802, 1, "RPL_CHECK"), // RPL_CHECK (inspIRCd)
+    (803, 4, "RPL_OTHERUMODEIS"), // RPL_OTHERUMODEIS (inspIRCd)
+    (804, 4, "RPL_OTHERSNOMASKIS"), // RPL_OTHERSNOMASKIS (inspIRCd)
+    (900, 4, "RPL_LOGGEDIN"), // RPL_LOGGEDIN (IRCv3)
+    (901, 3, "RPL_LOGGEDOUT"), // RPL_LOGGEDOUT (IRCv3)
+    (902, 2, "ERR_NICKLOCKED"), // ERR_NICKLOCKED (IRCv3)
+    (903, 2, "RPL_SASLSUCCESS"), // RPL_SASLSUCCESS (IRCv3)
+    (904, 2, "ERR_SASLFAIL"), // ERR_SASLFAIL (IRCv3)
+    (905, 2, "ERR_SASLTOOLONG"), // ERR_SASLTOOLONG (IRCv3)
+    (906, 2, "ERR_SASLABORTED"), // ERR_SASLABORTED (IRCv3)
+    (907, 2, "ERR_SASLALREADY"), // ERR_SASLALREADY (IRCv3)
+    (908, 3, "RPL_SASLMECHS"), // RPL_SASLMECHS (IRCv3)
+    (910, 3, "RPL_ACCESSLIST"), // RPL_ACCESSLIST (inspIRCd)
+    (911, 3, "RPL_ENDOFACCESSLIST"), // RPL_ENDOFACCESSLIST (inspIRCd)
+    (926, 3, "ERR_BADCHANNEL"), // ERR_BADCHANNEL (inspIRCd)
+    (936, 4, "ERR_WORDFILTERED"), // ERR_WORDFILTERED (inspIRCd) depreciated
+    (937, 3, "ERR_ALREADYCHANFILTERED"), // ERR_ALREADYCHANFILTERED (inspIRCd) depreciated
+    (938, 3, "ERR_NOSUCHCHANFILTER"), // ERR_NOSUCHCHANFILTER (inspIRCd) depreciated
+    (939, 3, "ERR_CHANFILTERFULL"), // ERR_CHANFILTERFULL (inspIRCd) depreciated
+    (940, 3, "RPL_ENDOFSPAMFILTER"), // RPL_ENDOFSPAMFILTER (inspIRCd)
+    (941, 5, "RPL_SPAMFILTER"), // RPL_SPAMFILTER (inspIRCd)
+    (942, 3, "ERR_INVALIDWATCHNICK"), // ERR_INVALIDWATCHNICK (inspIRCd)
+    (944, 2, "RPL_IDLETIMESET"), // RPL_IDLETIMESET (inspIRCd)
+    (945, 3, "RPL_NICKLOCKOFF"), // RPL_NICKLOCKOFF (inspIRCd)
+    (946, 3, "ERR_NICKNOTLOCKED"), // ERR_NICKNOTLOCKED (inspIRCd)
+    (947, 3, "RPL_NICKLOCKON"), // RPL_NICKLOCKON (inspIRCd)
+    (948, 2, "ERR_INVALIDIDLETIME"), // ERR_INVALIDIDLETIME (inspIRCd)
+    (950, 3, "RPL_UNSILENCED"), // RPL_UNSILENCED (inspIRCd)
+    (951, 3, "RPL_SILENCED"), // RPL_SILENCED (inspIRCd)
+    (952, 3, "ERR_SILENCE"), // ERR_SILENCE (inspIRCd)
+    (953, 3, "RPL_ENDOFEXEMPTIONLIST"), // RPL_ENDOFEXEMPTIONLIST (inspIRCd)
+    (954, 5, "RPL_EXEMPTIONLIST"), // RPL_EXEMPTIONLIST (inspIRCd)
+    (960, 3, "RPL_ENDOFPROPLIST"), // RPL_ENDOFPROPLIST (inspIRCd)
+    (961, 2, "RPL_PROPLIST"), // RPL_PROPLIST (inspIRCd)
+    (972, 3, "ERR_CANNOTDOCOMMAND"), // ERR_CANNOTDOCOMMAND (unreal) (conflict: ERR_CANTUNLOADMODULE (inspIRCd))
+    (973, 3, "RPL_UNLOADEDMODULE"), // RPL_UNLOADEDMODULE (inspIRCd)
+    (974, 3, "RPL_CANNOTCHANGECHANMODE"), // RPL_CANNOTCHANGECHANMODE (unreal) (conflict: ERR_CANTLOADMODULE (inspIRCd))
+    (975, 1, "RPL_LOADEDMODULE"), // RPL_LOADEDMODULE (inspIRCd) (conflict: ERR_LASTERROR (nefarious))
+    (981, 1, "ERR_TOOMANYLANGUAGES"), // ERR_TOOMANYLANGUAGES (ergo)
+    (982, 1, "ERR_NOLANGUAGE"), // ERR_NOLANGUAGE (ergo)
+    (988, 3, "RPL_SERVLOCKON"), // RPL_SERVLOCKON (inspIRCd)
+    (989, 3, "RPL_SERVLOCKOFF"), // RPL_SERVLOCKOFF (inspIRCd)
+    (990, 2, "RPL_DCCALLOWSTART"), // RPL_DCCALLOWSTART (inspIRCd)
+    (991, 3, "RPL_DCCALLOWLIST"), // RPL_DCCALLOWLIST (inspIRCd)
+    (992, 2, "RPL_DCCALLOWEND"), // RPL_DCCALLOWEND (inspIRCd)
+    (993, 3, "RPL_DCCALLOWTIMED"), // RPL_DCCALLOWTIMED (inspIRCd)
+    (994, 3, "RPL_DCCALLOWPERMANENT"), // RPL_DCCALLOWPERMANENT (inspIRCd)
+    (995, 3, "RPL_DCCALLOWREMOVED"), // RPL_DCCALLOWREMOVED (inspIRCd)
+    (996, 3, "ERR_DCCALLOWINVALID"), // ERR_DCCALLOWINVALID (inspIRCd)
+    (997, 3, "RPL_DCCALLOWEXPIRED"), // RPL_DCCALLOWEXPIRED (inspIRCd)
+    (998, 2, "ERR_UNKNOWNDCCALLOWCMD"), // ERR_UNKNOWNDCCALLOWCMD (inspIRCd) (depreciated: RPL_DCCALLOWHELP (inspIRCd))
+    (999, 1, "ERR_NUMERIC_ERR"), // ERR_NUMERIC_ERR (bahamut)/ERR_NUMERICERR/ERR_LAST_ERR_MSG (depreciated: RPL_ENDOFDCCALLOWHELP (inspIRCd))
+];
+
+// the minimum amount of parameters of every numeric indexed by its value where 0 means unsupported
+const NUMERIC_MIN_PARAMS: [u8; 1000] = {
+    let mut table = [0; 1000];
+    let mut index = 0;
+    while index < NUMERICS.len() {
+        let (numeric, min, _) = NUMERICS[index];
+        table[numeric as usize] = min;
+        index += 1;
+    }
+    table
+};
+
+const fn numeric_min_params(input: &[u8]) -> Option<u8> {
+    match numeric_value(input) {
+        Some(value) if NUMERIC_MIN_PARAMS[value as usize] > 0 => Some(NUMERIC_MIN_PARAMS[value as usize]),
+        _ => None,
+    }
+}
+
+// the name, kind and minimum amount of parameters of every supported named command sorted by name
+const NAMED_COMMANDS: [(&str, CommandKind, u8); 71] = [
+    ("ACCEPT", CommandKind::Accept, 0),
+    ("ACCOUNT", CommandKind::Account, 1),
+    ("ACK", CommandKind::Ack, 0),
+    ("ADMIN", CommandKind::Admin, 0),
+    ("AUTHENTICATE", CommandKind::Authenticate, 1),
+    ("AWAY", CommandKind::Away, 0),
+    ("BATCH", CommandKind::Batch, 1),
+    ("CAP", CommandKind::Cap, 1),
+    ("CHGHOST", CommandKind::Chghost, 2),
+    ("CNOTICE", CommandKind::Cnotice, 3),
+    ("CONNECT", CommandKind::Connect, 1),
+    ("CPRIVMSG", CommandKind::Cprivmsg, 3),
+    ("DIE", CommandKind::Die, 0),
+    ("ENCAP", CommandKind::Encap, 2),
+    ("ERROR", CommandKind::Error, 1),
+    ("ETRACE", CommandKind::Etrace, 0),
+    ("FAIL", CommandKind::Fail, 3),
+    ("HELP", CommandKind::Help, 0),
+    ("INFO", CommandKind::Info, 0),
+    ("INVITE", CommandKind::Invite, 2),
+    ("ISON", CommandKind::Ison, 1),
+    ("JOIN", CommandKind::Join, 1),
+    ("KICK", CommandKind::Kick, 2),
+    ("KILL", CommandKind::Kill, 2),
+    ("KNOCK", CommandKind::Knock, 1),
+    ("LINKS", CommandKind::Links, 0),
+    ("LIST", CommandKind::List, 0),
+    ("LUSERS", CommandKind::Lusers, 0),
+    ("MAP", CommandKind::Map, 0),
+    ("METADATA", CommandKind::Metadata, 2),
+    ("MODE", CommandKind::Mode, 1),
+    ("MONITOR", CommandKind::Monitor, 1),
+    ("MOTD", CommandKind::Motd, 0),
+    ("NAMES", CommandKind::Names, 1),
+    ("NICK", CommandKind::Nick, 1),
+    ("NOTE", CommandKind::Note, 3),
+    ("NOTICE", CommandKind::Notice, 2),
+    ("OPER", CommandKind::Oper, 2),
+    ("PART", CommandKind::Part, 1),
+    ("PASS", CommandKind::Pass, 1),
+    ("PING", CommandKind::Ping, 1),
+    ("PONG", CommandKind::Pong, 1),
+    ("PRIVMSG", CommandKind::Privmsg, 2),
+    ("QUIT", CommandKind::Quit, 0),
+    ("REHASH", CommandKind::Rehash, 0),
+    ("RESTART", CommandKind::Restart, 0),
+    ("SERVER", CommandKind::Server, 3),
+    ("SERVICE", CommandKind::Service, 6),
+    ("SERVLIST", CommandKind::Servlist, 0),
+    ("SETNAME", CommandKind::Setname, 1),
+    ("SILENCE", CommandKind::Silence, 0),
+    ("SQUERY", CommandKind::Squery, 2),
+    ("SQUIT", CommandKind::Squit, 2),
+    ("STATS", CommandKind::Stats, 1),
+    ("SUMMON", CommandKind::Summon, 1),
+    ("TAGMSG", CommandKind::Tagmsg, 1),
+    ("TIME", CommandKind::Time, 0),
+    ("TOPIC", CommandKind::Topic, 1),
+    ("TRACE", CommandKind::Trace, 0),
+    ("USER", CommandKind::User, 4),
+    ("USERHOST", CommandKind::Userhost, 1),
+    ("USERIP", CommandKind::Userip, 1),
+    ("USERS", CommandKind::Users, 0),
+    ("VERSION", CommandKind::Version, 0),
+    ("WALLOPS", CommandKind::Wallops, 1),
+    ("WARN", CommandKind::Warn, 3),
+    ("WATCH", CommandKind::Watch, 1),
+    ("WEBIRC", CommandKind::Webirc, 4),
+    ("WHO", CommandKind::Who, 1),
+    ("WHOIS", CommandKind::Whois, 1),
+    ("WHOWAS", CommandKind::Whowas, 1),
+];
+
+const fn named_command(input: &[u8]) -> Option<(&'static str, CommandKind, u8)> {
+    let (mut low, mut high) = (0, NAMED_COMMANDS.len());
+    while low < high {
+        let middle = low + (high - low) / 2;
+        let (name, _, _) = NAMED_COMMANDS[middle];
+        match compare_bytes(name.as_bytes(), input) {
+            Ordering::Equal => return Some(NAMED_COMMANDS[middle]),
+            Ordering::Less => low = middle + 1,
+            Ordering::Greater => high = middle,
+        }
+    }
+    None
+}
+
+const fn compare_bytes(first: &[u8], second: &[u8]) -> Ordering {
+    let mut index = 0;
+    while index < first.len() && index < second.len() {
+        if first[index] < second[index] {return Ordering::Less;}
+        else if first[index] > second[index] {return Ordering::Greater;}
+        index += 1;
+    }
+    if first.len() < second.len() {Ordering::Less}
+    else if first.len() > second.len() {Ordering::Greater}
+    else {Ordering::Equal}
+}
+
+const fn numeric_value(input: &[u8]) -> Option<u16> {
+    if input.len() != 3 {return None;}
+    let mut value = 0;
+    let mut index = 0;
+    while index < input.len() {
+        if !input[index].is_ascii_digit() {return None;}
+        value = value * 10 + (input[index] - b'0') as u16;
+        index += 1;
+    }
+    Some(value)
+}
+
+const fn numeric_name(value: u16) -> Option<&'static str> {
+    let (mut low, mut high) = (0, NUMERICS.len());
+    while low < high {
+        let middle = low + (high - low) / 2;
+        let (numeric, _, name) = NUMERICS[middle];
+        if numeric == value {return Some(name);}
+        else if numeric < value {low = middle + 1;}
+        else {high = middle;}
+    }
+    None
+}
+
 const fn is_invalid_char(input: u8) -> bool {
     !input.is_ascii_alphanumeric()
 }
@@ -737,9 +1135,12 @@ const fn command_to_uppercase_bytes(input: &[u8]) -> [u8; 12] {
 
 #[cfg(test)]
 mod const_tests {
+    use core::cmp::Ordering;
     use crate::is_identical;
-    use super::{Command, command_to_uppercase_bytes};
+    use super::{Command, CommandCategory, CommandKind, NAMED_COMMANDS, NUMERICS, UnhandledNumerics, compare_bytes,
+        command_to_uppercase_bytes, numeric_value};
     #[test]
+    #[allow(clippy::too_many_lines)]
     const fn parsing_command() {
         assert!(Command::parse(b"302", 1).is_ok());
         assert!(Command::parse(b"907", 2).is_ok());
@@ -878,6 +1279,134 @@ mod const_tests {
         let input = b"INFO";
         let output = command_to_uppercase_bytes(input);
         assert!(output.len() == 12);
-        assert!(is_identical(&output, &[b'I', b'N', b'F', b'O', b'0', b'0', b'0', b'0', b'0', b'0', b'0', b'0']));
+        assert!(is_identical(&output, b"INFO00000000"));
+    }
+    #[test]
+    const fn getting_kind() {
+        let cmd = Command::parse(b"privmsg", 2);
+        assert!(cmd.is_ok());
+        if let Ok(cmd) = cmd {assert!(matches!(cmd.kind(), Some(CommandKind::Privmsg)));}
+        let cmd = Command::parse(b"001", 2);
+        assert!(cmd.is_ok());
+        if let Ok(cmd) = cmd {assert!(matches!(cmd.kind(), Some(CommandKind::Numeric(1))));}
+        assert!(Command::Named("EXCELLENT").kind().is_none());
+        assert!(Command::Numeric("1a1").kind().is_none());
+    }
+    #[test]
+    const fn getting_numeric_value() {
+        assert!(matches!(numeric_value(b"433"), Some(433)));
+        assert!(matches!(numeric_value(b"005"), Some(5)));
+        assert!(numeric_value(b"4330").is_none());
+        assert!(numeric_value(b"43a").is_none());
+    }
+    #[test]
+    const fn getting_numeric_name() {
+        let cmd = Command::parse(b"001", 2);
+        assert!(cmd.is_ok());
+        if let Ok(cmd) = cmd {
+            assert!(cmd.numeric_name().is_some());
+            if let Some(name) = cmd.numeric_name() {assert!(is_identical(name.as_bytes(), b"RPL_WELCOME"));}
+        }
+        let cmd = Command::parse(b"433", 3);
+        assert!(cmd.is_ok());
+        if let Ok(cmd) = cmd {
+            assert!(cmd.numeric_name().is_some());
+            if let Some(name) = cmd.numeric_name() {assert!(is_identical(name.as_bytes(), b"ERR_NICKNAMEINUSE"));}
+        }
+        assert!(Command::Named("PRIVMSG").numeric_name().is_none());
+        assert!(Command::Numeric("000").numeric_name().is_none());
+    }
+    #[test]
+    const fn getting_min_params() {
+        assert!(matches!(Command::Named("PRIVMSG").min_params(), Some(2)));
+        assert!(matches!(Command::Named("INFO").min_params(), Some(0)));
+        assert!(matches!(Command::Numeric("352").min_params(), Some(8)));
+        assert!(Command::Named("EXCELLENT").min_params().is_none());
+        assert!(Command::Numeric("000").min_params().is_none());
+        assert!(Command::parse(b"EXCELLENTCOMMAND", 0).is_err());
+    }
+    #[test]
+    const fn getting_numeric_u16() {
+        let cmd = Command::parse(b"433", 3);
+        assert!(cmd.is_ok());
+        if let Ok(cmd) = cmd {assert!(matches!(cmd.as_numeric_u16(), Some(433)));}
+        assert!(matches!(Command::Numeric("001").as_numeric_u16(), Some(1)));
+        assert!(Command::Named("PRIVMSG").as_numeric_u16().is_none());
+    }
+    #[test]
+    const fn classifying_numerics() {
+        assert!(Command::Numeric("433").is_error_numeric());
+        assert!(!Command::Numeric("433").is_reply_numeric());
+        assert!(Command::Numeric("904").is_error_numeric());
+        assert!(Command::Numeric("599").is_reply_numeric());
+        assert!(Command::Numeric("001").is_reply_numeric());
+        assert!(Command::Numeric("555").is_error_numeric());
+        assert!(!Command::Named("PRIVMSG").is_error_numeric());
+        assert!(!Command::Named("PRIVMSG").is_reply_numeric());
+    }
+    #[test]
+    const fn numerics_sorted() {
+        let mut index = 1;
+        while index < NUMERICS.len() {
+            let (previous, _, _) = NUMERICS[index - 1];
+            let (current, min, _) = NUMERICS[index];
+            assert!(previous < current);
+            assert!(current < 1000 && min > 0);
+            index += 1;
+        }
+    }
+    #[test]
+    const fn named_commands_sorted() {
+        let mut index = 1;
+        while index < NAMED_COMMANDS.len() {
+            let (previous, _, _) = NAMED_COMMANDS[index - 1];
+            let (current, _, _) = NAMED_COMMANDS[index];
+            assert!(matches!(compare_bytes(previous.as_bytes(), current.as_bytes()), Ordering::Less));
+            index += 1;
+        }
+    }
+    #[test]
+    const fn parsing_unhandled_numerics() {
+        assert!(Command::parse_with_unhandled_numerics(b"000", 0, UnhandledNumerics::Accept).is_ok());
+        assert!(Command::parse_with_unhandled_numerics(b"000", 0, UnhandledNumerics::Reject).is_err());
+        assert!(Command::parse_with_unhandled_numerics(b"001", 0, UnhandledNumerics::Accept).is_err());
+        assert!(Command::parse_with_unhandled_numerics(b"0001", 1, UnhandledNumerics::Accept).is_err());
+    }
+    #[test]
+    const fn getting_required_capability() {
+        let cap = Command::Named("TAGMSG").required_capability();
+        assert!(cap.is_some());
+        if let Some(cap) = cap {assert!(is_identical(cap.as_bytes(), b"message-tags"));}
+        let cap = Command::Named("BATCH").required_capability();
+        assert!(cap.is_some());
+        if let Some(cap) = cap {assert!(is_identical(cap.as_bytes(), b"batch"));}
+        let cap = Command::Named("ACK").required_capability();
+        assert!(cap.is_some());
+        if let Some(cap) = cap {assert!(is_identical(cap.as_bytes(), b"labeled-response"));}
+        assert!(Command::Named("PRIVMSG").required_capability().is_none());
+        assert!(Command::Numeric("001").required_capability().is_none());
+    }
+    #[test]
+    const fn parsing_name_only() {
+        let cmd = Command::parse_name(b"privmsg");
+        assert!(cmd.is_ok());
+        if let Ok(cmd) = cmd {
+            if let Command::Named(name) = cmd {assert!(is_identical(name.as_bytes(), b"PRIVMSG"));}
+            assert!(cmd.validate_param_count(2).is_ok());
+            assert!(cmd.validate_param_count(1).is_err());
+        }
+        assert!(Command::parse_name(b"352").is_ok());
+        assert!(Command::parse_name(b"000").is_err());
+        assert!(Command::parse_name(b"EXCELLENT").is_err());
+        assert!(Command::Numeric("000").validate_param_count(0).is_ok());
+    }
+    #[test]
+    const fn getting_category() {
+        assert!(matches!(Command::Named("PRIVMSG").category(), Some(CommandCategory::Messaging)));
+        assert!(matches!(Command::Named("JOIN").category(), Some(CommandCategory::ChannelOps)));
+        assert!(matches!(Command::Named("CAP").category(), Some(CommandCategory::ConnectionRegistration)));
+        assert!(matches!(Command::Named("TAGMSG").category(), Some(CommandCategory::IrcV3)));
+        assert!(matches!(Command::Numeric("001").category(), Some(CommandCategory::Numeric)));
+        assert!(Command::Named("EXCELLENT").category().is_none());
     }
 }
@@ -0,0 +1,234 @@
+//! Methods for quoting and dequoting Client-To-Client Protocol (CTCP) payloads.
+//!
+//! ## Purpose
+//!
+//! CTCP messages are sent as the trailing parameter of a `PRIVMSG` or `NOTICE` [`IrcMsg`](crate::IrcMsg)
+//! delimited by `\x01`. The [specification] describes two levels of quoting so that arbitrary bytes can be sent.
+//! Low-level quoting uses `\x10` to escape null, carriage return, line feed and itself which can't appear in an
+//! [`IrcMsg`](crate::IrcMsg). CTCP-level quoting uses `\` to escape the `\x01` delimiter and itself.
+//! Quoting is applied CTCP-level first then low-level and dequoting is applied in reverse.
+//! Each method returns a [`CtcpBuffer`] of `N` bytes.
+//!
+//! [specification]: <https://www.irchelp.org/protocol/ctcpspec.html>
+
+/// The byte used for low-level quoting.
+pub const LOW_LEVEL_QUOTE: u8 = 0x10;
+/// The byte used for CTCP-level quoting.
+pub const CTCP_QUOTE: u8 = 0x5c;
+/// The byte delimiting a CTCP message.
+pub const CTCP_DELIMITER: u8 = 0x01;
+
+/// Applies low-level quoting to the `input`.
+///
+/// Null becomes `\x10` `0`, line feed becomes `\x10` `n`, carriage return becomes `\x10` `r` and `\x10` is doubled.
+///
+/// # Errors
+///
+/// Will return `Err` if the quoted output is longer than `N` bytes.
+pub const fn low_level_quote<const N: usize>(input: &[u8]) -> Result<CtcpBuffer<N>, CtcpQuoteError> {
+    let mut output = CtcpBuffer::new();
+    let mut index = 0;
+    while index < input.len() {
+        let quoted = match input[index] {
+            b'\0' => Some(b'0'),
+            b'\n' => Some(b'n'),
+            b'\r' => Some(b'r'),
+            LOW_LEVEL_QUOTE => Some(LOW_LEVEL_QUOTE),
+            _ => None,
+        };
+        output = match quoted {
+            Some(byte) => match output.push(LOW_LEVEL_QUOTE) {
+                Ok(output) => match output.push(byte) {Ok(output) => output, Err(e) => return Err(e)},
+                Err(e) => return Err(e),
+            },
+            None => match output.push(input[index]) {Ok(output) => output, Err(e) => return Err(e)},
+        };
+        index += 1;
+    }
+    Ok(output)
+}
+
+/// Removes low-level quoting from the `input`.
+///
+/// A `\x10` followed by a byte other than `0`, `n`, `r` or `\x10` is dropped as per the [specification].
+///
+/// # Errors
+///
+/// Will return `Err` if the input ends with an unpaired `\x10` or the dequoted output is longer than `N` bytes.
+///
+/// [specification]: <https://www.irchelp.org/protocol/ctcpspec.html>
+pub const fn low_level_dequote<const N: usize>(input: &[u8]) -> Result<CtcpBuffer<N>, CtcpQuoteError> {
+    let mut output = CtcpBuffer::new();
+    let mut index = 0;
+    while index < input.len() {
+        let byte = if input[index] == LOW_LEVEL_QUOTE {
+            index += 1;
+            if index == input.len() {return Err(CtcpQuoteError::IncompleteQuote);}
+            match input[index] {
+                b'0' => b'\0',
+                b'n' => b'\n',
+                b'r' => b'\r',
+                other => other,
+            }
+        } else {
+            input[index]
+        };
+        output = match output.push(byte) {Ok(output) => output, Err(e) => return Err(e)};
+        index += 1;
+    }
+    Ok(output)
+}
+
+/// Applies CTCP-level quoting to the `input`.
+///
+/// `\x01` becomes `\a` and `\` is doubled.
+///
+/// # Errors
+///
+/// Will return `Err` if the quoted output is longer than `N` bytes.
+pub const fn ctcp_quote<const N: usize>(input: &[u8]) -> Result<CtcpBuffer<N>, CtcpQuoteError> {
+    let mut output = CtcpBuffer::new();
+    let mut index = 0;
+    while index < input.len() {
+        let quoted = match input[index] {
+            CTCP_DELIMITER => Some(b'a'),
+            CTCP_QUOTE => Some(CTCP_QUOTE),
+            _ => None,
+        };
+        output = match quoted {
+            Some(byte) => match output.push(CTCP_QUOTE) {
+                Ok(output) => match output.push(byte) {Ok(output) => output, Err(e) => return Err(e)},
+                Err(e) => return Err(e),
+            },
+            None => match output.push(input[index]) {Ok(output) => output, Err(e) => return Err(e)},
+        };
+        index += 1;
+    }
+    Ok(output)
+}
+
+/// Removes CTCP-level quoting from the `input`.
+///
+/// A `\` followed by a byte other than `a` or `\` is dropped as per the [specification].
+///
+/// # Errors
+///
+/// Will return `Err` if the input ends with an unpaired `\` or the dequoted output is longer than `N` bytes.
+///
+/// [specification]: <https://www.irchelp.org/protocol/ctcpspec.html>
+pub const fn ctcp_dequote<const N: usize>(input: &[u8]) -> Result<CtcpBuffer<N>, CtcpQuoteError> {
+    let mut output = CtcpBuffer::new();
+    let mut index = 0;
+    while index < input.len() {
+        let byte = if input[index] == CTCP_QUOTE {
+            index += 1;
+            if index == input.len() {return Err(CtcpQuoteError::IncompleteQuote);}
+            match input[index] {
+                b'a' => CTCP_DELIMITER,
+                other => other,
+            }
+        } else {
+            input[index]
+        };
+        output = match output.push(byte) {Ok(output) => output, Err(e) => return Err(e)};
+        index += 1;
+    }
+    Ok(output)
+}
+
+/// A buffer of `N` bytes holding a quoted or dequoted CTCP payload.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CtcpBuffer<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> CtcpBuffer<N> {
+    pub(crate) const fn new() -> Self {
+        Self{buffer: [0; N], len: 0}
+    }
+    pub(crate) const fn push(mut self, input: u8) -> Result<Self, CtcpQuoteError> {
+        if self.len == N {return Err(CtcpQuoteError::BufferFull);}
+        self.buffer[self.len] = input;
+        self.len += 1;
+        Ok(self)
+    }
+    /// Returns the payload as a slice of bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        let (output, _) = self.buffer.split_at(self.len);
+        output
+    }
+    /// Returns the amount of bytes written.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Check if no bytes were written.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// The possible types of errors when quoting or dequoting a CTCP payload.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CtcpQuoteError {
+    /// The buffer is too small.
+    BufferFull,
+    /// The input ends with a quote byte that isn't followed by a quoted byte.
+    IncompleteQuote,
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{CtcpQuoteError, ctcp_dequote, ctcp_quote, low_level_dequote, low_level_quote};
+    use crate::is_identical;
+    #[test]
+    const fn low_level_quoting() {
+        let input = b"a\0b\nc\rd\x10e";
+        let quoted = low_level_quote::<32>(input);
+        assert!(quoted.is_ok());
+        if let Ok(quoted) = quoted {
+            assert!(is_identical(quoted.as_bytes(), b"a\x100b\x10nc\x10rd\x10\x10e"));
+            let dequoted = low_level_dequote::<32>(quoted.as_bytes());
+            assert!(dequoted.is_ok());
+            if let Ok(dequoted) = dequoted {assert!(is_identical(dequoted.as_bytes(), input));}
+        }
+        let dequoted = low_level_dequote::<32>(b"a\x10zb");
+        assert!(dequoted.is_ok());
+        if let Ok(dequoted) = dequoted {assert!(is_identical(dequoted.as_bytes(), b"azb"));}
+        assert!(matches!(low_level_dequote::<32>(b"a\x10"), Err(CtcpQuoteError::IncompleteQuote)));
+        assert!(matches!(low_level_quote::<4>(b"\0\0\0"), Err(CtcpQuoteError::BufferFull)));
+        let empty = low_level_quote::<0>(b"");
+        assert!(empty.is_ok());
+        if let Ok(empty) = empty {assert!(empty.is_empty());}
+    }
+    #[test]
+    const fn ctcp_level_quoting() {
+        let input = b"\x01ACTION\x01 \\o/";
+        let quoted = ctcp_quote::<32>(input);
+        assert!(quoted.is_ok());
+        if let Ok(quoted) = quoted {
+            assert!(is_identical(quoted.as_bytes(), b"\\aACTION\\a \\\\o/"));
+            let dequoted = ctcp_dequote::<32>(quoted.as_bytes());
+            assert!(dequoted.is_ok());
+            if let Ok(dequoted) = dequoted {
+                assert!(is_identical(dequoted.as_bytes(), input));
+                assert!(dequoted.len() == input.len());
+            }
+        }
+        let dequoted = ctcp_dequote::<32>(b"\\n");
+        assert!(dequoted.is_ok());
+        if let Ok(dequoted) = dequoted {assert!(is_identical(dequoted.as_bytes(), b"n"));}
+        assert!(matches!(ctcp_dequote::<32>(b"\\"), Err(CtcpQuoteError::IncompleteQuote)));
+        assert!(matches!(ctcp_quote::<3>(b"\\\\"), Err(CtcpQuoteError::BufferFull)));
+        let quoted = ctcp_quote::<32>(b"\x01\x10");
+        assert!(quoted.is_ok());
+        if let Ok(quoted) = quoted {
+            let quoted = low_level_quote::<32>(quoted.as_bytes());
+            assert!(quoted.is_ok());
+            if let Ok(quoted) = quoted {assert!(is_identical(quoted.as_bytes(), b"\\a\x10\x10"));}
+        }
+    }
+}
@@ -0,0 +1,397 @@
+//! Methods for parsing Direct Client-to-Client (DCC) offers.
+//!
+//! ## Purpose
+//!
+//! DCC offers are sent as a [CTCP](crate::ctcp) message in the trailing parameter of a `PRIVMSG`
+//! [`IrcMsg`](crate::IrcMsg). A `DCC SEND` offers a file to the recipient who connects to the sender on the
+//! advertised address and port to receive it. The [specification] allows filenames containing spaces to be
+//! surrounded by double quotes.
+//! A passive (reverse) `DCC SEND` advertises port `0` followed by a token when the sender can't accept connections.
+//! The recipient then answers with its own address and port along with the same token and the sender connects.
+//!
+//! [specification]: <https://modern.ircdocs.horse/dcc>
+
+use crate::{ctcp::{CtcpBuffer, CtcpQuoteError, CTCP_DELIMITER}, is_identical_ignoring_ascii_case, parse_ipv4_octets,
+    parse_ipv6_segments, parse_u32, parse_u64};
+
+/// A `DCC SEND` offer such as `DCC SEND "my file.txt" 3232235777 5000 1024`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DccSend<'msg> {
+    filename: &'msg [u8],
+    address: DccAddress<'msg>,
+    port: u16,
+    size: Option<u64>,
+    token: Option<&'msg [u8]>,
+}
+
+impl<'msg> DccSend<'msg> {
+    /// Generates a [`DccSend`] from a slice of bytes with or without the surrounding `\x01` CTCP delimiters.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the input is not a `DCC SEND`, a quoted filename is not terminated or the filename,
+    /// address or port are missing or invalid. The size is optional but must be numeric if present. A token may only
+    /// follow the size.
+    pub const fn parse(input: &'msg [u8]) -> Result<Self, DccError> {
+        let input = strip_delimiters(input);
+        let (dcc, index) = next_word(input, 0);
+        if !is_identical_ignoring_ascii_case(dcc, b"DCC") {return Err(DccError::NotDcc);}
+        let (send, index) = next_word(input, index);
+        if !is_identical_ignoring_ascii_case(send, b"SEND") {return Err(DccError::NotSend);}
+        let (filename, index) = match next_filename(input, index) {Ok(filename) => filename, Err(e) => return Err(e)};
+        let (address, index) = next_word(input, index);
+        let address = match DccAddress::parse(address) {Ok(address) => address, Err(e) => return Err(e)};
+        let (port, index) = next_word(input, index);
+        if port.is_empty() {return Err(DccError::MissingPort);}
+        let Some(port) = parse_u16(port) else {return Err(DccError::InvalidPort);};
+        let (size, index) = next_word(input, index);
+        let size = if size.is_empty() {None} else {
+            match parse_u64(size) {Some(size) => Some(size), None => return Err(DccError::InvalidSize)}
+        };
+        let (token, index) = next_word(input, index);
+        let token = if token.is_empty() {None} else {Some(token)};
+        let (extra, _) = next_word(input, index);
+        if !extra.is_empty() {return Err(DccError::TooManyArguments);}
+        Ok(Self{filename, address, port, size, token})
+    }
+    /// Returns the filename without any surrounding double quotes.
+    #[must_use]
+    pub const fn filename(&self) -> &'msg [u8] {
+        self.filename
+    }
+    /// Returns the [`DccAddress`] of the sender.
+    #[must_use]
+    pub const fn address(&self) -> DccAddress<'msg> {
+        self.address
+    }
+    /// Returns the port of the sender.
+    #[must_use]
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+    /// Returns the size of the file in bytes if advertised.
+    #[must_use]
+    pub const fn size(&self) -> Option<u64> {
+        self.size
+    }
+    /// Returns the token identifying a passive [`DccSend`] if present.
+    #[must_use]
+    pub const fn token(&self) -> Option<&'msg [u8]> {
+        self.token
+    }
+    /// Check whether the [`DccSend`] is a passive offer requesting the recipient to answer with a reverse DCC.
+    ///
+    /// A passive offer has a port of `0`, a size and a token.
+    #[must_use]
+    pub const fn is_passive(&self) -> bool {
+        self.port == 0 && self.size.is_some() && self.token.is_some()
+    }
+    /// Check whether the [`DccSend`] is the answer to a passive offer containing the connectable address and port.
+    #[must_use]
+    pub const fn is_passive_answer(&self) -> bool {
+        self.port != 0 && self.token.is_some()
+    }
+    /// Writes the `DCC SEND` answering a passive offer with the `address` and `port` the sender should connect to.
+    ///
+    /// The filename, size and token are copied from the offer and the output is surrounded by `\x01` CTCP
+    /// delimiters ready to be used as the trailing parameter of a `PRIVMSG`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the [`DccSend`] is not a passive offer, the `port` is `0` or the output is longer than
+    /// `N` bytes.
+    pub const fn passive_answer<const N: usize>(&self, address: DccAddress, port: u16)
+    -> Result<CtcpBuffer<N>, DccAnswerError> {
+        let (Some(size), Some(token)) = (self.size, self.token) else {return Err(DccAnswerError::NotPassive);};
+        if self.port != 0 {return Err(DccAnswerError::NotPassive);}
+        else if port == 0 {return Err(DccAnswerError::InvalidPort);}
+        let output = match push_slices(CtcpBuffer::new(), &[&[CTCP_DELIMITER], b"DCC SEND "]) {
+            Ok(output) => output,
+            Err(e) => return Err(e),
+        };
+        let quoted = contains_space(self.filename);
+        let output = match push_slices(output, &[if quoted {b"\""} else {b""}, self.filename,
+            if quoted {b"\" "} else {b" "}]) {
+            Ok(output) => output,
+            Err(e) => return Err(e),
+        };
+        let output = match address {
+            DccAddress::Ipv4(address) => push_decimal(output, address as u64),
+            DccAddress::Ipv6(address) => push_slices(output, &[address]),
+        };
+        let output = match output {Ok(output) => push_slices(output, &[b" "]), Err(e) => return Err(e)};
+        let output = match output {Ok(output) => push_decimal(output, port as u64), Err(e) => return Err(e)};
+        let output = match output {Ok(output) => push_slices(output, &[b" "]), Err(e) => return Err(e)};
+        let output = match output {Ok(output) => push_decimal(output, size), Err(e) => return Err(e)};
+        match output {
+            Ok(output) => push_slices(output, &[b" ", token, &[CTCP_DELIMITER]]),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// The address of the sender in a DCC offer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DccAddress<'msg> {
+    /// An IPv4 address in its decimal integer representation.
+    Ipv4(u32),
+    /// An IPv6 address in its textual representation.
+    Ipv6(&'msg [u8]),
+}
+
+impl<'msg> DccAddress<'msg> {
+    /// Generates a [`DccAddress::Ipv4`] from the octets of a dotted-quad IPv4 address.
+    #[must_use]
+    pub const fn from_ipv4_octets(octets: [u8; 4]) -> Self {
+        Self::Ipv4(u32::from_be_bytes(octets))
+    }
+    /// Returns the octets of a [`DccAddress::Ipv4`] in dotted-quad order.
+    #[must_use]
+    pub const fn ipv4_octets(&self) -> Option<[u8; 4]> {
+        match self {
+            Self::Ipv4(address) => Some(address.to_be_bytes()),
+            Self::Ipv6(_) => None,
+        }
+    }
+    /// Returns the eight 16-bit segments of a [`DccAddress::Ipv6`].
+    #[must_use]
+    pub const fn ipv6_segments(&self) -> Option<[u16; 8]> {
+        match self {
+            Self::Ipv4(_) => None,
+            Self::Ipv6(address) => parse_ipv6_segments(address),
+        }
+    }
+    const fn parse(input: &'msg [u8]) -> Result<Self, DccError> {
+        if input.is_empty() {return Err(DccError::MissingAddress);}
+        let mut index = 0;
+        while index < input.len() {
+            match input[index] {
+                b':' => return match parse_ipv6_segments(input) {
+                    Some(_) => Ok(Self::Ipv6(input)),
+                    None => Err(DccError::InvalidAddress),
+                },
+                b'.' => return match parse_ipv4_octets(input) {
+                    Some(octets) => Ok(Self::from_ipv4_octets(octets)),
+                    None => Err(DccError::InvalidAddress),
+                },
+                _ => index += 1,
+            }
+        }
+        match parse_u32(input) {
+            Some(address) => Ok(Self::Ipv4(address)),
+            None => Err(DccError::InvalidAddress),
+        }
+    }
+}
+
+/// The possible types of errors when writing the answer to a passive [`DccSend`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DccAnswerError {
+    /// The [`DccSend`] is not a passive offer.
+    NotPassive,
+    /// The port of the answer is `0`.
+    InvalidPort,
+    /// The buffer is too small.
+    BufferFull,
+}
+
+/// The possible types of errors when parsing a DCC offer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DccError {
+    /// The input does not start with `DCC`.
+    NotDcc,
+    /// The DCC type is not `SEND`.
+    NotSend,
+    /// The filename is missing.
+    MissingFilename,
+    /// A filename starting with a double quote has no closing double quote.
+    UnterminatedQuote,
+    /// The address is missing.
+    MissingAddress,
+    /// The address is not a decimal integer or dotted-quad IPv4 address or a textual IPv6 address.
+    InvalidAddress,
+    /// The port is missing.
+    MissingPort,
+    /// The port is not a number in the range of a `u16`.
+    InvalidPort,
+    /// The size is not a number in the range of a `u64`.
+    InvalidSize,
+    /// Unexpected arguments follow the token.
+    TooManyArguments,
+}
+
+const fn push_slices<const N: usize>(mut output: CtcpBuffer<N>, input: &[&[u8]])
+-> Result<CtcpBuffer<N>, DccAnswerError> {
+    let mut slice = 0;
+    while slice < input.len() {
+        let mut index = 0;
+        while index < input[slice].len() {
+            output = match output.push(input[slice][index]) {
+                Ok(output) => output,
+                Err(CtcpQuoteError::BufferFull | CtcpQuoteError::IncompleteQuote) => {
+                    return Err(DccAnswerError::BufferFull);
+                },
+            };
+            index += 1;
+        }
+        slice += 1;
+    }
+    Ok(output)
+}
+
+const fn push_decimal<const N: usize>(output: CtcpBuffer<N>, input: u64) -> Result<CtcpBuffer<N>, DccAnswerError> {
+    let mut digits = [0; 20];
+    let (mut len, mut value) = (0, input);
+    loop {
+        digits[digits.len() - 1 - len] = b'0' + (value % 10) as u8;
+        len += 1;
+        value /= 10;
+        if value == 0 {break;}
+    }
+    let (_, digits) = digits.split_at(digits.len() - len);
+    push_slices(output, &[digits])
+}
+
+const fn contains_space(input: &[u8]) -> bool {
+    let mut index = 0;
+    while index < input.len() {
+        if input[index] == b' ' {return true;}
+        index += 1;
+    }
+    false
+}
+
+const fn strip_delimiters(input: &[u8]) -> &[u8] {
+    let input = match input {
+        [CTCP_DELIMITER, rest @ ..] => rest,
+        _ => input,
+    };
+    match input {
+        [rest @ .., CTCP_DELIMITER] => rest,
+        _ => input,
+    }
+}
+
+// Returns the next word after any spaces and the index following it.
+const fn next_word(input: &[u8], mut index: usize) -> (&[u8], usize) {
+    while index < input.len() && input[index] == b' ' {index += 1;}
+    let (_, rest) = input.split_at(index);
+    let mut end = 0;
+    while end < rest.len() && rest[end] != b' ' {end += 1;}
+    let (word, _) = rest.split_at(end);
+    (word, index + end)
+}
+
+const fn next_filename(input: &[u8], mut index: usize) -> Result<(&[u8], usize), DccError> {
+    while index < input.len() && input[index] == b' ' {index += 1;}
+    if index == input.len() {return Err(DccError::MissingFilename);}
+    if input[index] != b'"' {return Ok(next_word(input, index));}
+    let (_, rest) = input.split_at(index + 1);
+    let mut end = 0;
+    while end < rest.len() && rest[end] != b'"' {end += 1;}
+    if end == rest.len() {return Err(DccError::UnterminatedQuote);}
+    let (filename, _) = rest.split_at(end);
+    if filename.is_empty() {return Err(DccError::MissingFilename);}
+    Ok((filename, index + end + 2))
+}
+
+#[allow(clippy::cast_possible_truncation)]
+const fn parse_u16(input: &[u8]) -> Option<u16> {
+    match parse_u64(input) {
+        Some(value) if value <= u16::MAX as u64 => Some(value as u16),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{DccAddress, DccAnswerError, DccError, DccSend};
+    use crate::is_identical;
+    #[test]
+    const fn parsing_send() {
+        let send = DccSend::parse(b"\x01DCC SEND \"my file.txt\" 3232235777 5000 1024\x01");
+        assert!(send.is_ok());
+        if let Ok(send) = send {
+            assert!(is_identical(send.filename(), b"my file.txt"));
+            assert!(matches!(send.address(), DccAddress::Ipv4(3_232_235_777)));
+            assert!(send.port() == 5000);
+            assert!(matches!(send.size(), Some(1024)));
+        }
+        let send = DccSend::parse(b"DCC SEND notes.txt ::1 5000");
+        assert!(send.is_ok());
+        if let Ok(send) = send {
+            assert!(is_identical(send.filename(), b"notes.txt"));
+            let address = send.address();
+            assert!(matches!(address, DccAddress::Ipv6(_)));
+            if let DccAddress::Ipv6(address) = address {assert!(is_identical(address, b"::1"));}
+            assert!(send.size().is_none());
+        }
+    }
+    #[test]
+    const fn parsing_send_errors() {
+        assert!(matches!(DccSend::parse(b"VERSION"), Err(DccError::NotDcc)));
+        assert!(matches!(DccSend::parse(b"DCC CHAT chat 3232235777 5000"), Err(DccError::NotSend)));
+        assert!(matches!(DccSend::parse(b"DCC SEND"), Err(DccError::MissingFilename)));
+        assert!(matches!(DccSend::parse(b"DCC SEND \"my file 1 2 3"), Err(DccError::UnterminatedQuote)));
+        assert!(matches!(DccSend::parse(b"DCC SEND a.txt"), Err(DccError::MissingAddress)));
+        assert!(matches!(DccSend::parse(b"DCC SEND a.txt 4294967296 5000"), Err(DccError::InvalidAddress)));
+        assert!(matches!(DccSend::parse(b"DCC SEND a.txt 1"), Err(DccError::MissingPort)));
+        assert!(matches!(DccSend::parse(b"DCC SEND a.txt 1 65536"), Err(DccError::InvalidPort)));
+        assert!(matches!(DccSend::parse(b"DCC SEND a.txt 1 2 big"), Err(DccError::InvalidSize)));
+        assert!(matches!(DccSend::parse(b"DCC SEND a.txt 1 2 3 4 5"), Err(DccError::TooManyArguments)));
+    }
+    #[test]
+    const fn converting_addresses() {
+        let address = DccAddress::Ipv4(3_232_235_777);
+        assert!(matches!(address.ipv4_octets(), Some([192, 168, 1, 1])));
+        assert!(matches!(DccAddress::from_ipv4_octets([192, 168, 1, 1]), DccAddress::Ipv4(3_232_235_777)));
+        assert!(address.ipv6_segments().is_none());
+        let send = DccSend::parse(b"DCC SEND a.txt 192.168.1.1 5000");
+        assert!(send.is_ok());
+        if let Ok(send) = send {assert!(matches!(send.address(), DccAddress::Ipv4(3_232_235_777)));}
+        let send = DccSend::parse(b"DCC SEND a.txt 2001:db8::1 5000");
+        assert!(send.is_ok());
+        if let Ok(send) = send {
+            assert!(matches!(send.address().ipv6_segments(), Some([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1])));
+        }
+        assert!(matches!(DccSend::parse(b"DCC SEND a.txt 2001:db8:::1 5000"), Err(DccError::InvalidAddress)));
+        assert!(matches!(DccSend::parse(b"DCC SEND a.txt 010.001.1.1 5000"), Err(DccError::InvalidAddress)));
+    }
+    #[test]
+    const fn passive_send() {
+        let offer = DccSend::parse(b"\x01DCC SEND \"my file.txt\" 3232235777 0 1024 42\x01");
+        assert!(offer.is_ok());
+        if let Ok(offer) = offer {
+            assert!(offer.is_passive() && !offer.is_passive_answer());
+            let token = offer.token();
+            assert!(token.is_some());
+            if let Some(token) = token {assert!(is_identical(token, b"42"));}
+            let address = DccAddress::from_ipv4_octets([10, 0, 0, 1]);
+            let answer = offer.passive_answer::<64>(address, 5000);
+            assert!(answer.is_ok());
+            if let Ok(answer) = answer {
+                assert!(is_identical(answer.as_bytes(), b"\x01DCC SEND \"my file.txt\" 167772161 5000 1024 42\x01"));
+                let answer = DccSend::parse(answer.as_bytes());
+                assert!(answer.is_ok());
+                if let Ok(answer) = answer {assert!(answer.is_passive_answer() && !answer.is_passive());}
+            }
+            let offer = DccSend::parse(b"DCC SEND a.txt 3232235777 0 0 7");
+            assert!(offer.is_ok());
+            if let Ok(offer) = offer {
+                let answer = offer.passive_answer::<64>(DccAddress::Ipv6(b"::1"), 65535);
+                assert!(answer.is_ok());
+                if let Ok(answer) = answer {
+                    assert!(is_identical(answer.as_bytes(), b"\x01DCC SEND a.txt ::1 65535 0 7\x01"));
+                }
+                assert!(matches!(offer.passive_answer::<64>(address, 0), Err(DccAnswerError::InvalidPort)));
+                assert!(matches!(offer.passive_answer::<16>(address, 1), Err(DccAnswerError::BufferFull)));
+            }
+            let offer = DccSend::parse(b"DCC SEND a.txt 3232235777 5000 1024");
+            assert!(offer.is_ok());
+            if let Ok(offer) = offer {
+                assert!(!offer.is_passive() && offer.token().is_none());
+                assert!(matches!(offer.passive_answer::<64>(address, 5000), Err(DccAnswerError::NotPassive)));
+            }
+        }
+    }
+}
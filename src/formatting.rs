@@ -10,6 +10,8 @@
 //!
 //! [specification]: <https://modern.ircdocs.horse/formatting>
 
+use crate::hex_value;
+
 /// A part of the input split up.
 pub type MsgPart<'input> = &'input [u8];
 /// A [`MsgPart`] wrapped in an [`Option`].
@@ -66,6 +68,21 @@ impl IrcFmtByte {
             _ => None,
         }
     }
+    /// Returns the [`u8`] value of the [`IrcFmtByte`].
+    #[must_use]
+    pub const fn to_byte(&self) -> u8 {
+        match self {
+            Self::Bold => 2,
+            Self::Colour => 3,
+            Self::HexColour => 4,
+            Self::Reset => 15,
+            Self::Monospace => 17,
+            Self::ReverseColour => 22,
+            Self::Italics => 29,
+            Self::Strikethrough => 30,
+            Self::Underline => 31,
+        }
+    }
     /// Checks whether the `input` contains an [`IrcFmtByte`].
     #[must_use]
     pub const fn contains_irc_formatting(input: &[u8]) -> bool {
@@ -103,6 +120,20 @@ impl IrcFmtByte {
         }
         count
     }
+    /// Counts the bytes in `input` that are displayed.
+    ///
+    /// The count does not contain any [`IrcFmtByte`] or the bytes that represent irc colour codes or hex values.
+    #[must_use]
+    pub const fn visible_len(input: &[u8]) -> usize {
+        let mut count = 0;
+        let mut remaining = if input.is_empty() {None} else {Some(input)};
+        while let Some(part) = remaining {
+            let Some((before, _, _, after)) = Self::split_at_first_fmt_byte(part) else {break;};
+            if let Some(before) = before {count += before.len();}
+            remaining = after;
+        }
+        count
+    }
     /// Returns message parts split both sides of the [`IrcFmtByte`] along with the byte and colours if present.
     ///
     /// If `input` is empty it returns `None`.
@@ -117,7 +148,7 @@ impl IrcFmtByte {
     ///
     /// 4 - All bytes after the colours if present including further formatting bytes. `None` if the last byte is part of a colour or the [`IrcFmtByte`].
     #[must_use]
-    pub const fn split_at_first_fmt_byte(input: &[u8]) -> Option<(OptMsgPart, Option<Self>, OptIrcColours, OptMsgPart)> {
+    pub const fn split_at_first_fmt_byte(input: &[u8]) -> Option<(OptMsgPart<'_>, Option<Self>, OptIrcColours<'_>, OptMsgPart<'_>)> {
         if input.is_empty() {return None;} // already made sure input is not empty
         if Self::contains_irc_formatting(input) {
             let mut index = 0;
@@ -197,11 +228,56 @@ impl IrcFmtByte {
         }
         Some((Some(input), None, None, None))
     }
-    const fn one_colour(after: &[u8], index: usize) -> (OptIrcColours, OptMsgPart) {
+    /// Returns a [`FmtWriter`] containing `input` with normalized formatting.
+    ///
+    /// A [`IrcFmtByte::HexColour`] followed by an incomplete hex colour is dropped so clients don't disagree on
+    /// whether the hex digits are text or colour. If `allowed` is provided every [`IrcFmtByte`] not included
+    /// is removed along with any colours following it. Colour codes are kept as parsed so they are at most `99`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `N` is too small for the sanitized text. A buffer the size of `input` always suffices.
+    pub const fn sanitize<const N: usize>(input: &[u8], allowed: Option<&[Self]>)
+    -> Result<FmtWriter<N>, FmtWriterError> {
+        let mut writer = FmtWriter::new();
+        let mut remaining = if input.is_empty() {None} else {Some(input)};
+        while let Some(part) = remaining {
+            let Some((before, fmt_byte, colours, after)) = Self::split_at_first_fmt_byte(part) else {break;};
+            if let Some(before) = before {
+                writer = match writer.plain(before) {Ok(writer) => writer, Err(e) => return Err(e)};
+            }
+            if let Some(fmt_byte) = fmt_byte {
+                let dangling_hex = matches!(fmt_byte, Self::HexColour) && colours.is_none()
+                    && matches!(after, Some([first, ..]) if first.is_ascii_hexdigit());
+                if !dangling_hex && fmt_byte.is_allowed(allowed) {
+                    writer = match writer.plain(&[fmt_byte.to_byte()]) {Ok(writer) => writer, Err(e) => return Err(e)};
+                    if let Some((foreground, background)) = colours {
+                        writer = match writer.plain(foreground) {Ok(writer) => writer, Err(e) => return Err(e)};
+                        if let Some(background) = background {
+                            writer = match writer.plain(b",") {Ok(writer) => writer, Err(e) => return Err(e)};
+                            writer = match writer.plain(background) {Ok(writer) => writer, Err(e) => return Err(e)};
+                        }
+                    }
+                }
+            }
+            remaining = after;
+        }
+        Ok(writer)
+    }
+    const fn is_allowed(self, allowed: Option<&[Self]>) -> bool {
+        let Some(allowed) = allowed else {return true;};
+        let mut index = 0;
+        while index < allowed.len() {
+            if allowed[index].to_byte() == self.to_byte() {return true;}
+            index += 1;
+        }
+        false
+    }
+    const fn one_colour(after: &[u8], index: usize) -> (OptIrcColours<'_>, OptMsgPart<'_>) {
         let (code, after_code) = after.split_at(index);
         (Some((code, None)), if after_code.is_empty() {None} else {Some(after_code)})
     }
-    const fn two_colours(after: &[u8], first_split: usize, last_split: usize) -> (OptIrcColours, OptMsgPart) {
+    const fn two_colours(after: &[u8], first_split: usize, last_split: usize) -> (OptIrcColours<'_>, OptMsgPart<'_>) {
         let (foreground, comma_onwards) = after.split_at(first_split);
         let (_, after_comma) = comma_onwards.split_at(1);
         let (background, after_codes) = after_comma.split_at(last_split);
@@ -221,7 +297,7 @@ impl IrcFmtByte {
                     else {break;}
                 },
                 2 => {
-                    if input[index].is_ascii_digit() {background_first = true;}
+                    if input[index].is_ascii_digit() && comma {background_first = true;}
                     else if input[index] == b',' {comma = true;}
                     else {break;}
                 },
@@ -249,6 +325,566 @@ impl IrcFmtByte {
     }
 }
 
+/// A colour applied by an [`IrcFmtByte::Colour`] or [`IrcFmtByte::HexColour`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IrcColour {
+    /// An irc colour code in the range `0`-`98`.
+    Code(u8),
+    /// The red, green and blue values of a hex colour.
+    Rgb(u8, u8, u8),
+}
+
+impl IrcColour {
+    /// Returns the red, green and blue values of an irc colour code.
+    ///
+    /// Codes `0`-`15` are usually author defined so commonly used values are returned.
+    /// Returns `None` for code `99` (the default colour) and codes greater than `99`.
+    #[must_use]
+    pub const fn to_rgb(code: u8) -> Option<(u8, u8, u8)> {
+        if code as usize >= IRC_COLOUR_RGB.len() {return None;}
+        let [_, r, g, b] = IRC_COLOUR_RGB[code as usize].to_be_bytes();
+        Some((r, g, b))
+    }
+    /// Returns the red, green and blue values of the [`IrcColour`].
+    #[must_use]
+    pub const fn rgb(&self) -> Option<(u8, u8, u8)> {
+        match self {
+            Self::Code(code) => Self::to_rgb(*code),
+            Self::Rgb(r, g, b) => Some((*r, *g, *b)),
+        }
+    }
+    const fn from_code(input: &[u8]) -> Option<Self> {
+        let code = match input {
+            [single] => *single - b'0',
+            [first, second] => (*first - b'0') * 10 + (*second - b'0'),
+            _ => return None,
+        };
+        if code == 99 {None} else {Some(Self::Code(code))}
+    }
+    const fn from_hex(input: &[u8]) -> Option<Self> {
+        match input {
+            [r1, r2, g1, g2, b1, b2] => match (hex_value(*r1), hex_value(*r2), hex_value(*g1), hex_value(*g2),
+                hex_value(*b1), hex_value(*b2)) {
+                (Some(r1), Some(r2), Some(g1), Some(g2), Some(b1), Some(b2)) =>
+                    Some(Self::Rgb(r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2)),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// The red, green and blue values of irc colour codes `0`-`98` as per the [specification].
+///
+/// [specification]: <https://modern.ircdocs.horse/formatting#colors-16-98>
+#[allow(clippy::unreadable_literal)]
+const IRC_COLOUR_RGB: [u32; 99] = [
+    // 0-15 (commonly used values, clients may define their own)
+    0xffffff, 0x000000, 0x00007f, 0x009300, 0xff0000, 0x7f0000, 0x9c009c, 0xfc7f00,
+    0xffff00, 0x00fc00, 0x009393, 0x00ffff, 0x0000fc, 0xff00ff, 0x7f7f7f, 0xd2d2d2,
+    // 16-27
+    0x470000, 0x472100, 0x474700, 0x324700, 0x004700, 0x00472c,
+    0x004747, 0x002747, 0x000047, 0x2e0047, 0x470047, 0x47002a,
+    // 28-39
+    0x740000, 0x743a00, 0x747400, 0x517400, 0x007400, 0x007449,
+    0x007474, 0x004074, 0x000074, 0x4b0074, 0x740074, 0x740045,
+    // 40-51
+    0xb50000, 0xb56300, 0xb5b500, 0x7db500, 0x00b500, 0x00b571,
+    0x00b5b5, 0x0063b5, 0x0000b5, 0x7500b5, 0xb500b5, 0xb5006b,
+    // 52-63
+    0xff0000, 0xff8c00, 0xffff00, 0xb2ff00, 0x00ff00, 0x00ffa0,
+    0x00ffff, 0x008cff, 0x0000ff, 0xa500ff, 0xff00ff, 0xff0098,
+    // 64-75
+    0xff5959, 0xffb459, 0xffff71, 0xcfff60, 0x6fff6f, 0x65ffc9,
+    0x6dffff, 0x59b4ff, 0x5959ff, 0xc459ff, 0xff66ff, 0xff59bc,
+    // 76-87
+    0xff9c9c, 0xffd39c, 0xffff9c, 0xe2ff9c, 0x9cff9c, 0x9cffdb,
+    0x9cffff, 0x9cd3ff, 0x9c9cff, 0xdc9cff, 0xff9cff, 0xff94d3,
+    // 88-98
+    0x000000, 0x131313, 0x282828, 0x363636, 0x4d4d4d, 0x656565, 0x818181, 0x9f9f9f, 0xbcbcbc, 0xe2e2e2, 0xffffff,
+];
+
+/// The formatting active at a point in formatted text.
+///
+/// A colour of `None` indicates the default colour.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct FmtState {
+    bold: bool,
+    italics: bool,
+    underline: bool,
+    strikethrough: bool,
+    monospace: bool,
+    reverse: bool,
+    foreground: Option<IrcColour>,
+    background: Option<IrcColour>,
+}
+
+impl FmtState {
+    /// Generates a [`FmtState`] with no formatting applied.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self{bold: false, italics: false, underline: false, strikethrough: false, monospace: false, reverse: false,
+            foreground: None, background: None}
+    }
+    /// Applies an [`IrcFmtByte`] and the colours following it to the [`FmtState`].
+    ///
+    /// Bold, italics, underline, strikethrough, monospace and reverse are toggled. [`IrcFmtByte::Reset`] removes
+    /// all formatting. [`IrcFmtByte::Colour`] and [`IrcFmtByte::HexColour`] without colours reset both colours,
+    /// with only a foreground colour they keep the current background colour.
+    /// The `colours` are as returned by [`IrcFmtByte::split_at_first_fmt_byte`] and are ignored for other bytes.
+    #[must_use]
+    pub const fn apply(mut self, fmt_byte: IrcFmtByte, colours: OptIrcColours<'_>) -> Self {
+        match fmt_byte {
+            IrcFmtByte::Bold => self.bold = !self.bold,
+            IrcFmtByte::Italics => self.italics = !self.italics,
+            IrcFmtByte::Underline => self.underline = !self.underline,
+            IrcFmtByte::Strikethrough => self.strikethrough = !self.strikethrough,
+            IrcFmtByte::Monospace => self.monospace = !self.monospace,
+            IrcFmtByte::ReverseColour => self.reverse = !self.reverse,
+            IrcFmtByte::Reset => self = Self::new(),
+            IrcFmtByte::Colour | IrcFmtByte::HexColour => if let Some((foreground, background)) = colours {
+                let hex = matches!(fmt_byte, IrcFmtByte::HexColour);
+                self.foreground = if hex {IrcColour::from_hex(foreground)} else {IrcColour::from_code(foreground)};
+                if let Some(background) = background {
+                    self.background = if hex {IrcColour::from_hex(background)} else {IrcColour::from_code(background)};
+                }
+            } else {
+                self.foreground = None;
+                self.background = None;
+            },
+        }
+        self
+    }
+    /// Checks whether bold formatting is active.
+    #[must_use]
+    pub const fn is_bold(&self) -> bool {
+        self.bold
+    }
+    /// Checks whether italic formatting is active.
+    #[must_use]
+    pub const fn is_italics(&self) -> bool {
+        self.italics
+    }
+    /// Checks whether underline formatting is active.
+    #[must_use]
+    pub const fn is_underline(&self) -> bool {
+        self.underline
+    }
+    /// Checks whether strikethrough formatting is active.
+    #[must_use]
+    pub const fn is_strikethrough(&self) -> bool {
+        self.strikethrough
+    }
+    /// Checks whether monospace formatting is active.
+    #[must_use]
+    pub const fn is_monospace(&self) -> bool {
+        self.monospace
+    }
+    /// Checks whether the foreground and background colours are reversed.
+    #[must_use]
+    pub const fn is_reverse(&self) -> bool {
+        self.reverse
+    }
+    /// Returns the foreground [`IrcColour`] or `None` for the default colour.
+    #[must_use]
+    pub const fn foreground(&self) -> Option<IrcColour> {
+        self.foreground
+    }
+    /// Returns the background [`IrcColour`] or `None` for the default colour.
+    #[must_use]
+    pub const fn background(&self) -> Option<IrcColour> {
+        self.background
+    }
+    /// Checks whether the same formatting is active in both [`FmtState`].
+    #[must_use]
+    pub const fn is_same(&self, other: &Self) -> bool {
+        self.bold == other.bold && self.italics == other.italics && self.underline == other.underline
+            && self.strikethrough == other.strikethrough && self.monospace == other.monospace
+            && self.reverse == other.reverse && is_same_colour(self.foreground, other.foreground)
+            && is_same_colour(self.background, other.background)
+    }
+    /// Checks whether no formatting is active.
+    #[must_use]
+    pub const fn is_plain(&self) -> bool {
+        !self.bold && !self.italics && !self.underline && !self.strikethrough && !self.monospace && !self.reverse
+            && self.foreground.is_none() && self.background.is_none()
+    }
+}
+
+/// A cursor for reading formatted text one span at a time along with the [`FmtState`] applied to it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FmtSpans<'input> {
+    remaining: OptMsgPart<'input>,
+    state: FmtState,
+}
+
+impl<'input> FmtSpans<'input> {
+    /// Generates [`FmtSpans`] for `input` starting with no formatting applied.
+    #[must_use]
+    pub const fn new(input: &'input [u8]) -> Self {
+        Self{remaining: if input.is_empty() {None} else {Some(input)}, state: FmtState::new()}
+    }
+    /// Returns the next span of text without formatting bytes, the [`FmtState`] applied to it
+    /// and the advanced [`FmtSpans`].
+    ///
+    /// Returns `None` once all text has been read. Spans are never empty.
+    #[must_use]
+    pub const fn next_span(self) -> Option<(MsgPart<'input>, FmtState, Self)> {
+        let mut remaining = self.remaining;
+        let mut state = self.state;
+        while let Some(input) = remaining {
+            match IrcFmtByte::split_at_first_fmt_byte(input) {
+                Some((before, fmt_byte, colours, after)) => {
+                    let next_state = match fmt_byte {
+                        Some(fmt_byte) => state.apply(fmt_byte, colours),
+                        None => state,
+                    };
+                    if let Some(text) = before {
+                        return Some((text, state, Self{remaining: after, state: next_state}));
+                    }
+                    state = next_state;
+                    remaining = after;
+                },
+                None => remaining = None,
+            }
+        }
+        None
+    }
+    /// Returns the [`FmtState`] that will apply to the next span.
+    #[must_use]
+    pub const fn state(&self) -> FmtState {
+        self.state
+    }
+}
+
+/// A cursor for splitting formatted text into chunks of at most `max_visible` displayed bytes.
+///
+/// The formatting active at the start of each chunk is written again at the start of the chunk so that long
+/// formatted messages can be sent as multiple messages without losing formatting. A colour code paired with a hex
+/// colour is written with both colour bytes. Utf8 characters are not split unless a single character exceeds
+/// `max_visible`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FmtChunks<'input> {
+    spans: FmtSpans<'input>,
+    pending: Option<(MsgPart<'input>, FmtState)>,
+    max_visible: usize,
+}
+
+impl<'input> FmtChunks<'input> {
+    /// Generates [`FmtChunks`] for `input` with at most `max_visible` displayed bytes in each chunk.
+    ///
+    /// A `max_visible` of `0` produces no chunks.
+    #[must_use]
+    pub const fn new(input: &'input [u8], max_visible: usize) -> Self {
+        Self{spans: FmtSpans::new(input), pending: None, max_visible}
+    }
+    /// Returns the next chunk as a [`FmtWriter`] and the advanced [`FmtChunks`].
+    ///
+    /// Returns `None` once all text has been read.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if `N` is too small for the chunk and its formatting.
+    pub const fn next_chunk<const N: usize>(self) -> Result<Option<(FmtWriter<N>, Self)>, FmtWriterError> {
+        if self.max_visible == 0 {return Ok(None);}
+        let (mut spans, mut pending) = (self.spans, self.pending);
+        let mut writer = FmtWriter::new();
+        let mut written = FmtState::new();
+        let mut visible = 0;
+        while visible < self.max_visible {
+            let (text, state) = match pending {
+                Some(pending) => pending,
+                None => match spans.next_span() {
+                    Some((text, state, next)) => {
+                        spans = next;
+                        (text, state)
+                    },
+                    None => break,
+                },
+            };
+            pending = None;
+            let mut cut = if text.len() > self.max_visible - visible {self.max_visible - visible} else {text.len()};
+            while cut > 0 && cut < text.len() && is_utf8_continuation(text[cut]) {cut -= 1;}
+            if cut == 0 {
+                if visible > 0 {
+                    pending = Some((text, state));
+                    break;
+                }
+                cut = self.max_visible;
+                while cut < text.len() && is_utf8_continuation(text[cut]) {cut += 1;}
+            }
+            let (now, later) = text.split_at(cut);
+            writer = match writer.state(written, state) {Ok(writer) => writer, Err(e) => return Err(e)};
+            writer = match writer.plain(now) {Ok(writer) => writer, Err(e) => return Err(e)};
+            written = state;
+            visible += now.len();
+            if !later.is_empty() {pending = Some((later, state));}
+        }
+        if visible == 0 {return Ok(None);}
+        Ok(Some((writer, Self{spans, pending, max_visible: self.max_visible})))
+    }
+}
+
+/// A writer for composing formatted text into a buffer of `N` bytes.
+///
+/// Each piece of text is wrapped in the requested formatting which is terminated straight after it.
+/// Colour codes are always written with two digits so following digits in the text aren't read as colours.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FmtWriter<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FmtWriter<N> {
+    /// Generates an empty [`FmtWriter`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self{buffer: [0; N], len: 0}
+    }
+    /// Appends `text` without formatting.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the buffer is too small.
+    pub const fn plain(self, text: &[u8]) -> Result<Self, FmtWriterError> {
+        if self.len + text.len() > N {return Err(FmtWriterError::BufferFull);}
+        Ok(self.push(text))
+    }
+    /// Appends `text` in bold.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the buffer is too small.
+    pub const fn bold(self, text: &[u8]) -> Result<Self, FmtWriterError> {
+        self.toggled(IrcFmtByte::Bold, text)
+    }
+    /// Appends `text` in italics.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the buffer is too small.
+    pub const fn italics(self, text: &[u8]) -> Result<Self, FmtWriterError> {
+        self.toggled(IrcFmtByte::Italics, text)
+    }
+    /// Appends `text` underlined.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the buffer is too small.
+    pub const fn underline(self, text: &[u8]) -> Result<Self, FmtWriterError> {
+        self.toggled(IrcFmtByte::Underline, text)
+    }
+    /// Appends `text` with strikethrough.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the buffer is too small.
+    pub const fn strikethrough(self, text: &[u8]) -> Result<Self, FmtWriterError> {
+        self.toggled(IrcFmtByte::Strikethrough, text)
+    }
+    /// Appends `text` in monospace.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the buffer is too small.
+    pub const fn monospace(self, text: &[u8]) -> Result<Self, FmtWriterError> {
+        self.toggled(IrcFmtByte::Monospace, text)
+    }
+    /// Appends `text` with reversed colours.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the buffer is too small.
+    pub const fn reverse(self, text: &[u8]) -> Result<Self, FmtWriterError> {
+        self.toggled(IrcFmtByte::ReverseColour, text)
+    }
+    /// Appends `text` in the `foreground` and optional `background` irc colour codes.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the buffer is too small or a colour code is greater than `99`.
+    pub const fn colour(self, foreground: u8, background: Option<u8>, text: &[u8]) -> Result<Self, FmtWriterError> {
+        if foreground > 99 {return Err(FmtWriterError::InvalidColourCode(foreground));}
+        let codes = match background {
+            Some(background) if background > 99 => return Err(FmtWriterError::InvalidColourCode(background)),
+            Some(background) => [b'0' + foreground / 10, b'0' + foreground % 10, b',',
+                b'0' + background / 10, b'0' + background % 10],
+            None => [b'0' + foreground / 10, b'0' + foreground % 10, 0, 0, 0],
+        };
+        let codes_len = if background.is_some() {5} else {2};
+        if self.len + text.len() + codes_len + 2 > N {return Err(FmtWriterError::BufferFull);}
+        let (codes, _) = codes.split_at(codes_len);
+        Ok(self.push(&[IrcFmtByte::Colour.to_byte()]).push(codes).push(text).push(&[IrcFmtByte::Colour.to_byte()]))
+    }
+    /// Appends `text` in the `foreground` and optional `background` hex colours given as red, green and blue values.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the buffer is too small.
+    pub const fn hex_colour(self, foreground: (u8, u8, u8), background: Option<(u8, u8, u8)>, text: &[u8])
+    -> Result<Self, FmtWriterError> {
+        let codes_len = if background.is_some() {13} else {6};
+        if self.len + text.len() + codes_len + 2 > N {return Err(FmtWriterError::BufferFull);}
+        let mut output = self.push(&[IrcFmtByte::HexColour.to_byte()]).push(&hex_digits(foreground));
+        if let Some(background) = background {output = output.push(b",").push(&hex_digits(background));}
+        Ok(output.push(text).push(&[IrcFmtByte::HexColour.to_byte()]))
+    }
+    const fn state(self, from: FmtState, to: FmtState) -> Result<Self, FmtWriterError> {
+        if from.is_same(&to) {return Ok(self);}
+        let mut codes = [0; 24];
+        let mut len = 0;
+        if !from.is_plain() {
+            codes[len] = IrcFmtByte::Reset.to_byte();
+            len += 1;
+        }
+        let (foreground, background) = (to.foreground(), to.background());
+        let (colour, hex_colour) = (IrcFmtByte::Colour.to_byte(), IrcFmtByte::HexColour.to_byte());
+        match (foreground, background) {
+            (None, None) => (),
+            (Some(IrcColour::Rgb(..)), None | Some(IrcColour::Rgb(..))) => {
+                (codes, len) = push_colour(codes, len, hex_colour, foreground);
+                if background.is_some() {(codes, len) = push_colour(codes, len, b',', background);}
+            },
+            // Neither colour byte mixes codes and hex colours so the background is set first with a default
+            // foreground which is then replaced while keeping the background.
+            (_, Some(IrcColour::Rgb(..))) => {
+                (codes, len) = push_colour(codes, len, hex_colour, Some(IrcColour::Rgb(0, 0, 0)));
+                (codes, len) = push_colour(codes, len, b',', background);
+                (codes, len) = push_colour(codes, len, colour, foreground);
+            },
+            (Some(IrcColour::Rgb(..)), _) => {
+                (codes, len) = push_colour(codes, len, colour, None);
+                (codes, len) = push_colour(codes, len, b',', background);
+                (codes, len) = push_colour(codes, len, hex_colour, foreground);
+            },
+            _ => {
+                (codes, len) = push_colour(codes, len, colour, foreground);
+                (codes, len) = push_colour(codes, len, b',', background);
+            },
+        }
+        let toggles = [(to.is_bold(), IrcFmtByte::Bold), (to.is_italics(), IrcFmtByte::Italics),
+            (to.is_underline(), IrcFmtByte::Underline), (to.is_strikethrough(), IrcFmtByte::Strikethrough),
+            (to.is_monospace(), IrcFmtByte::Monospace), (to.is_reverse(), IrcFmtByte::ReverseColour)];
+        let mut index = 0;
+        while index < toggles.len() {
+            if toggles[index].0 {
+                codes[len] = toggles[index].1.to_byte();
+                len += 1;
+            }
+            index += 1;
+        }
+        let (codes, _) = codes.split_at(len);
+        self.plain(codes)
+    }
+    const fn toggled(self, fmt_byte: IrcFmtByte, text: &[u8]) -> Result<Self, FmtWriterError> {
+        if self.len + text.len() + 2 > N {return Err(FmtWriterError::BufferFull);}
+        Ok(self.push(&[fmt_byte.to_byte()]).push(text).push(&[fmt_byte.to_byte()]))
+    }
+    const fn push(mut self, input: &[u8]) -> Self {
+        let mut index = 0;
+        while index < input.len() {
+            self.buffer[self.len] = input[index];
+            self.len += 1;
+            index += 1;
+        }
+        self
+    }
+    /// Returns the written formatted text as a slice of bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        let (output, _) = self.buffer.split_at(self.len);
+        output
+    }
+}
+
+impl<const N: usize> Default for FmtWriter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The possible types of errors when composing formatted text with a [`FmtWriter`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FmtWriterError {
+    /// The buffer is too small for the formatted text.
+    BufferFull,
+    /// The irc colour code is greater than `99`.
+    InvalidColourCode(u8),
+}
+
+/// Writes formatted text as HTML markup into `output`.
+///
+/// Each span of text is wrapped in `<b>`, `<i>`, `<u>`, `<s>`, `<code>` and `<span style>` elements according to
+/// its [`FmtState`]. Reversed colours swap the foreground and background colours. Text is escaped and non-utf8
+/// bytes are replaced with `U+FFFD`.
+///
+/// Requires the `html` feature. Not const as functions on Traits are not yet allowed to be const.
+///
+/// # Errors
+///
+/// Will return `Err` if writing to `output` fails.
+#[cfg(feature = "html")]
+pub fn write_html<W: core::fmt::Write>(input: &[u8], output: &mut W) -> core::fmt::Result {
+    let mut spans = FmtSpans::new(input);
+    while let Some((text, state, next)) = spans.next_span() {
+        let (foreground, background) = if state.is_reverse() {
+            (state.background(), state.foreground())
+        } else {
+            (state.foreground(), state.background())
+        };
+        let foreground = match foreground {Some(colour) => colour.rgb(), None => None};
+        let background = match background {Some(colour) => colour.rgb(), None => None};
+        if state.is_bold() {output.write_str("<b>")?;}
+        if state.is_italics() {output.write_str("<i>")?;}
+        if state.is_underline() {output.write_str("<u>")?;}
+        if state.is_strikethrough() {output.write_str("<s>")?;}
+        if state.is_monospace() {output.write_str("<code>")?;}
+        if foreground.is_some() || background.is_some() {
+            output.write_str("<span style=\"")?;
+            if let Some((r, g, b)) = foreground {write!(output, "color:#{r:02x}{g:02x}{b:02x};")?;}
+            if let Some((r, g, b)) = background {write!(output, "background-color:#{r:02x}{g:02x}{b:02x};")?;}
+            output.write_str("\">")?;
+        }
+        write_escaped_html(text, output)?;
+        if foreground.is_some() || background.is_some() {output.write_str("</span>")?;}
+        if state.is_monospace() {output.write_str("</code>")?;}
+        if state.is_strikethrough() {output.write_str("</s>")?;}
+        if state.is_underline() {output.write_str("</u>")?;}
+        if state.is_italics() {output.write_str("</i>")?;}
+        if state.is_bold() {output.write_str("</b>")?;}
+        spans = next;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "html")]
+fn write_escaped_html<W: core::fmt::Write>(mut input: &[u8], output: &mut W) -> core::fmt::Result {
+    while !input.is_empty() {
+        let (valid, invalid, rest) = match core::str::from_utf8(input) {
+            Ok(valid) => (valid, false, &[][..]),
+            Err(e) => {
+                let (valid, invalid) = input.split_at(e.valid_up_to());
+                let (_, rest) = invalid.split_at(e.error_len().unwrap_or(invalid.len()));
+                (core::str::from_utf8(valid).unwrap_or_default(), true, rest)
+            },
+        };
+        for c in valid.chars() {
+            match c {
+                '&' => output.write_str("&amp;")?,
+                '<' => output.write_str("&lt;")?,
+                '>' => output.write_str("&gt;")?,
+                '"' => output.write_str("&quot;")?,
+                '\'' => output.write_str("&#39;")?,
+                _ => output.write_char(c)?,
+            }
+        }
+        if invalid {output.write_char(char::REPLACEMENT_CHARACTER)?;}
+        input = rest;
+    }
+    Ok(())
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 enum ColourCodeSize {
     SingleDigit,
@@ -268,10 +904,44 @@ const fn is_hex_colour(input: &[u8]) -> bool {
     true
 }
 
+const fn is_same_colour(first: Option<IrcColour>, second: Option<IrcColour>) -> bool {
+    match (first, second) {
+        (None, None) => true,
+        (Some(IrcColour::Code(a)), Some(IrcColour::Code(b))) => a == b,
+        (Some(IrcColour::Rgb(r1, g1, b1)), Some(IrcColour::Rgb(r2, g2, b2))) => r1 == r2 && g1 == g2 && b1 == b2,
+        _ => false,
+    }
+}
+
+const fn is_utf8_continuation(input: u8) -> bool {
+    input & 0b1100_0000 == 0b1000_0000
+}
+
+const fn push_colour(mut codes: [u8; 24], len: usize, lead: u8, colour: Option<IrcColour>) -> ([u8; 24], usize) {
+    codes[len] = lead;
+    let (digits, amount) = match colour {
+        Some(IrcColour::Rgb(r, g, b)) => (hex_digits((r, g, b)), 6),
+        Some(IrcColour::Code(code)) => ([b'0' + code / 10, b'0' + code % 10, 0, 0, 0, 0], 2),
+        None => ([b'9'; 6], 2),
+    };
+    let mut index = 0;
+    while index < amount {
+        codes[len + 1 + index] = digits[index];
+        index += 1;
+    }
+    (codes, len + 1 + amount)
+}
+
+const fn hex_digits((r, g, b): (u8, u8, u8)) -> [u8; 6] {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    [DIGITS[(r >> 4) as usize], DIGITS[(r & 15) as usize], DIGITS[(g >> 4) as usize], DIGITS[(g & 15) as usize],
+        DIGITS[(b >> 4) as usize], DIGITS[(b & 15) as usize]]
+}
+
 #[cfg(test)]
 mod const_tests {
     use crate::is_identical;
-    use super::IrcFmtByte;
+    use super::{FmtChunks, FmtSpans, FmtState, FmtWriter, FmtWriterError, IrcColour, IrcFmtByte};
     #[test]
     const fn detect_irc_formatting() {
         assert!(IrcFmtByte::contains_irc_formatting(b"Hey \x0366,88wha\x0399t's\x0400ff07,6672f4 u\x0fp!"));
@@ -286,7 +956,7 @@ mod const_tests {
         let result = IrcFmtByte::find_nth_fmt_byte_and_position(b"Hey \x0366,88wha\x0399t's\x0400ff07 u\x0fp!", 1);
         assert!(result.is_some());
         if let Some((fb, index)) = result {
-            if let IrcFmtByte::Colour = fb {assert!(true);}
+            assert!(matches!(fb, IrcFmtByte::Colour));
             assert!(index == 13);
         }
         assert!(IrcFmtByte::find_nth_fmt_byte_and_position(b"Hey what's up!", 1).is_none());
@@ -310,13 +980,14 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey wh"));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::Monospace = fb {assert!(true);}}
+            assert!(matches!(fb, Some(IrcFmtByte::Monospace)));
             assert!(colours.is_none());
             assert!(after.is_some());
             if let Some(after) = after {assert!(is_identical(after, b"at's up!"));}
         }
     }
     #[test]
+    #[allow(clippy::too_many_lines)]
     const fn splitting_messages_colours() {
         let output = IrcFmtByte::split_at_first_fmt_byte(b"Hey \x037what's up!");
         assert!(output.is_some());
@@ -324,7 +995,7 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey "));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::Colour = fb {assert!(true);}}
+            if let Some(fb) = fb {assert!(matches!(fb, IrcFmtByte::Colour));}
             assert!(colours.is_some());
             if let Some((fg, bg)) = colours {
                 assert!(is_identical(fg, b"7"));
@@ -339,7 +1010,7 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey "));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::Colour = fb {assert!(true);}}
+            if let Some(fb) = fb {assert!(matches!(fb, IrcFmtByte::Colour));}
             assert!(colours.is_some());
             if let Some((fg, bg)) = colours {
                 assert!(is_identical(fg, b"77"));
@@ -354,7 +1025,7 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey "));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::Colour = fb {assert!(true);}}
+            if let Some(fb) = fb {assert!(matches!(fb, IrcFmtByte::Colour));}
             assert!(colours.is_some());
             if let Some((fg, bg)) = colours {
                 assert!(is_identical(fg, b"7"));
@@ -370,7 +1041,7 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey "));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::Colour = fb {assert!(true);}}
+            if let Some(fb) = fb {assert!(matches!(fb, IrcFmtByte::Colour));}
             assert!(colours.is_some());
             if let Some((fg, bg)) = colours {
                 assert!(is_identical(fg, b"76"));
@@ -386,7 +1057,7 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey "));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::Colour = fb {assert!(true);}}
+            if let Some(fb) = fb {assert!(matches!(fb, IrcFmtByte::Colour));}
             assert!(colours.is_some());
             if let Some((fg, bg)) = colours {
                 assert!(is_identical(fg, b"76"));
@@ -402,7 +1073,7 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey "));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::Colour = fb {assert!(true);}}
+            if let Some(fb) = fb {assert!(matches!(fb, IrcFmtByte::Colour));}
             assert!(colours.is_some());
             if let Some((fg, bg)) = colours {
                 assert!(is_identical(fg, b"7"));
@@ -418,7 +1089,7 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey "));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::Colour = fb {assert!(true);}}
+            if let Some(fb) = fb {assert!(matches!(fb, IrcFmtByte::Colour));}
             assert!(colours.is_none());
             assert!(after.is_none());
         }
@@ -428,7 +1099,7 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey "));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::Colour = fb {assert!(true);}}
+            if let Some(fb) = fb {assert!(matches!(fb, IrcFmtByte::Colour));}
             assert!(colours.is_none());
             assert!(after.is_some());
             if let Some(after) = after {assert!(is_identical(after, b"!"));}
@@ -442,7 +1113,7 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey "));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::HexColour = fb {assert!(true);}}
+            assert!(matches!(fb, Some(IrcFmtByte::HexColour)));
             assert!(colours.is_some());
             if let Some((fg, bg)) = colours {
                 assert!(is_identical(fg, b"787878"));
@@ -457,7 +1128,7 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey "));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::HexColour = fb {assert!(true);}}
+            assert!(matches!(fb, Some(IrcFmtByte::HexColour)));
             assert!(colours.is_some());
             if let Some((fg, bg)) = colours {
                 assert!(is_identical(fg, b"787878"));
@@ -472,7 +1143,7 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey "));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::HexColour = fb {assert!(true);}}
+            assert!(matches!(fb, Some(IrcFmtByte::HexColour)));
             assert!(colours.is_none());
             assert!(after.is_none());
         }
@@ -482,10 +1153,252 @@ mod const_tests {
             assert!(before.is_some());
             if let Some(before) = before {assert!(is_identical(before, b"Hey "));}
             assert!(fb.is_some());
-            if let Some(fb) = fb {if let IrcFmtByte::HexColour = fb {assert!(true);}}
+            assert!(matches!(fb, Some(IrcFmtByte::HexColour)));
             assert!(colours.is_none());
             assert!(after.is_some());
             if let Some(after) = after {assert!(is_identical(after, b"!"));}
         }
     }
+    #[test]
+    const fn walking_spans() {
+        let spans = FmtSpans::new(b"Hey \x02\x0304,2bold red\x02\x03 plain\x04ff8000orange\x0f");
+        let spans = spans.next_span();
+        assert!(matches!(spans, Some((_, _, _))));
+        if let Some((text, state, spans)) = spans {
+            assert!(is_identical(text, b"Hey "));
+            assert!(state.is_plain());
+            let spans = spans.next_span();
+            assert!(matches!(spans, Some((_, _, _))));
+            if let Some((text, state, spans)) = spans {
+                assert!(is_identical(text, b"bold red"));
+                assert!(state.is_bold());
+                assert!(matches!(state.foreground(), Some(IrcColour::Code(4))));
+                assert!(matches!(state.background(), Some(IrcColour::Code(2))));
+                let spans = spans.next_span();
+                assert!(matches!(spans, Some((_, _, _))));
+                if let Some((text, state, spans)) = spans {
+                    assert!(is_identical(text, b" plain"));
+                    assert!(state.is_plain());
+                    let spans = spans.next_span();
+                    assert!(matches!(spans, Some((_, _, _))));
+                    if let Some((text, state, spans)) = spans {
+                        assert!(is_identical(text, b"orange"));
+                        assert!(matches!(state.foreground(), Some(IrcColour::Rgb(255, 128, 0))));
+                        assert!(state.background().is_none());
+                        assert!(spans.next_span().is_none());
+                        assert!(spans.state().is_plain());
+                    }
+                }
+            }
+        }
+        assert!(FmtSpans::new(b"").next_span().is_none());
+        assert!(FmtSpans::new(b"\x02\x1d").next_span().is_none());
+    }
+    #[test]
+    const fn applying_fmt_state() {
+        let state = FmtState::new().apply(IrcFmtByte::Bold, None).apply(IrcFmtByte::Underline, None);
+        assert!(state.is_bold() && state.is_underline() && !state.is_italics());
+        let state = state.apply(IrcFmtByte::Bold, None).apply(IrcFmtByte::ReverseColour, None);
+        assert!(!state.is_bold() && state.is_reverse());
+        let state = state.apply(IrcFmtByte::Colour, Some((b"12", Some(b"3"))));
+        assert!(matches!(state.foreground(), Some(IrcColour::Code(12))));
+        assert!(matches!(state.background(), Some(IrcColour::Code(3))));
+        let state = state.apply(IrcFmtByte::Colour, Some((b"99", None)));
+        assert!(state.foreground().is_none());
+        assert!(matches!(state.background(), Some(IrcColour::Code(3))));
+        let state = state.apply(IrcFmtByte::HexColour, Some((b"00FF7f", None)));
+        assert!(matches!(state.foreground(), Some(IrcColour::Rgb(0, 255, 127))));
+        let state = state.apply(IrcFmtByte::Colour, None);
+        assert!(state.foreground().is_none() && state.background().is_none());
+        assert!(state.is_underline() && state.is_reverse());
+        assert!(state.apply(IrcFmtByte::Reset, None).is_plain());
+    }
+    #[test]
+    const fn converting_colours_to_rgb() {
+        assert!(matches!(IrcColour::to_rgb(0), Some((255, 255, 255))));
+        assert!(matches!(IrcColour::to_rgb(4), Some((255, 0, 0))));
+        assert!(matches!(IrcColour::to_rgb(16), Some((0x47, 0, 0))));
+        assert!(matches!(IrcColour::to_rgb(53), Some((0xff, 0x8c, 0))));
+        assert!(matches!(IrcColour::to_rgb(98), Some((255, 255, 255))));
+        assert!(IrcColour::to_rgb(99).is_none());
+        assert!(IrcColour::to_rgb(200).is_none());
+        assert!(matches!(IrcColour::Code(1).rgb(), Some((0, 0, 0))));
+        assert!(matches!(IrcColour::Rgb(1, 2, 3).rgb(), Some((1, 2, 3))));
+    }
+    const fn written<const N: usize>(writer: Result<FmtWriter<N>, FmtWriterError>) -> FmtWriter<N> {
+        assert!(writer.is_ok());
+        match writer {Ok(writer) => writer, Err(_) => FmtWriter::new()}
+    }
+    #[test]
+    const fn composing_formatted_text() {
+        let writer = written(FmtWriter::<64>::new().plain(b"Hi "));
+        let writer = written(writer.bold(b"dan"));
+        let writer = written(writer.colour(4, None, b"1st"));
+        let writer = written(writer.colour(1, Some(8), b"!"));
+        let writer = written(writer.hex_colour((255, 128, 0), None, b"o"));
+        assert!(is_identical(writer.as_bytes(), b"Hi \x02dan\x02\x03041st\x03\x0301,08!\x03\x04ff8000o\x04"));
+        assert!(matches!(writer.colour(100, None, b"x"), Err(FmtWriterError::InvalidColourCode(100))));
+        assert!(matches!(writer.colour(1, Some(100), b"x"), Err(FmtWriterError::InvalidColourCode(100))));
+        assert!(matches!(FmtWriter::<4>::new().italics(b"abc"), Err(FmtWriterError::BufferFull)));
+        let spans = FmtSpans::new(writer.as_bytes()).next_span();
+        assert!(matches!(spans, Some((_, _, _))));
+        if let Some((_, _, spans)) = spans {
+            let spans = spans.next_span();
+            assert!(matches!(spans, Some((_, _, _))));
+            if let Some((_, _, spans)) = spans {
+                let span = spans.next_span();
+                assert!(matches!(span, Some((_, _, _))));
+                if let Some((text, state, _)) = span {
+                    assert!(is_identical(text, b"1st"));
+                    assert!(matches!(state.foreground(), Some(IrcColour::Code(4))));
+                }
+            }
+        }
+        let output = IrcFmtByte::split_at_first_fmt_byte(b"\x03041st");
+        assert!(matches!(output, Some((None, Some(IrcFmtByte::Colour), Some((_, None)), Some(_)))));
+        if let Some((None, Some(IrcFmtByte::Colour), Some((fg, None)), Some(after))) = output {
+            assert!(is_identical(fg, b"04") && is_identical(after, b"1st"));
+        }
+        assert!(IrcFmtByte::Underline.to_byte() == 31);
+    }
+    #[test]
+    const fn sanitizing_formatting() {
+        let input = b"\x02hi\x02 \x04ff0 \x0304,05red\x03 \x04aabbccx\x04!";
+        let output = IrcFmtByte::sanitize::<64>(input, None);
+        assert!(output.is_ok());
+        if let Ok(output) = output {
+            assert!(is_identical(output.as_bytes(), b"\x02hi\x02 ff0 \x0304,05red\x03 \x04aabbccx\x04!"));
+        }
+        let output = IrcFmtByte::sanitize::<64>(input, Some(&[IrcFmtByte::Bold]));
+        assert!(output.is_ok());
+        if let Ok(output) = output {assert!(is_identical(output.as_bytes(), b"\x02hi\x02 ff0 red x!"));}
+        let output = IrcFmtByte::sanitize::<64>(input, Some(&[]));
+        assert!(output.is_ok());
+        if let Ok(output) = output {assert!(!IrcFmtByte::contains_irc_formatting(output.as_bytes()));}
+        assert!(matches!(IrcFmtByte::sanitize::<4>(input, None), Err(FmtWriterError::BufferFull)));
+        let output = IrcFmtByte::sanitize::<0>(b"", None);
+        assert!(output.is_ok());
+        if let Ok(output) = output {assert!(output.as_bytes().is_empty());}
+    }
+    #[test]
+    const fn counting_visible_bytes() {
+        assert!(IrcFmtByte::visible_len(b"") == 0);
+        assert!(IrcFmtByte::visible_len(b"Hey what's up!") == 14);
+        assert!(IrcFmtByte::visible_len(b"Hey \x0366,88wha\x0399t's\x0400ff07,6672f4 u\x0fp!") == 14);
+        assert!(IrcFmtByte::visible_len(b"\x02\x1d\x1f\x1e\x11\x16\x037\x04\x0f") == 0);
+    }
+    const fn resumes_with(input: &[u8], expected: &[u8]) -> bool {
+        let chunks = FmtChunks::new(input, 2).next_chunk::<32>();
+        assert!(matches!(chunks, Ok(Some((_, _)))));
+        if let Ok(Some((_, chunks))) = chunks {
+            let chunk = chunks.next_chunk::<32>();
+            assert!(matches!(chunk, Ok(Some((_, _)))));
+            if let Ok(Some((chunk, _))) = chunk {return is_identical(chunk.as_bytes(), expected);}
+        }
+        false
+    }
+    #[test]
+    const fn splitting_into_chunks() {
+        let chunks = FmtChunks::new(b"ab\x02cd\x0304,12efg\x0f hi", 3);
+        let chunks = chunks.next_chunk::<32>();
+        assert!(matches!(chunks, Ok(Some((_, _)))));
+        if let Ok(Some((chunk, chunks))) = chunks {
+            assert!(is_identical(chunk.as_bytes(), b"ab\x02c"));
+            let chunks = chunks.next_chunk::<32>();
+            assert!(matches!(chunks, Ok(Some((_, _)))));
+            if let Ok(Some((chunk, chunks))) = chunks {
+                assert!(is_identical(chunk.as_bytes(), b"\x02d\x0f\x0304,12\x02ef"));
+                let chunks = chunks.next_chunk::<32>();
+                assert!(matches!(chunks, Ok(Some((_, _)))));
+                if let Ok(Some((chunk, chunks))) = chunks {
+                    assert!(is_identical(chunk.as_bytes(), b"\x0304,12\x02g\x0f h"));
+                    let chunks = chunks.next_chunk::<32>();
+                    assert!(matches!(chunks, Ok(Some((_, _)))));
+                    if let Ok(Some((chunk, chunks))) = chunks {
+                        assert!(is_identical(chunk.as_bytes(), b"i"));
+                        assert!(matches!(chunks.next_chunk::<32>(), Ok(None)));
+                    }
+                }
+            }
+        }
+        let chunks = FmtChunks::new("a\u{e9}\u{e9}".as_bytes(), 2);
+        let chunks = chunks.next_chunk::<32>();
+        assert!(matches!(chunks, Ok(Some((_, _)))));
+        if let Ok(Some((chunk, chunks))) = chunks {
+            assert!(is_identical(chunk.as_bytes(), b"a"));
+            let chunks = chunks.next_chunk::<32>();
+            assert!(matches!(chunks, Ok(Some((_, _)))));
+            if let Ok(Some((chunk, chunks))) = chunks {
+                assert!(is_identical(chunk.as_bytes(), "\u{e9}".as_bytes()));
+                let chunk = chunks.next_chunk::<32>();
+                assert!(matches!(chunk, Ok(Some((_, _)))));
+                if let Ok(Some((chunk, _))) = chunk {assert!(is_identical(chunk.as_bytes(), "\u{e9}".as_bytes()));}
+            }
+        }
+        let chunks = FmtChunks::new(b"\x04ff8000,0000ffhex", 8);
+        let chunk = chunks.next_chunk::<32>();
+        assert!(matches!(chunk, Ok(Some((_, _)))));
+        if let Ok(Some((chunk, _))) = chunk {assert!(is_identical(chunk.as_bytes(), b"\x04ff8000,0000ffhex"));}
+        assert!(resumes_with(b"\x04ff0000,00ff00ab\x0399cd", b"\x04000000,00ff00\x0399cd"));
+        assert!(resumes_with(b"\x04ff8000,0000ffab\x0304cd", b"\x04000000,0000ff\x0304cd"));
+        assert!(resumes_with(b"\x0304,12ab\x04ff8000cd", b"\x0399,12\x04ff8000cd"));
+        assert!(matches!(FmtChunks::new(b"abc", 0).next_chunk::<32>(), Ok(None)));
+        assert!(matches!(FmtChunks::new(b"\x02abc", 3).next_chunk::<2>(), Err(FmtWriterError::BufferFull)));
+    }
+}
+
+#[cfg(all(test, feature = "html"))]
+mod html_tests {
+    use crate::is_identical;
+    use super::write_html;
+    struct HtmlBuffer {
+        buffer: [u8; 256],
+        len: usize,
+    }
+    impl core::fmt::Write for HtmlBuffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len + s.len();
+            if end > self.buffer.len() {return Err(core::fmt::Error);}
+            self.buffer[self.len..end].copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+    fn html(input: &[u8]) -> HtmlBuffer {
+        let mut output = HtmlBuffer{buffer: [0; 256], len: 0};
+        assert!(write_html(input, &mut output).is_ok());
+        output
+    }
+    fn renders(input: &[u8], expected: &str) -> bool {
+        let output = html(input);
+        is_identical(&output.buffer[..output.len], expected.as_bytes())
+    }
+    #[test]
+    fn escaping_html() {
+        assert!(renders(b"<a href=\"x\">&'</a>", "&lt;a href=&quot;x&quot;&gt;&amp;&#39;&lt;/a&gt;"));
+        assert!(renders(b"", ""));
+    }
+    #[test]
+    fn nesting_styles() {
+        assert!(renders(b"\x02\x1d\x1f\x1e\x11all", "<b><i><u><s><code>all</code></s></u></i></b>"));
+        assert!(renders(b"\x02bold \x1dboth", "<b>bold </b><b><i>both</i></b>"));
+    }
+    #[test]
+    fn colouring_spans() {
+        assert!(renders(b"\x0304,01red", "<span style=\"color:#ff0000;background-color:#000000;\">red</span>"));
+        assert!(renders(b"\x0304red", "<span style=\"color:#ff0000;\">red</span>"));
+        assert!(renders(b"\x04ff8000,0000FFhex", "<span style=\"color:#ff8000;background-color:#0000ff;\">hex</span>"));
+        assert!(renders(b"\x0304,01\x16rev", "<span style=\"color:#000000;background-color:#ff0000;\">rev</span>"));
+    }
+    #[test]
+    fn resetting_mid_text() {
+        let expected = "<b><span style=\"color:#ff0000;\">bold red</span></b> plain";
+        assert!(renders(b"\x02\x0304bold red\x0f plain", expected));
+    }
+    #[test]
+    fn replacing_invalid_utf8() {
+        assert!(renders(b"a\xffb", "a\u{FFFD}b"));
+        assert!(renders(b"<\xc3\xa9\xe2\x82", "&lt;\u{e9}\u{FFFD}"));
+        assert!(renders(b"\x02\xff&", "<b>\u{FFFD}&amp;</b>"));
+    }
 }
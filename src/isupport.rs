@@ -9,8 +9,12 @@
 //! These [`ISupportToken`]s can be used for keeping track of appliction state for the advertised features.
 //! The first and trailing parameter in the `RPL_ISUPPORT` (`005`) numeric [`IrcMsg`](crate::IrcMsg) are not
 //! [`ISupportToken`]s. All the [`Parameters`](crate::Parameters) inbetween them are.
+//! By default only the bytes of a value are checked. [`ValueValidation::Strict`] also checks the values of
+//! [`KnownISupport`] parameters against their documented format.
 
-use crate::{ContentType, is_identical};
+use crate::{ContentType, IrcMsg, IrcMsgWriter, IrcMsgWriterError, casemapping::IrcCaseMapping, contains_byte,
+    is_identical, is_identical_ignoring_ascii_case, parameters::{CommaList, ParametersCursor},
+    parse_u32};
 
 /// A single ISUPPORT token.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -62,6 +66,56 @@ impl<'msg> ISupportToken<'msg> {
         };
         Ok(ISupportToken{set, parameter, equals_present, value})
     }
+    /// Generates an [`ISupportToken`] from a slice of bytes using the specified [`ValueValidation`].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the input is empty, the parameter or value contains an invalid byte as per the
+    /// [specification], the lack of a parameter before the `=`, the presence of an `=` if the token starts with `-`
+    /// or if using [`ValueValidation::Strict`] the value of a [`KnownISupport`] parameter is not in its documented
+    /// format.
+    ///
+    /// [specification]: <https://modern.ircdocs.horse/#rplisupport-005>
+    pub const fn parse_with_value_validation(input: &'msg[u8], validation: ValueValidation)
+    -> Result<Self, ISupportTokenError> {
+        match Self::parse(input) {
+            Ok(token) => match validation {
+                ValueValidation::Lenient => Ok(token),
+                ValueValidation::Strict => match token.validate_value() {
+                    Ok(()) => Ok(token),
+                    Err(e) => Err(e),
+                },
+            },
+            Err(e) => Err(e),
+        }
+    }
+    /// Checks the value of a [`KnownISupport`] parameter against its documented format.
+    ///
+    /// Lengths and limits must be numeric, `PREFIX`, `CHANMODES`, `TARGMAX`, `CHANLIMIT`, `MAXLIST` must parse and
+    /// `CASEMAPPING` must be a supported [`IrcCaseMapping`]. Tokens that are unset, have no value or have a parameter
+    /// not known to this library are always valid.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the value doesn't match the documented format of its parameter.
+    pub const fn validate_value(&self) -> Result<(), ISupportTokenError> {
+        let (Some(known), Some(value)) = (self.known(), self.value) else {return Ok(());};
+        let value = value.as_bytes();
+        let valid = match known {
+            KnownISupport::AwayLen | KnownISupport::ChannelLen | KnownISupport::HostLen | KnownISupport::KeyLen |
+            KnownISupport::KickLen | KnownISupport::LineLen | KnownISupport::MaxBans | KnownISupport::MaxChannels |
+            KnownISupport::MaxNickLen | KnownISupport::MaxPara | KnownISupport::MaxTargets | KnownISupport::Modes |
+            KnownISupport::Monitor | KnownISupport::NickLen | KnownISupport::Silence | KnownISupport::TopicLen |
+            KnownISupport::UserLen | KnownISupport::Watch => parse_u32(value).is_some(),
+            KnownISupport::Prefix => Prefix::parse(value).is_ok(),
+            KnownISupport::ChanModes => ChanModes::parse(value).is_ok(),
+            KnownISupport::TargMax | KnownISupport::ChanLimit => TargMax::parse(value).is_ok(),
+            KnownISupport::MaxList => MaxList::parse(value).is_ok(),
+            KnownISupport::CaseMapping => IrcCaseMapping::from_value(value).is_ok(),
+            _ => true,
+        };
+        if valid {Ok(())} else {Err(ISupportTokenError::InvalidKnownValue(known))}
+    }
     /// Generates an [`ISupportToken`] from a [`ContentType`].
     ///
     /// # Errors
@@ -76,6 +130,20 @@ impl<'msg> ISupportToken<'msg> {
             ContentType::NonUtf8ByteSlice(bytes) => Self::parse(bytes),
         }
     }
+    /// Generates [`ISupportTokens`] for reading each [`ISupportToken`] of an `RPL_ISUPPORT` (`005`) [`IrcMsg`].
+    ///
+    /// The first (client) and last (`are supported by this server`) [`Parameters`](crate::Parameters) are skipped.
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_ISUPPORT` (`005`) numeric.
+    #[must_use]
+    pub const fn iter_from_msg(msg: &IrcMsg<'msg>) -> Option<ISupportTokens<'msg>> {
+        if !matches!(msg.command().as_numeric_u16(), Some(5)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        let remaining = params.count().saturating_sub(2);
+        match params.cursor().next_param() {
+            Some((_, cursor)) => Some(ISupportTokens{cursor, remaining}),
+            None => None,
+        }
+    }
     /// Checks a slice of [`ISupportToken`]s for duplicate parameters.
     ///
     /// An IRC server should not send the same [`ISupportToken`] in a single `RPL_ISUPPORT` (`005`)
@@ -99,14 +167,33 @@ impl<'msg> ISupportToken<'msg> {
     }
     /// Returns the parameter of the [`ISupportToken`] as a [`ContentType`].
     #[must_use]
-    pub const fn parameter(&self) -> ContentType {
+    pub const fn parameter(&self) -> ContentType<'msg> {
         self.parameter
     }
     /// Returns the value of the [`ISupportToken`] as a [`ContentType`] if it exists.
     #[must_use]
-    pub const fn value(&self) -> Option<ContentType> {
+    pub const fn value(&self) -> Option<ContentType<'msg>> {
         self.value
     }
+    /// Returns the [`KnownISupport`] of the [`ISupportToken`] parameter.
+    ///
+    /// Returns `None` if the parameter is not known to this library.
+    #[must_use]
+    pub const fn known(&self) -> Option<KnownISupport> {
+        let parameter = self.parameter.as_bytes();
+        let mut index = 0;
+        while index < KNOWN_ISUPPORT.len() {
+            if is_identical(parameter, KNOWN_ISUPPORT[index].0.as_bytes()) {return Some(KNOWN_ISUPPORT[index].1);}
+            index += 1;
+        }
+        None
+    }
+    const fn parts(&self) -> [&'msg [u8]; 3] {
+        let value = match self.value {Some(value) => value.as_bytes(), None => &[]};
+        if !self.set {[b"-", self.parameter.as_bytes(), &[]]}
+        else if self.equals_present {[self.parameter.as_bytes(), b"=", value]}
+        else {[self.parameter.as_bytes(), &[], &[]]}
+    }
     /// Check whether the [`ISupportToken`] is set.
     #[must_use]
     pub const fn is_set(&self) -> bool {
@@ -114,6 +201,99 @@ impl<'msg> ISupportToken<'msg> {
     }
 }
 
+/// How thoroughly to check the value of an [`ISupportToken`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ValueValidation {
+    /// Only check the value contains valid bytes.
+    #[default]
+    Lenient,
+    /// Also check the value of a [`KnownISupport`] parameter against its documented format.
+    Strict,
+}
+
+/// A cursor for reading each [`ISupportToken`] of an `RPL_ISUPPORT` (`005`) [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ISupportTokens<'msg> {
+    cursor: ParametersCursor<'msg>,
+    remaining: usize,
+}
+
+impl<'msg> ISupportTokens<'msg> {
+    /// Returns the next parsed [`ISupportToken`] and the advanced [`ISupportTokens`].
+    ///
+    /// Returns `None` once all tokens have been read.
+    #[must_use]
+    pub const fn next_token(self) -> Option<(Result<ISupportToken<'msg>, ISupportTokenError>, Self)> {
+        if self.remaining == 0 {return None;}
+        match self.cursor.next_param() {
+            Some((param, cursor)) => {
+                Some((ISupportToken::from_contenttype(param), Self{cursor, remaining: self.remaining - 1}))
+            },
+            None => None,
+        }
+    }
+    /// Returns the amount of tokens that have not been read yet.
+    #[must_use]
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+/// The maximum amount of [`ISupportToken`]s in a single `RPL_ISUPPORT` (`005`) [`IrcMsg`].
+pub const MAX_TOKENS_PER_REPLY: usize = 13;
+
+const ISUPPORT_TRAILING: &[u8] = b"are supported by this server";
+
+/// A cursor for writing a slice of [`ISupportToken`]s as `RPL_ISUPPORT` (`005`) [`IrcMsg`]s.
+///
+/// Each [`IrcMsg`] contains the source, the client, up to [`MAX_TOKENS_PER_REPLY`] tokens and the trailing
+/// `are supported by this server`. Tokens continue in the next [`IrcMsg`] when the buffer is full.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ISupportReplies<'a> {
+    source: &'a [u8],
+    client: &'a [u8],
+    tokens: &'a [ISupportToken<'a>],
+    index: usize,
+}
+
+impl<'a> ISupportReplies<'a> {
+    /// Generates [`ISupportReplies`] sent from the servername `source` to the nickname `client`.
+    #[must_use]
+    pub const fn new(source: &'a [u8], client: &'a [u8], tokens: &'a [ISupportToken<'a>]) -> Self {
+        Self{source, client, tokens, index: 0}
+    }
+    /// Returns the next `RPL_ISUPPORT` (`005`) [`IrcMsg`] as an [`IrcMsgWriter`] and the advanced
+    /// [`ISupportReplies`].
+    ///
+    /// Returns `None` once all tokens have been written.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the source or client are invalid or `N` is too small for a single token.
+    pub const fn next_msg<const N: usize>(self) -> Result<Option<(IrcMsgWriter<N>, Self)>, IrcMsgWriterError> {
+        if self.index >= self.tokens.len() {return Ok(None);}
+        let writer = match IrcMsgWriter::new().source(self.source) {Ok(writer) => writer, Err(e) => return Err(e)};
+        let writer = match writer.command(b"005") {Ok(writer) => writer, Err(e) => return Err(e)};
+        let mut writer = match writer.middle(self.client) {Ok(writer) => writer, Err(e) => return Err(e)};
+        let (mut index, mut amount) = (self.index, 0);
+        while index < self.tokens.len() && amount < MAX_TOKENS_PER_REPLY {
+            let parts = self.tokens[index].parts();
+            let needed = 1 + parts[0].len() + parts[1].len() + parts[2].len() + 2 + ISUPPORT_TRAILING.len();
+            if writer.as_bytes().len() + needed > N {
+                if amount == 0 {return Err(IrcMsgWriterError::BufferFull);}
+                break;
+            }
+            writer = match writer.middle_parts(&parts) {Ok(writer) => writer, Err(e) => return Err(e)};
+            index += 1;
+            amount += 1;
+        }
+        match writer.trailing(ISUPPORT_TRAILING) {
+            Ok(writer) => Ok(Some((writer, Self{index, ..self}))),
+            Err(e) => Err(e),
+        }
+    }
+}
+
 impl core::fmt::Display for ISupportToken<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if !self.is_set() {write!(f, "-")?;}
@@ -123,6 +303,789 @@ impl core::fmt::Display for ISupportToken<'_> {
     }
 }
 
+/// The type of a channel mode as advertised by the `CHANMODES` [`ISupportToken`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModeType {
+    /// Modifies a list such as bans. Always has a parameter when sent by the server.
+    A,
+    /// Modifies a setting. Always has a parameter.
+    B,
+    /// Modifies a setting. Only has a parameter when set.
+    C,
+    /// Modifies a setting. Never has a parameter.
+    D,
+}
+
+/// The channel modes of each [`ModeType`] from the value of the `CHANMODES` [`ISupportToken`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ChanModes<'msg> {
+    a: &'msg [u8],
+    b: &'msg [u8],
+    c: &'msg [u8],
+    d: &'msg [u8],
+}
+
+impl<'msg> ChanModes<'msg> {
+    /// Generates [`ChanModes`] from the value of a `CHANMODES` [`ISupportToken`] such as `b,k,l,imnpst`.
+    ///
+    /// Groups after the fourth are ignored as per the [specification].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if there are fewer than 4 comma separated groups or a mode is not an ascii letter.
+    ///
+    /// [specification]: <https://modern.ircdocs.horse/#chanmodes-parameter>
+    pub const fn parse(input: &'msg [u8]) -> Result<Self, ChanModesError> {
+        let mut groups: [&[u8]; 4] = [&[]; 4];
+        let mut amount = 0;
+        let mut start = 0;
+        let mut index = 0;
+        while index <= input.len() && amount < 4 {
+            if index == input.len() || input[index] == b',' {
+                let (before, _) = input.split_at(index);
+                let (_, group) = before.split_at(start);
+                groups[amount] = group;
+                amount += 1;
+                start = index + 1;
+            } else if !input[index].is_ascii_alphabetic() {
+                return Err(ChanModesError::InvalidByte(input[index]));
+            }
+            index += 1;
+        }
+        if amount < 4 {return Err(ChanModesError::TooFewGroups(amount));}
+        Ok(Self{a: groups[0], b: groups[1], c: groups[2], d: groups[3]})
+    }
+    /// Returns the [`ModeType`] of a channel `mode` or `None` if the mode is not advertised.
+    #[must_use]
+    pub const fn mode_type(&self, mode: u8) -> Option<ModeType> {
+        if contains_byte(self.a, mode) {Some(ModeType::A)}
+        else if contains_byte(self.b, mode) {Some(ModeType::B)}
+        else if contains_byte(self.c, mode) {Some(ModeType::C)}
+        else if contains_byte(self.d, mode) {Some(ModeType::D)}
+        else {None}
+    }
+    /// Returns the channel modes of the specified [`ModeType`].
+    #[must_use]
+    pub const fn modes(&self, mode_type: ModeType) -> &'msg [u8] {
+        match mode_type {
+            ModeType::A => self.a,
+            ModeType::B => self.b,
+            ModeType::C => self.c,
+            ModeType::D => self.d,
+        }
+    }
+}
+
+/// The possible types of errors when parsing [`ChanModes`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChanModesError {
+    /// Fewer than 4 groups of modes were found.
+    TooFewGroups(usize),
+    /// Use of an invalid byte when parsing a mode.
+    InvalidByte(u8),
+}
+
+/// The maximum amount of targets per command from the value of the `TARGMAX` [`ISupportToken`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TargMax<'msg> {
+    content: &'msg [u8],
+}
+
+impl<'msg> TargMax<'msg> {
+    /// Generates [`TargMax`] from the value of a `TARGMAX` [`ISupportToken`] such as `PRIVMSG:3,WHOIS:1,JOIN:`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a command is empty or not followed by `:` or a limit is not a valid [`u32`].
+    pub const fn parse(input: &'msg [u8]) -> Result<Self, TargMaxError> {
+        let mut list = CommaList::new(ContentType::new(input));
+        while let Some((item, next)) = list.next_item() {
+            match split_at_colon(item.as_bytes()) {
+                Some((command, limit)) => {
+                    if command.is_empty() {return Err(TargMaxError::EmptyCommand);}
+                    else if !limit.is_empty() && parse_u32(limit).is_none() {return Err(TargMaxError::InvalidLimit);}
+                },
+                None => return Err(TargMaxError::MissingColon),
+            }
+            list = next;
+        }
+        Ok(Self{content: input})
+    }
+    /// Returns the maximum amount of targets for the `command` or `None` if there is no limit.
+    ///
+    /// The `command` is compared case insensitively. A `command` not advertised is also `None`,
+    /// use [`contains`](TargMax::contains) to tell them apart.
+    #[must_use]
+    pub const fn limit_for(&self, command: &[u8]) -> Option<u32> {
+        match self.find(command) {
+            Some(limit) => parse_u32(limit),
+            None => None,
+        }
+    }
+    /// Checks whether the `command` is advertised.
+    ///
+    /// The `command` is compared case insensitively.
+    #[must_use]
+    pub const fn contains(&self, command: &[u8]) -> bool {
+        self.find(command).is_some()
+    }
+    const fn find(&self, target: &[u8]) -> Option<&'msg [u8]> {
+        let mut list = CommaList::new(ContentType::new(self.content));
+        while let Some((item, next)) = list.next_item() {
+            if let Some((command, limit)) = split_at_colon(item.as_bytes()) {
+                if is_identical_ignoring_ascii_case(command, target) {return Some(limit);}
+            }
+            list = next;
+        }
+        None
+    }
+}
+
+/// The possible types of errors when parsing [`TargMax`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TargMaxError {
+    /// A command is missing before the `:`.
+    EmptyCommand,
+    /// A command is not followed by `:`.
+    MissingColon,
+    /// A limit is not a valid [`u32`].
+    InvalidLimit,
+}
+
+/// The maximum amount of entries in list modes from the value of the `MAXLIST` [`ISupportToken`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MaxList<'msg> {
+    content: &'msg [u8],
+}
+
+impl<'msg> MaxList<'msg> {
+    /// Generates [`MaxList`] from the value of a `MAXLIST` [`ISupportToken`] such as `beI:60,q:20`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if modes are empty, not ascii letters or not followed by `:` or a limit is not a valid
+    /// [`u32`].
+    pub const fn parse(input: &'msg [u8]) -> Result<Self, MaxListError> {
+        let mut list = CommaList::new(ContentType::new(input));
+        while let Some((item, next)) = list.next_item() {
+            match split_at_colon(item.as_bytes()) {
+                Some((modes, limit)) => {
+                    if modes.is_empty() {return Err(MaxListError::EmptyModes);}
+                    let mut index = 0;
+                    while index < modes.len() {
+                        if !modes[index].is_ascii_alphabetic() {return Err(MaxListError::InvalidMode(modes[index]));}
+                        index += 1;
+                    }
+                    if parse_u32(limit).is_none() {return Err(MaxListError::InvalidLimit);}
+                },
+                None => return Err(MaxListError::MissingColon),
+            }
+            list = next;
+        }
+        Ok(Self{content: input})
+    }
+    /// Returns the maximum amount of entries for the list `mode` or `None` if the mode is not advertised.
+    ///
+    /// Modes advertised together such as `beI:60` share the limit between them.
+    #[must_use]
+    pub const fn limit_for(&self, mode: u8) -> Option<u32> {
+        let mut list = CommaList::new(ContentType::new(self.content));
+        while let Some((item, next)) = list.next_item() {
+            if let Some((modes, limit)) = split_at_colon(item.as_bytes()) {
+                if contains_byte(modes, mode) {return parse_u32(limit);}
+            }
+            list = next;
+        }
+        None
+    }
+}
+
+/// The possible types of errors when parsing [`MaxList`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MaxListError {
+    /// Modes are missing before the `:`.
+    EmptyModes,
+    /// Use of an invalid byte as a mode.
+    InvalidMode(u8),
+    /// Modes are not followed by `:`.
+    MissingColon,
+    /// A limit is not a valid [`u32`].
+    InvalidLimit,
+}
+
+/// The channel membership modes and their prefixes from the value of the `PREFIX` [`ISupportToken`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Prefix<'msg> {
+    modes: &'msg [u8],
+    prefixes: &'msg [u8],
+}
+
+impl<'msg> Prefix<'msg> {
+    /// The default `PREFIX` value of `(ov)@+` to use when the IRC server doesn't advertise one.
+    pub const DEFAULT: Prefix<'static> = Prefix{modes: b"ov", prefixes: b"@+"};
+    /// Generates a [`Prefix`] from the value of a `PREFIX` [`ISupportToken`] such as `(qaohv)~&@%+`.
+    ///
+    /// An empty value indicates no channel membership prefixes.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the modes are not enclosed in parentheses or the amount of modes and prefixes differ.
+    pub const fn parse(input: &'msg [u8]) -> Result<Self, PrefixError> {
+        if input.is_empty() {return Ok(Self{modes: &[], prefixes: &[]});}
+        else if input[0] != b'(' {return Err(PrefixError::MissingParentheses);}
+        let mut index = 1;
+        while index < input.len() {
+            if input[index] == b')' {
+                let (modes, prefixes) = input.split_at(index);
+                let (_, modes) = modes.split_at(1);
+                let (_, prefixes) = prefixes.split_at(1);
+                if modes.len() != prefixes.len() {return Err(PrefixError::LengthMismatch);}
+                return Ok(Self{modes, prefixes});
+            }
+            index += 1;
+        }
+        Err(PrefixError::MissingParentheses)
+    }
+    /// Returns the channel membership modes from highest to lowest rank.
+    #[must_use]
+    pub const fn modes(&self) -> &'msg [u8] {
+        self.modes
+    }
+    /// Returns the channel membership prefixes from highest to lowest rank.
+    #[must_use]
+    pub const fn prefixes(&self) -> &'msg [u8] {
+        self.prefixes
+    }
+    /// Returns the prefix of a channel membership `mode` if advertised.
+    #[must_use]
+    pub const fn prefix_for_mode(&self, mode: u8) -> Option<u8> {
+        let mut index = 0;
+        while index < self.modes.len() {
+            if self.modes[index] == mode {return Some(self.prefixes[index]);}
+            index += 1;
+        }
+        None
+    }
+    /// Returns the channel membership mode of a `prefix` if advertised.
+    #[must_use]
+    pub const fn mode_for_prefix(&self, prefix: u8) -> Option<u8> {
+        let mut index = 0;
+        while index < self.prefixes.len() {
+            if self.prefixes[index] == prefix {return Some(self.modes[index]);}
+            index += 1;
+        }
+        None
+    }
+}
+
+/// The possible types of errors when parsing a [`Prefix`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PrefixError {
+    /// The modes are not enclosed in parentheses.
+    MissingParentheses,
+    /// The amount of modes and prefixes differ.
+    LengthMismatch,
+}
+
+/// The maximum length of an [`ISupportToken`] parameter stored by [`ISupport`].
+pub const MAX_ISUPPORT_PARAMETER_LEN: usize = 32;
+/// The maximum length of an [`ISupportToken`] value stored by [`ISupport`].
+pub const MAX_ISUPPORT_VALUE_LEN: usize = 128;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct ISupportEntry {
+    parameter: [u8; MAX_ISUPPORT_PARAMETER_LEN],
+    parameter_len: usize,
+    value: [u8; MAX_ISUPPORT_VALUE_LEN],
+    value_len: usize,
+}
+
+impl ISupportEntry {
+    const EMPTY: Self = Self{parameter: [0; MAX_ISUPPORT_PARAMETER_LEN], parameter_len: 0,
+        value: [0; MAX_ISUPPORT_VALUE_LEN], value_len: 0};
+    const fn parameter(&self) -> &[u8] {
+        let (output, _) = self.parameter.split_at(self.parameter_len);
+        output
+    }
+    const fn value(&self) -> Option<&[u8]> {
+        if self.value_len == 0 {return None;}
+        let (output, _) = self.value.split_at(self.value_len);
+        Some(output)
+    }
+}
+
+/// Keeps track of up to `N` [`ISupportToken`]s currently set by an IRC server.
+///
+/// The parameters and values are copied so the [`IrcMsg`](crate::IrcMsg)s they came from don't need to be kept.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ISupport<const N: usize> {
+    entries: [ISupportEntry; N],
+    amount: usize,
+}
+
+impl<const N: usize> ISupport<N> {
+    /// Generates an [`ISupport`] without any [`ISupportToken`]s set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self{entries: [ISupportEntry::EMPTY; N], amount: 0}
+    }
+    /// Applies an [`ISupportToken`] by setting, replacing or unsetting its parameter.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a new parameter doesn't fit in the `N` entries or the parameter or value is longer than
+    /// [`MAX_ISUPPORT_PARAMETER_LEN`] or [`MAX_ISUPPORT_VALUE_LEN`].
+    pub const fn apply(mut self, token: &ISupportToken<'_>) -> Result<Self, ISupportError> {
+        let parameter = token.parameter.as_bytes();
+        let position = self.position(parameter);
+        if !token.set {
+            if let Some(position) = position {
+                self.amount -= 1;
+                self.entries[position] = self.entries[self.amount];
+                self.entries[self.amount] = ISupportEntry::EMPTY;
+            }
+            return Ok(self);
+        }
+        let value = match token.value {Some(value) => value.as_bytes(), None => &[]};
+        if parameter.len() > MAX_ISUPPORT_PARAMETER_LEN {return Err(ISupportError::ParameterTooLong);}
+        else if value.len() > MAX_ISUPPORT_VALUE_LEN {return Err(ISupportError::ValueTooLong);}
+        let position = match position {
+            Some(position) => position,
+            None if self.amount == N => return Err(ISupportError::TableFull),
+            None => {
+                self.amount += 1;
+                self.amount - 1
+            },
+        };
+        let mut entry = ISupportEntry::EMPTY;
+        let mut index = 0;
+        while index < parameter.len() {
+            entry.parameter[index] = parameter[index];
+            index += 1;
+        }
+        entry.parameter_len = parameter.len();
+        index = 0;
+        while index < value.len() {
+            entry.value[index] = value[index];
+            index += 1;
+        }
+        entry.value_len = value.len();
+        self.entries[position] = entry;
+        Ok(self)
+    }
+    const fn position(&self, parameter: &[u8]) -> Option<usize> {
+        let mut index = 0;
+        while index < self.amount {
+            if is_identical(self.entries[index].parameter(), parameter) {return Some(index);}
+            index += 1;
+        }
+        None
+    }
+    /// Returns the amount of [`ISupportToken`]s currently set.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.amount
+    }
+    /// Checks whether the `parameter` is currently set.
+    #[must_use]
+    pub const fn is_set(&self, parameter: &[u8]) -> bool {
+        self.position(parameter).is_some()
+    }
+    /// Returns the value of the `parameter` if it is currently set with a value.
+    #[must_use]
+    pub const fn value(&self, parameter: &[u8]) -> Option<&[u8]> {
+        match self.position(parameter) {
+            Some(position) => self.entries[position].value(),
+            None => None,
+        }
+    }
+    const fn number(&self, parameter: &[u8]) -> Option<u32> {
+        match self.value(parameter) {
+            Some(value) => parse_u32(value),
+            None => None,
+        }
+    }
+    /// Returns the value of `NICKLEN` if set and valid.
+    #[must_use]
+    pub const fn nicklen(&self) -> Option<u32> {
+        self.number(b"NICKLEN")
+    }
+    /// Returns the value of `CHANNELLEN` if set and valid.
+    #[must_use]
+    pub const fn channellen(&self) -> Option<u32> {
+        self.number(b"CHANNELLEN")
+    }
+    /// Returns the value of `TOPICLEN` if set and valid.
+    #[must_use]
+    pub const fn topiclen(&self) -> Option<u32> {
+        self.number(b"TOPICLEN")
+    }
+    /// Returns the value of `KICKLEN` if set and valid.
+    #[must_use]
+    pub const fn kicklen(&self) -> Option<u32> {
+        self.number(b"KICKLEN")
+    }
+    /// Returns the value of `AWAYLEN` if set and valid.
+    #[must_use]
+    pub const fn awaylen(&self) -> Option<u32> {
+        self.number(b"AWAYLEN")
+    }
+    /// Returns the value of `NETWORK` if set.
+    #[must_use]
+    pub const fn network(&self) -> Option<&[u8]> {
+        self.value(b"NETWORK")
+    }
+    /// Returns the value of `CHANTYPES` if set.
+    #[must_use]
+    pub const fn chantypes(&self) -> Option<&[u8]> {
+        self.value(b"CHANTYPES")
+    }
+    /// Returns the value of `STATUSMSG` if set.
+    #[must_use]
+    pub const fn statusmsg(&self) -> Option<&[u8]> {
+        self.value(b"STATUSMSG")
+    }
+    /// Returns the [`IrcCaseMapping`] from `CASEMAPPING` if set to a supported value.
+    ///
+    /// Returns `None` for `rfc8265` and `rfc7613` as [`IrcCaseMapping::PrecisApproximation`] must be opted into.
+    #[must_use]
+    pub const fn casemapping(&self) -> Option<IrcCaseMapping> {
+        match self.value(b"CASEMAPPING") {
+            Some(value) => match IrcCaseMapping::from_value(value) {
+                Ok(casemapping) => Some(casemapping),
+                Err(_) => None,
+            },
+            None => None,
+        }
+    }
+    /// Returns the [`Prefix`] from `PREFIX`.
+    ///
+    /// Returns [`Prefix::DEFAULT`] if not set or invalid. A `PREFIX` set without a value has no prefixes.
+    #[must_use]
+    pub const fn prefix(&self) -> Prefix<'_> {
+        match self.position(b"PREFIX") {
+            Some(position) => match self.entries[position].value() {
+                Some(value) => match Prefix::parse(value) {
+                    Ok(prefix) => prefix,
+                    Err(_) => Prefix::DEFAULT,
+                },
+                None => Prefix{modes: &[], prefixes: &[]},
+            },
+            None => Prefix::DEFAULT,
+        }
+    }
+    /// Returns the [`ChanModes`] from `CHANMODES` if set and valid.
+    #[must_use]
+    pub const fn chanmodes(&self) -> Option<ChanModes<'_>> {
+        match self.value(b"CHANMODES") {
+            Some(value) => match ChanModes::parse(value) {
+                Ok(chanmodes) => Some(chanmodes),
+                Err(_) => None,
+            },
+            None => None,
+        }
+    }
+    /// Returns the [`TargMax`] from `TARGMAX` if set and valid.
+    #[must_use]
+    pub const fn targmax(&self) -> Option<TargMax<'_>> {
+        match self.value(b"TARGMAX") {
+            Some(value) => match TargMax::parse(value) {
+                Ok(targmax) => Some(targmax),
+                Err(_) => None,
+            },
+            None => None,
+        }
+    }
+    /// Returns the [`MaxList`] from `MAXLIST` if set and valid.
+    #[must_use]
+    pub const fn maxlist(&self) -> Option<MaxList<'_>> {
+        match self.value(b"MAXLIST") {
+            Some(value) => match MaxList::parse(value) {
+                Ok(maxlist) => Some(maxlist),
+                Err(_) => None,
+            },
+            None => None,
+        }
+    }
+}
+
+/// A difference between two [`ISupport`] states.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ISupportChange<'a> {
+    /// A parameter and its value only set in the newer state.
+    Added(&'a [u8], Option<&'a [u8]>),
+    /// A parameter only set in the older state.
+    Removed(&'a [u8]),
+    /// A parameter set in both states along with its older and newer value.
+    Changed(&'a [u8], Option<&'a [u8]>, Option<&'a [u8]>),
+}
+
+/// A cursor for reading each [`ISupportChange`] between two [`ISupport`] states.
+///
+/// Removed and changed parameters are read first followed by added parameters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ISupportDiff<'a, const N: usize, const M: usize> {
+    older: &'a ISupport<N>,
+    newer: &'a ISupport<M>,
+    index: usize,
+}
+
+impl<'a, const N: usize, const M: usize> ISupportDiff<'a, N, M> {
+    /// Generates an [`ISupportDiff`] comparing the `older` state to the `newer` state.
+    #[must_use]
+    pub const fn new(older: &'a ISupport<N>, newer: &'a ISupport<M>) -> Self {
+        Self{older, newer, index: 0}
+    }
+    /// Returns the next [`ISupportChange`] and the advanced [`ISupportDiff`].
+    ///
+    /// Returns `None` once all changes have been read.
+    #[must_use]
+    pub const fn next_change(self) -> Option<(ISupportChange<'a>, Self)> {
+        let mut index = self.index;
+        while index < self.older.amount {
+            let entry = &self.older.entries[index];
+            index += 1;
+            let next = Self{older: self.older, newer: self.newer, index};
+            match self.newer.position(entry.parameter()) {
+                Some(position) => {
+                    let (old, new) = (entry.value(), self.newer.entries[position].value());
+                    if !is_same_value(old, new) {
+                        return Some((ISupportChange::Changed(entry.parameter(), old, new), next));
+                    }
+                },
+                None => return Some((ISupportChange::Removed(entry.parameter()), next)),
+            }
+        }
+        while index < self.older.amount + self.newer.amount {
+            let entry = &self.newer.entries[index - self.older.amount];
+            index += 1;
+            if self.older.position(entry.parameter()).is_none() {
+                let next = Self{older: self.older, newer: self.newer, index};
+                return Some((ISupportChange::Added(entry.parameter(), entry.value()), next));
+            }
+        }
+        None
+    }
+    /// Checks whether there are no differences between the two [`ISupport`] states.
+    #[must_use]
+    pub const fn is_unchanged(&self) -> bool {
+        Self::new(self.older, self.newer).next_change().is_none()
+    }
+}
+
+const fn is_same_value(first: Option<&[u8]>, second: Option<&[u8]>) -> bool {
+    match (first, second) {
+        (Some(first), Some(second)) => is_identical(first, second),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+impl<const N: usize> Default for ISupport<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The possible types of errors when applying an [`ISupportToken`] to [`ISupport`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ISupportError {
+    /// All entries are in use.
+    TableFull,
+    /// The parameter is longer than [`MAX_ISUPPORT_PARAMETER_LEN`].
+    ParameterTooLong,
+    /// The value is longer than [`MAX_ISUPPORT_VALUE_LEN`].
+    ValueTooLong,
+}
+
+const fn split_at_colon(input: &[u8]) -> Option<(&[u8], &[u8])> {
+    let mut index = 0;
+    while index < input.len() {
+        if input[index] == b':' {
+            let (before, after) = input.split_at(index);
+            let (_, after) = after.split_at(1);
+            return Some((before, after));
+        }
+        index += 1;
+    }
+    None
+}
+
+/// The [`ISupportToken`] parameters known to this library.
+///
+/// See the [specification] and the [irc defs] list for their meaning.
+///
+/// [specification]: <https://modern.ircdocs.horse/#rplisupport-parameters>
+/// [irc defs]: <https://defs.ircdocs.horse/defs/isupport>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KnownISupport {
+    /// The `ACCEPT` token.
+    Accept,
+    /// The `ACCOUNTEXTBAN` token.
+    AccountExtban,
+    /// The `AWAYLEN` token.
+    AwayLen,
+    /// The `BOT` token.
+    Bot,
+    /// The `CALLERID` token.
+    CallerId,
+    /// The `CASEMAPPING` token.
+    CaseMapping,
+    /// The `CHANLIMIT` token.
+    ChanLimit,
+    /// The `CHANMODES` token.
+    ChanModes,
+    /// The `CHANNELLEN` token.
+    ChannelLen,
+    /// The `CHANTYPES` token.
+    ChanTypes,
+    /// The `CHARSET` token.
+    Charset,
+    /// The `CLIENTTAGDENY` token.
+    ClientTagDeny,
+    /// The `CLIENTVER` token.
+    ClientVer,
+    /// The `CNOTICE` token.
+    CNotice,
+    /// The `CPRIVMSG` token.
+    CPrivmsg,
+    /// The `DEAF` token.
+    Deaf,
+    /// The `ELIST` token.
+    EList,
+    /// The `ETRACE` token.
+    ETrace,
+    /// The `EXCEPTS` token.
+    Excepts,
+    /// The `EXTBAN` token.
+    ExtBan,
+    /// The `FNC` token.
+    Fnc,
+    /// The `HOSTLEN` token.
+    HostLen,
+    /// The `INVEX` token.
+    Invex,
+    /// The `KEYLEN` token.
+    KeyLen,
+    /// The `KICKLEN` token.
+    KickLen,
+    /// The `KNOCK` token.
+    Knock,
+    /// The `LINELEN` token.
+    LineLen,
+    /// The `MAXBANS` token.
+    MaxBans,
+    /// The `MAXCHANNELS` token.
+    MaxChannels,
+    /// The `MAXLIST` token.
+    MaxList,
+    /// The `MAXNICKLEN` token.
+    MaxNickLen,
+    /// The `MAXPARA` token.
+    MaxPara,
+    /// The `MAXTARGETS` token.
+    MaxTargets,
+    /// The `METADATA` token.
+    Metadata,
+    /// The `MODES` token.
+    Modes,
+    /// The `MONITOR` token.
+    Monitor,
+    /// The `MSGREFTYPES` token.
+    MsgRefTypes,
+    /// The `NAMESX` token.
+    NamesX,
+    /// The `NETWORK` token.
+    Network,
+    /// The `NICKLEN` token.
+    NickLen,
+    /// The `PENALTY` token.
+    Penalty,
+    /// The `PREFIX` token.
+    Prefix,
+    /// The `SAFELIST` token.
+    SafeList,
+    /// The `SECURELIST` token.
+    SecureList,
+    /// The `SILENCE` token.
+    Silence,
+    /// The `STATUSMSG` token.
+    StatusMsg,
+    /// The `TARGMAX` token.
+    TargMax,
+    /// The `TOPICLEN` token.
+    TopicLen,
+    /// The `UHNAMES` token.
+    UhNames,
+    /// The `USERIP` token.
+    UserIp,
+    /// The `USERLEN` token.
+    UserLen,
+    /// The `UTF8ONLY` token.
+    Utf8Only,
+    /// The `WATCH` token.
+    Watch,
+    /// The `WHOX` token.
+    WhoX,
+}
+
+const KNOWN_ISUPPORT: [(&str, KnownISupport); 54] = [
+    ("ACCEPT", KnownISupport::Accept),
+    ("ACCOUNTEXTBAN", KnownISupport::AccountExtban),
+    ("AWAYLEN", KnownISupport::AwayLen),
+    ("BOT", KnownISupport::Bot),
+    ("CALLERID", KnownISupport::CallerId),
+    ("CASEMAPPING", KnownISupport::CaseMapping),
+    ("CHANLIMIT", KnownISupport::ChanLimit),
+    ("CHANMODES", KnownISupport::ChanModes),
+    ("CHANNELLEN", KnownISupport::ChannelLen),
+    ("CHANTYPES", KnownISupport::ChanTypes),
+    ("CHARSET", KnownISupport::Charset),
+    ("CLIENTTAGDENY", KnownISupport::ClientTagDeny),
+    ("CLIENTVER", KnownISupport::ClientVer),
+    ("CNOTICE", KnownISupport::CNotice),
+    ("CPRIVMSG", KnownISupport::CPrivmsg),
+    ("DEAF", KnownISupport::Deaf),
+    ("ELIST", KnownISupport::EList),
+    ("ETRACE", KnownISupport::ETrace),
+    ("EXCEPTS", KnownISupport::Excepts),
+    ("EXTBAN", KnownISupport::ExtBan),
+    ("FNC", KnownISupport::Fnc),
+    ("HOSTLEN", KnownISupport::HostLen),
+    ("INVEX", KnownISupport::Invex),
+    ("KEYLEN", KnownISupport::KeyLen),
+    ("KICKLEN", KnownISupport::KickLen),
+    ("KNOCK", KnownISupport::Knock),
+    ("LINELEN", KnownISupport::LineLen),
+    ("MAXBANS", KnownISupport::MaxBans),
+    ("MAXCHANNELS", KnownISupport::MaxChannels),
+    ("MAXLIST", KnownISupport::MaxList),
+    ("MAXNICKLEN", KnownISupport::MaxNickLen),
+    ("MAXPARA", KnownISupport::MaxPara),
+    ("MAXTARGETS", KnownISupport::MaxTargets),
+    ("METADATA", KnownISupport::Metadata),
+    ("MODES", KnownISupport::Modes),
+    ("MONITOR", KnownISupport::Monitor),
+    ("MSGREFTYPES", KnownISupport::MsgRefTypes),
+    ("NAMESX", KnownISupport::NamesX),
+    ("NETWORK", KnownISupport::Network),
+    ("NICKLEN", KnownISupport::NickLen),
+    ("PENALTY", KnownISupport::Penalty),
+    ("PREFIX", KnownISupport::Prefix),
+    ("SAFELIST", KnownISupport::SafeList),
+    ("SECURELIST", KnownISupport::SecureList),
+    ("SILENCE", KnownISupport::Silence),
+    ("STATUSMSG", KnownISupport::StatusMsg),
+    ("TARGMAX", KnownISupport::TargMax),
+    ("TOPICLEN", KnownISupport::TopicLen),
+    ("UHNAMES", KnownISupport::UhNames),
+    ("USERIP", KnownISupport::UserIp),
+    ("USERLEN", KnownISupport::UserLen),
+    ("UTF8ONLY", KnownISupport::Utf8Only),
+    ("WATCH", KnownISupport::Watch),
+    ("WHOX", KnownISupport::WhoX),
+];
+
 const fn is_invalid_parameter_byte(input: u8) -> bool {
     !input.is_ascii_uppercase() && !input.is_ascii_digit()
 }
@@ -145,12 +1108,16 @@ pub enum ISupportTokenError {
     InvalidParameterByte(u8),
     /// Use of an invalid byte when parsing the value.
     InvalidValueByte(u8),
+    /// The value of the [`KnownISupport`] parameter is not in its documented format.
+    InvalidKnownValue(KnownISupport),
 }
 
 #[cfg(test)]
 mod const_tests {
-    use crate::{ContentType, is_identical};
-    use super::ISupportToken;
+    use crate::{ContentType, IrcMsg, is_identical};
+    use super::{ChanModes, ChanModesError, ISupport, ISupportChange, ISupportDiff, ISupportError, ISupportReplies,
+        ISupportToken, ISupportTokenError, KnownISupport, MAX_TOKENS_PER_REPLY, MaxList, MaxListError, ModeType, Prefix,
+        PrefixError, TargMax, TargMaxError, ValueValidation};
     #[test]
     const fn parse_token() {
         assert!(ISupportToken::parse(b"-FNC").is_ok());
@@ -195,4 +1162,255 @@ mod const_tests {
         assert!(token.is_ok());
         if let Ok(token) = token {assert!(token.is_set());}
     }
+    #[test]
+    const fn getting_known() {
+        let prefix = ISupportToken::parse(b"PREFIX=(ov)@+");
+        assert!(prefix.is_ok());
+        if let Ok(token) = prefix {assert!(matches!(token.known(), Some(KnownISupport::Prefix)));}
+        let nicklen = ISupportToken::parse(b"-NICKLEN");
+        assert!(nicklen.is_ok());
+        if let Ok(token) = nicklen {assert!(matches!(token.known(), Some(KnownISupport::NickLen)));}
+        let utf8only = ISupportToken::parse(b"UTF8ONLY");
+        assert!(utf8only.is_ok());
+        if let Ok(token) = utf8only {assert!(matches!(token.known(), Some(KnownISupport::Utf8Only)));}
+        let unknown = ISupportToken::parse(b"EXCELLENT=1");
+        assert!(unknown.is_ok());
+        if let Ok(token) = unknown {assert!(token.known().is_none());}
+    }
+    #[test]
+    const fn parsing_chanmodes() {
+        let modes = ChanModes::parse(b"beI,k,l,imnpst");
+        assert!(modes.is_ok());
+        if let Ok(modes) = modes {
+            assert!(matches!(modes.mode_type(b'I'), Some(ModeType::A)));
+            assert!(matches!(modes.mode_type(b'k'), Some(ModeType::B)));
+            assert!(matches!(modes.mode_type(b'l'), Some(ModeType::C)));
+            assert!(matches!(modes.mode_type(b't'), Some(ModeType::D)));
+            assert!(modes.mode_type(b'o').is_none());
+            assert!(is_identical(modes.modes(ModeType::D), b"imnpst"));
+        }
+        let modes = ChanModes::parse(b",,,s,xyz");
+        assert!(modes.is_ok());
+        if let Ok(modes) = modes {
+            assert!(modes.modes(ModeType::A).is_empty());
+            assert!(matches!(modes.mode_type(b's'), Some(ModeType::D)));
+            assert!(modes.mode_type(b'x').is_none());
+        }
+        assert!(matches!(ChanModes::parse(b"b,k,l"), Err(ChanModesError::TooFewGroups(3))));
+        assert!(matches!(ChanModes::parse(b"b,k,l,+"), Err(ChanModesError::InvalidByte(b'+'))));
+    }
+    #[test]
+    const fn looking_up_targmax() {
+        let targmax = TargMax::parse(b"PRIVMSG:3,WHOIS:1,JOIN:");
+        assert!(targmax.is_ok());
+        if let Ok(targmax) = targmax {
+            assert!(matches!(targmax.limit_for(b"PRIVMSG"), Some(3)));
+            assert!(matches!(targmax.limit_for(b"whois"), Some(1)));
+            assert!(targmax.limit_for(b"JOIN").is_none());
+            assert!(targmax.contains(b"JOIN"));
+            assert!(targmax.limit_for(b"NOTICE").is_none());
+            assert!(!targmax.contains(b"NOTICE"));
+        }
+        assert!(TargMax::parse(b"").is_ok());
+        assert!(matches!(TargMax::parse(b"PRIVMSG"), Err(TargMaxError::MissingColon)));
+        assert!(matches!(TargMax::parse(b":4"), Err(TargMaxError::EmptyCommand)));
+        assert!(matches!(TargMax::parse(b"PRIVMSG:x"), Err(TargMaxError::InvalidLimit)));
+        assert!(matches!(TargMax::parse(b"PRIVMSG:99999999999"), Err(TargMaxError::InvalidLimit)));
+    }
+    #[test]
+    const fn looking_up_maxlist() {
+        let maxlist = MaxList::parse(b"beI:60,q:20");
+        assert!(maxlist.is_ok());
+        if let Ok(maxlist) = maxlist {
+            assert!(matches!(maxlist.limit_for(b'e'), Some(60)));
+            assert!(matches!(maxlist.limit_for(b'q'), Some(20)));
+            assert!(maxlist.limit_for(b'o').is_none());
+        }
+        assert!(matches!(MaxList::parse(b"b"), Err(MaxListError::MissingColon)));
+        assert!(matches!(MaxList::parse(b":5"), Err(MaxListError::EmptyModes)));
+        assert!(matches!(MaxList::parse(b"b+:5"), Err(MaxListError::InvalidMode(b'+'))));
+        assert!(matches!(MaxList::parse(b"b:"), Err(MaxListError::InvalidLimit)));
+    }
+    #[test]
+    const fn parsing_prefix() {
+        let prefix = Prefix::parse(b"(qaohv)~&@%+");
+        assert!(prefix.is_ok());
+        if let Ok(prefix) = prefix {
+            assert!(matches!(prefix.prefix_for_mode(b'h'), Some(b'%')));
+            assert!(matches!(prefix.mode_for_prefix(b'~'), Some(b'q')));
+            assert!(prefix.mode_for_prefix(b'!').is_none());
+        }
+        let prefix = Prefix::parse(b"");
+        assert!(prefix.is_ok());
+        if let Ok(prefix) = prefix {assert!(prefix.modes().is_empty());}
+        assert!(matches!(Prefix::parse(b"ov@+"), Err(PrefixError::MissingParentheses)));
+        assert!(matches!(Prefix::parse(b"(ov)@"), Err(PrefixError::LengthMismatch)));
+    }
+    const fn apply_tokens<const N: usize>(mut isupport: ISupport<N>, inputs: &[&[u8]]) -> ISupport<N> {
+        let mut index = 0;
+        while index < inputs.len() {
+            let token = ISupportToken::parse(inputs[index]);
+            assert!(token.is_ok());
+            if let Ok(token) = token {
+                let applied = isupport.apply(&token);
+                assert!(applied.is_ok());
+                if let Ok(applied) = applied {isupport = applied;}
+            }
+            index += 1;
+        }
+        isupport
+    }
+    #[test]
+    const fn tracking_isupport() {
+        let isupport = ISupport::<4>::new();
+        assert!(is_identical(isupport.prefix().prefixes(), b"@+"));
+        let tokens: [&[u8]; 4] = [b"NICKLEN=30", b"PREFIX=(qov)~@+", b"CHANMODES=b,k,l,imnpst", b"SAFELIST"];
+        let isupport = apply_tokens(isupport, &tokens);
+        assert!(isupport.count() == 4);
+        assert!(matches!(isupport.nicklen(), Some(30)));
+        assert!(matches!(isupport.prefix().prefix_for_mode(b'q'), Some(b'~')));
+        assert!(isupport.chanmodes().is_some());
+        assert!(isupport.is_set(b"SAFELIST") && isupport.value(b"SAFELIST").is_none());
+        let token = ISupportToken::parse(b"NETWORK=Libera.Chat");
+        assert!(token.is_ok());
+        if let Ok(token) = token {assert!(matches!(isupport.apply(&token), Err(ISupportError::TableFull)));}
+        let isupport = apply_tokens(isupport, &[b"NICKLEN=16"]);
+        assert!(matches!(isupport.nicklen(), Some(16)));
+        let isupport = apply_tokens(isupport, &[b"-NICKLEN"]);
+        assert!(isupport.count() == 3);
+        assert!(isupport.nicklen().is_none());
+        assert!(isupport.is_set(b"SAFELIST"));
+        let isupport = apply_tokens(isupport, &[b"NETWORK=Libera.Chat"]);
+        let network = isupport.network();
+        assert!(network.is_some());
+        if let Some(network) = network {assert!(is_identical(network, b"Libera.Chat"));}
+    }
+    #[test]
+    const fn reading_tokens_from_msg() {
+        let input = b":irc.example.com 005 dan NICKLEN=30 -SAFELIST PREFIX=(ov)@+ :are supported by this server";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let tokens = ISupportToken::iter_from_msg(&msg);
+            assert!(tokens.is_some());
+            if let Some(tokens) = tokens {
+                assert!(tokens.remaining() == 3);
+                let tokens = tokens.next_token();
+                assert!(matches!(tokens, Some((Ok(_), _))));
+                if let Some((Ok(token), tokens)) = tokens {
+                    assert!(matches!(token.known(), Some(KnownISupport::NickLen)));
+                    let tokens = tokens.next_token();
+                    assert!(matches!(tokens, Some((Ok(_), _))));
+                    if let Some((Ok(token), tokens)) = tokens {
+                        assert!(!token.is_set());
+                        let tokens = tokens.next_token();
+                        assert!(matches!(tokens, Some((Ok(_), _))));
+                        if let Some((Ok(token), tokens)) = tokens {
+                            assert!(matches!(token.known(), Some(KnownISupport::Prefix)));
+                            assert!(tokens.next_token().is_none());
+                        }
+                    }
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com 001 dan :Welcome");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(ISupportToken::iter_from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn validating_values() {
+        assert!(ISupportToken::parse_with_value_validation(b"NICKLEN=30", ValueValidation::Strict).is_ok());
+        assert!(ISupportToken::parse_with_value_validation(b"NICKLEN=abc", ValueValidation::Lenient).is_ok());
+        assert!(matches!(ISupportToken::parse_with_value_validation(b"NICKLEN=abc", ValueValidation::Strict),
+            Err(ISupportTokenError::InvalidKnownValue(KnownISupport::NickLen))));
+        assert!(matches!(ISupportToken::parse_with_value_validation(b"PREFIX=ov@+", ValueValidation::Strict),
+            Err(ISupportTokenError::InvalidKnownValue(KnownISupport::Prefix))));
+        assert!(matches!(ISupportToken::parse_with_value_validation(b"CASEMAPPING=utf8", ValueValidation::Strict),
+            Err(ISupportTokenError::InvalidKnownValue(KnownISupport::CaseMapping))));
+        assert!(ISupportToken::parse_with_value_validation(b"CASEMAPPING=rfc1459", ValueValidation::Strict).is_ok());
+        assert!(ISupportToken::parse_with_value_validation(b"CHANLIMIT=#&:10,+:", ValueValidation::Strict).is_ok());
+        assert!(ISupportToken::parse_with_value_validation(b"EXCELLENT=?", ValueValidation::Strict).is_ok());
+        assert!(ISupportToken::parse_with_value_validation(b"-NICKLEN", ValueValidation::Strict).is_ok());
+        assert!(ISupportToken::parse_with_value_validation(b"NICKLEN=", ValueValidation::Strict).is_ok());
+    }
+    #[test]
+    const fn diffing_isupport() {
+        let older = apply_tokens(ISupport::<4>::new(), &[b"NICKLEN=30", b"NETWORK=Old"]);
+        let newer = apply_tokens(ISupport::<8>::new(), &[b"NETWORK=New", b"SAFELIST"]);
+        let diff = ISupportDiff::new(&older, &newer);
+        assert!(!diff.is_unchanged());
+        let diff = diff.next_change();
+        assert!(matches!(diff, Some((ISupportChange::Removed(_), _))));
+        if let Some((ISupportChange::Removed(parameter), diff)) = diff {
+            assert!(is_identical(parameter, b"NICKLEN"));
+            let diff = diff.next_change();
+            assert!(matches!(diff, Some((ISupportChange::Changed(_, Some(_), Some(_)), _))));
+            if let Some((ISupportChange::Changed(parameter, Some(old), Some(new)), diff)) = diff {
+                assert!(is_identical(parameter, b"NETWORK"));
+                assert!(is_identical(old, b"Old") && is_identical(new, b"New"));
+                let diff = diff.next_change();
+                assert!(matches!(diff, Some((ISupportChange::Added(_, None), _))));
+                if let Some((ISupportChange::Added(parameter, None), diff)) = diff {
+                    assert!(is_identical(parameter, b"SAFELIST"));
+                    assert!(diff.next_change().is_none());
+                }
+            }
+        }
+        assert!(ISupportDiff::new(&older, &older).is_unchanged());
+    }
+    #[test]
+    const fn writing_replies() {
+        let values = (ISupportToken::parse(b"NICKLEN=30"), ISupportToken::parse(b"SAFELIST"),
+            ISupportToken::parse(b"-EXCEPTS"));
+        assert!(matches!(values, (Ok(_), Ok(_), Ok(_))));
+        if let (Ok(nicklen), Ok(safelist), Ok(excepts)) = values {
+            let tokens = [nicklen, safelist, excepts];
+            let replies = ISupportReplies::new(b"irc.example.com", b"dan", &tokens);
+            let replies = replies.next_msg::<512>();
+            assert!(matches!(replies, Ok(Some((_, _)))));
+            if let Ok(Some((msg, replies))) = replies {
+                assert!(is_identical(msg.as_bytes(),
+                    b":irc.example.com 005 dan NICKLEN=30 SAFELIST -EXCEPTS :are supported by this server"));
+                assert!(matches!(replies.next_msg::<512>(), Ok(None)));
+            }
+            let replies = ISupportReplies::new(b"irc.example.com", b"dan", &tokens);
+            let replies = replies.next_msg::<80>();
+            assert!(matches!(replies, Ok(Some((_, _)))));
+            if let Ok(Some((msg, replies))) = replies {
+                assert!(is_identical(msg.as_bytes(),
+                    b":irc.example.com 005 dan NICKLEN=30 SAFELIST :are supported by this server"));
+                let replies = replies.next_msg::<80>();
+                assert!(matches!(replies, Ok(Some((_, _)))));
+                if let Ok(Some((msg, replies))) = replies {
+                    assert!(is_identical(msg.as_bytes(),
+                        b":irc.example.com 005 dan -EXCEPTS :are supported by this server"));
+                    assert!(matches!(replies.next_msg::<80>(), Ok(None)));
+                }
+            }
+            let replies = ISupportReplies::new(b"irc.example.com", b"dan", &tokens);
+            assert!(replies.next_msg::<60>().is_err());
+            let tokens = [safelist; 14];
+            let replies = ISupportReplies::new(b"irc.example.com", b"dan", &tokens);
+            let replies = replies.next_msg::<512>();
+            assert!(matches!(replies, Ok(Some((_, _)))));
+            if let Ok(Some((msg, replies))) = replies {
+                assert!(token_count(msg.as_bytes()) == MAX_TOKENS_PER_REPLY);
+                let replies = replies.next_msg::<512>();
+                assert!(matches!(replies, Ok(Some((_, _)))));
+                if let Ok(Some((msg, replies))) = replies {
+                    assert!(is_identical(msg.as_bytes(),
+                        b":irc.example.com 005 dan SAFELIST :are supported by this server"));
+                    assert!(matches!(replies.next_msg::<512>(), Ok(None)));
+                }
+            }
+        }
+    }
+    const fn token_count(reply: &[u8]) -> usize {
+        let msg = IrcMsg::parse(reply);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            if let Some(parameters) = msg.parameters() {return parameters.count() - 2;}
+        }
+        0
+    }
 }
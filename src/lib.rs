@@ -11,9 +11,13 @@
 //! I wanted to see how much of an IRC message parser can be written in a [const context]. Every public
 //! and private function is const. I was even able to make all the tests const functions even
 //! though it ends up being more verbose. The only exceptions are the Display impls as functions on Traits are
-//! not yet allowed to be const ([click here for details]). I am also unaware of how to
+//! not yet allowed to be const ([click here for details]). The same applies to the HTML rendering behind the
+//! optional `html` feature as it writes into a [`core::fmt::Write`]. I am also unaware of how to
 //! test Display impls in a const manner for code coverage. Suggestions welcome.
 //!
+//! As mutable references can't be used in a const context cursors and writers consume `self` and return the
+//! advanced value. A cursor step returns the item alongside the advanced cursor and a write returns the writer.
+//!
 //! ## Usage
 //!
 //! Ensure you have a single message as a slice of bytes from your network.
@@ -27,9 +31,10 @@
 #![allow(clippy::module_name_repetitions)]
 
 use tags::{Tags, TagsError};
-use source::{Source, SourceError};
-use command::{Command, CommandError};
-use parameters::{Parameters, ParametersError};
+use source::{Source, SourceError, ServernameDetection, Ts6Detection};
+use command::{Command, CommandError, CommandKind, UnhandledNumerics};
+use parameters::{Parameters, ParametersError, ParamsWriterError};
+use casemapping::IrcCaseMapping;
 
 pub mod tags;
 pub mod source;
@@ -38,6 +43,16 @@ pub mod parameters;
 pub mod formatting;
 pub mod isupport;
 pub mod casemapping;
+pub mod validation;
+pub mod ctcp;
+pub mod dcc;
+pub mod cap;
+pub mod sasl;
+pub mod base64;
+pub mod batch;
+pub mod typing;
+pub mod mode;
+pub mod views;
 
 /// A single IRC Message created from a slice of bytes.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -55,6 +70,14 @@ impl<'msg> IrcMsg<'msg> {
     ///
     /// Will return `Err` if the input is empty or any of the [`IrcMsg`] components fail to parse.
     pub const fn parse(input: &'msg[u8]) -> Result<Self, IrcMsgError<'msg>> {
+        Self::parse_with_options(input, ParseOptions::new())
+    }
+    /// Generates an [`IrcMsg`] from a slice of bytes using the specified [`ParseOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the input is empty or any of the [`IrcMsg`] components fail to parse.
+    pub const fn parse_with_options(input: &'msg[u8], options: ParseOptions) -> Result<Self, IrcMsgError<'msg>> {
         if input.is_empty() {return Err(IrcMsgError::EmptyInput);}
         let (mut tags, mut tag_present, mut after_tag_end, mut tag_finished) = (None, false, 0, false);
         let (mut source, mut source_present, mut after_source_end, mut source_finished) = (None, false, 0, false);
@@ -85,7 +108,7 @@ impl<'msg> IrcMsg<'msg> {
                 after_source_end = index + 1;
                 let (s, rest) = copy.split_at(index - after_tag_end);
                 copy = remove_possible_leading_space(rest);
-                match Source::parse(s) {
+                match Source::parse_with_options(s, options) {
                     Ok(src) => source = Some(src),
                     Err(e) => return Err(IrcMsgError::Source(e)),
                 }
@@ -107,7 +130,7 @@ impl<'msg> IrcMsg<'msg> {
                 Ok(params) => {
                     parameters = params;
                     if let Some(params) = params {
-                        match Command::parse(copy, params.count()) {
+                        match Command::parse_with_unhandled_numerics(copy, params.count(), options.unhandled_numerics) {
                             Ok(cmd) => cmd,
                             Err(e) => return Err(IrcMsgError::Command(e)),
                         }
@@ -116,7 +139,7 @@ impl<'msg> IrcMsg<'msg> {
                 Err(e) => return Err(IrcMsgError::Parameters(e)),
             }
         } else {
-            match Command::parse(copy, 0) {
+            match Command::parse_with_unhandled_numerics(copy, 0, options.unhandled_numerics) {
                 Ok(cmd) => cmd,
                 Err(e) => return Err(IrcMsgError::Command(e)),
             }
@@ -142,22 +165,22 @@ impl<'msg> IrcMsg<'msg> {
     }
     /// Extract the [`Tags`] from an [`IrcMsg`] if they exist.
     #[must_use]
-    pub const fn tags(&self) -> Option<Tags> {
+    pub const fn tags(&self) -> Option<Tags<'msg>> {
         self.tags
     }
     /// Extract the [`Source`] from an [`IrcMsg`] if it exists.
     #[must_use]
-    pub const fn source(&self) -> Option<Source> {
+    pub const fn source(&self) -> Option<Source<'msg>> {
         self.source
     }
     /// Extract the [`Command`] from an [`IrcMsg`].
     #[must_use]
-    pub const fn command(&self) -> Command {
+    pub const fn command(&self) -> Command<'msg> {
         self.command
     }
     /// Extract the [`Parameters`] from an [`IrcMsg`] if they exist.
     #[must_use]
-    pub const fn parameters(&self) -> Option<Parameters> {
+    pub const fn parameters(&self) -> Option<Parameters<'msg>> {
         self.parameters
     }
     /// Strips the [`Tags`] from an [`IrcMsg`].
@@ -172,6 +195,32 @@ impl<'msg> IrcMsg<'msg> {
         if self.tags.is_some() {self.tags = None;}
         self
     }
+    /// Check whether a `PRIVMSG`, `NOTICE` or `TAGMSG` [`IrcMsg`] was sent by the IRC client itself.
+    ///
+    /// With the [echo-message] capability enabled an IRC server sends a client its own messages back.
+    /// The nick of the [`Source`] is compared to `my_nick` according to the [`IrcCaseMapping`].
+    /// A [`Source`] parsed with [`ServernameDetection::Ambiguous`] is compared as if it were a nick.
+    ///
+    /// [echo-message]: <https://ircv3.net/specs/extensions/echo-message>
+    #[must_use]
+    pub const fn is_echo_of_self(&self, my_nick: &[u8], casemapping: IrcCaseMapping) -> bool {
+        if !matches!(self.command.kind(), Some(CommandKind::Privmsg | CommandKind::Notice | CommandKind::Tagmsg)) {
+            return false;
+        }
+        let Some(source) = self.source else {return false;};
+        match source.nick() {
+            Some(nick) => casemapping.is_equivalent(nick.as_bytes(), my_nick),
+            None => false,
+        }
+    }
+    /// Returns the typed [`MessageView`](views::MessageView) of the [`IrcMsg`].
+    ///
+    /// Commands without a view or whose parameters don't fit their view are returned as
+    /// [`MessageView::Other`](views::MessageView::Other).
+    #[must_use]
+    pub const fn view(&self) -> views::MessageView<'msg> {
+        views::MessageView::from_msg(self)
+    }
 }
 
 impl core::fmt::Display for IrcMsg<'_> {
@@ -183,6 +232,310 @@ impl core::fmt::Display for IrcMsg<'_> {
     }
 }
 
+/// Options for resolving the parts of an [`IrcMsg`] that IRC networks interpret differently.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ParseOptions {
+    servername_detection: ServernameDetection,
+    ts6_detection: Ts6Detection,
+    unhandled_numerics: UnhandledNumerics,
+}
+
+impl ParseOptions {
+    /// Generates [`ParseOptions`] matching the behaviour of [`IrcMsg::parse`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self{
+            servername_detection: ServernameDetection::Dotted,
+            ts6_detection: Ts6Detection::Disabled,
+            unhandled_numerics: UnhandledNumerics::Reject,
+        }
+    }
+    /// Set how a [`Source`] containing a `.` but no user or host is classified.
+    #[must_use]
+    pub const fn servername_detection(mut self, detection: ServernameDetection) -> Self {
+        self.servername_detection = detection;
+        self
+    }
+    /// Set whether a [`Source`] shaped like a TS6 server or user ID is classified as such.
+    #[must_use]
+    pub const fn ts6_detection(mut self, detection: Ts6Detection) -> Self {
+        self.ts6_detection = detection;
+        self
+    }
+    /// Set how a `Numeric` [`Command`] not supported by this parser is handled.
+    #[must_use]
+    pub const fn unhandled_numerics(mut self, unhandled: UnhandledNumerics) -> Self {
+        self.unhandled_numerics = unhandled;
+        self
+    }
+}
+
+/// A writer for building an [`IrcMsg`] into a buffer of `N` bytes.
+///
+/// Optional [`Tags`] are written first followed by an optional [`Source`], the [`Command`] and then the
+/// [`Parameters`].
+/// The `:` before the trailing parameter is inserted only when required. The carriage return and line feed
+/// ending the message are not written.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IrcMsgWriter<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+    tags_open: bool,
+    command_written: bool,
+    params_amount: usize,
+    trailing_written: bool,
+}
+
+impl<const N: usize> IrcMsgWriter<N> {
+    /// Generates an empty [`IrcMsgWriter`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self{
+            buffer: [0; N], len: 0, tags_open: false, command_written: false, params_amount: 0, trailing_written: false,
+        }
+    }
+    /// Appends a tag with an optional value.
+    ///
+    /// The `key` includes the client only prefix and the `vendor` such as `+example.com/foo`.
+    /// The value is escaped as per the [Message Tag Specification] and an empty value is omitted.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a [`Source`] or [`Command`] has already been written, the buffer is full, the `key` is
+    /// empty or contains bytes other than ascii letters, digits, `-`, `.` and `/` after an optional leading `+` or
+    /// the value contains a null.
+    ///
+    /// [Message Tag Specification]: <https://ircv3.net/specs/extensions/message-tags.html>
+    pub const fn tag(mut self, key: &[u8], value: Option<&[u8]>) -> Result<Self, IrcMsgWriterError> {
+        if self.len > 0 && !self.tags_open {return Err(IrcMsgWriterError::TagAfterStart);}
+        let name = if let [b'+', name @ ..] = key {name} else {key};
+        if name.is_empty() {return Err(IrcMsgWriterError::InvalidTagKey);}
+        let mut index = 0;
+        while index < name.len() {
+            if !matches!(name[index], b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'.' | b'/') {
+                return Err(IrcMsgWriterError::InvalidTagKey);
+            }
+            index += 1;
+        }
+        let value = match value {
+            Some(value) => value,
+            None => &[],
+        };
+        let mut needed = 1 + key.len() + if value.is_empty() {0} else {1};
+        index = 0;
+        while index < value.len() {
+            needed += match value[index] {
+                b'\0' => return Err(IrcMsgWriterError::InvalidTagValue),
+                b';' | b' ' | b'\\' | b'\r' | b'\n' => 2,
+                _ => 1,
+            };
+            index += 1;
+        }
+        if self.len + needed > N {return Err(IrcMsgWriterError::BufferFull);}
+        self = self.push(if self.tags_open {b";"} else {b"@"}).push(key);
+        if !value.is_empty() {self = self.push(b"=");}
+        index = 0;
+        while index < value.len() {
+            self = match value[index] {
+                b';' => self.push(b"\\:"),
+                b' ' => self.push(b"\\s"),
+                b'\\' => self.push(b"\\\\"),
+                b'\r' => self.push(b"\\r"),
+                b'\n' => self.push(b"\\n"),
+                byte => self.push(&[byte]),
+            };
+            index += 1;
+        }
+        self.tags_open = true;
+        Ok(self)
+    }
+    /// Writes the [`Source`] without the leading `:`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if anything other than [`Tags`] has already been written, the buffer is full or the input
+    /// is empty or contains a space, null, carriage return or line feed.
+    pub const fn source(mut self, input: &[u8]) -> Result<Self, IrcMsgWriterError> {
+        if self.len > 0 && !self.tags_open {return Err(IrcMsgWriterError::SourceAfterStart);}
+        else if input.is_empty() {return Err(IrcMsgWriterError::InvalidSource);}
+        let mut index = 0;
+        while index < input.len() {
+            if matches!(input[index], b'\0' | b'\r' | b'\n' | b' ') {return Err(IrcMsgWriterError::InvalidSource);}
+            index += 1;
+        }
+        if self.len + self.tags_open as usize + input.len() + 2 > N {return Err(IrcMsgWriterError::BufferFull);}
+        self = self.close_tags();
+        Ok(self.push(b":").push(input).push(b" "))
+    }
+    /// Writes the [`Command`].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a [`Command`] has already been written, the buffer is full or the input is empty or
+    /// contains bytes other than ascii letters and digits.
+    pub const fn command(mut self, input: &[u8]) -> Result<Self, IrcMsgWriterError> {
+        if self.command_written {return Err(IrcMsgWriterError::CommandAlreadyWritten);}
+        else if input.is_empty() {return Err(IrcMsgWriterError::InvalidCommand);}
+        let mut index = 0;
+        while index < input.len() {
+            if !input[index].is_ascii_alphanumeric() {return Err(IrcMsgWriterError::InvalidCommand);}
+            index += 1;
+        }
+        if self.len + self.tags_open as usize + input.len() > N {return Err(IrcMsgWriterError::BufferFull);}
+        self = self.close_tags().push(input);
+        self.command_written = true;
+        Ok(self)
+    }
+    /// Appends a middle parameter.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if no [`Command`] has been written, a trailing parameter has already been written, the
+    /// maximum of [`MAX_STORED_PARAMETERS`](parameters::MAX_STORED_PARAMETERS) is reached, the buffer is full or
+    /// the input is empty, starts with `:` or contains a space, null, carriage return or line feed.
+    pub const fn middle(self, input: &[u8]) -> Result<Self, IrcMsgWriterError> {
+        self.middle_parts(&[input])
+    }
+    /// Appends a middle parameter made up of the concatenation of `parts`.
+    ///
+    /// Useful when a parameter such as an [`ISupportToken`](isupport::ISupportToken) is not a single slice of bytes.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if no [`Command`] has been written, a trailing parameter has already been written, the
+    /// maximum of [`MAX_STORED_PARAMETERS`](parameters::MAX_STORED_PARAMETERS) is reached, the buffer is full or
+    /// the concatenated input is empty, starts with `:` or contains a space, null, carriage return or line feed.
+    pub const fn middle_parts(mut self, parts: &[&[u8]]) -> Result<Self, IrcMsgWriterError> {
+        let (mut total, mut index) = (0, 0);
+        while index < parts.len() {
+            if let Err(e) = parameters::validate_middle(parts[index]) {
+                match e {
+                    ParamsWriterError::EmptyMiddle => {},
+                    ParamsWriterError::ColonStartsMiddle if total > 0 => {},
+                    _ => return Err(IrcMsgWriterError::Parameter(e)),
+                }
+            }
+            total += parts[index].len();
+            index += 1;
+        }
+        if total == 0 {return Err(IrcMsgWriterError::Parameter(ParamsWriterError::EmptyMiddle));}
+        if let Err(e) = self.check(total) {return Err(e);}
+        self = self.push(b" ");
+        index = 0;
+        while index < parts.len() {
+            self = self.push(parts[index]);
+            index += 1;
+        }
+        self.params_amount += 1;
+        Ok(self)
+    }
+    /// Appends the trailing parameter.
+    ///
+    /// A `:` is inserted before it if it is empty, contains a space or starts with `:`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if no [`Command`] has been written, a trailing parameter has already been written, the
+    /// maximum of [`MAX_STORED_PARAMETERS`](parameters::MAX_STORED_PARAMETERS) is reached, the buffer is full or
+    /// the input contains a null, carriage return or line feed.
+    pub const fn trailing(self, input: &[u8]) -> Result<Self, IrcMsgWriterError> {
+        self.write_trailing(input, false)
+    }
+    /// Appends the trailing parameter always preceded by a `:`.
+    ///
+    /// Some commands such as `SETNAME` expect a free form parameter to be marked as trailing even when a `:` is not
+    /// required.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if no [`Command`] has been written, a trailing parameter has already been written, the
+    /// maximum of [`MAX_STORED_PARAMETERS`](parameters::MAX_STORED_PARAMETERS) is reached, the buffer is full or
+    /// the input contains a null, carriage return or line feed.
+    pub const fn trailing_with_colon(self, input: &[u8]) -> Result<Self, IrcMsgWriterError> {
+        self.write_trailing(input, true)
+    }
+    const fn write_trailing(mut self, input: &[u8], force_colon: bool) -> Result<Self, IrcMsgWriterError> {
+        let colon = match parameters::validate_trailing(input) {
+            Ok(colon) => colon || force_colon,
+            Err(e) => return Err(IrcMsgWriterError::Parameter(e)),
+        };
+        if let Err(e) = self.check(input.len() + if colon {1} else {0}) {return Err(e);}
+        self = self.push(if colon {b" :"} else {b" "}).push(input);
+        self.params_amount += 1;
+        self.trailing_written = true;
+        Ok(self)
+    }
+    const fn check(&self, needed: usize) -> Result<(), IrcMsgWriterError> {
+        if !self.command_written {return Err(IrcMsgWriterError::MissingCommand);}
+        else if self.trailing_written {
+            return Err(IrcMsgWriterError::Parameter(ParamsWriterError::TrailingAlreadyWritten));
+        } else if self.params_amount == parameters::MAX_STORED_PARAMETERS {
+            return Err(IrcMsgWriterError::Parameter(ParamsWriterError::TooManyParameters));
+        } else if self.len + needed + 1 > N {
+            return Err(IrcMsgWriterError::Parameter(ParamsWriterError::BufferFull));
+        }
+        Ok(())
+    }
+    const fn close_tags(mut self) -> Self {
+        if self.tags_open {
+            self.tags_open = false;
+            self = self.push(b" ");
+        }
+        self
+    }
+    const fn push(mut self, input: &[u8]) -> Self {
+        let mut index = 0;
+        while index < input.len() {
+            self.buffer[self.len] = input[index];
+            self.len += 1;
+            index += 1;
+        }
+        self
+    }
+    /// Returns the written [`IrcMsg`] as a slice of bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        let (output, _) = self.buffer.split_at(self.len);
+        output
+    }
+    /// Returns the amount of [`Parameters`] written.
+    #[must_use]
+    pub const fn params_count(&self) -> usize {
+        self.params_amount
+    }
+}
+
+impl<const N: usize> Default for IrcMsgWriter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The possible types of errors when building an [`IrcMsg`] with an [`IrcMsgWriter`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IrcMsgWriterError {
+    /// The buffer is too small.
+    BufferFull,
+    /// The [`Tags`] must be written first.
+    TagAfterStart,
+    /// The key of a tag is empty or contains an invalid byte.
+    InvalidTagKey,
+    /// The value of a tag contains a null.
+    InvalidTagValue,
+    /// The [`Source`] must be written before the [`Command`].
+    SourceAfterStart,
+    /// The [`Source`] is empty or contains an invalid byte.
+    InvalidSource,
+    /// The [`Command`] is empty or contains an invalid byte.
+    InvalidCommand,
+    /// The [`Command`] has already been written.
+    CommandAlreadyWritten,
+    /// The [`Command`] must be written before the [`Parameters`].
+    MissingCommand,
+    /// An error occurred in writing the [`Parameters`].
+    Parameter(ParamsWriterError),
+}
+
 /// The possible types of errors when parsing an [`IrcMsg`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum IrcMsgError<'msg> {
@@ -245,12 +598,19 @@ impl<'msg> ContentType<'msg> {
     }
     /// Returns the inner contents as an array of bytes.
     #[must_use]
-    pub const fn as_bytes(&self) -> &[u8] {
+    pub const fn as_bytes(&self) -> &'msg [u8] {
         match self {
             ContentType::StringSlice(slice) => slice.as_bytes(),
             ContentType::NonUtf8ByteSlice(b) => b,
         }
     }
+    /// Parses the inner contents as a decimal [`u64`] such as a Unix timestamp.
+    ///
+    /// Returns `None` if the contents are empty, contain anything but ascii digits or overflow a [`u64`].
+    #[must_use]
+    pub const fn to_u64(&self) -> Option<u64> {
+        parse_u64(self.as_bytes())
+    }
 }
 
 impl core::fmt::Display for ContentType<'_> {
@@ -274,12 +634,158 @@ const fn is_identical(first: &[u8], second: &[u8]) -> bool {
     false
 }
 
+pub(crate) const fn contains_byte(input: &[u8], target: u8) -> bool {
+    let mut index = 0;
+    while index < input.len() {
+        if input[index] == target {return true;}
+        index += 1;
+    }
+    false
+}
+
+pub(crate) const fn is_identical_ignoring_ascii_case(first: &[u8], second: &[u8]) -> bool {
+    if first.len() != second.len() {return false;}
+    let mut index = 0;
+    while index < first.len() {
+        if !first[index].eq_ignore_ascii_case(&second[index]) {return false;}
+        index += 1;
+    }
+    true
+}
+
+pub(crate) const fn hex_value(input: u8) -> Option<u8> {
+    match input {
+        b'0'..=b'9' => Some(input - b'0'),
+        b'a'..=b'f' => Some(input - b'a' + 10),
+        b'A'..=b'F' => Some(input - b'A' + 10),
+        _ => None,
+    }
+}
+
+pub(crate) const fn parse_u64(input: &[u8]) -> Option<u64> {
+    if input.is_empty() {return None;}
+    let (mut index, mut value) = (0, 0_u64);
+    while index < input.len() {
+        if !input[index].is_ascii_digit() {return None;}
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((input[index] - b'0') as u64) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+        index += 1;
+    }
+    Some(value)
+}
+
+#[allow(clippy::cast_possible_truncation)]
+pub(crate) const fn parse_u32(input: &[u8]) -> Option<u32> {
+    match parse_u64(input) {
+        Some(value) if value <= u32::MAX as u64 => Some(value as u32),
+        _ => None,
+    }
+}
+
+/// Parses a dotted-quad IPv4 address such as `192.168.1.1` into its octets.
+///
+/// Returns `None` if there aren't exactly four decimal octets in the range of a `u8` or an octet has a leading
+/// zero as it might be read as octal.
+pub(crate) const fn parse_ipv4_octets(input: &[u8]) -> Option<[u8; 4]> {
+    let mut octets = [0; 4];
+    let (mut count, mut index) = (0, 0);
+    while count < 4 {
+        let start = index;
+        let mut octet: u16 = 0;
+        while index < input.len() && input[index].is_ascii_digit() && index - start < 3 {
+            octet = octet * 10 + (input[index] - b'0') as u16;
+            index += 1;
+        }
+        if index == start || octet > u8::MAX as u16 || (index - start > 1 && input[start] == b'0') {return None;}
+        let [_, low] = octet.to_be_bytes();
+        octets[count] = low;
+        count += 1;
+        if count < 4 {
+            if index == input.len() || input[index] != b'.' {return None;}
+            index += 1;
+        }
+    }
+    if index == input.len() {Some(octets)} else {None}
+}
+
+/// Parses a textual IPv6 address such as `2001:db8::1` into its eight 16-bit segments.
+///
+/// The `::` compression of zero segments and a trailing dotted-quad IPv4 address such as `::ffff:192.168.1.1`
+/// are supported. Returns `None` if the input is not a valid IPv6 address.
+pub(crate) const fn parse_ipv6_segments(input: &[u8]) -> Option<[u16; 8]> {
+    let mut segments = [0; 8];
+    let (mut count, mut index, mut gap): (usize, usize, Option<usize>) = (0, 0, None);
+    match input {
+        [b':', b':'] => return Some(segments),
+        [b':', b':', ..] => {gap = Some(0); index = 2;},
+        [b':', ..] | [] => return None,
+        _ => {},
+    }
+    loop {
+        let start = index;
+        let mut segment: u16 = 0;
+        while index < input.len() && index - start < 5 {
+            let Some(digit) = hex_value(input[index]) else {break;};
+            segment = (segment << 4) | digit as u16;
+            index += 1;
+        }
+        if index < input.len() && input[index] == b'.' {
+            let (_, rest) = input.split_at(start);
+            let Some([a, b, c, d]) = parse_ipv4_octets(rest) else {return None;};
+            if count > 6 {return None;}
+            segments[count] = u16::from_be_bytes([a, b]);
+            segments[count + 1] = u16::from_be_bytes([c, d]);
+            count += 2;
+            break;
+        }
+        if index == start || index - start > 4 || count == 8 {return None;}
+        segments[count] = segment;
+        count += 1;
+        if index == input.len() {break;}
+        if input[index] != b':' {return None;}
+        index += 1;
+        if index < input.len() && input[index] == b':' {
+            if gap.is_some() {return None;}
+            gap = Some(count);
+            index += 1;
+            if index == input.len() {break;}
+        } else if index == input.len() {
+            return None;
+        }
+    }
+    match gap {
+        None if count == 8 => Some(segments),
+        Some(gap) if count < 8 => {
+            let mut expanded = [0; 8];
+            let mut index = 0;
+            while index < gap {
+                expanded[index] = segments[index];
+                index += 1;
+            }
+            while index < count {
+                expanded[8 - count + index] = segments[index];
+                index += 1;
+            }
+            Some(expanded)
+        },
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod const_tests {
-    use crate::{remove_possible_leading_space, ContentType, IrcMsg, source::Origin, command::Command, is_identical};
+    use crate::{remove_possible_leading_space, ContentType, IrcMsg, IrcMsgWriter, IrcMsgWriterError, ParseOptions,
+        source::{Origin, ServernameDetection, Ts6Detection}, command::{Command, UnhandledNumerics}, is_identical,
+        casemapping::IrcCaseMapping, hex_value, is_identical_ignoring_ascii_case, parse_ipv4_octets,
+        parse_ipv6_segments};
     pub const fn is_nick(input: Origin) -> bool {
         match input {
-            Origin::Servername(_) => false,
+            Origin::Servername(_) | Origin::Ambiguous(_) | Origin::Sid(_) | Origin::Uid(_) => false,
             Origin::Nickname(_) => true,
         }
     }
@@ -290,6 +796,32 @@ mod const_tests {
         }
     }
     #[test]
+    const fn comparing_ignoring_ascii_case() {
+        assert!(is_identical_ignoring_ascii_case(b"Dcc SeNd", b"DCC SEND"));
+        assert!(!is_identical_ignoring_ascii_case(b"DCC", b"DCC SEND"));
+        assert!(matches!(hex_value(b'F'), Some(15)) && matches!(hex_value(b'a'), Some(10)));
+        assert!(hex_value(b'g').is_none());
+    }
+    #[test]
+    const fn parsing_ip_addresses() {
+        assert!(matches!(parse_ipv4_octets(b"10.0.0.255"), Some([10, 0, 0, 255])));
+        assert!(parse_ipv4_octets(b"10.0.0.256").is_none());
+        assert!(parse_ipv4_octets(b"10.0.0").is_none());
+        assert!(parse_ipv4_octets(b"10.0.0.1.").is_none());
+        assert!(parse_ipv4_octets(b"010.001.1.1").is_none());
+        assert!(matches!(parse_ipv6_segments(b"2001:db8::1"), Some([0x2001, 0xdb8, 0, 0, 0, 0, 0, 1])));
+        assert!(matches!(parse_ipv6_segments(b"::"), Some([0, 0, 0, 0, 0, 0, 0, 0])));
+        assert!(matches!(parse_ipv6_segments(b"fe80::"), Some([0xfe80, 0, 0, 0, 0, 0, 0, 0])));
+        assert!(matches!(parse_ipv6_segments(b"1:2:3:4:5:6:7:8"), Some([1, 2, 3, 4, 5, 6, 7, 8])));
+        assert!(matches!(parse_ipv6_segments(b"::ffff:192.168.1.1"), Some([0, 0, 0, 0, 0, 0xffff, 0xc0a8, 0x0101])));
+        assert!(parse_ipv6_segments(b"1::2::3").is_none());
+        assert!(parse_ipv6_segments(b"1:2:3:4:5:6:7").is_none());
+        assert!(parse_ipv6_segments(b"1:2:3:4:5:6:7:8:9").is_none());
+        assert!(parse_ipv6_segments(b"12345::").is_none());
+        assert!(parse_ipv6_segments(b"1:").is_none());
+        assert!(parse_ipv6_segments(b":1").is_none());
+    }
+    #[test]
     const fn removing_first_space() {
         assert!(!is_identical(b" whatever", remove_possible_leading_space(b" whatever")));
         assert!(is_identical(b"whatever", remove_possible_leading_space(b"whatever")));
@@ -310,6 +842,57 @@ mod const_tests {
         }
     }
     #[test]
+    const fn detecting_echo() {
+        let msg = IrcMsg::parse(b":Dan[]!d@localhost PRIVMSG #chan :Yo!");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            assert!(msg.is_echo_of_self(b"dan{}", IrcCaseMapping::Rfc1459));
+            assert!(!msg.is_echo_of_self(b"dan{}", IrcCaseMapping::Ascii));
+            assert!(!msg.is_echo_of_self(b"bob", IrcCaseMapping::Rfc1459));
+        }
+        let msg = IrcMsg::parse(b"@+typing=active :dan TAGMSG #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(msg.is_echo_of_self(b"dan", IrcCaseMapping::Ascii));}
+        let msg = IrcMsg::parse(b":dan!d@localhost JOIN #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(!msg.is_echo_of_self(b"dan", IrcCaseMapping::Ascii));}
+        let msg = IrcMsg::parse(b"NOTICE #chan :no source");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(!msg.is_echo_of_self(b"dan", IrcCaseMapping::Ascii));}
+        let options = ParseOptions::new().servername_detection(ServernameDetection::Ambiguous);
+        let msg = IrcMsg::parse_with_options(b":dan.away NOTICE #chan :Yo!", options);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(msg.is_echo_of_self(b"DAN.AWAY", IrcCaseMapping::Ascii));}
+    }
+    #[test]
+    const fn parsing_with_options() {
+        let options = ParseOptions::new().servername_detection(ServernameDetection::Ambiguous);
+        let msg = IrcMsg::parse_with_options(b":dan.away PRIVMSG #chan :Yo!", options);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            assert!(msg.source().is_some());
+            if let Some(src) = msg.source() {assert!(matches!(src.origin(), Origin::Ambiguous(_)));}
+        }
+        assert!(IrcMsg::parse(b":irc.example.com 000 dan :Brand new").is_err());
+        let options = ParseOptions::new().unhandled_numerics(UnhandledNumerics::Accept);
+        assert!(IrcMsg::parse_with_options(b":irc.example.com 000 dan :Brand new", options).is_ok());
+        let options = ParseOptions::new().ts6_detection(Ts6Detection::Enabled);
+        let msg = IrcMsg::parse_with_options(b":042AAAAAB PRIVMSG #chan :Yo!", options);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            assert!(msg.source().is_some());
+            if let Some(src) = msg.source() {assert!(matches!(src.origin(), Origin::Uid(_)));}
+        }
+    }
+    #[test]
+    const fn parsing_content_as_u64() {
+        assert!(matches!(ContentType::new(b"1700000000").to_u64(), Some(1_700_000_000)));
+        assert!(matches!(ContentType::new(b"18446744073709551615").to_u64(), Some(u64::MAX)));
+        assert!(ContentType::new(b"18446744073709551616").to_u64().is_none());
+        assert!(ContentType::new(b"").to_u64().is_none() && ContentType::new(b"-1").to_u64().is_none());
+        assert!(ContentType::new(b"12a").to_u64().is_none());
+    }
+    #[test]
     const fn get_command() {
         let msg = IrcMsg::parse(b"INFO");
         assert!(msg.is_ok());
@@ -328,8 +911,9 @@ mod const_tests {
         if let Ok(msg) = msg {assert!(msg.parameters().is_none());}
     }
     #[test]
+    #[allow(clippy::too_many_lines)]
     const fn parsing_ircmsg() {
-        assert!(IrcMsg::parse(b"@id=2\034AB :dan!d@localhost PRIVMSG #chan :Hey what's up!").is_err());
+        assert!(IrcMsg::parse(b"@id=2\x0034AB :dan!d@localhost PRIVMSG #chan :Hey what's up!").is_err());
         assert!(IrcMsg::parse(b"@id=234AB :dan!d@lo\0calhost PRIVMSG #chan :Hey what's up!").is_err());
         assert!(IrcMsg::parse(b"@id=234AB :dan!d@localhost PRI\0VMSG #chan :Hey what's up!").is_err());
         assert!(IrcMsg::parse(b"@id=234AB :dan!d@localhost PRIVMSG #ch\0an :Hey what's up!").is_err());
@@ -475,4 +1059,83 @@ mod const_tests {
             }
         }
     }
+    const fn written<const N: usize>(writer: Result<IrcMsgWriter<N>, IrcMsgWriterError>) -> IrcMsgWriter<N> {
+        assert!(writer.is_ok());
+        match writer {Ok(writer) => writer, Err(_) => IrcMsgWriter::new()}
+    }
+    #[test]
+    const fn writing_msg() {
+        let writer = written(IrcMsgWriter::<64>::new().source(b"irc.example.com"));
+        let writer = written(writer.command(b"005"));
+        let writer = written(writer.middle(b"dan"));
+        let writer = written(writer.middle_parts(&[b"NICKLEN", b"=", b"30"]));
+        let writer = written(writer.trailing(b"are supported"));
+        assert!(is_identical(writer.as_bytes(), b":irc.example.com 005 dan NICKLEN=30 :are supported"));
+        assert!(writer.params_count() == 3);
+        assert!(IrcMsg::parse(writer.as_bytes()).is_ok());
+        assert!(matches!(writer.middle(b"x"), Err(IrcMsgWriterError::Parameter(_))));
+        let writer = IrcMsgWriter::<16>::new().command(b"PING");
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {
+            let ping = writer.trailing(b"token");
+            assert!(ping.is_ok());
+            if let Ok(ping) = ping {assert!(is_identical(ping.as_bytes(), b"PING token"));}
+            let ping = writer.trailing_with_colon(b"token");
+            assert!(ping.is_ok());
+            if let Ok(ping) = ping {assert!(is_identical(ping.as_bytes(), b"PING :token"));}
+            assert!(matches!(writer.source(b"dan"), Err(IrcMsgWriterError::SourceAfterStart)));
+            assert!(matches!(writer.command(b"PONG"), Err(IrcMsgWriterError::CommandAlreadyWritten)));
+            assert!(matches!(writer.middle_parts(&[b"", b":x"]), Err(IrcMsgWriterError::Parameter(_))));
+            assert!(matches!(writer.trailing(b"far too long for this"), Err(IrcMsgWriterError::Parameter(_))));
+        }
+        assert!(matches!(IrcMsgWriter::<16>::new().middle(b"x"), Err(IrcMsgWriterError::MissingCommand)));
+        assert!(matches!(IrcMsgWriter::<16>::new().command(b"PR VMSG"), Err(IrcMsgWriterError::InvalidCommand)));
+    }
+    #[test]
+    const fn writing_tags() {
+        let writer = IrcMsgWriter::<96>::new().tag(b"+example.com/x", Some(b"a; b\\c"));
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {
+            let writer = writer.tag(b"draft/multiline-concat", None);
+            assert!(writer.is_ok());
+            if let Ok(writer) = writer {
+                let writer = writer.source(b"dan");
+                assert!(writer.is_ok());
+                if let Ok(writer) = writer {
+                    assert!(matches!(writer.tag(b"msgid", None), Err(IrcMsgWriterError::TagAfterStart)));
+                    let writer = writer.command(b"TAGMSG");
+                    assert!(writer.is_ok());
+                    if let Ok(writer) = writer {
+                        let writer = writer.middle(b"#chan");
+                        assert!(writer.is_ok());
+                        if let Ok(writer) = writer {
+                            let expected = b"@+example.com/x=a\\:\\sb\\\\c;draft/multiline-concat :dan TAGMSG #chan";
+                            assert!(is_identical(writer.as_bytes(), expected));
+                            let msg = IrcMsg::parse(writer.as_bytes());
+                            assert!(msg.is_ok());
+                            if let Ok(msg) = msg {
+                                let tags = msg.tags();
+                                assert!(tags.is_some());
+                                if let Some(tags) = tags {assert!(tags.count() == 2);}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let writer = IrcMsgWriter::<16>::new().tag(b"msgid", Some(b""));
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {
+            let writer = writer.command(b"PING");
+            assert!(writer.is_ok());
+            if let Ok(writer) = writer {assert!(is_identical(writer.as_bytes(), b"@msgid PING"));}
+        }
+        assert!(matches!(IrcMsgWriter::<16>::new().tag(b"+", None), Err(IrcMsgWriterError::InvalidTagKey)));
+        assert!(matches!(IrcMsgWriter::<16>::new().tag(b"a=b", None), Err(IrcMsgWriterError::InvalidTagKey)));
+        assert!(matches!(IrcMsgWriter::<16>::new().tag(b"a", Some(b"\0")), Err(IrcMsgWriterError::InvalidTagValue)));
+        assert!(matches!(IrcMsgWriter::<4>::new().tag(b"msgid", None), Err(IrcMsgWriterError::BufferFull)));
+        let writer = IrcMsgWriter::<6>::new().tag(b"msgid", None);
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {assert!(matches!(writer.command(b"A"), Err(IrcMsgWriterError::BufferFull)));}
+    }
 }
@@ -0,0 +1,203 @@
+//! Methods for reading the channel mode changes of a modestring.
+//!
+//! ## Purpose
+//!
+//! A modestring such as `+ov-l` sets or unsets several modes at once and the arguments of the modes that take
+//! one follow it in order. It is sent in `MODE` messages and the `RPL_CHANNELMODEIS` (`324`) numeric.
+//! Which modes take an argument depends on the `CHANMODES` and `PREFIX`
+//! [`ISupportToken`](crate::isupport::ISupportToken)s so [`ModeChanges`] needs both to pair each change with its
+//! argument.
+
+use crate::{ContentType, isupport::{ChanModes, ModeType, Prefix}, parameters::ParametersCursor};
+
+/// A single channel mode being set or unset.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ModeChange<'msg> {
+    set: bool,
+    mode: u8,
+    argument: Option<ContentType<'msg>>,
+}
+
+impl<'msg> ModeChange<'msg> {
+    /// Check whether the mode is set (`+`) rather than unset (`-`).
+    #[must_use]
+    pub const fn is_set(&self) -> bool {
+        self.set
+    }
+    /// Returns the mode character.
+    #[must_use]
+    pub const fn mode(&self) -> u8 {
+        self.mode
+    }
+    /// Returns the argument of the mode if it takes one.
+    #[must_use]
+    pub const fn argument(&self) -> Option<ContentType<'msg>> {
+        self.argument
+    }
+}
+
+/// A cursor for reading channel mode changes one at a time.
+///
+/// Channel membership modes from `PREFIX` and [`ModeType::B`] modes always take an argument, [`ModeType::C`] modes
+/// only when set and [`ModeType::D`] modes never. [`ModeType::A`] modes take an argument if one is left so that a
+/// list query such as `MODE #chan +b` reads as a change without an argument.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ModeChanges<'msg, 'modes> {
+    modestring: &'msg [u8],
+    index: usize,
+    set: bool,
+    arguments: ParametersCursor<'msg>,
+    chanmodes: ChanModes<'modes>,
+    prefix: Prefix<'modes>,
+}
+
+impl<'msg, 'modes> ModeChanges<'msg, 'modes> {
+    /// Generates [`ModeChanges`] from a modestring such as `+o-v` and a cursor positioned at its first argument.
+    #[must_use]
+    pub const fn new(modestring: ContentType<'msg>, arguments: ParametersCursor<'msg>, chanmodes: ChanModes<'modes>,
+        prefix: Prefix<'modes>) -> Self {
+        Self{modestring: modestring.as_bytes(), index: 0, set: true, arguments, chanmodes, prefix}
+    }
+    /// Returns the next [`ModeChange`] and the advanced [`ModeChanges`].
+    ///
+    /// Returns `None` once all mode changes have been read.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a mode is not advertised by `CHANMODES` or `PREFIX` or a mode requiring an argument
+    /// has none left.
+    pub const fn next_change(mut self) -> Result<Option<(ModeChange<'msg>, Self)>, ModeChangeError> {
+        while self.index < self.modestring.len() {
+            let mode = self.modestring[self.index];
+            self.index += 1;
+            let takes_argument = match mode {
+                b'+' => {
+                    self.set = true;
+                    continue;
+                },
+                b'-' => {
+                    self.set = false;
+                    continue;
+                },
+                _ if self.prefix.prefix_for_mode(mode).is_some() => Some(true),
+                _ => match self.chanmodes.mode_type(mode) {
+                    Some(ModeType::A) => None,
+                    Some(ModeType::B) => Some(true),
+                    Some(ModeType::C) => Some(self.set),
+                    Some(ModeType::D) => Some(false),
+                    None => return Err(ModeChangeError::UnknownMode(mode)),
+                },
+            };
+            let argument = match (takes_argument, self.arguments.next_param()) {
+                (Some(true) | None, Some((argument, arguments))) => {
+                    self.arguments = arguments;
+                    Some(argument)
+                },
+                (Some(true), None) => return Err(ModeChangeError::MissingArgument(mode)),
+                (Some(false), _) | (None, None) => None,
+            };
+            return Ok(Some((ModeChange{set: self.set, mode, argument}, self)));
+        }
+        Ok(None)
+    }
+}
+
+/// The possible types of errors when reading [`ModeChanges`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ModeChangeError {
+    /// The mode is not advertised by `CHANMODES` or `PREFIX`.
+    UnknownMode(u8),
+    /// The mode requires an argument but none is left.
+    MissingArgument(u8),
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{ModeChangeError, ModeChanges};
+    use crate::{is_identical, isupport::{ChanModes, Prefix}, parameters::Parameters};
+    const fn changes_of<'msg>(input: &'msg [u8], chanmodes: ChanModes<'msg>) -> Option<ModeChanges<'msg, 'msg>> {
+        let params = Parameters::parse(input);
+        assert!(matches!(params, Ok(Some(_))));
+        if let Ok(Some(params)) = params {
+            let cursor = params.cursor().next_param();
+            assert!(cursor.is_some());
+            if let Some((modestring, arguments)) = cursor {
+                return Some(ModeChanges::new(modestring, arguments, chanmodes, Prefix::DEFAULT));
+            }
+        }
+        None
+    }
+    #[test]
+    const fn reading_mode_changes() {
+        let chanmodes = ChanModes::parse(b"beI,k,l,imnpst");
+        assert!(chanmodes.is_ok());
+        if let Ok(chanmodes) = chanmodes {
+            let changes = changes_of(b"+ov-l+kb bob alice secret *!*@spam", chanmodes);
+            assert!(changes.is_some());
+            if let Some(changes) = changes {
+                let changes = changes.next_change();
+                assert!(matches!(changes, Ok(Some((_, _)))));
+                if let Ok(Some((op, changes))) = changes {
+                    let nick = op.argument();
+                    assert!(nick.is_some());
+                    if let Some(nick) = nick {
+                        assert!(op.is_set() && op.mode() == b'o' && is_identical(nick.as_bytes(), b"bob"));
+                    }
+                    let changes = changes.next_change();
+                    assert!(matches!(changes, Ok(Some((_, _)))));
+                    if let Ok(Some((_, changes))) = changes {
+                        let changes = changes.next_change();
+                        assert!(matches!(changes, Ok(Some((_, _)))));
+                        if let Ok(Some((limit, changes))) = changes {
+                            assert!(!limit.is_set() && limit.mode() == b'l' && limit.argument().is_none());
+                            let changes = changes.next_change();
+                            assert!(matches!(changes, Ok(Some((_, _)))));
+                            if let Ok(Some((key, changes))) = changes {
+                                let secret = key.argument();
+                                assert!(secret.is_some());
+                                if let Some(secret) = secret {
+                                    assert!(key.is_set() && key.mode() == b'k');
+                                    assert!(is_identical(secret.as_bytes(), b"secret"));
+                                }
+                                let changes = changes.next_change();
+                                assert!(matches!(changes, Ok(Some((_, _)))));
+                                if let Ok(Some((ban, changes))) = changes {
+                                    let mask = ban.argument();
+                                    assert!(mask.is_some());
+                                    if let Some(mask) = mask {
+                                        assert!(ban.mode() == b'b' && is_identical(mask.as_bytes(), b"*!*@spam"));
+                                    }
+                                    assert!(matches!(changes.next_change(), Ok(None)));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    #[test]
+    const fn reading_invalid_mode_changes() {
+        let chanmodes = ChanModes::parse(b"beI,k,l,imnpst");
+        assert!(chanmodes.is_ok());
+        if let Ok(chanmodes) = chanmodes {
+            let changes = changes_of(b"b", chanmodes);
+            assert!(changes.is_some());
+            if let Some(changes) = changes {
+                let ban = changes.next_change();
+                assert!(matches!(ban, Ok(Some((_, _)))));
+                if let Ok(Some((ban, _))) = ban {assert!(ban.is_set() && ban.argument().is_none());}
+            }
+            let changes = changes_of(b"+o", chanmodes);
+            assert!(changes.is_some());
+            if let Some(changes) = changes {
+                assert!(matches!(changes.next_change(), Err(ModeChangeError::MissingArgument(b'o'))));
+            }
+            let changes = changes_of(b"+X", chanmodes);
+            assert!(changes.is_some());
+            if let Some(changes) = changes {
+                assert!(matches!(changes.next_change(), Err(ModeChangeError::UnknownMode(b'X'))));
+            }
+        }
+    }
+}
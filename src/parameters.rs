@@ -13,16 +13,28 @@
 
 use crate::ContentType;
 
+/// The maximum amount of parameters whose boundaries are stored when parsing [`Parameters`].
+///
+/// The [IRC Client Protocol Specification] permits at most 15 parameters.
+/// Parameters beyond this amount are still accessible but are found by scanning.
+///
+/// [IRC Client Protocol Specification]: <https://modern.ircdocs.horse/#parameters>
+pub const MAX_STORED_PARAMETERS: usize = 15;
+
 /// All the parameters of an [`IrcMsg`](crate::IrcMsg).
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Parameters<'msg> {
     amount: usize,
     content: ContentType<'msg>,
+    bounds: [(usize, usize); MAX_STORED_PARAMETERS],
 }
 
 impl<'msg> Parameters<'msg> {
     /// Generates [`Parameters`] from a slice of bytes.
     ///
+    /// The start and end of the first [`MAX_STORED_PARAMETERS`] parameters are stored so that extracting them
+    /// doesn't require scanning the input again.
+    ///
     /// # Errors
     ///
     /// Will return `Err` if the input contains an invalid character as per the [IRC Client Protocol Specification].
@@ -31,6 +43,7 @@ impl<'msg> Parameters<'msg> {
     pub const fn parse(input: &'msg [u8]) -> Result<Option<Self>, ParametersError> {
         if input.is_empty() {return Ok(None);}
         let mut amount = 1;
+        let mut bounds = [(0, 0); MAX_STORED_PARAMETERS];
         let mut previous_char = b'\0';
         let mut trailing_parameter = false;
         let mut index = 0;
@@ -40,12 +53,15 @@ impl<'msg> Parameters<'msg> {
             } else if (previous_char == b' ' || index == 0) && input[index] == b':' {
                 trailing_parameter = true;
             } else if !trailing_parameter && input[index] == b' ' {
+                if amount <= MAX_STORED_PARAMETERS {bounds[amount-1].1 = index;}
                 amount += 1;
+                if amount <= MAX_STORED_PARAMETERS {bounds[amount-1].0 = index + 1;}
             }
             previous_char = input[index];
             index += 1;
         }
-        Ok(Some(Parameters{amount, content: ContentType::new(input)}))
+        if amount <= MAX_STORED_PARAMETERS {bounds[amount-1].1 = input.len();}
+        Ok(Some(Parameters{amount, content: ContentType::new(input), bounds}))
     }
     /// Returns the amount of parameters in [`Parameters`].
     #[must_use]
@@ -56,14 +72,14 @@ impl<'msg> Parameters<'msg> {
     ///
     /// This includes the `:` before the last parameter if present.
     #[must_use]
-    pub const fn content(&self) -> ContentType {
+    pub const fn content(&self) -> ContentType<'msg> {
         self.content
     }
     /// Returns the first parameter as a [`ContentType`].
     ///
     /// Does not include `:` for the trailing parameter.
     #[must_use]
-    pub const fn extract_first(&self) -> ContentType {
+    pub const fn extract_first(&self) -> ContentType<'msg> {
         match self.extract_specific(0) {
             Some(output) => output,
             None => unreachable!(),
@@ -73,7 +89,7 @@ impl<'msg> Parameters<'msg> {
     ///
     /// Does not include `:` for the trailing parameter.
     #[must_use]
-    pub const fn extract_last(&self) -> ContentType {
+    pub const fn extract_last(&self) -> ContentType<'msg> {
         match self.extract_specific(self.amount-1) {
             Some(output) => output,
             None => unreachable!(),
@@ -83,46 +99,131 @@ impl<'msg> Parameters<'msg> {
     ///
     /// Index starts at 0. If out of bounds it returns `None`. Does not include `:` for the trailing parameter.
     #[must_use]
-    pub const fn extract_specific(&self, target_index: usize) -> Option<ContentType> {
-        if target_index > self.amount {return None;}
+    pub const fn extract_specific(&self, target_index: usize) -> Option<ContentType<'msg>> {
+        if target_index >= self.amount {return None;}
+        let bytes = self.content.as_bytes();
+        if target_index >= MAX_STORED_PARAMETERS {
+            let (_, rest) = bytes.split_at(self.start_of(target_index));
+            return match (ParametersCursor{remaining: Some(rest)}).next_param() {
+                Some((param, _)) => Some(param),
+                None => None,
+            };
+        }
+        let (start, end) = self.bounds[target_index];
+        let (param, _) = bytes.split_at(end);
+        let (_, param) = param.split_at(start);
+        if !param.is_empty() && param[0] == b':' {
+            let (_, output) = param.split_at(1);
+            Some(ContentType::new(output))
+        } else {
+            Some(ContentType::new(param))
+        }
+    }
+    /// Returns the raw bytes from the parameter at the specified index to the end of the [`Parameters`].
+    ///
+    /// Index starts at 0. If out of bounds it returns `None`. Includes the `:` before the trailing parameter.
+    #[must_use]
+    pub const fn raw_from(&self, target_index: usize) -> Option<&'msg [u8]> {
+        if target_index >= self.amount {return None;}
+        let (_, rest) = self.content.as_bytes().split_at(self.start_of(target_index));
+        Some(rest)
+    }
+    const fn start_of(&self, target_index: usize) -> usize {
+        if target_index < MAX_STORED_PARAMETERS {return self.bounds[target_index].0;}
         let bytes = self.content.as_bytes();
-        let mut current_param = 1;
-        let mut param_started = false;
-        let mut param_start = 0;
-        let mut param_end = 0;
-        let mut last_param = false;
-        let mut previous_byte = b'\0';
+        let mut start = self.bounds[MAX_STORED_PARAMETERS-1].1 + 1;
+        let mut current = MAX_STORED_PARAMETERS;
+        while current < target_index {
+            while bytes[start] != b' ' {start += 1;}
+            start += 1;
+            current += 1;
+        }
+        start
+    }
+    /// Splits the parameters into an array of `N` [`ContentType`] in one pass.
+    ///
+    /// Also returns the amount of parameters stored in the array. Parameters beyond `N` are not included
+    /// and unused slots are `None`. Does not include `:` for the trailing parameter.
+    #[must_use]
+    pub const fn to_array<const N: usize>(&self) -> ([Option<ContentType<'msg>>; N], usize) {
+        let mut output = [None; N];
+        let mut cursor = self.cursor();
         let mut index = 0;
-        while index < bytes.len() {
-            if target_index == current_param - 1 && !param_started {
-                param_started = true;
-                param_start = index;
-            }
-            if bytes[index] == b' ' && !last_param {
-                current_param += 1;
-            } else if bytes[index] == b':' && (previous_byte == b' ' || index == 0) {
-                last_param = true;
+        while index < N {
+            match cursor.next_param() {
+                Some((param, next)) => {
+                    output[index] = Some(param);
+                    cursor = next;
+                },
+                None => break,
             }
-            if param_started && current_param == target_index + 2 {param_end = index; break;}
-            previous_byte = bytes[index];
-            param_end = index;
             index += 1;
         }
-        let (_, rest) = bytes.split_at(param_start);
-        param_end -= param_start;
-        let param = if last_param {
-            rest
-        } else {
-            let (p, _) = rest.split_at(if self.amount == 1 {param_end+1} else {param_end});
-            p
-        };
-        if param[0] == b':' {
-            match param.split_first() {
-                Some((_, output)) => Some(ContentType::new(output)),
-                None => unreachable!(),
-            }
-        } else {
-            Some(ContentType::new(param))
+        (output, index)
+    }
+    /// Checks whether the last parameter is a trailing parameter introduced by `:`.
+    #[must_use]
+    pub const fn has_trailing(&self) -> bool {
+        trailing_start(self.content.as_bytes()).is_some()
+    }
+    /// Returns the trailing parameter as a [`ContentType`] if it is introduced by `:`.
+    ///
+    /// Does not include the `:`. The trailing parameter may contain spaces and may be empty.
+    #[must_use]
+    pub const fn trailing(&self) -> Option<ContentType<'msg>> {
+        let bytes = self.content.as_bytes();
+        match trailing_start(bytes) {
+            Some(index) => {
+                let (_, trailing) = bytes.split_at(index + 1);
+                Some(ContentType::new(trailing))
+            },
+            None => None,
+        }
+    }
+    /// Returns all the parameters before the trailing parameter as a [`ContentType`].
+    ///
+    /// The parameters are separated by a space. If there is no trailing parameter introduced by `:` this is
+    /// the same as [`content`](Parameters::content). Returns `None` if the trailing parameter is the only parameter.
+    #[must_use]
+    pub const fn middles(&self) -> Option<ContentType<'msg>> {
+        let bytes = self.content.as_bytes();
+        match trailing_start(bytes) {
+            Some(0) => None,
+            Some(index) => {
+                let (middles, _) = bytes.split_at(index - 1);
+                Some(ContentType::new(middles))
+            },
+            None => Some(self.content),
+        }
+    }
+    /// Returns a [`ParametersCursor`] positioned at the first parameter.
+    #[must_use]
+    pub const fn cursor(&self) -> ParametersCursor<'msg> {
+        ParametersCursor{remaining: Some(self.content.as_bytes())}
+    }
+    /// Returns the index of the first parameter containing non-utf8 bytes.
+    ///
+    /// Index starts at 0. Returns `None` if all parameters are valid utf8.
+    #[must_use]
+    pub const fn utf8_invalid_index(&self) -> Option<usize> {
+        if self.content.is_valid_utf8() {return None;}
+        let mut cursor = self.cursor();
+        let mut index = 0;
+        while let Some((param, next)) = cursor.next_param() {
+            if !param.is_valid_utf8() {return Some(index);}
+            cursor = next;
+            index += 1;
+        }
+        None
+    }
+    /// Returns a [`CommaList`] of the requested parameter at the specified index.
+    ///
+    /// Index starts at 0. If out of bounds it returns `None`.
+    #[must_use]
+    pub const fn comma_list(&self, target_index: usize) -> Option<CommaList<'msg>> {
+        match self.extract_specific(target_index) {
+            Some(param) => Some(CommaList::new(param)),
+            None => None,
         }
     }
     /// Checks whether the [`Parameters`] contains non-utf8 bytes.
@@ -141,6 +242,309 @@ impl core::fmt::Display for Parameters<'_> {
     }
 }
 
+const fn trailing_start(input: &[u8]) -> Option<usize> {
+    let mut previous_byte = b' ';
+    let mut index = 0;
+    while index < input.len() {
+        if previous_byte == b' ' && input[index] == b':' {return Some(index);}
+        previous_byte = input[index];
+        index += 1;
+    }
+    None
+}
+
+/// A cursor for reading [`Parameters`] one at a time from first to last.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParametersCursor<'msg> {
+    remaining: Option<&'msg [u8]>,
+}
+
+impl<'msg> ParametersCursor<'msg> {
+    /// Returns the next parameter as a [`ContentType`] and the advanced [`ParametersCursor`].
+    ///
+    /// Returns `None` once all parameters have been read. Does not include `:` for the trailing parameter.
+    #[must_use]
+    pub const fn next_param(self) -> Option<(ContentType<'msg>, Self)> {
+        let Some(bytes) = self.remaining else {return None;};
+        if !bytes.is_empty() && bytes[0] == b':' {
+            let (_, trailing) = bytes.split_at(1);
+            return Some((ContentType::new(trailing), Self{remaining: None}));
+        }
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] == b' ' {
+                let (param, rest) = bytes.split_at(index);
+                let (_, rest) = rest.split_at(1);
+                return Some((ContentType::new(param), Self{remaining: Some(rest)}));
+            }
+            index += 1;
+        }
+        Some((ContentType::new(bytes), Self{remaining: None}))
+    }
+    /// Checks whether all parameters have been read.
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.remaining.is_none()
+    }
+}
+
+/// A cursor for reading comma-separated values of a single parameter one at a time.
+///
+/// Many commands such as `JOIN`, `PRIVMSG`, `MONITOR` and `ISON` accept a list of values separated by `,`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CommaList<'msg> {
+    remaining: Option<&'msg [u8]>,
+}
+
+impl<'msg> CommaList<'msg> {
+    /// Generates a [`CommaList`] from a [`ContentType`].
+    ///
+    /// An empty input has no values.
+    #[must_use]
+    pub const fn new(input: ContentType<'msg>) -> Self {
+        let bytes = input.as_bytes();
+        if bytes.is_empty() {Self{remaining: None}} else {Self{remaining: Some(bytes)}}
+    }
+    /// Returns the next value as a [`ContentType`] and the advanced [`CommaList`].
+    ///
+    /// Returns `None` once all values have been read. Values may be empty such as in `a,,b`.
+    #[must_use]
+    pub const fn next_item(self) -> Option<(ContentType<'msg>, Self)> {
+        let Some(bytes) = self.remaining else {return None;};
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] == b',' {
+                let (item, rest) = bytes.split_at(index);
+                let (_, rest) = rest.split_at(1);
+                return Some((ContentType::new(item), Self{remaining: Some(rest)}));
+            }
+            index += 1;
+        }
+        Some((ContentType::new(bytes), Self{remaining: None}))
+    }
+    /// Returns the amount of values in the [`CommaList`] that have not been read yet.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        let Some(bytes) = self.remaining else {return 0;};
+        let mut amount = 1;
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] == b',' {amount += 1;}
+            index += 1;
+        }
+        amount
+    }
+    /// Returns the value at the specified index as a [`ContentType`].
+    ///
+    /// Index starts at 0. If out of bounds it returns `None`.
+    #[must_use]
+    pub const fn nth(&self, target_index: usize) -> Option<ContentType<'msg>> {
+        let mut list = *self;
+        let mut index = 0;
+        while let Some((item, next)) = list.next_item() {
+            if index == target_index {return Some(item);}
+            list = next;
+            index += 1;
+        }
+        None
+    }
+    /// Checks whether all values have been read.
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.remaining.is_none()
+    }
+}
+
+/// A cursor for reading space-separated values of a single parameter one at a time.
+///
+/// Replies such as `RPL_NAMREPLY` and `RPL_WATCHLIST` pack a list of values separated by spaces into the trailing
+/// parameter. Repeated spaces are skipped so values are never empty.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SpaceList<'msg> {
+    remaining: &'msg [u8],
+}
+
+impl<'msg> SpaceList<'msg> {
+    /// Generates a [`SpaceList`] from a [`ContentType`].
+    #[must_use]
+    pub const fn new(input: ContentType<'msg>) -> Self {
+        Self{remaining: input.as_bytes()}
+    }
+    /// Returns the next value as a [`ContentType`] and the advanced [`SpaceList`].
+    ///
+    /// Returns `None` once all values have been read.
+    #[must_use]
+    pub const fn next_item(self) -> Option<(ContentType<'msg>, Self)> {
+        let mut bytes = self.remaining;
+        while let [b' ', rest @ ..] = bytes {bytes = rest;}
+        if bytes.is_empty() {return None;}
+        let mut index = 0;
+        while index < bytes.len() && bytes[index] != b' ' {index += 1;}
+        let (item, rest) = bytes.split_at(index);
+        Some((ContentType::new(item), Self{remaining: rest}))
+    }
+    /// Returns the amount of values in the [`SpaceList`] that have not been read yet.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        let mut list = *self;
+        let mut amount = 0;
+        while let Some((_, next)) = list.next_item() {
+            list = next;
+            amount += 1;
+        }
+        amount
+    }
+    /// Checks whether all values have been read.
+    #[must_use]
+    pub const fn is_finished(&self) -> bool {
+        self.next_item().is_none()
+    }
+}
+
+/// A writer for building [`Parameters`] into a buffer of `N` bytes.
+///
+/// Parameters are separated by a space. The `:` before the trailing parameter is inserted only when required.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ParamsWriter<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+    amount: usize,
+    trailing_written: bool,
+}
+
+impl<const N: usize> ParamsWriter<N> {
+    /// Generates an empty [`ParamsWriter`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self{buffer: [0; N], len: 0, amount: 0, trailing_written: false}
+    }
+    /// Appends a middle parameter.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a trailing parameter has already been written, the maximum of
+    /// [`MAX_STORED_PARAMETERS`] is reached, the buffer is full or the input is empty, starts with `:`
+    /// or contains a space, null, carriage return or line feed.
+    pub const fn middle(self, input: &[u8]) -> Result<Self, ParamsWriterError> {
+        if let Err(e) = validate_middle(input) {return Err(e);}
+        match self.check(input, false) {
+            Ok(()) => Ok(self.push(input, false)),
+            Err(e) => Err(e),
+        }
+    }
+    /// Appends the trailing parameter.
+    ///
+    /// A `:` is inserted before it if it is empty, contains a space or starts with `:`.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a trailing parameter has already been written, the maximum of
+    /// [`MAX_STORED_PARAMETERS`] is reached, the buffer is full or the input contains a null,
+    /// carriage return or line feed.
+    pub const fn trailing(mut self, input: &[u8]) -> Result<Self, ParamsWriterError> {
+        let colon = match validate_trailing(input) {Ok(colon) => colon, Err(e) => return Err(e)};
+        match self.check(input, colon) {
+            Ok(()) => {
+                self = self.push(input, colon);
+                self.trailing_written = true;
+                Ok(self)
+            },
+            Err(e) => Err(e),
+        }
+    }
+    const fn check(&self, input: &[u8], colon: bool) -> Result<(), ParamsWriterError> {
+        if self.trailing_written {return Err(ParamsWriterError::TrailingAlreadyWritten);}
+        else if self.amount == MAX_STORED_PARAMETERS {return Err(ParamsWriterError::TooManyParameters);}
+        let needed = input.len() + if self.amount > 0 {1} else {0} + if colon {1} else {0};
+        if self.len + needed > N {return Err(ParamsWriterError::BufferFull);}
+        Ok(())
+    }
+    const fn push(mut self, input: &[u8], colon: bool) -> Self {
+        if self.amount > 0 {
+            self.buffer[self.len] = b' ';
+            self.len += 1;
+        }
+        if colon {
+            self.buffer[self.len] = b':';
+            self.len += 1;
+        }
+        let mut index = 0;
+        while index < input.len() {
+            self.buffer[self.len] = input[index];
+            self.len += 1;
+            index += 1;
+        }
+        self.amount += 1;
+        self
+    }
+    /// Returns the written parameters as a slice of bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        let (output, _) = self.buffer.split_at(self.len);
+        output
+    }
+    /// Returns the amount of parameters written.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.amount
+    }
+}
+
+impl<const N: usize> Default for ParamsWriter<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) const fn validate_middle(input: &[u8]) -> Result<(), ParamsWriterError> {
+    if input.is_empty() {return Err(ParamsWriterError::EmptyMiddle);}
+    else if input[0] == b':' {return Err(ParamsWriterError::ColonStartsMiddle);}
+    let mut index = 0;
+    while index < input.len() {
+        match input[index] {
+            b' ' => return Err(ParamsWriterError::SpaceInMiddle),
+            b'\0' | b'\r' | b'\n' => return Err(ParamsWriterError::InvalidByte(input[index])),
+            _ => {},
+        }
+        index += 1;
+    }
+    Ok(())
+}
+
+/// Returns whether the trailing parameter requires a `:` before it.
+pub(crate) const fn validate_trailing(input: &[u8]) -> Result<bool, ParamsWriterError> {
+    let mut colon = input.is_empty() || input[0] == b':';
+    let mut index = 0;
+    while index < input.len() {
+        match input[index] {
+            b' ' => colon = true,
+            b'\0' | b'\r' | b'\n' => return Err(ParamsWriterError::InvalidByte(input[index])),
+            _ => {},
+        }
+        index += 1;
+    }
+    Ok(colon)
+}
+
+/// The possible types of errors when writing parameters with a [`ParamsWriter`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParamsWriterError {
+    /// Use of an invalid byte when writing a parameter.
+    InvalidByte(u8),
+    /// A middle parameter can't be empty.
+    EmptyMiddle,
+    /// A middle parameter can't contain a space.
+    SpaceInMiddle,
+    /// A middle parameter can't start with `:`.
+    ColonStartsMiddle,
+    /// No parameter can follow the trailing parameter.
+    TrailingAlreadyWritten,
+    /// The maximum amount of parameters has been reached.
+    TooManyParameters,
+    /// The buffer is too small for the parameter.
+    BufferFull,
+}
+
 /// The possible types of errors when parsing [`Parameters`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ParametersError {
@@ -151,20 +555,27 @@ pub enum ParametersError {
 #[cfg(test)]
 mod const_tests {
     use crate::{ContentType, is_identical};
-    use super::Parameters;
+    use super::{MAX_STORED_PARAMETERS, CommaList, Parameters, ParamsWriter, ParamsWriterError, SpaceList};
+    const fn parsed(input: &[u8]) -> Parameters<'_> {
+        let params = Parameters::parse(input);
+        assert!(matches!(params, Ok(Some(_))));
+        match params {
+            Ok(Some(params)) => params,
+            _ => Parameters{amount: 0, content: ContentType::new(b""), bounds: [(0, 0); MAX_STORED_PARAMETERS]},
+        }
+    }
     #[test]
     const fn get_first() {
-        let params = Parameters{amount: 3, content: ContentType::new(b"* LS :multi-prefix sasl")};
+        let params = parsed(b"* LS :multi-prefix sasl");
         let first_param = params.extract_first();
         let first = first_param.as_bytes();
         assert!(first[0] == b'*');
         assert!(first.len() == 1);
-        let params = Parameters{amount: 1, content: ContentType::new(b":")};
+        let params = parsed(b":");
         let first_param = params.extract_first();
         let first = first_param.as_bytes();
         assert!(first.is_empty());
-        assert!(first.len() == 0);
-        let params = Parameters{amount: 1, content: ContentType::new(b"#chat")};
+        let params = parsed(b"#chat");
         let first_param = params.extract_first();
         let first = first_param.as_bytes();
         assert!(first.len() == 5);
@@ -172,12 +583,12 @@ mod const_tests {
     }
     #[test]
     const fn get_last() {
-        let params = Parameters{amount: 3, content: ContentType::new(b"* LS :multi-prefix sasl")};
+        let params = parsed(b"* LS :multi-prefix sasl");
         let last_param = params.extract_last();
         let last = last_param.as_bytes();
         assert!(last.len() == 17);
         assert!(is_identical(last, b"multi-prefix sasl"));
-        let params = Parameters{amount: 1, content: ContentType::new(b":multi-prefix sasl")};
+        let params = parsed(b":multi-prefix sasl");
         let last_param = params.extract_last();
         let last = last_param.as_bytes();
         assert!(last.len() == 17);
@@ -185,7 +596,7 @@ mod const_tests {
     }
     #[test]
     const fn get_specific() {
-        let params = Parameters{amount: 3, content: ContentType::new(b"* LS :multi-prefix sasl")};
+        let params = parsed(b"* LS :multi-prefix sasl");
         let first_param = params.extract_specific(0);
         assert!(first_param.is_some());
         if let Some(first_param) = first_param {
@@ -241,4 +652,200 @@ mod const_tests {
         }
         assert!(Parameters::parse(b"\0\0\0\0").is_err());
     }
+    #[test]
+    const fn splitting_middles_and_trailing() {
+        let params = parsed(b"* LS :multi-prefix sasl");
+        assert!(params.has_trailing());
+        let trailing = params.trailing();
+        assert!(trailing.is_some());
+        if let Some(trailing) = trailing {assert!(is_identical(trailing.as_bytes(), b"multi-prefix sasl"));}
+        let middles = params.middles();
+        assert!(middles.is_some());
+        if let Some(middles) = middles {assert!(is_identical(middles.as_bytes(), b"* LS"));}
+        let params = parsed(b":");
+        assert!(params.has_trailing());
+        let trailing = params.trailing();
+        assert!(trailing.is_some());
+        if let Some(trailing) = trailing {assert!(trailing.as_bytes().is_empty());}
+        assert!(params.middles().is_none());
+        let params = parsed(b"#chat dan:away");
+        assert!(!params.has_trailing());
+        assert!(params.trailing().is_none());
+        let middles = params.middles();
+        assert!(middles.is_some());
+        if let Some(middles) = middles {assert!(is_identical(middles.as_bytes(), b"#chat dan:away"));}
+    }
+    #[test]
+    const fn using_cursor() {
+        let params = parsed(b"* LS :multi-prefix sasl");
+        let cursor = params.cursor();
+        assert!(!cursor.is_finished());
+        let cursor = cursor.next_param();
+        assert!(matches!(cursor, Some((_, _))));
+        if let Some((first, cursor)) = cursor {
+            assert!(is_identical(first.as_bytes(), b"*"));
+            let cursor = cursor.next_param();
+            assert!(matches!(cursor, Some((_, _))));
+            if let Some((second, cursor)) = cursor {
+                assert!(is_identical(second.as_bytes(), b"LS"));
+                let cursor = cursor.next_param();
+                assert!(matches!(cursor, Some((_, _))));
+                if let Some((third, cursor)) = cursor {
+                    assert!(is_identical(third.as_bytes(), b"multi-prefix sasl"));
+                    assert!(cursor.is_finished());
+                    assert!(cursor.next_param().is_none());
+                }
+            }
+        }
+    }
+    #[test]
+    const fn extracting_beyond_stored() {
+        let params = parsed(b"0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 :sixteen and seventeen");
+        assert!(params.count() == 17);
+        let param = params.extract_specific(14);
+        assert!(param.is_some());
+        if let Some(param) = param {assert!(is_identical(param.as_bytes(), b"14"));}
+        let param = params.extract_specific(15);
+        assert!(param.is_some());
+        if let Some(param) = param {assert!(is_identical(param.as_bytes(), b"15"));}
+        assert!(is_identical(params.extract_last().as_bytes(), b"sixteen and seventeen"));
+        assert!(params.extract_specific(17).is_none());
+    }
+    #[test]
+    const fn splitting_into_array() {
+        let params = parsed(b"dan 0 * :Dan Smith");
+        let (array, amount) = params.to_array::<4>();
+        assert!(amount == 4);
+        assert!(matches!(array, [Some(_), Some(_), Some(_), Some(_)]));
+        if let [Some(user), Some(mode), Some(unused), Some(realname)] = array {
+            assert!(is_identical(user.as_bytes(), b"dan"));
+            assert!(is_identical(mode.as_bytes(), b"0"));
+            assert!(is_identical(unused.as_bytes(), b"*"));
+            assert!(is_identical(realname.as_bytes(), b"Dan Smith"));
+        }
+        let (array, amount) = params.to_array::<6>();
+        assert!(amount == 4);
+        assert!(array[4].is_none() && array[5].is_none());
+        let (array, amount) = params.to_array::<2>();
+        assert!(amount == 2);
+        let mode = array[1];
+        assert!(mode.is_some());
+        if let Some(mode) = mode {assert!(is_identical(mode.as_bytes(), b"0"));}
+    }
+    #[test]
+    const fn splitting_comma_list() {
+        let params = parsed(b"#chat,&local,#empty key1,key2");
+        let channels = params.comma_list(0);
+        assert!(channels.is_some());
+        if let Some(channels) = channels {
+            assert!(channels.count() == 3);
+            let channels = channels.next_item();
+            assert!(matches!(channels, Some((_, _))));
+            if let Some((first, channels)) = channels {
+                assert!(is_identical(first.as_bytes(), b"#chat"));
+                assert!(channels.count() == 2);
+                let channels = channels.next_item();
+                assert!(matches!(channels, Some((_, _))));
+                if let Some((second, channels)) = channels {
+                    assert!(is_identical(second.as_bytes(), b"&local"));
+                    let channels = channels.next_item();
+                    assert!(matches!(channels, Some((_, _))));
+                    if let Some((third, channels)) = channels {
+                        assert!(is_identical(third.as_bytes(), b"#empty"));
+                        assert!(channels.is_finished());
+                        assert!(channels.next_item().is_none());
+                    }
+                }
+            }
+        }
+        let keys = params.comma_list(1);
+        assert!(keys.is_some());
+        if let Some(keys) = keys {
+            let key = keys.nth(1);
+            assert!(key.is_some());
+            if let Some(key) = key {assert!(is_identical(key.as_bytes(), b"key2"));}
+            assert!(keys.nth(2).is_none());
+        }
+        assert!(params.comma_list(2).is_none());
+        let list = CommaList::new(parsed(b"a,,b").extract_first());
+        assert!(list.count() == 3);
+        let empty = list.nth(1);
+        assert!(empty.is_some());
+        if let Some(empty) = empty {assert!(empty.as_bytes().is_empty());}
+        assert!(CommaList::new(parsed(b":").extract_first()).count() == 0);
+    }
+    #[test]
+    const fn splitting_space_list() {
+        let list = SpaceList::new(parsed(b":  dan bob   alice ").extract_first());
+        assert!(list.count() == 3 && !list.is_finished());
+        let list = list.next_item();
+        assert!(matches!(list, Some((_, _))));
+        if let Some((first, list)) = list {
+            assert!(is_identical(first.as_bytes(), b"dan"));
+            let list = list.next_item();
+            assert!(matches!(list, Some((_, _))));
+            if let Some((_, list)) = list {
+                let list = list.next_item();
+                assert!(matches!(list, Some((_, _))));
+                if let Some((third, list)) = list {
+                    assert!(is_identical(third.as_bytes(), b"alice"));
+                    assert!(list.is_finished() && list.next_item().is_none());
+                }
+            }
+        }
+        assert!(SpaceList::new(parsed(b":").extract_first()).count() == 0);
+    }
+    #[test]
+    const fn getting_raw_from() {
+        let params = parsed(b"#chat dan :you are kicked");
+        let raw = params.raw_from(0);
+        assert!(raw.is_some());
+        if let Some(raw) = raw {assert!(is_identical(raw, b"#chat dan :you are kicked"));}
+        let raw = params.raw_from(2);
+        assert!(raw.is_some());
+        if let Some(raw) = raw {assert!(is_identical(raw, b":you are kicked"));}
+        assert!(params.raw_from(3).is_none());
+        let params = parsed(b"0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 :sixteen");
+        let raw = params.raw_from(16);
+        assert!(raw.is_some());
+        if let Some(raw) = raw {assert!(is_identical(raw, b":sixteen"));}
+    }
+    #[test]
+    const fn writing_parameters() {
+        let writer = ParamsWriter::<64>::new().middle(b"#chat");
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {
+            let writer = writer.trailing(b"hello there");
+            assert!(writer.is_ok());
+            if let Ok(writer) = writer {
+                assert!(is_identical(writer.as_bytes(), b"#chat :hello there"));
+                assert!(writer.count() == 2);
+                assert!(matches!(writer.middle(b"dan"), Err(ParamsWriterError::TrailingAlreadyWritten)));
+            }
+        }
+        let writer = ParamsWriter::<64>::new().middle(b"#chat");
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {
+            let plain = writer.trailing(b"hello");
+            assert!(plain.is_ok());
+            if let Ok(plain) = plain {assert!(is_identical(plain.as_bytes(), b"#chat hello"));}
+            let empty = writer.trailing(b"");
+            assert!(empty.is_ok());
+            if let Ok(empty) = empty {assert!(is_identical(empty.as_bytes(), b"#chat :"));}
+            let colon = writer.trailing(b":)");
+            assert!(colon.is_ok());
+            if let Ok(colon) = colon {assert!(is_identical(colon.as_bytes(), b"#chat ::)"));}
+            assert!(matches!(writer.middle(b":dan"), Err(ParamsWriterError::ColonStartsMiddle)));
+            assert!(matches!(writer.middle(b"d n"), Err(ParamsWriterError::SpaceInMiddle)));
+            assert!(matches!(writer.middle(b""), Err(ParamsWriterError::EmptyMiddle)));
+            assert!(matches!(writer.trailing(b"a\r\n"), Err(ParamsWriterError::InvalidByte(b'\r'))));
+        }
+        assert!(matches!(ParamsWriter::<4>::new().middle(b"#chat"), Err(ParamsWriterError::BufferFull)));
+    }
+    #[test]
+    const fn finding_invalid_utf8() {
+        assert!(parsed(b"#chat :hello").utf8_invalid_index().is_none());
+        assert!(matches!(parsed(&[b'#', b'c', b' ', 159, 146, 150, b' ', b'x']).utf8_invalid_index(), Some(1)));
+        assert!(matches!(parsed(&[b'#', b'c', b' ', b':', b'a', 159, 146]).utf8_invalid_index(), Some(1)));
+    }
 }
@@ -0,0 +1,323 @@
+//! Methods for sending and receiving SASL authentication payloads.
+//!
+//! ## Purpose
+//!
+//! [SASL] authentication happens during capability negotiation using the `AUTHENTICATE` command.
+//! Payloads are base64 encoded and split into chunks of at most [`SASL_CHUNK_LEN`] bytes.
+//! A chunk shorter than [`SASL_CHUNK_LEN`] ends the payload. A payload that is empty or whose last chunk is exactly
+//! [`SASL_CHUNK_LEN`] bytes is ended with an additional `+`.
+//!
+//! A [`SaslPlain`] writes the `AUTHENTICATE` lines of the `PLAIN` mechanism in one call.
+//! The resulting account state is read from `RPL_LOGGEDIN` (`900`) and `RPL_LOGGEDOUT` (`901`) with
+//! [`AccountLogin`](crate::views::logged_in::AccountLogin).
+//!
+//! [SASL]: <https://ircv3.net/specs/extensions/sasl-3.1>
+
+use crate::base64;
+
+/// The maximum amount of bytes in a single `AUTHENTICATE` chunk.
+pub const SASL_CHUNK_LEN: usize = 400;
+
+/// A cursor for splitting a SASL payload into `AUTHENTICATE` chunks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SaslChunks<'a> {
+    remaining: &'a [u8],
+    finished: bool,
+}
+
+impl<'a> SaslChunks<'a> {
+    /// Generates [`SaslChunks`] from an already base64 encoded payload.
+    #[must_use]
+    pub const fn new(payload: &'a [u8]) -> Self {
+        Self{remaining: payload, finished: false}
+    }
+    /// Returns the next chunk to send as the parameter of an `AUTHENTICATE` and the advanced [`SaslChunks`].
+    ///
+    /// Returns `+` after a chunk of exactly [`SASL_CHUNK_LEN`] bytes or for an empty payload.
+    /// Returns `None` once all chunks have been returned.
+    #[must_use]
+    pub const fn next_chunk(self) -> Option<(&'a [u8], Self)> {
+        if self.finished {return None;}
+        if self.remaining.is_empty() {return Some((b"+", Self{remaining: self.remaining, finished: true}));}
+        if self.remaining.len() < SASL_CHUNK_LEN {
+            return Some((self.remaining, Self{remaining: &[], finished: true}));
+        }
+        let (chunk, remaining) = self.remaining.split_at(SASL_CHUNK_LEN);
+        Some((chunk, Self{remaining, finished: false}))
+    }
+}
+
+/// A buffer of `N` bytes reassembling incoming `AUTHENTICATE` chunks into a SASL payload.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SaslAssembler<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+    complete: bool,
+}
+
+impl<const N: usize> SaslAssembler<N> {
+    /// Generates an empty [`SaslAssembler`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self{buffer: [0; N], len: 0, complete: false}
+    }
+    /// Appends the parameter of an incoming `AUTHENTICATE`.
+    ///
+    /// A `+` or a chunk shorter than [`SASL_CHUNK_LEN`] completes the payload.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the payload is already complete, the chunk is longer than [`SASL_CHUNK_LEN`] or the
+    /// payload is longer than `N` bytes.
+    pub const fn push(mut self, chunk: &[u8]) -> Result<Self, SaslChunkError> {
+        if self.complete {return Err(SaslChunkError::AlreadyComplete);}
+        else if chunk.len() > SASL_CHUNK_LEN {return Err(SaslChunkError::ChunkTooLong);}
+        if let [b'+'] = chunk {
+            self.complete = true;
+            return Ok(self);
+        }
+        if self.len + chunk.len() > N {return Err(SaslChunkError::BufferFull);}
+        let mut index = 0;
+        while index < chunk.len() {
+            self.buffer[self.len] = chunk[index];
+            self.len += 1;
+            index += 1;
+        }
+        self.complete = chunk.len() < SASL_CHUNK_LEN;
+        Ok(self)
+    }
+    /// Check whether the payload is complete.
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.complete
+    }
+    /// Returns the reassembled payload as a slice of bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        let (output, _) = self.buffer.split_at(self.len);
+        output
+    }
+}
+
+impl<const N: usize> Default for SaslAssembler<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The possible types of errors when reassembling `AUTHENTICATE` chunks.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SaslChunkError {
+    /// The payload was already complete.
+    AlreadyComplete,
+    /// The chunk is longer than [`SASL_CHUNK_LEN`].
+    ChunkTooLong,
+    /// The buffer is too small.
+    BufferFull,
+}
+
+/// A builder for the response of the SASL `PLAIN` mechanism.
+///
+/// The response `authzid\0authcid\0password` is base64 encoded and written as complete `AUTHENTICATE` lines.
+/// It should be sent after `AUTHENTICATE PLAIN` has been answered by the IRC server with `AUTHENTICATE +`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SaslPlain<'a> {
+    authzid: &'a [u8],
+    authcid: &'a [u8],
+    password: &'a [u8],
+}
+
+impl<'a> SaslPlain<'a> {
+    /// Generates a [`SaslPlain`] for the account name `authcid` and its `password` with an empty `authzid`.
+    #[must_use]
+    pub const fn new(authcid: &'a [u8], password: &'a [u8]) -> Self {
+        Self{authzid: &[], authcid, password}
+    }
+    /// Sets the account name to act as if different to the `authcid`.
+    #[must_use]
+    pub const fn authzid(mut self, authzid: &'a [u8]) -> Self {
+        self.authzid = authzid;
+        self
+    }
+    /// Writes each `AUTHENTICATE` line ending in a carriage return and line feed into a buffer of `N` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the `authcid` or `password` is empty, any part contains a null or the lines are longer
+    /// than `N` bytes.
+    pub const fn write_lines<const N: usize>(&self) -> Result<AuthenticateLines<N>, SaslPlainError> {
+        if self.authcid.is_empty() || self.password.is_empty() {return Err(SaslPlainError::EmptyCredential);}
+        else if contains_null(self.authzid) || contains_null(self.authcid) || contains_null(self.password) {
+            return Err(SaslPlainError::NullInCredential);
+        }
+        let len = self.authzid.len() + self.authcid.len() + self.password.len() + 2;
+        let mut output = AuthenticateLines{buffer: [0; N], len: 0};
+        let mut start = 0;
+        loop {
+            let mut raw = [0; SASL_CHUNK_LEN / 4 * 3];
+            let mut amount = 0;
+            while amount < raw.len() && start + amount < len {
+                raw[amount] = self.byte_at(start + amount);
+                amount += 1;
+            }
+            let (raw, _) = raw.split_at(amount);
+            let Ok(encoded) = base64::encode::<SASL_CHUNK_LEN>(raw) else {return Err(SaslPlainError::BufferFull);};
+            let chunk = if encoded.is_empty() {b"+"} else {encoded.as_bytes()};
+            output = match output.push_line(chunk) {Ok(output) => output, Err(e) => return Err(e)};
+            start += amount;
+            if chunk.len() < SASL_CHUNK_LEN {break;}
+        }
+        Ok(output)
+    }
+    const fn byte_at(&self, index: usize) -> u8 {
+        let authcid_start = self.authzid.len() + 1;
+        let password_start = authcid_start + self.authcid.len() + 1;
+        if index < self.authzid.len() {self.authzid[index]}
+        else if index < authcid_start {0}
+        else if index < password_start - 1 {self.authcid[index - authcid_start]}
+        else if index < password_start {0}
+        else {self.password[index - password_start]}
+    }
+}
+
+/// A buffer of `N` bytes holding complete `AUTHENTICATE` lines.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AuthenticateLines<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> AuthenticateLines<N> {
+    const fn push_line(mut self, chunk: &[u8]) -> Result<Self, SaslPlainError> {
+        let prefix = b"AUTHENTICATE ";
+        if self.len + prefix.len() + chunk.len() + 2 > N {return Err(SaslPlainError::BufferFull);}
+        let mut part = 0;
+        while part < 3 {
+            let bytes: &[u8] = match part {0 => prefix, 1 => chunk, _ => b"\r\n"};
+            let mut index = 0;
+            while index < bytes.len() {
+                self.buffer[self.len] = bytes[index];
+                self.len += 1;
+                index += 1;
+            }
+            part += 1;
+        }
+        Ok(self)
+    }
+    /// Returns the lines as a slice of bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        let (output, _) = self.buffer.split_at(self.len);
+        output
+    }
+}
+
+/// The possible types of errors when writing a [`SaslPlain`] response.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SaslPlainError {
+    /// The `authcid` or `password` is empty.
+    EmptyCredential,
+    /// A null byte is used in one of the credentials.
+    NullInCredential,
+    /// The buffer is too small.
+    BufferFull,
+}
+
+const fn contains_null(input: &[u8]) -> bool {
+    let mut index = 0;
+    while index < input.len() {
+        if input[index] == 0 {return true;}
+        index += 1;
+    }
+    false
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{SASL_CHUNK_LEN, SaslAssembler, SaslChunkError, SaslChunks, SaslPlain, SaslPlainError};
+    use crate::is_identical;
+    #[test]
+    const fn chunking_payload() {
+        let payload = [b'A'; 2 * SASL_CHUNK_LEN + 5];
+        let chunks = SaslChunks::new(&payload).next_chunk();
+        assert!(matches!(chunks, Some((_, _))));
+        if let Some((first, chunks)) = chunks {
+            assert!(first.len() == SASL_CHUNK_LEN);
+            let chunks = chunks.next_chunk();
+            assert!(matches!(chunks, Some((_, _))));
+            if let Some((second, chunks)) = chunks {
+                assert!(second.len() == SASL_CHUNK_LEN);
+                let chunks = chunks.next_chunk();
+                assert!(matches!(chunks, Some((_, _))));
+                if let Some((third, chunks)) = chunks {
+                    assert!(third.len() == 5);
+                    assert!(chunks.next_chunk().is_none());
+                }
+            }
+        }
+        let payload = [b'A'; SASL_CHUNK_LEN];
+        let chunks = SaslChunks::new(&payload).next_chunk();
+        assert!(matches!(chunks, Some((_, _))));
+        if let Some((first, chunks)) = chunks {
+            assert!(first.len() == SASL_CHUNK_LEN);
+            let chunks = chunks.next_chunk();
+            assert!(matches!(chunks, Some((_, _))));
+            if let Some((plus, chunks)) = chunks {
+                assert!(is_identical(plus, b"+"));
+                assert!(chunks.next_chunk().is_none());
+            }
+        }
+        let chunks = SaslChunks::new(b"").next_chunk();
+        assert!(matches!(chunks, Some((_, _))));
+        if let Some((plus, chunks)) = chunks {assert!(is_identical(plus, b"+") && chunks.next_chunk().is_none());}
+    }
+    #[test]
+    const fn reassembling_payload() {
+        let chunk = [b'A'; SASL_CHUNK_LEN];
+        let assembler = SaslAssembler::<1024>::new().push(&chunk);
+        assert!(assembler.is_ok());
+        if let Ok(assembler) = assembler {
+            assert!(!assembler.is_complete());
+            let assembler = assembler.push(b"+");
+            assert!(assembler.is_ok());
+            if let Ok(assembler) = assembler {
+                assert!(assembler.is_complete() && assembler.as_bytes().len() == SASL_CHUNK_LEN);
+                assert!(matches!(assembler.push(b"AA"), Err(SaslChunkError::AlreadyComplete)));
+            }
+        }
+        let assembler = SaslAssembler::<1024>::new().push(&chunk);
+        assert!(assembler.is_ok());
+        if let Ok(assembler) = assembler {
+            let assembler = assembler.push(b"BB");
+            assert!(assembler.is_ok());
+            if let Ok(assembler) = assembler {
+                assert!(assembler.is_complete() && assembler.as_bytes().len() == SASL_CHUNK_LEN + 2);
+            }
+        }
+        let assembler = SaslAssembler::<0>::new().push(b"+");
+        assert!(assembler.is_ok());
+        if let Ok(assembler) = assembler {assert!(assembler.is_complete() && assembler.as_bytes().is_empty());}
+        assert!(matches!(SaslAssembler::<8>::new().push(&chunk), Err(SaslChunkError::BufferFull)));
+        let long = [b'A'; SASL_CHUNK_LEN + 1];
+        assert!(matches!(SaslAssembler::<1024>::new().push(&long), Err(SaslChunkError::ChunkTooLong)));
+    }
+    #[test]
+    const fn writing_plain() {
+        let lines = SaslPlain::new(b"dan", b"hunter2").write_lines::<64>();
+        assert!(lines.is_ok());
+        if let Ok(lines) = lines {assert!(is_identical(lines.as_bytes(), b"AUTHENTICATE AGRhbgBodW50ZXIy\r\n"));}
+        let lines = SaslPlain::new(b"dan", b"hunter2").authzid(b"bot").write_lines::<64>();
+        assert!(lines.is_ok());
+        if let Ok(lines) = lines {assert!(is_identical(lines.as_bytes(), b"AUTHENTICATE Ym90AGRhbgBodW50ZXIy\r\n"));}
+        let password = [b'p'; 295];
+        let lines = SaslPlain::new(b"dan", &password).write_lines::<512>();
+        assert!(lines.is_ok());
+        if let Ok(lines) = lines {
+            let (first, second) = lines.as_bytes().split_at(13 + SASL_CHUNK_LEN + 2);
+            assert!(first.len() == 415 && is_identical(second, b"AUTHENTICATE +\r\n"));
+        }
+        assert!(matches!(SaslPlain::new(b"", b"hunter2").write_lines::<64>(), Err(SaslPlainError::EmptyCredential)));
+        assert!(matches!(SaslPlain::new(b"d\0n", b"x").write_lines::<64>(), Err(SaslPlainError::NullInCredential)));
+        assert!(matches!(SaslPlain::new(b"dan", b"hunter2").write_lines::<16>(), Err(SaslPlainError::BufferFull)));
+    }
+}
@@ -14,7 +14,8 @@
 //!
 //! [IRC Message Protocol]: <https://modern.ircdocs.horse/#source>
 
-use crate::ContentType;
+use crate::{ContentType, ParseOptions, casemapping::IrcCaseMapping, is_identical, parse_ipv4_octets,
+    parse_ipv6_segments};
 
 /// The source of an [`IrcMsg`](crate::IrcMsg).
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -32,7 +33,18 @@ impl<'msg> Source<'msg> {
     /// as per the [IRC Client Protocol Specification].
     ///
     /// [IRC Client Protocol Specification]: <https://modern.ircdocs.horse/#source>
-    pub const fn parse(mut input: &'msg [u8]) -> Result<Self, SourceError> {
+    pub const fn parse(input: &'msg [u8]) -> Result<Self, SourceError> {
+        Self::parse_with_options(input, ParseOptions::new())
+    }
+    /// Generates a [`Source`] from a slice of bytes using the specified [`ParseOptions`].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the input is empty, doesn't start with `:` or contains an invalid character
+    /// as per the [IRC Client Protocol Specification].
+    ///
+    /// [IRC Client Protocol Specification]: <https://modern.ircdocs.horse/#source>
+    pub const fn parse_with_options(mut input: &'msg [u8], options: ParseOptions) -> Result<Self, SourceError> {
         if input.is_empty() {return Err(SourceError::EmptyInput);}
         let prefix = if input[0] == b':' {':'} else {return Err(SourceError::InvalidStartingPrefix(input[0]))};
         let (mut nick_end, mut user_end, mut probably_servername) = (0, 0, false);
@@ -53,8 +65,17 @@ impl<'msg> Source<'msg> {
             index += 1;
         }
         if let Some((_, rest)) = input.split_first() {input = rest;}
-        let from = if probably_servername {
-            Origin::Servername(Servername(ContentType::new(input)))
+        let ts6_origin = match options.ts6_detection {
+            Ts6Detection::Enabled if user_prefix.is_none() => ts6_origin(input),
+            _ => None,
+        };
+        let from = if let Some(origin) = ts6_origin {
+            origin
+        } else if probably_servername && user_prefix.is_none() {
+            match options.servername_detection {
+                ServernameDetection::Dotted => Origin::Servername(Servername(ContentType::new(input))),
+                ServernameDetection::Ambiguous => Origin::Ambiguous(AmbiguousName(ContentType::new(input))),
+            }
         } else if user_prefix.is_some() {
             let (nick, rest) = input.split_at(nick_end);
             input = rest;
@@ -77,9 +98,44 @@ impl<'msg> Source<'msg> {
     }
     /// Extract the [`Origin`] of [`Source`].
     #[must_use]
-    pub const fn origin(&self) -> Origin {
+    pub const fn origin(&self) -> Origin<'msg> {
         self.from
     }
+    /// Extract the nick from the [`Source`] if it could be a [`Nickname`].
+    ///
+    /// An [`AmbiguousName`] is treated as a [`Nickname`]. Returns `None` for any other [`Origin`].
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        match self.from {
+            Origin::Nickname(nickname) => Some(nickname.nick),
+            Origin::Ambiguous(name) => Some(name.0),
+            Origin::Servername(_) | Origin::Sid(_) | Origin::Uid(_) => None,
+        }
+    }
+    /// Check if both [`Source`]s are equivalent according to the [`IrcCaseMapping`].
+    ///
+    /// The nick, user and host of a [`Nickname`] are each compared using the casemapping as are the contents
+    /// of a [`Servername`] or an [`AmbiguousName`]. A [`Sid`] or [`Uid`] must be identical.
+    /// Sources with a different kind of [`Origin`] are never equivalent.
+    #[must_use]
+    pub const fn eq_casemapped(&self, other: &Source, casemapping: IrcCaseMapping) -> bool {
+        match (self.from, other.from) {
+            (Origin::Servername(first), Origin::Servername(second)) => {
+                casemapping.is_equivalent(first.0.as_bytes(), second.0.as_bytes())
+            },
+            (Origin::Ambiguous(first), Origin::Ambiguous(second)) => {
+                casemapping.is_equivalent(first.0.as_bytes(), second.0.as_bytes())
+            },
+            (Origin::Nickname(first), Origin::Nickname(second)) => {
+                casemapping.is_equivalent(first.nick.as_bytes(), second.nick.as_bytes())
+                && is_optional_equivalent(first.user, second.user, casemapping)
+                && is_optional_equivalent(first.host, second.host, casemapping)
+            },
+            (Origin::Sid(first), Origin::Sid(second)) => is_identical(first.0.as_bytes(), second.0.as_bytes()),
+            (Origin::Uid(first), Origin::Uid(second)) => is_identical(first.0.as_bytes(), second.0.as_bytes()),
+            _ => false,
+        }
+    }
 }
 
 impl core::fmt::Display for Source<'_> {
@@ -88,6 +144,15 @@ impl core::fmt::Display for Source<'_> {
     }
 }
 
+const fn is_optional_equivalent(first: Option<ContentType>, second: Option<ContentType>,
+casemapping: IrcCaseMapping) -> bool {
+    match (first, second) {
+        (Some(first), Some(second)) => casemapping.is_equivalent(first.as_bytes(), second.as_bytes()),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
 const fn is_invalid_byte(input: u8) -> bool {
     match input {
         // null ('\0'), linefeed ('\n'), carriage return ('\r'), space (' ')
@@ -96,6 +161,34 @@ const fn is_invalid_byte(input: u8) -> bool {
     }
 }
 
+/// How a [`Source`] containing a `.` but no user or host is classified.
+///
+/// Servernames always contain a `.` but some IRC networks also permit a `.` in nicknames.
+/// Without the user and host details it is impossible to tell the two apart from the [`Source`] alone.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ServernameDetection {
+    /// Treat the [`Source`] as an [`Origin::Servername`].
+    #[default]
+    Dotted,
+    /// Treat the [`Source`] as an [`Origin::Ambiguous`] leaving the caller to decide.
+    Ambiguous,
+}
+
+/// Whether a [`Source`] consisting of a [TS6] server or user ID is classified as such.
+///
+/// Server to server links identify servers and clients by IDs such as `042` and `042AAAAAB`. On a client
+/// connection a source of that shape is far more likely to be a nickname or servername so detection is opt-in.
+///
+/// [TS6]: <https://github.com/grawity/irc-docs/blob/master/server/ts6.txt>
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Ts6Detection {
+    /// Classify the [`Source`] without considering TS6 IDs.
+    #[default]
+    Disabled,
+    /// Treat a [`Source`] shaped like a TS6 ID as an [`Origin::Sid`] or [`Origin::Uid`].
+    Enabled,
+}
+
 /// Indicates where the [`IrcMsg`](crate::IrcMsg) was originally generated.
 ///
 /// An IRC client must never send an [`IrcMsg`](crate::IrcMsg) with [`Source`] but must
@@ -108,6 +201,22 @@ pub enum Origin<'msg> {
     Servername(Servername<'msg>),
     /// The nickname and possibly user and host details where the [`IrcMsg`](crate::IrcMsg) originated from.
     Nickname(Nickname<'msg>),
+    /// Either a servername or a nickname containing a `.` where the [`IrcMsg`](crate::IrcMsg) originated from.
+    ///
+    /// Only generated when parsing with [`ServernameDetection::Ambiguous`].
+    Ambiguous(AmbiguousName<'msg>),
+    /// The [TS6] server ID of the server where the [`IrcMsg`](crate::IrcMsg) originated from.
+    ///
+    /// Only generated when parsing with [`Ts6Detection::Enabled`].
+    ///
+    /// [TS6]: <https://github.com/grawity/irc-docs/blob/master/server/ts6.txt>
+    Sid(Sid<'msg>),
+    /// The [TS6] user ID of the client where the [`IrcMsg`](crate::IrcMsg) originated from.
+    ///
+    /// Only generated when parsing with [`Ts6Detection::Enabled`].
+    ///
+    /// [TS6]: <https://github.com/grawity/irc-docs/blob/master/server/ts6.txt>
+    Uid(Uid<'msg>),
 }
 
 impl Origin<'_> {
@@ -116,11 +225,13 @@ impl Origin<'_> {
     pub const fn is_valid_utf8(&self) -> bool {
         match self {
             Self::Servername(servername) => servername.0.is_valid_utf8(),
+            Self::Ambiguous(name) => name.0.is_valid_utf8(),
             Self::Nickname(nickname) => {
                 let valid_user = if let Some(user) = nickname.user {user.is_valid_utf8()} else {true};
                 let valid_host = if let Some(host) = nickname.host {host.is_valid_utf8()} else {true};
                 nickname.nick.is_valid_utf8() && valid_user && valid_host
             },
+            Self::Sid(_) | Self::Uid(_) => true,
         }
     }
 }
@@ -130,6 +241,9 @@ impl core::fmt::Display for Origin<'_> {
         match self {
             Origin::Servername(servername) => write!(f, "{servername}"),
             Origin::Nickname(nickname) => write!(f, "{nickname}"),
+            Origin::Ambiguous(name) => write!(f, "{name}"),
+            Origin::Sid(sid) => write!(f, "{sid}"),
+            Origin::Uid(uid) => write!(f, "{uid}"),
         }
     }
 }
@@ -138,10 +252,10 @@ impl core::fmt::Display for Origin<'_> {
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Servername<'msg>(ContentType<'msg>);
 
-impl Servername<'_> {
+impl<'msg> Servername<'msg> {
     /// Extract the server name from the [`Source`].
     #[must_use]
-    pub const fn content(&self) -> ContentType {
+    pub const fn content(&self) -> ContentType<'msg> {
         self.0
     }
 }
@@ -152,6 +266,114 @@ impl core::fmt::Display for Servername<'_> {
     }
 }
 
+/// A name containing a `.` that is either a servername or a nickname.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AmbiguousName<'msg>(ContentType<'msg>);
+
+impl<'msg> AmbiguousName<'msg> {
+    /// Extract the name from the [`Source`].
+    #[must_use]
+    pub const fn content(&self) -> ContentType<'msg> {
+        self.0
+    }
+    /// Treat the name as a [`Servername`].
+    #[must_use]
+    pub const fn to_servername(&self) -> Servername<'msg> {
+        Servername(self.0)
+    }
+    /// Treat the name as a [`Nickname`] without user or host details.
+    #[must_use]
+    pub const fn to_nickname(&self) -> Nickname<'msg> {
+        Nickname{nick: self.0, user_prefix: None, user: None, host_prefix: None, host: None}
+    }
+}
+
+impl core::fmt::Display for AmbiguousName<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The [TS6] server ID of the server where the [`IrcMsg`](crate::IrcMsg) originated from.
+///
+/// A server ID is a digit followed by two uppercase ascii letters or digits such as `042`.
+///
+/// [TS6]: <https://github.com/grawity/irc-docs/blob/master/server/ts6.txt>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Sid<'msg>(ContentType<'msg>);
+
+impl<'msg> Sid<'msg> {
+    /// Extract the server ID from the [`Source`].
+    #[must_use]
+    pub const fn content(&self) -> ContentType<'msg> {
+        self.0
+    }
+}
+
+impl core::fmt::Display for Sid<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// The [TS6] user ID of the client where the [`IrcMsg`](crate::IrcMsg) originated from.
+///
+/// A user ID is the [`Sid`] of the server the client is connected to followed by an uppercase ascii letter and
+/// five uppercase ascii letters or digits such as `042AAAAAB`.
+///
+/// [TS6]: <https://github.com/grawity/irc-docs/blob/master/server/ts6.txt>
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Uid<'msg>(ContentType<'msg>, Sid<'msg>);
+
+impl<'msg> Uid<'msg> {
+    /// Extract the user ID from the [`Source`].
+    #[must_use]
+    pub const fn content(&self) -> ContentType<'msg> {
+        self.0
+    }
+    /// Extract the [`Sid`] of the server the client is connected to.
+    #[must_use]
+    pub const fn sid(&self) -> Sid<'msg> {
+        self.1
+    }
+}
+
+impl core::fmt::Display for Uid<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+const fn ts6_origin(input: &[u8]) -> Option<Origin<'_>> {
+    if is_ts6_sid(input) {
+        Some(Origin::Sid(Sid(ContentType::new(input))))
+    } else if is_ts6_uid(input) {
+        let (sid, _) = input.split_at(3);
+        Some(Origin::Uid(Uid(ContentType::new(input), Sid(ContentType::new(sid)))))
+    } else {
+        None
+    }
+}
+
+const fn is_ts6_sid(input: &[u8]) -> bool {
+    input.len() == 3 && input[0].is_ascii_digit() && is_ts6_id_byte(input[1]) && is_ts6_id_byte(input[2])
+}
+
+const fn is_ts6_uid(input: &[u8]) -> bool {
+    if input.len() != 9 || !input[3].is_ascii_uppercase() {return false;}
+    let (sid, id) = input.split_at(3);
+    let mut index = 1;
+    while index < id.len() {
+        if !is_ts6_id_byte(id[index]) {return false;}
+        index += 1;
+    }
+    is_ts6_sid(sid)
+}
+
+const fn is_ts6_id_byte(input: u8) -> bool {
+    input.is_ascii_uppercase() || input.is_ascii_digit()
+}
+
 /// The nickname and possibly user and host details where the [`IrcMsg`](crate::IrcMsg) originated from.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Nickname<'msg> {
@@ -162,10 +384,47 @@ pub struct Nickname<'msg> {
     host: Option<ContentType<'msg>>,
 }
 
-impl Nickname<'_> {
+impl<'msg> Nickname<'msg> {
+    /// Generates a [`Nickname`] from a `nick!user@host` mask found in the parameters of an
+    /// [`IrcMsg`](crate::IrcMsg).
+    ///
+    /// The user and host are optional. The mask is split at the first `!` and the first `@` following it.
+    /// No validation of the contents is performed.
+    #[must_use]
+    pub const fn from_mask(input: &'msg [u8]) -> Self {
+        let (mut bang, mut at) = (None, None);
+        let mut index = 0;
+        while index < input.len() {
+            if input[index] == b'!' && bang.is_none() && at.is_none() {bang = Some(index);}
+            else if input[index] == b'@' && at.is_none() {at = Some(index);}
+            index += 1;
+        }
+        let nick_end = match (bang, at) {
+            (Some(end), _) | (None, Some(end)) => end,
+            (None, None) => input.len(),
+        };
+        let (nick, rest) = input.split_at(nick_end);
+        let (user_prefix, user, rest) = match bang {
+            Some(bang) => {
+                let (_, rest) = rest.split_at(1);
+                let user_len = match at {Some(at) => at - bang - 1, None => rest.len()};
+                let (user, rest) = rest.split_at(user_len);
+                (Some('!'), Some(ContentType::new(user)), rest)
+            },
+            None => (None, None, rest),
+        };
+        let (host_prefix, host) = match at {
+            Some(_) => {
+                let (_, host) = rest.split_at(1);
+                (Some('@'), Some(ContentType::new(host)))
+            },
+            None => (None, None),
+        };
+        Nickname{nick: ContentType::new(nick), user_prefix, user, host_prefix, host}
+    }
     /// Extract the nick from the [`Source`].
     #[must_use]
-    pub const fn nick(&self) -> ContentType {
+    pub const fn nick(&self) -> ContentType<'msg> {
         self.nick
     }
     /// Extract the user prefix character `!` from the [`Source`] if it exists.
@@ -175,7 +434,7 @@ impl Nickname<'_> {
     }
     /// Extract the user from the [`Source`] if it exists.
     #[must_use]
-    pub const fn user(&self) -> Option<ContentType> {
+    pub const fn user(&self) -> Option<ContentType<'msg>> {
         self.user
     }
     /// Extract the host prefix character `@` from the [`Source`] if it exists.
@@ -185,22 +444,66 @@ impl Nickname<'_> {
     }
     /// Extract the host from the [`Source`] if it exists.
     #[must_use]
-    pub const fn host(&self) -> Option<ContentType> {
+    pub const fn host(&self) -> Option<ContentType<'msg>> {
         self.host
     }
+    /// Determine the [`HostKind`] of the host from the [`Source`] if it exists.
+    #[must_use]
+    pub const fn host_kind(&self) -> Option<HostKind> {
+        match self.host {
+            Some(host) => Some(HostKind::detect(host.as_bytes())),
+            None => None,
+        }
+    }
 }
 
 impl core::fmt::Display for Nickname<'_> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        if self.user_prefix.is_some() && self.host_prefix.is_some() {
-            write!(f, "{}{}{}{}{}", self.nick, self.user_prefix.unwrap(), self.user.as_ref().unwrap(),
-                self.host_prefix.unwrap(), self.host.as_ref().unwrap())
+        if let (Some(user_prefix), Some(user), Some(host_prefix), Some(host)) =
+            (self.user_prefix, self.user, self.host_prefix, self.host) {
+            write!(f, "{}{}{}{}{}", self.nick, user_prefix, user, host_prefix, host)
         } else {
             write!(f, "{}", self.nick)
         }
     }
 }
 
+/// The kind of host found in the [`Nickname`] of a [`Source`].
+///
+/// IRC servers may show the real address of a client, a resolved hostname or a cloak hiding either of them.
+/// Cloaks are only detected when they contain bytes that are not permitted in a hostname such as `/`.
+/// Cloaks designed to look like a hostname are indistinguishable from one and are treated as a [`HostKind::Hostname`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HostKind {
+    /// A raw IPv4 address literal such as `192.0.2.1`.
+    Ipv4,
+    /// A raw IPv6 address literal such as `2001:db8::1`.
+    Ipv6,
+    /// A hostname such as `host.example.com`.
+    Hostname,
+    /// A cloak such as `user/dan`.
+    Cloak,
+}
+
+impl HostKind {
+    const fn detect(input: &[u8]) -> Self {
+        if parse_ipv4_octets(input).is_some() {Self::Ipv4}
+        else if parse_ipv6_segments(input).is_some() {Self::Ipv6}
+        else if is_hostname(input) {Self::Hostname}
+        else {Self::Cloak}
+    }
+}
+
+const fn is_hostname(input: &[u8]) -> bool {
+    if input.is_empty() {return false;}
+    let mut index = 0;
+    while index < input.len() {
+        if !input[index].is_ascii_alphanumeric() && input[index] != b'-' && input[index] != b'.' {return false;}
+        index += 1;
+    }
+    true
+}
+
 /// The possible types of errors when parsing [`Source`].
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum SourceError {
@@ -214,8 +517,10 @@ pub enum SourceError {
 
 #[cfg(test)]
 mod const_tests {
-    use crate::{const_tests::is_nick, ContentType, is_identical};
-    use super::{Origin, Nickname, Servername, Source, is_invalid_byte};
+    use crate::{const_tests::is_nick, ContentType, ParseOptions, is_identical};
+    use crate::casemapping::IrcCaseMapping;
+    use super::{HostKind, Origin, Nickname, Servername, ServernameDetection, Source, Ts6Detection, is_invalid_byte,
+        is_ts6_sid, is_ts6_uid};
     const fn is_same_content(first: ContentType, second: &str) -> bool {
         match first {
             ContentType::StringSlice(s) => is_identical(s.as_bytes(), second.as_bytes()),
@@ -272,4 +577,122 @@ mod const_tests {
             if let Origin::Servername(s) = src.from {assert!(is_same_content(s.0, "example.com"));}
         }
     }
+    #[test]
+    const fn detecting_host_kind() {
+        assert!(matches!(HostKind::detect(b"192.0.2.1"), HostKind::Ipv4));
+        assert!(matches!(HostKind::detect(b"2001:db8::1"), HostKind::Ipv6));
+        assert!(matches!(HostKind::detect(b"0::1"), HostKind::Ipv6));
+        assert!(matches!(HostKind::detect(b"2001:db8:0:0:0:0:0:1"), HostKind::Ipv6));
+        assert!(matches!(HostKind::detect(b"0::ffff:192.0.2.1"), HostKind::Ipv6));
+        assert!(matches!(HostKind::detect(b"host.example.com"), HostKind::Hostname));
+        assert!(matches!(HostKind::detect(b"192.0.2.256"), HostKind::Hostname));
+        assert!(matches!(HostKind::detect(b"user/dan"), HostKind::Cloak));
+        assert!(matches!(HostKind::detect(b"2001::db8::1"), HostKind::Cloak));
+        let src = Source::parse(b":dan!d@192.0.2.1");
+        assert!(src.is_ok());
+        if let Ok(src) = src {
+            if let Origin::Nickname(n) = src.from {assert!(matches!(n.host_kind(), Some(HostKind::Ipv4)));}
+        }
+        let src = Source::parse(b":dan");
+        assert!(src.is_ok());
+        if let Ok(src) = src {
+            if let Origin::Nickname(n) = src.from {assert!(n.host_kind().is_none());}
+        }
+    }
+    #[test]
+    const fn parsing_ts6_source() {
+        assert!(is_ts6_sid(b"042"));
+        assert!(is_ts6_sid(b"4XY"));
+        assert!(!is_ts6_sid(b"X42"));
+        assert!(!is_ts6_sid(b"4xy"));
+        assert!(is_ts6_uid(b"042AAAAAB"));
+        assert!(!is_ts6_uid(b"0421AAAAB"));
+        assert!(!is_ts6_uid(b"042AAAAA"));
+        let ts6 = ParseOptions::new().ts6_detection(Ts6Detection::Enabled);
+        let src = Source::parse_with_options(b":042AAAAAB", ts6);
+        assert!(src.is_ok());
+        if let Ok(src) = src {
+            assert!(matches!(src.from, Origin::Uid(_)));
+            if let Origin::Uid(uid) = src.from {
+                assert!(is_identical(uid.content().as_bytes(), b"042AAAAAB"));
+                assert!(is_identical(uid.sid().content().as_bytes(), b"042"));
+            }
+        }
+        let src = Source::parse_with_options(b":042", ts6);
+        assert!(src.is_ok());
+        if let Ok(src) = src {
+            assert!(matches!(src.from, Origin::Sid(_)));
+            if let Origin::Sid(sid) = src.from {assert!(is_identical(sid.content().as_bytes(), b"042"));}
+        }
+        let src = Source::parse(b":042");
+        assert!(src.is_ok());
+        if let Ok(src) = src {assert!(is_nick(src.from));}
+        let src = Source::parse(b":042AAAAAB");
+        assert!(src.is_ok());
+        if let Ok(src) = src {assert!(is_nick(src.from));}
+        let src = Source::parse_with_options(b":042AAAAAB!d@localhost", ts6);
+        assert!(src.is_ok());
+        if let Ok(src) = src {assert!(is_nick(src.from));}
+    }
+    #[test]
+    const fn detecting_servername() {
+        let src = Source::parse(b":dan.away!d@localhost");
+        assert!(src.is_ok());
+        if let Ok(src) = src {
+            assert!(is_nick(src.from));
+            if let Origin::Nickname(n) = src.from {assert!(is_same_content(n.nick, "dan.away"));}
+        }
+        let ambiguous = ParseOptions::new().servername_detection(ServernameDetection::Ambiguous);
+        let src = Source::parse_with_options(b":irc.example.com", ambiguous);
+        assert!(src.is_ok());
+        if let Ok(src) = src {
+            assert!(matches!(src.from, Origin::Ambiguous(_)));
+            if let Origin::Ambiguous(name) = src.from {
+                assert!(is_same_content(name.to_servername().content(), "irc.example.com"));
+                assert!(is_same_content(name.to_nickname().nick(), "irc.example.com"));
+            }
+        }
+        let src = Source::parse_with_options(b":dan", ambiguous);
+        assert!(src.is_ok());
+        if let Ok(src) = src {assert!(is_nick(src.from));}
+    }
+    #[test]
+    const fn comparing_casemapped() {
+        let casemapping = IrcCaseMapping::Rfc1459;
+        let first = Source::parse(b":[Dan]!D@LocalHost");
+        let second = Source::parse(b":{dan}!d@localhost");
+        let third = Source::parse(b":{dan}");
+        let fourth = Source::parse(b":IRC.example.com");
+        let fifth = Source::parse(b":irc.EXAMPLE.com");
+        assert!(first.is_ok() && second.is_ok() && third.is_ok() && fourth.is_ok() && fifth.is_ok());
+        if let (Ok(first), Ok(second), Ok(third), Ok(fourth), Ok(fifth)) = (first, second, third, fourth, fifth) {
+            assert!(first.eq_casemapped(&second, casemapping));
+            assert!(!first.eq_casemapped(&second, IrcCaseMapping::Ascii));
+            assert!(!first.eq_casemapped(&third, casemapping));
+            assert!(fourth.eq_casemapped(&fifth, casemapping));
+            assert!(!third.eq_casemapped(&fourth, casemapping));
+        }
+    }
+    #[test]
+    const fn nickname_from_mask() {
+        let nickname = Nickname::from_mask(b"dan!d@localhost");
+        let pair = (nickname.user(), nickname.host());
+        assert!(matches!(pair, (Some(_), Some(_))));
+        if let (Some(user), Some(host)) = pair {
+            assert!(is_identical(nickname.nick().as_bytes(), b"dan") && is_identical(user.as_bytes(), b"d"));
+            assert!(is_identical(host.as_bytes(), b"localhost"));
+        }
+        assert!(matches!((nickname.user_prefix(), nickname.host_prefix()), (Some('!'), Some('@'))));
+        let nickname = Nickname::from_mask(b"dan");
+        assert!(is_identical(nickname.nick().as_bytes(), b"dan"));
+        assert!(nickname.user().is_none() && nickname.host().is_none());
+        let nickname = Nickname::from_mask(b"dan@localhost");
+        let host = nickname.host();
+        assert!(host.is_some());
+        if let Some(host) = host {assert!(nickname.user().is_none() && is_identical(host.as_bytes(), b"localhost"));}
+        let nickname = Nickname::from_mask(b"dan!d");
+        let user = nickname.user();
+        assert!(user.is_some());
+        if let Some(user) = user {assert!(is_identical(user.as_bytes(), b"d") && nickname.host().is_none());}
+    }
 }
@@ -74,12 +74,12 @@ impl<'msg> Tags<'msg> {
     ///
     /// This includes the leading `@` but excludes the trailing space.
     #[must_use]
-    pub const fn content(&self) -> &str {
+    pub const fn content(&self) -> &'msg str {
         self.content
     }
     /// Returns the first [`Tag`] from all the [`Tags`].
     #[must_use]
-    pub const fn extract_first(&self) -> Tag {
+    pub const fn extract_first(&self) -> Tag<'msg> {
         match self.extract_specific(0) {
             Some(tag) => tag,
             None => unreachable!(),
@@ -87,7 +87,7 @@ impl<'msg> Tags<'msg> {
     }
     /// Returns the last [`Tag`] from all the [`Tags`].
     #[must_use]
-    pub const fn extract_last(&self) -> Tag {
+    pub const fn extract_last(&self) -> Tag<'msg> {
         match self.extract_specific(self.amount-1) {
             Some(tag) => tag,
             None => unreachable!(),
@@ -97,67 +97,59 @@ impl<'msg> Tags<'msg> {
     ///
     /// Index starts at 0. If out of bounds it returns `None`.
     #[must_use]
-    pub const fn extract_specific(&self, target_index: usize) -> Option<Tag> {
-        if target_index > self.amount {return None;}
+    pub const fn extract_specific(&self, target_index: usize) -> Option<Tag<'msg>> {
+        if target_index >= self.amount {return None;}
         let bytes = self.content.as_bytes();
         let mut current_tag = 0;
-        let mut current_tag_start = 1;
-        let mut tag = Tag {client_prefix: false, vendor: None, key_name: "", escaped_value: None};
-        let mut copy = bytes;
-        let mut offset = 0;
-        let mut key_name_start = 0;
-        let mut index = 0;
+        let mut start = 1;
+        let mut index = 1;
         while index < bytes.len() {
-            if current_tag == target_index {
-                if bytes[index] == b'+' {
-                    tag.client_prefix = true;
-                } else if bytes[index] == b'/' && tag.vendor.is_none() {
-                    if tag.client_prefix {
-                        (_, copy) = bytes.split_at(current_tag_start + 1);
-                        offset = current_tag_start + 1;
-                    } else {
-                        (_, copy) = bytes.split_at(current_tag_start);
-                        offset = current_tag_start;
-                    }
-                    (copy, _) = copy.split_at((index) - offset);
-                    if let Ok(vendor) = core::str::from_utf8(copy) {tag.vendor = Some(vendor);}
-                    (_, copy) = bytes.split_at(index + 1);
-                    offset = index + 1;
-                    key_name_start = offset;
-                } else if bytes[index] == b'=' {
-                    if tag.vendor.is_some() {
-                        (copy, _) = copy.split_at(index - offset);
-                        if let Ok(key_name) = core::str::from_utf8(copy) {tag.key_name = key_name;}
-                    } else {
-                        (_, copy) = bytes.split_at(current_tag_start);
-                        (copy, _) = copy.split_at(index - 1);
-                        if let Ok(key_name) = core::str::from_utf8(copy) {tag.key_name = key_name;}
-                    }
-                    if index + 1 == bytes.len() - 1 {break;}
-                    offset = index + 1; // start of escaped_value
-                } else if bytes[index] == b';' || index == bytes.len() - 1 {
-                    if !tag.key_name.is_empty() {
-                        (_, copy) = bytes.split_at(offset);
-                        if index != bytes.len() - 1 {(copy, _) = copy.split_at(index - offset);}
-                        if let Ok(ev) = core::str::from_utf8(copy) {tag.escaped_value = Some(ev);}
-                    } else if tag.vendor.is_some() {
-                        (_, copy) = bytes.split_at(key_name_start);
-                        if index != bytes.len() - 1 {(copy, _) = copy.split_at(index - key_name_start);}
-                        if let Ok(key_name) = core::str::from_utf8(copy) {tag.key_name = key_name;}
-                    } else {
-                        (_, copy) = bytes.split_at(current_tag_start);
-                        if index != bytes.len() - 1 {(copy, _) = copy.split_at(index - current_tag_start);}
-                        if let Ok(key_name) = core::str::from_utf8(copy) {tag.key_name = key_name;}
-                    }
-                    break;
-                }
-            } else if bytes[index] == b';' {
+            if bytes[index] == b';' {
+                if current_tag == target_index {break;}
                 current_tag += 1;
-                current_tag_start = index + 1;
+                start = index + 1;
             }
             index += 1;
         }
-        Some(tag)
+        let (_, rest) = bytes.split_at(start);
+        let (raw, _) = rest.split_at(index - start);
+        let mut key_end = 0;
+        while key_end < raw.len() && raw[key_end] != b'=' {key_end += 1;}
+        let (mut key, value) = raw.split_at(key_end);
+        let client_prefix = if let [b'+', remaining @ ..] = key {key = remaining; true} else {false};
+        let mut vendor_end = 0;
+        while vendor_end < key.len() && key[vendor_end] != b'/' {vendor_end += 1;}
+        let vendor = if vendor_end < key.len() {
+            let (vendor, remaining) = key.split_at(vendor_end);
+            let (_, key_name) = remaining.split_at(1);
+            key = key_name;
+            Some(vendor)
+        } else {
+            None
+        };
+        let escaped_value = match value {
+            [] | [b'='] => None,
+            [_, escaped_value @ ..] => Some(escaped_value),
+        };
+        let key_name = match as_str(Some(key)) {
+            Some(key_name) => key_name,
+            None => "",
+        };
+        Some(Tag{client_prefix, vendor: as_str(vendor), key_name, escaped_value: as_str(escaped_value)})
+    }
+    /// Returns the first [`Tag`] whose full key matches the `key`.
+    ///
+    /// The full key includes the client only prefix and the `vendor` such as `+example.com/foo`.
+    #[must_use]
+    pub const fn find(&self, key: &[u8]) -> Option<Tag<'msg>> {
+        let mut index = 0;
+        while index < self.amount {
+            if let Some(tag) = self.extract_specific(index) {
+                if tag.is_key(key) {return Some(tag);}
+            }
+            index += 1;
+        }
+        None
     }
 }
 
@@ -176,30 +168,49 @@ pub struct Tag<'msg> {
     escaped_value: Option<&'msg str>,
 }
 
-impl Tag<'_> {
+impl<'msg> Tag<'msg> {
     /// Check if the [`Tag`] is a client only tag.
     ///
-    /// Vendors can have a client only prefix denoted by `+`.
-    /// In the absence of a `vendor` this will return `false`.
+    /// Client only tags have their key prefixed by `+`.
     #[must_use]
     pub const fn is_client_only_tag(&self) -> bool {
         self.client_prefix
     }
     /// Return the `vendor` of a [`Tag`] if it exists.
     #[must_use]
-    pub const fn vendor(&self) -> Option<&str> {
+    pub const fn vendor(&self) -> Option<&'msg str> {
         self.vendor
     }
     /// Return the `key_name` of a [`Tag`].
     #[must_use]
-    pub const fn key_name(&self) -> &str {
+    pub const fn key_name(&self) -> &'msg str {
         self.key_name
     }
     /// Return the `escaped_value` of a [`Tag`] if it exists.
     #[must_use]
-    pub const fn escaped_value(&self) -> Option<&str> {
+    pub const fn escaped_value(&self) -> Option<&'msg str> {
         self.escaped_value
     }
+    /// Check if the full key of a [`Tag`] matches the `key`.
+    ///
+    /// The full key includes the client only prefix and the `vendor` such as `+example.com/foo`.
+    #[must_use]
+    pub const fn is_key(&self, key: &[u8]) -> bool {
+        let mut rest = key;
+        if self.client_prefix {
+            let [b'+', remaining @ ..] = rest else {return false;};
+            rest = remaining;
+        }
+        if let Some(vendor) = self.vendor {
+            let vendor = vendor.as_bytes();
+            if rest.len() <= vendor.len() || rest[vendor.len()] != b'/' {return false;}
+            let (start, remaining) = rest.split_at(vendor.len());
+            if !crate::is_identical(start, vendor) {return false;}
+            let (_, remaining) = remaining.split_at(1);
+            rest = remaining;
+        }
+        crate::is_identical(rest, self.key_name.as_bytes())
+    }
 }
 
 impl core::fmt::Display for Tag<'_> {
@@ -211,6 +222,16 @@ impl core::fmt::Display for Tag<'_> {
     }
 }
 
+const fn as_str(input: Option<&[u8]>) -> Option<&str> {
+    match input {
+        Some(bytes) => match core::str::from_utf8(bytes) {
+            Ok(output) => Some(output),
+            Err(_) => None,
+        },
+        None => None,
+    }
+}
+
 const fn is_invalid_escaped_value_byte(input: u8) -> bool {
     match input {
         // null ('\0'), linefeed ('\n'), carriage return ('\r'), space (' ')
@@ -334,4 +355,37 @@ mod const_tests {
             }
         }
     }
+    #[test]
+    const fn find_by_key() {
+        let tags = Tags::parse(b"@aaa=bbb;+typing=active;time=1+2;+example.com/ddd=eee;batch");
+        assert!(tags.is_ok());
+        if let Ok(tags) = tags {
+            let typing = tags.extract_specific(1);
+            assert!(typing.is_some());
+            if let Some(typing) = typing {
+                assert!(typing.is_client_only_tag() && is_identical(typing.key_name().as_bytes(), b"typing"));
+                assert!(typing.is_key(b"+typing") && !typing.is_key(b"typing"));
+            }
+            let time = tags.find(b"time");
+            assert!(time.is_some());
+            if let Some(time) = time {
+                assert!(!time.is_client_only_tag());
+                let value = time.escaped_value();
+                assert!(value.is_some());
+                if let Some(value) = value {assert!(is_identical(value.as_bytes(), b"1+2"));}
+            }
+            let vendored = tags.find(b"+example.com/ddd");
+            assert!(vendored.is_some());
+            if let Some(vendored) = vendored {
+                let value = vendored.escaped_value();
+                assert!(value.is_some());
+                if let Some(value) = value {assert!(is_identical(value.as_bytes(), b"eee"));}
+            }
+            assert!(tags.find(b"example.com/ddd").is_none() && tags.find(b"ddd").is_none());
+            let batch = tags.find(b"batch");
+            assert!(batch.is_some());
+            if let Some(batch) = batch {assert!(batch.escaped_value().is_none());}
+            assert!(tags.extract_specific(5).is_none());
+        }
+    }
 }
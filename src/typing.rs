@@ -0,0 +1,112 @@
+//! Methods for reading and sending typing notifications.
+//!
+//! ## Purpose
+//!
+//! The [typing] client only tag `+typing` lets an IRC client notify others that its user is typing a message.
+//! It is usually sent with a `TAGMSG` to the channel or user being typed to but may also accompany a `PRIVMSG`.
+//! A [`TypingState`] is read from a received [`IrcMsg`] and [`typing_notification`] writes the `TAGMSG` to send.
+//!
+//! [typing]: <https://ircv3.net/specs/client-tags/typing>
+
+use crate::{IrcMsg, IrcMsgWriter, IrcMsgWriterError, command::CommandKind};
+
+/// The state of a typing notification.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TypingState {
+    /// The user is typing a message.
+    Active,
+    /// The user has typed part of a message but stopped typing.
+    Paused,
+    /// The user is no longer typing and cleared any typed message.
+    Done,
+}
+
+impl TypingState {
+    /// Generates a [`TypingState`] from the value of a `+typing` tag.
+    #[must_use]
+    pub const fn from_bytes(input: &[u8]) -> Option<Self> {
+        match input {
+            b"active" => Some(Self::Active),
+            b"paused" => Some(Self::Paused),
+            b"done" => Some(Self::Done),
+            _ => None,
+        }
+    }
+    /// Returns the [`TypingState`] of the `+typing` tag of a `TAGMSG` or `PRIVMSG` [`IrcMsg`].
+    ///
+    /// Returns `None` for any other command or if the tag is missing or has an unknown value.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Tagmsg | CommandKind::Privmsg)) {return None;}
+        let Some(tags) = msg.tags() else {return None;};
+        let Some(tag) = tags.find(b"+typing") else {return None;};
+        match tag.escaped_value() {
+            Some(value) => Self::from_bytes(value.as_bytes()),
+            None => None,
+        }
+    }
+    /// Returns the value of the `+typing` tag.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &'static [u8] {
+        match self {
+            Self::Active => b"active",
+            Self::Paused => b"paused",
+            Self::Done => b"done",
+        }
+    }
+}
+
+/// Writes a `TAGMSG` notifying the `target` of the [`TypingState`].
+///
+/// # Errors
+///
+/// Will return `Err` if the `target` is not a valid middle parameter or the message is longer than `N` bytes.
+pub const fn typing_notification<const N: usize>(target: &[u8], state: TypingState)
+-> Result<IrcMsgWriter<N>, IrcMsgWriterError> {
+    let writer = match IrcMsgWriter::new().tag(b"+typing", Some(state.as_bytes())) {
+        Ok(writer) => writer,
+        Err(e) => return Err(e),
+    };
+    let writer = match writer.command(b"TAGMSG") {
+        Ok(writer) => writer,
+        Err(e) => return Err(e),
+    };
+    writer.middle(target)
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{TypingState, typing_notification};
+    use crate::{IrcMsg, IrcMsgWriterError, is_identical};
+    #[test]
+    const fn reading_typing() {
+        let msg = IrcMsg::parse(b"@+typing=active;msgid=1 :dan!d@localhost TAGMSG #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(TypingState::from_msg(&msg), Some(TypingState::Active)));}
+        let msg = IrcMsg::parse(b"@time=x;+typing=done :dan!d@localhost PRIVMSG bob :sent");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(TypingState::from_msg(&msg), Some(TypingState::Done)));}
+        let msg = IrcMsg::parse(b"@+typing=paused :dan!d@localhost NOTICE #chan :x");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(TypingState::from_msg(&msg).is_none());}
+        let msg = IrcMsg::parse(b"@+typing=bored :dan!d@localhost TAGMSG #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(TypingState::from_msg(&msg).is_none());}
+        let msg = IrcMsg::parse(b"@typing=active :dan!d@localhost TAGMSG #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(TypingState::from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn writing_typing() {
+        let writer = typing_notification::<64>(b"#chan", TypingState::Paused);
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {
+            assert!(is_identical(writer.as_bytes(), b"@+typing=paused TAGMSG #chan"));
+            let msg = IrcMsg::parse(writer.as_bytes());
+            assert!(msg.is_ok());
+            if let Ok(msg) = msg {assert!(matches!(TypingState::from_msg(&msg), Some(TypingState::Paused)));}
+        }
+        assert!(matches!(typing_notification::<16>(b"#chan", TypingState::Done), Err(IrcMsgWriterError::BufferFull)));
+        assert!(typing_notification::<64>(b":bad", TypingState::Done).is_err());
+    }
+}
@@ -0,0 +1,82 @@
+//! Methods for validating names before using them in an [`IrcMsg`](crate::IrcMsg).
+//!
+//! ## Purpose
+//!
+//! IRC clients and bots choose their own nickname when registering a connection or sending a `NICK` command.
+//! The [specification] restricts which bytes a nickname may contain and which bytes it may start with.
+//! IRC servers advertise the maximum nickname length in the `NICKLEN` [`ISupportToken`](crate::isupport::ISupportToken).
+//! Validating a nickname before sending it avoids a round trip to the server ending in an
+//! `ERR_ERRONEUSNICKNAME` (`432`) numeric [`IrcMsg`](crate::IrcMsg).
+//!
+//! [specification]: <https://modern.ircdocs.horse/#clients>
+
+/// Checks whether the `input` is a valid nickname no longer than `max_len` bytes.
+///
+/// The `max_len` should be the value of the `NICKLEN` [`ISupportToken`](crate::isupport::ISupportToken)
+/// advertised by the IRC server.
+///
+/// # Errors
+///
+/// Will return `Err` if the input is empty, longer than `max_len`, starts with `$`, `:`, a default channel type
+/// (`#`, `&`) or a default channel membership prefix (`~`, `@`, `%`, `+`) or contains a space, `,`, `*`, `?`,
+/// `!`, `@`, null, carriage return or line feed as per the [specification].
+///
+/// [specification]: <https://modern.ircdocs.horse/#clients>
+pub const fn validate_nick(input: &[u8], max_len: usize) -> Result<(), NickError> {
+    if input.is_empty() {return Err(NickError::EmptyInput);}
+    else if input.len() > max_len {return Err(NickError::NickLengthExceededBy(input.len() - max_len));}
+    else if is_invalid_starting_nick_byte(input[0]) {return Err(NickError::InvalidStartingByte(input[0]));}
+    let mut index = 0;
+    while index < input.len() {
+        if is_invalid_nick_byte(input[index]) {return Err(NickError::InvalidByte(input[index]));}
+        index += 1;
+    }
+    Ok(())
+}
+
+const fn is_invalid_starting_nick_byte(input: u8) -> bool {
+    matches!(input, b'$' | b':' | b'#' | b'&' | b'~' | b'@' | b'%' | b'+')
+}
+
+const fn is_invalid_nick_byte(input: u8) -> bool {
+    // null ('\0'), linefeed ('\n'), carriage return ('\r'), space (' ')
+    matches!(input, 0 | 10 | 13 | 32 | b',' | b'*' | b'?' | b'!' | b'@')
+}
+
+/// The possible types of errors when validating a nickname.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NickError {
+    /// The byte slice input is empty.
+    EmptyInput,
+    /// The amount of bytes greater than the maximum permitted for a nickname.
+    NickLengthExceededBy(usize),
+    /// Use of an invalid byte at the start of the nickname.
+    InvalidStartingByte(u8),
+    /// Use of an invalid byte in the nickname.
+    InvalidByte(u8),
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{NickError, validate_nick};
+    const fn is_same_error(first: Result<(), NickError>, second: NickError) -> bool {
+        match (first, second) {
+            (Err(NickError::EmptyInput), NickError::EmptyInput) => true,
+            (Err(NickError::NickLengthExceededBy(a)), NickError::NickLengthExceededBy(b)) => a == b,
+            (Err(NickError::InvalidStartingByte(a)), NickError::InvalidStartingByte(b)) |
+            (Err(NickError::InvalidByte(a)), NickError::InvalidByte(b)) => a == b,
+            _ => false,
+        }
+    }
+    #[test]
+    const fn validating_nick() {
+        assert!(validate_nick(b"dan", 9).is_ok());
+        assert!(validate_nick(b"[dan]|away", 30).is_ok());
+        assert!(is_same_error(validate_nick(b"", 9), NickError::EmptyInput));
+        assert!(is_same_error(validate_nick(b"danielsmith", 9), NickError::NickLengthExceededBy(2)));
+        assert!(is_same_error(validate_nick(b"#dan", 9), NickError::InvalidStartingByte(b'#')));
+        assert!(is_same_error(validate_nick(b":dan", 9), NickError::InvalidStartingByte(b':')));
+        assert!(is_same_error(validate_nick(b"dan!d", 9), NickError::InvalidByte(b'!')));
+        assert!(is_same_error(validate_nick(b"da n", 9), NickError::InvalidByte(b' ')));
+    }
+}
@@ -0,0 +1,221 @@
+//! Typed views over the [`IrcMsg`](crate::IrcMsg) of specific commands.
+//!
+//! ## Purpose
+//!
+//! An [`IrcMsg`](crate::IrcMsg) only exposes its [`Parameters`](crate::parameters::Parameters) by position.
+//! The meaning of each position depends on the [`Command`](crate::command::Command).
+//! Each view checks the [`Command`](crate::command::Command) and names the parameters so that consumers don't need
+//! to know which position holds which piece of information.
+
+pub mod account;
+pub mod away;
+pub mod invite;
+pub mod chghost;
+pub mod setname;
+pub mod monitor;
+pub mod watch;
+pub mod standard_reply;
+pub mod webirc;
+pub mod privmsg;
+pub mod join;
+pub mod part;
+pub mod quit;
+pub mod nick;
+pub mod mode;
+pub mod topic;
+pub mod kick;
+pub mod ping;
+pub mod error;
+pub mod wallops;
+pub mod kill;
+pub mod squit;
+pub mod welcome;
+pub mod myinfo;
+pub mod names;
+pub mod who;
+pub mod whois;
+pub mod list;
+pub mod creation_time;
+pub mod lusers;
+pub mod mask_list;
+pub mod motd;
+pub mod nick_error;
+pub mod bounce;
+pub mod umode;
+pub mod logged_in;
+
+use crate::{IrcMsg, batch::BatchMsg, cap::CapMsg, command::CommandKind};
+use account::AccountMsg;
+use away::AwayMsg;
+use chghost::ChghostMsg;
+use error::ErrorMsg;
+use invite::InviteMsg;
+use join::JoinMsg;
+use kick::KickMsg;
+use kill::KillMsg;
+use mode::ModeMsg;
+use monitor::MonitorMsg;
+use nick::NickMsg;
+use part::PartMsg;
+use ping::PingMsg;
+use privmsg::PrivmsgView;
+use quit::QuitMsg;
+use setname::SetnameMsg;
+use squit::SquitMsg;
+use standard_reply::StandardReply;
+use topic::TopicMsg;
+use wallops::WallopsMsg;
+use webirc::WebircMsg;
+
+/// The typed view of an [`IrcMsg`] as returned by [`IrcMsg::view`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MessageView<'msg> {
+    /// A `PRIVMSG` command.
+    Privmsg(PrivmsgView<'msg>),
+    /// A `NOTICE` command.
+    Notice(PrivmsgView<'msg>),
+    /// A `JOIN` command.
+    Join(JoinMsg<'msg>),
+    /// A `PART` command.
+    Part(PartMsg<'msg>),
+    /// A `QUIT` command.
+    Quit(QuitMsg<'msg>),
+    /// A `NICK` command.
+    Nick(NickMsg<'msg>),
+    /// A `MODE` command.
+    Mode(ModeMsg<'msg>),
+    /// A `TOPIC` command.
+    Topic(TopicMsg<'msg>),
+    /// A `KICK` command.
+    Kick(KickMsg<'msg>),
+    /// An `INVITE` command.
+    Invite(InviteMsg<'msg>),
+    /// A `PING` command.
+    Ping(PingMsg<'msg>),
+    /// A `PONG` command.
+    Pong(PingMsg<'msg>),
+    /// An `ERROR` command.
+    Error(ErrorMsg<'msg>),
+    /// A `WALLOPS` command.
+    Wallops(WallopsMsg<'msg>),
+    /// A `KILL` command.
+    Kill(KillMsg<'msg>),
+    /// A `SQUIT` command.
+    Squit(SquitMsg<'msg>),
+    /// A `CAP` command.
+    Cap(CapMsg<'msg>),
+    /// A `BATCH` command.
+    Batch(BatchMsg<'msg>),
+    /// An `ACCOUNT` command.
+    Account(AccountMsg<'msg>),
+    /// An `AWAY` command.
+    Away(AwayMsg<'msg>),
+    /// A `CHGHOST` command.
+    Chghost(ChghostMsg<'msg>),
+    /// A `SETNAME` command.
+    Setname(SetnameMsg<'msg>),
+    /// A `MONITOR` command.
+    Monitor(MonitorMsg<'msg>),
+    /// A `FAIL`, `WARN` or `NOTE` command.
+    StandardReply(StandardReply<'msg>),
+    /// A `WEBIRC` command.
+    Webirc(WebircMsg<'msg>),
+    /// A `Numeric` command with its value.
+    Numeric(u16),
+    /// Any other command or a command whose parameters don't fit its view.
+    Other,
+}
+
+impl<'msg> MessageView<'msg> {
+    /// Generates a [`MessageView`] from an [`IrcMsg`].
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Self {
+        if let Some(value) = msg.command().as_numeric_u16() {return Self::Numeric(value);}
+        let Some(kind) = msg.command().kind() else {return Self::Other;};
+        let view = match kind {
+            CommandKind::Privmsg => match PrivmsgView::from_msg(msg) {Some(v) => Some(Self::Privmsg(v)), None => None},
+            CommandKind::Notice => match PrivmsgView::from_msg(msg) {Some(v) => Some(Self::Notice(v)), None => None},
+            CommandKind::Join => match JoinMsg::from_msg(msg) {Some(v) => Some(Self::Join(v)), None => None},
+            CommandKind::Part => match PartMsg::from_msg(msg) {Some(v) => Some(Self::Part(v)), None => None},
+            CommandKind::Quit => match QuitMsg::from_msg(msg) {Some(v) => Some(Self::Quit(v)), None => None},
+            CommandKind::Nick => match NickMsg::from_msg(msg) {Some(v) => Some(Self::Nick(v)), None => None},
+            CommandKind::Mode => match ModeMsg::from_msg(msg) {Some(v) => Some(Self::Mode(v)), None => None},
+            CommandKind::Topic => match TopicMsg::from_msg(msg) {Some(v) => Some(Self::Topic(v)), None => None},
+            CommandKind::Kick => match KickMsg::from_msg(msg) {Some(v) => Some(Self::Kick(v)), None => None},
+            CommandKind::Invite => match InviteMsg::from_msg(msg) {Some(v) => Some(Self::Invite(v)), None => None},
+            CommandKind::Ping => match PingMsg::from_msg(msg) {Some(v) => Some(Self::Ping(v)), None => None},
+            CommandKind::Pong => match PingMsg::from_msg(msg) {Some(v) => Some(Self::Pong(v)), None => None},
+            CommandKind::Error => match ErrorMsg::from_msg(msg) {Some(v) => Some(Self::Error(v)), None => None},
+            CommandKind::Wallops => match WallopsMsg::from_msg(msg) {Some(v) => Some(Self::Wallops(v)), None => None},
+            CommandKind::Kill => match KillMsg::from_msg(msg) {Some(v) => Some(Self::Kill(v)), None => None},
+            CommandKind::Squit => match SquitMsg::from_msg(msg) {Some(v) => Some(Self::Squit(v)), None => None},
+            CommandKind::Cap => match CapMsg::from_msg(msg) {Ok(v) => Some(Self::Cap(v)), Err(_) => None},
+            CommandKind::Batch => match BatchMsg::from_msg(msg) {Ok(v) => Some(Self::Batch(v)), Err(_) => None},
+            CommandKind::Account => match AccountMsg::from_msg(msg) {Some(v) => Some(Self::Account(v)), None => None},
+            CommandKind::Away => match AwayMsg::from_msg(msg) {Some(v) => Some(Self::Away(v)), None => None},
+            CommandKind::Chghost => match ChghostMsg::from_msg(msg) {Some(v) => Some(Self::Chghost(v)), None => None},
+            CommandKind::Setname => match SetnameMsg::from_msg(msg) {Some(v) => Some(Self::Setname(v)), None => None},
+            CommandKind::Monitor => match MonitorMsg::from_msg(msg) {Some(v) => Some(Self::Monitor(v)), None => None},
+            CommandKind::Fail | CommandKind::Warn | CommandKind::Note => match StandardReply::from_msg(msg) {
+                Some(v) => Some(Self::StandardReply(v)),
+                None => None,
+            },
+            CommandKind::Webirc => match WebircMsg::from_msg(msg) {Ok(v) => Some(Self::Webirc(v)), Err(_) => None},
+            _ => None,
+        };
+        match view {
+            Some(view) => view,
+            None => Self::Other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::MessageView;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn viewing_messages() {
+        let msg = IrcMsg::parse(b":dan!d@localhost PRIVMSG #chan :hi");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let view = msg.view();
+            assert!(matches!(view, MessageView::Privmsg(_)));
+            if let MessageView::Privmsg(view) = view {assert!(is_identical(view.text().as_bytes(), b"hi"));}
+        }
+        let msg = IrcMsg::parse(b":dan!d@localhost NOTICE #chan :hi");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(msg.view(), MessageView::Notice(_)));}
+        let msg = IrcMsg::parse(b":dan!d@localhost JOIN #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(msg.view(), MessageView::Join(_)));}
+        let msg = IrcMsg::parse(b":dan!d@localhost NICK dan_");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let nick = msg.view();
+            assert!(matches!(nick, MessageView::Nick(_)));
+            if let MessageView::Nick(nick) = nick {assert!(is_identical(nick.new_nick().as_bytes(), b"dan_"));}
+        }
+        let msg = IrcMsg::parse(b"PING :token");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(msg.view(), MessageView::Ping(_)));}
+        let msg = IrcMsg::parse(b"PONG :token");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(msg.view(), MessageView::Pong(_)));}
+        let msg = IrcMsg::parse(b"ERROR :Closing Link");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(msg.view(), MessageView::Error(_)));}
+        let msg = IrcMsg::parse(b":irc.host CAP * LS :multi-prefix sasl");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(msg.view(), MessageView::Cap(_)));}
+        let msg = IrcMsg::parse(b"FAIL * ACCOUNT_REQUIRED :Authentication required");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(msg.view(), MessageView::StandardReply(_)));}
+        let msg = IrcMsg::parse(b":irc.host 001 dan :Welcome");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(msg.view(), MessageView::Numeric(1)));}
+        let msg = IrcMsg::parse(b"INFO");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(msg.view(), MessageView::Other));}
+    }
+}
@@ -0,0 +1,92 @@
+//! Methods for reading `ACCOUNT` messages.
+//!
+//! ## Purpose
+//!
+//! With the [account-notify] capability enabled an IRC server sends `ACCOUNT` whenever a user in a shared channel
+//! logs in or out of an account. The new account name is the only parameter and `*` indicates a log out.
+//!
+//! [account-notify]: <https://ircv3.net/specs/extensions/account-notify>
+
+use crate::{ContentType, IrcMsg, command::CommandKind, is_identical, source::Source};
+
+/// A view over an `ACCOUNT` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AccountMsg<'msg> {
+    source: Option<Source<'msg>>,
+    account: Option<ContentType<'msg>>,
+}
+
+impl<'msg> AccountMsg<'msg> {
+    /// Generates an [`AccountMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `ACCOUNT` command with an account parameter.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Account)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        let account = params.extract_first();
+        let account = if is_identical(account.as_bytes(), b"*") {None} else {Some(account)};
+        Some(Self{source: msg.source(), account})
+    }
+    /// Returns the [`Source`] of the user whose account changed.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the user whose account changed.
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Returns the account name logged into or `None` if logged out.
+    #[must_use]
+    pub const fn account(&self) -> Option<ContentType<'msg>> {
+        self.account
+    }
+    /// Check whether the user logged into an account.
+    #[must_use]
+    pub const fn is_login(&self) -> bool {
+        self.account.is_some()
+    }
+    /// Check whether the user logged out of their account.
+    #[must_use]
+    pub const fn is_logout(&self) -> bool {
+        self.account.is_none()
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::AccountMsg;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_account() {
+        let msg = IrcMsg::parse(b":dan!d@localhost ACCOUNT danny");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let account = AccountMsg::from_msg(&msg);
+            assert!(account.is_some());
+            if let Some(account) = account {
+                assert!(account.is_login() && !account.is_logout() && account.source().is_some());
+                let pair = (account.nick(), account.account());
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(nick), Some(name)) = pair {
+                    assert!(is_identical(nick.as_bytes(), b"dan") && is_identical(name.as_bytes(), b"danny"));
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":dan!d@localhost ACCOUNT *");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let account = AccountMsg::from_msg(&msg);
+            assert!(account.is_some());
+            if let Some(account) = account {assert!(account.is_logout() && account.account().is_none());}
+        }
+        let msg = IrcMsg::parse(b":dan!d@localhost AWAY");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(AccountMsg::from_msg(&msg).is_none());}
+    }
+}
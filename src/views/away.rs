@@ -0,0 +1,208 @@
+//! Methods for reading `AWAY` messages and the related numeric replies.
+//!
+//! ## Purpose
+//!
+//! An IRC client sends `AWAY` with a message to be marked as away and without one to be marked as present again.
+//! With the [away-notify] capability enabled an IRC server forwards the `AWAY` of users in shared channels.
+//! The IRC server confirms the change with `RPL_NOWAWAY` (`306`) or `RPL_UNAWAY` (`305`) and replies with
+//! `RPL_AWAY` (`301`) when messaging or querying a user who is away.
+//!
+//! [away-notify]: <https://ircv3.net/specs/extensions/away-notify>
+
+use crate::{ContentType, IrcMsg, command::CommandKind, source::Source};
+
+/// A view over an `AWAY` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AwayMsg<'msg> {
+    source: Option<Source<'msg>>,
+    message: Option<ContentType<'msg>>,
+}
+
+impl<'msg> AwayMsg<'msg> {
+    /// Generates an [`AwayMsg`] from an [`IrcMsg`].
+    ///
+    /// An empty away message is treated the same as a missing one.
+    /// Returns `None` if the [`IrcMsg`] is not an `AWAY` command.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Away)) {return None;}
+        let message = match msg.parameters() {
+            Some(params) if !params.extract_first().as_bytes().is_empty() => Some(params.extract_first()),
+            _ => None,
+        };
+        Some(Self{source: msg.source(), message})
+    }
+    /// Returns the [`Source`] of the user whose away status changed.
+    ///
+    /// Returns `None` for an [`AwayMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the user whose away status changed.
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Check whether the user went away.
+    #[must_use]
+    pub const fn is_away(&self) -> bool {
+        self.message.is_some()
+    }
+    /// Returns the away message or `None` if the user returned.
+    #[must_use]
+    pub const fn message(&self) -> Option<ContentType<'msg>> {
+        self.message
+    }
+}
+
+/// A view over an `RPL_AWAY` (`301`), `RPL_UNAWAY` (`305`) or `RPL_NOWAWAY` (`306`) numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AwayReply<'msg> {
+    numeric: u16,
+    client: ContentType<'msg>,
+    nick: Option<ContentType<'msg>>,
+    text: ContentType<'msg>,
+}
+
+impl<'msg> AwayReply<'msg> {
+    /// Generates an [`AwayReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_AWAY` (`301`), `RPL_UNAWAY` (`305`) or `RPL_NOWAWAY` (`306`)
+    /// numeric with enough parameters.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(numeric) = msg.command().as_numeric_u16() else {return None;};
+        let Some(params) = msg.parameters() else {return None;};
+        let client = params.extract_first();
+        match numeric {
+            301 if params.count() >= 3 => Some(Self{numeric, client, nick: params.extract_specific(1),
+                text: params.extract_last()}),
+            305 | 306 if params.count() >= 2 => Some(Self{numeric, client, nick: None, text: params.extract_last()}),
+            _ => None,
+        }
+    }
+    /// Returns the numeric of the [`AwayReply`].
+    #[must_use]
+    pub const fn numeric(&self) -> u16 {
+        self.numeric
+    }
+    /// Returns the nickname of the client receiving the [`AwayReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the nick of the user who is away from an `RPL_AWAY` (`301`).
+    ///
+    /// Returns `None` for `RPL_UNAWAY` (`305`) and `RPL_NOWAWAY` (`306`) as they concern the client itself.
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        self.nick
+    }
+    /// Check whether the [`AwayReply`] concerns the client itself.
+    #[must_use]
+    pub const fn is_self(&self) -> bool {
+        self.nick.is_none()
+    }
+    /// Check whether the user or client is away.
+    ///
+    /// Only `RPL_UNAWAY` (`305`) indicates being present.
+    #[must_use]
+    pub const fn is_away(&self) -> bool {
+        self.numeric != 305
+    }
+    /// Returns the away message of the user from an `RPL_AWAY` (`301`).
+    #[must_use]
+    pub const fn message(&self) -> Option<ContentType<'msg>> {
+        if self.numeric == 301 {Some(self.text)} else {None}
+    }
+    /// Returns the human readable text.
+    ///
+    /// For an `RPL_AWAY` (`301`) this is the away message of the user.
+    #[must_use]
+    pub const fn text(&self) -> ContentType<'msg> {
+        self.text
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{AwayMsg, AwayReply};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_away() {
+        let msg = IrcMsg::parse(b":dan!d@localhost AWAY :Gone to lunch");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let away = AwayMsg::from_msg(&msg);
+            assert!(away.is_some());
+            if let Some(away) = away {
+                let pair = (away.nick(), away.message());
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(nick), Some(message)) = pair {
+                    assert!(away.is_away() && is_identical(nick.as_bytes(), b"dan"));
+                    assert!(is_identical(message.as_bytes(), b"Gone to lunch"));
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":dan!d@localhost AWAY");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let away = AwayMsg::from_msg(&msg);
+            assert!(away.is_some());
+            if let Some(away) = away {assert!(!away.is_away() && away.message().is_none() && away.source().is_some());}
+        }
+        let msg = IrcMsg::parse(b"AWAY :");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let away = AwayMsg::from_msg(&msg);
+            assert!(away.is_some());
+            if let Some(away) = away {assert!(!away.is_away() && away.nick().is_none());}
+        }
+        let msg = IrcMsg::parse(b"ACCOUNT *");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(AwayMsg::from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn reading_away_replies() {
+        let msg = IrcMsg::parse(b":irc.example.com 301 bob dan :Gone to lunch");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = AwayReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                let pair = (reply.nick(), reply.message());
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(nick), Some(message)) = pair {
+                    assert!(reply.is_away() && !reply.is_self() && reply.numeric() == 301);
+                    assert!(is_identical(nick.as_bytes(), b"dan"));
+                    assert!(is_identical(message.as_bytes(), b"Gone to lunch"));
+                }
+                assert!(is_identical(reply.client().as_bytes(), b"bob"));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 305 dan :You are no longer marked as being away");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = AwayReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {assert!(!reply.is_away() && reply.is_self() && reply.message().is_none());}
+        }
+        let msg = IrcMsg::parse(b":irc.example.com 306 dan :You have been marked as being away");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = AwayReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(reply.is_away() && reply.is_self());
+                assert!(is_identical(reply.text().as_bytes(), b"You have been marked as being away"));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com 302 dan :dan=+d@localhost");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(AwayReply::from_msg(&msg).is_none());}
+    }
+}
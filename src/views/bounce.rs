@@ -0,0 +1,151 @@
+//! Methods for reading server redirects.
+//!
+//! ## Purpose
+//!
+//! An IRC server that is full or otherwise unable to accept the connection may redirect the IRC client to another
+//! server with `RPL_BOUNCE` (`010`) naming the hostname and port to connect to instead.
+//! [RFC 2812] originally assigned this to `005` as `Try server <server name>, port <port number>` before `005` was
+//! taken over by `RPL_ISUPPORT`. A `005` is only read as a redirect if its text matches that historical form. Every
+//! other `005` is an `RPL_ISUPPORT` read with
+//! [`ISupportToken::iter_from_msg`](crate::isupport::ISupportToken::iter_from_msg).
+//!
+//! [RFC 2812]: <https://datatracker.ietf.org/doc/html/rfc2812#section-5.1>
+
+use crate::{ContentType, IrcMsg, is_identical, parse_u32};
+
+const LEGACY_PREFIX: &[u8] = b"Try server ";
+const LEGACY_PORT: &[u8] = b", port ";
+
+/// A view over an `RPL_BOUNCE` (`010`) numeric [`IrcMsg`] or a historical `005` redirect.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BounceReply<'msg> {
+    client: ContentType<'msg>,
+    hostname: ContentType<'msg>,
+    port: ContentType<'msg>,
+    info: Option<ContentType<'msg>>,
+}
+
+impl<'msg> BounceReply<'msg> {
+    /// Generates a [`BounceReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is neither an `RPL_BOUNCE` (`010`) numeric nor a `005` numeric with the
+    /// historical `Try server <server name>, port <port number>` text.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(params) = msg.parameters() else {return None;};
+        match msg.command().as_numeric_u16() {
+            Some(10) => {
+                let (Some(hostname), Some(port)) = (params.extract_specific(1), params.extract_specific(2)) else {
+                    return None;
+                };
+                Some(Self{client: params.extract_first(), hostname, port, info: params.extract_specific(3)})
+            },
+            Some(5) if params.count() == 2 => match split_legacy(params.extract_last().as_bytes()) {
+                Some((hostname, port)) => {
+                    let (hostname, port) = (ContentType::new(hostname), ContentType::new(port));
+                    Some(Self{client: params.extract_first(), hostname, port, info: None})
+                },
+                None => None,
+            },
+            _ => None,
+        }
+    }
+    /// Returns the nickname of the client receiving the [`BounceReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the hostname of the IRC server to connect to instead.
+    #[must_use]
+    pub const fn hostname(&self) -> ContentType<'msg> {
+        self.hostname
+    }
+    /// Returns the port of the IRC server to connect to instead.
+    ///
+    /// Returns `None` if the port is not a valid number.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn port(&self) -> Option<u16> {
+        match parse_u32(self.port.as_bytes()) {
+            Some(port) if port <= u16::MAX as u32 => Some(port as u16),
+            _ => None,
+        }
+    }
+    /// Returns the human readable text of an `RPL_BOUNCE` (`010`).
+    ///
+    /// Returns `None` for a historical `005` redirect.
+    #[must_use]
+    pub const fn info(&self) -> Option<ContentType<'msg>> {
+        self.info
+    }
+    /// Check whether the [`BounceReply`] is a historical `005` redirect.
+    #[must_use]
+    pub const fn is_legacy(&self) -> bool {
+        self.info.is_none()
+    }
+}
+
+const fn split_legacy(text: &[u8]) -> Option<(&[u8], &[u8])> {
+    if text.len() <= LEGACY_PREFIX.len() {return None;}
+    let (prefix, rest) = text.split_at(LEGACY_PREFIX.len());
+    if !is_identical(prefix, LEGACY_PREFIX) {return None;}
+    let mut index = 0;
+    while index < rest.len() {
+        if rest[index] == b',' {
+            let (hostname, rest) = rest.split_at(index);
+            if hostname.is_empty() || rest.len() <= LEGACY_PORT.len() {return None;}
+            let (separator, port) = rest.split_at(LEGACY_PORT.len());
+            if !is_identical(separator, LEGACY_PORT) {return None;}
+            return Some((hostname, port));
+        }
+        index += 1;
+    }
+    None
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::BounceReply;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_bounce() {
+        let msg = IrcMsg::parse(b":irc.host 010 dan irc.other.net 6697 :Server full, try this one");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let bounce = BounceReply::from_msg(&msg);
+            assert!(bounce.is_some());
+            if let Some(bounce) = bounce {
+                let pair = (bounce.port(), bounce.info());
+                assert!(matches!(pair, (Some(6697), Some(_))));
+                if let (Some(6697), Some(info)) = pair {
+                    assert!(is_identical(bounce.hostname().as_bytes(), b"irc.other.net") && !bounce.is_legacy());
+                    assert!(is_identical(info.as_bytes(), b"Server full, try this one"));
+                }
+                assert!(is_identical(bounce.client().as_bytes(), b"dan"));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 005 dan :Try server irc.other.net, port 6667");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let bounce = BounceReply::from_msg(&msg);
+            assert!(bounce.is_some());
+            if let Some(bounce) = bounce {
+                assert!(is_identical(bounce.hostname().as_bytes(), b"irc.other.net") && bounce.is_legacy());
+                assert!(matches!(bounce.port(), Some(6667)) && bounce.info().is_none());
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 005 dan NICKLEN=30 :are supported by this server");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(BounceReply::from_msg(&msg).is_none());}
+        let msg = IrcMsg::parse(b":irc.host 005 dan :Try server irc.other.net");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(BounceReply::from_msg(&msg).is_none());}
+        let msg = IrcMsg::parse(b":irc.host 010 dan irc.other.net 99999 :Try this");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let bounce = BounceReply::from_msg(&msg);
+            assert!(bounce.is_some());
+            if let Some(bounce) = bounce {assert!(bounce.port().is_none());}
+        }
+    }
+}
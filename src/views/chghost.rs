@@ -0,0 +1,114 @@
+//! Methods for reading `CHGHOST` messages.
+//!
+//! ## Purpose
+//!
+//! With the [chghost] capability enabled an IRC server sends `CHGHOST` when the user or host of a user in a shared
+//! channel changes instead of faking a `QUIT` and `JOIN`. The [`Source`] holds the old user and host while the
+//! parameters hold the new ones.
+//!
+//! [chghost]: <https://ircv3.net/specs/extensions/chghost>
+
+use crate::{ContentType, IrcMsg, command::CommandKind, source::{Origin, Source}};
+
+/// A view over a `CHGHOST` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ChghostMsg<'msg> {
+    source: Option<Source<'msg>>,
+    user: ContentType<'msg>,
+    host: ContentType<'msg>,
+}
+
+impl<'msg> ChghostMsg<'msg> {
+    /// Generates a [`ChghostMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `CHGHOST` command with a new user and host.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Chghost)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        let Some(host) = params.extract_specific(1) else {return None;};
+        Some(Self{source: msg.source(), user: params.extract_first(), host})
+    }
+    /// Returns the [`Source`] of the user whose user or host changed.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the user whose user or host changed.
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Returns the old user from the [`Source`] if present.
+    #[must_use]
+    pub const fn old_user(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => match source.origin() {
+                Origin::Nickname(nickname) => nickname.user(),
+                _ => None,
+            },
+            None => None,
+        }
+    }
+    /// Returns the old host from the [`Source`] if present.
+    #[must_use]
+    pub const fn old_host(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => match source.origin() {
+                Origin::Nickname(nickname) => nickname.host(),
+                _ => None,
+            },
+            None => None,
+        }
+    }
+    /// Returns the new user.
+    #[must_use]
+    pub const fn new_user(&self) -> ContentType<'msg> {
+        self.user
+    }
+    /// Returns the new host.
+    #[must_use]
+    pub const fn new_host(&self) -> ContentType<'msg> {
+        self.host
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::ChghostMsg;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_chghost() {
+        let msg = IrcMsg::parse(b":dan!d@localhost CHGHOST dan user/dan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let chghost = ChghostMsg::from_msg(&msg);
+            assert!(chghost.is_some());
+            if let Some(chghost) = chghost {
+                let values = (chghost.nick(), chghost.old_user(), chghost.old_host());
+                assert!(matches!(values, (Some(_), Some(_), Some(_))));
+                if let (Some(nick), Some(user), Some(host)) = values {
+                    assert!(is_identical(nick.as_bytes(), b"dan") && is_identical(user.as_bytes(), b"d"));
+                    assert!(is_identical(host.as_bytes(), b"localhost"));
+                }
+                assert!(is_identical(chghost.new_user().as_bytes(), b"dan"));
+                assert!(is_identical(chghost.new_host().as_bytes(), b"user/dan"));
+            }
+        }
+        let msg = IrcMsg::parse(b"CHGHOST dan localhost");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let chghost = ChghostMsg::from_msg(&msg);
+            assert!(chghost.is_some());
+            if let Some(chghost) = chghost {
+                assert!(chghost.source().is_none() && chghost.old_user().is_none() && chghost.old_host().is_none());
+            }
+        }
+        let msg = IrcMsg::parse(b":dan!d@localhost SETNAME :Dan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(ChghostMsg::from_msg(&msg).is_none());}
+    }
+}
@@ -0,0 +1,75 @@
+//! Methods for reading `RPL_CREATIONTIME` (`329`) numerics.
+//!
+//! ## Purpose
+//!
+//! On joining a channel or querying its modes the IRC server sends `RPL_CREATIONTIME` with the Unix timestamp of
+//! when the channel was created. Together with the `RPL_TOPICWHOTIME` (`333`) of a
+//! [`TopicRecord`](crate::views::topic::TopicRecord) it dates the state of a channel.
+
+use crate::{ContentType, IrcMsg};
+
+/// A view over an `RPL_CREATIONTIME` (`329`) numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CreationTimeReply<'msg> {
+    client: ContentType<'msg>,
+    channel: ContentType<'msg>,
+    created_at: u64,
+}
+
+impl<'msg> CreationTimeReply<'msg> {
+    /// Generates a [`CreationTimeReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_CREATIONTIME` (`329`) numeric with a channel and a valid
+    /// Unix timestamp.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(329) = msg.command().as_numeric_u16() else {return None;};
+        let Some(params) = msg.parameters() else {return None;};
+        let (Some(channel), Some(created_at)) = (params.extract_specific(1), params.extract_specific(2)) else {
+            return None;
+        };
+        let Some(created_at) = created_at.to_u64() else {return None;};
+        Some(Self{client: params.extract_first(), channel, created_at})
+    }
+    /// Returns the nickname of the client receiving the [`CreationTimeReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the channel.
+    #[must_use]
+    pub const fn channel(&self) -> ContentType<'msg> {
+        self.channel
+    }
+    /// Returns the Unix timestamp of when the channel was created.
+    #[must_use]
+    pub const fn created_at(&self) -> u64 {
+        self.created_at
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::CreationTimeReply;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_creation_time() {
+        let msg = IrcMsg::parse(b":irc.host 329 dan #chan 1700000000");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = CreationTimeReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(is_identical(reply.client().as_bytes(), b"dan"));
+                assert!(is_identical(reply.channel().as_bytes(), b"#chan"));
+                assert!(reply.created_at() == 1_700_000_000);
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 329 dan #chan :yesterday");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(CreationTimeReply::from_msg(&msg).is_none());}
+        let msg = IrcMsg::parse(b":irc.host 333 dan #chan bob 1700000000");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(CreationTimeReply::from_msg(&msg).is_none());}
+    }
+}
@@ -0,0 +1,126 @@
+//! Methods for reading `ERROR` messages.
+//!
+//! ## Purpose
+//!
+//! An IRC server sends `ERROR` with a reason right before closing the connection. The reason is free form text
+//! so [`ErrorReason`] classifies the common ones by looking for well known phrases to help decide whether and
+//! when to reconnect.
+
+use crate::{ContentType, IrcMsg, command::CommandKind};
+
+/// A common reason for an IRC server closing the connection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ErrorReason {
+    /// The IRC client reconnected too fast or opened too many connections.
+    Throttled,
+    /// The IRC client is banned from the IRC server or network.
+    Banned,
+    /// The IRC client did not reply to a `PING` in time.
+    PingTimeout,
+    /// Any other reason.
+    Other,
+}
+
+const THROTTLED: [&[u8]; 4] = [b"throttl", b"too fast", b"too many connections", b"reconnect too"];
+const BANNED: [&[u8]; 6] = [b"banned", b"k-line", b"g-line", b"z-line", b"d-line", b"akill"];
+
+impl ErrorReason {
+    /// Classifies the reason text of an `ERROR` by looking for well known phrases ignoring ASCII case.
+    #[must_use]
+    pub const fn classify(reason: &[u8]) -> Self {
+        if contains_ignore_case(reason, b"ping timeout") {return Self::PingTimeout;}
+        let mut index = 0;
+        while index < THROTTLED.len() {
+            if contains_ignore_case(reason, THROTTLED[index]) {return Self::Throttled;}
+            index += 1;
+        }
+        let mut index = 0;
+        while index < BANNED.len() {
+            if contains_ignore_case(reason, BANNED[index]) {return Self::Banned;}
+            index += 1;
+        }
+        Self::Other
+    }
+}
+
+/// A view over an `ERROR` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ErrorMsg<'msg> {
+    reason: ContentType<'msg>,
+}
+
+impl<'msg> ErrorMsg<'msg> {
+    /// Generates an [`ErrorMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `ERROR` command with a reason.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Error)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        Some(Self{reason: params.extract_last()})
+    }
+    /// Returns the reason text.
+    #[must_use]
+    pub const fn reason(&self) -> ContentType<'msg> {
+        self.reason
+    }
+    /// Returns the [`ErrorReason`] classifying the reason text.
+    #[must_use]
+    pub const fn reason_kind(&self) -> ErrorReason {
+        ErrorReason::classify(self.reason.as_bytes())
+    }
+}
+
+const fn contains_ignore_case(haystack: &[u8], needle: &[u8]) -> bool {
+    if needle.len() > haystack.len() {return false;}
+    let mut start = 0;
+    while start + needle.len() <= haystack.len() {
+        let mut offset = 0;
+        while offset < needle.len() && haystack[start + offset].eq_ignore_ascii_case(&needle[offset]) {
+            offset += 1;
+        }
+        if offset == needle.len() {return true;}
+        start += 1;
+    }
+    false
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{ErrorMsg, ErrorReason};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_error() {
+        let input = b"ERROR :Closing Link: dan[localhost] (Ping timeout: 240 seconds)";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let error = ErrorMsg::from_msg(&msg);
+            assert!(error.is_some());
+            if let Some(error) = error {
+                assert!(matches!(error.reason_kind(), ErrorReason::PingTimeout));
+                let reason = error.reason();
+                assert!(is_identical(reason.as_bytes(), b"Closing Link: dan[localhost] (Ping timeout: 240 seconds)"));
+            }
+        }
+        let msg = IrcMsg::parse(b"ERROR :Trying to reconnect too fast.");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let error = ErrorMsg::from_msg(&msg);
+            assert!(error.is_some());
+            if let Some(error) = error {assert!(matches!(error.reason_kind(), ErrorReason::Throttled));}
+        }
+        let msg = IrcMsg::parse(b"ERROR :Closing Link: localhost (K-Lined)");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let error = ErrorMsg::from_msg(&msg);
+            assert!(error.is_some());
+            if let Some(error) = error {assert!(matches!(error.reason_kind(), ErrorReason::Banned));}
+        }
+        assert!(matches!(ErrorReason::classify(b"Closing Link: localhost (Quit: bye)"), ErrorReason::Other));
+        assert!(matches!(ErrorReason::classify(b""), ErrorReason::Other));
+        let msg = IrcMsg::parse(b"QUIT :Ping timeout");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(ErrorMsg::from_msg(&msg).is_none());}
+    }
+}
@@ -0,0 +1,195 @@
+//! Methods for reading and writing `INVITE` messages.
+//!
+//! ## Purpose
+//!
+//! An IRC client sends `INVITE` to invite a user to a channel. The IRC server forwards it to the invited user with
+//! the inviter as the [`Source`]. With the [invite-notify] capability enabled the IRC server also sends it to other
+//! members of the channel. The IRC client that sent the invite receives `RPL_INVITING` (`341`) as confirmation.
+//!
+//! [invite-notify]: <https://ircv3.net/specs/extensions/invite-notify>
+
+use crate::{ContentType, IrcMsg, IrcMsgWriter, IrcMsgWriterError, casemapping::IrcCaseMapping,
+    command::CommandKind, source::Source};
+
+/// A view over an `INVITE` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InviteMsg<'msg> {
+    source: Option<Source<'msg>>,
+    invitee: ContentType<'msg>,
+    channel: ContentType<'msg>,
+}
+
+impl<'msg> InviteMsg<'msg> {
+    /// Generates an [`InviteMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `INVITE` command with a nick and channel.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Invite)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        let Some(channel) = params.extract_specific(1) else {return None;};
+        Some(Self{source: msg.source(), invitee: params.extract_first(), channel})
+    }
+    /// Returns the [`Source`] of the user who sent the invite.
+    ///
+    /// Returns `None` for an [`InviteMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the user who sent the invite.
+    ///
+    /// Returns `None` for an [`InviteMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn inviter(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Returns the nick of the invited user.
+    #[must_use]
+    pub const fn invitee(&self) -> ContentType<'msg> {
+        self.invitee
+    }
+    /// Returns the channel the user is invited to.
+    #[must_use]
+    pub const fn channel(&self) -> ContentType<'msg> {
+        self.channel
+    }
+    /// Check whether the [`InviteMsg`] was sent by an IRC server.
+    #[must_use]
+    pub const fn is_from_server(&self) -> bool {
+        self.source.is_some()
+    }
+    /// Check whether the invitee is `my_nick` according to the [`IrcCaseMapping`].
+    ///
+    /// With [invite-notify] an [`InviteMsg`] for another user only informs about the invite.
+    ///
+    /// [invite-notify]: <https://ircv3.net/specs/extensions/invite-notify>
+    #[must_use]
+    pub const fn is_for(&self, my_nick: &[u8], casemapping: IrcCaseMapping) -> bool {
+        casemapping.is_equivalent(self.invitee.as_bytes(), my_nick)
+    }
+}
+
+/// A view over an `RPL_INVITING` (`341`) numeric [`IrcMsg`] confirming a sent invite.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct InvitingReply<'msg> {
+    client: ContentType<'msg>,
+    invitee: ContentType<'msg>,
+    channel: ContentType<'msg>,
+}
+
+impl<'msg> InvitingReply<'msg> {
+    /// Generates an [`InvitingReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_INVITING` (`341`) numeric with enough parameters.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(341) = msg.command().as_numeric_u16() else {return None;};
+        let Some(params) = msg.parameters() else {return None;};
+        let (Some(invitee), Some(channel)) = (params.extract_specific(1), params.extract_specific(2)) else {
+            return None;
+        };
+        Some(Self{client: params.extract_first(), invitee, channel})
+    }
+    /// Returns the nickname of the client that sent the invite.
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the nick of the invited user.
+    #[must_use]
+    pub const fn invitee(&self) -> ContentType<'msg> {
+        self.invitee
+    }
+    /// Returns the channel the user is invited to.
+    #[must_use]
+    pub const fn channel(&self) -> ContentType<'msg> {
+        self.channel
+    }
+}
+
+/// Writes an `INVITE` inviting the user with the `nick` to the `channel`.
+///
+/// # Errors
+///
+/// Will return `Err` if the `nick` or `channel` is not a valid middle parameter or the message is longer than
+/// `N` bytes.
+pub const fn invite<const N: usize>(nick: &[u8], channel: &[u8]) -> Result<IrcMsgWriter<N>, IrcMsgWriterError> {
+    let writer = match IrcMsgWriter::new().command(b"INVITE") {
+        Ok(writer) => writer,
+        Err(e) => return Err(e),
+    };
+    match writer.middle(nick) {
+        Ok(writer) => writer.middle(channel),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{InviteMsg, InvitingReply, invite};
+    use crate::{IrcMsg, casemapping::IrcCaseMapping, is_identical};
+    #[test]
+    const fn reading_invite() {
+        let msg = IrcMsg::parse(b":dan!d@localhost INVITE bob #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let invite = InviteMsg::from_msg(&msg);
+            assert!(invite.is_some());
+            if let Some(invite) = invite {
+                let inviter = invite.inviter();
+                assert!(inviter.is_some());
+                if let Some(inviter) = inviter {
+                    assert!(invite.is_from_server() && is_identical(inviter.as_bytes(), b"dan"));
+                }
+                assert!(is_identical(invite.invitee().as_bytes(), b"bob"));
+                assert!(is_identical(invite.channel().as_bytes(), b"#chan"));
+            }
+        }
+        let msg = IrcMsg::parse(b"INVITE bob :#chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let invite = InviteMsg::from_msg(&msg);
+            assert!(invite.is_some());
+            if let Some(invite) = invite {
+                assert!(!invite.is_from_server() && invite.inviter().is_none() && invite.source().is_none());
+                assert!(is_identical(invite.channel().as_bytes(), b"#chan"));
+            }
+        }
+        let msg = IrcMsg::parse(b":dan!d@localhost KICK #chan bob");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(InviteMsg::from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn inviting_both_directions() {
+        let writer = invite::<32>(b"bob", b"#chan");
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {assert!(is_identical(writer.as_bytes(), b"INVITE bob #chan"));}
+        assert!(invite::<32>(b"bob", b":chan").is_err() && invite::<8>(b"bob", b"#chan").is_err());
+        let msg = IrcMsg::parse(b":irc.host 341 dan bob #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = InvitingReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(is_identical(reply.client().as_bytes(), b"dan"));
+                assert!(is_identical(reply.invitee().as_bytes(), b"bob"));
+                assert!(is_identical(reply.channel().as_bytes(), b"#chan"));
+            }
+        }
+        let msg = IrcMsg::parse(b":dan!d@localhost INVITE Bob #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let received = InviteMsg::from_msg(&msg);
+            assert!(received.is_some());
+            if let Some(received) = received {
+                assert!(received.is_for(b"bob", IrcCaseMapping::Ascii));
+                assert!(!received.is_for(b"alice", IrcCaseMapping::Ascii));
+            }
+            assert!(InvitingReply::from_msg(&msg).is_none());
+        }
+    }
+}
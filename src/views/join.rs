@@ -0,0 +1,254 @@
+//! Methods for reading `JOIN` messages.
+//!
+//! ## Purpose
+//!
+//! An IRC client sends `JOIN` to join channels and the IRC server sends it back with the user as the [`Source`]
+//! for every channel joined.
+//!
+//! The channels and their keys are comma separated lists where the first key belongs to the first channel and so
+//! on. Channels without a key come after the ones with a key. The special `JOIN 0` makes the IRC client leave all
+//! channels it is in.
+//!
+//! With the `extended-join` [`Capability`](crate::cap::Capability) the IRC server adds the account name and the
+//! realname of the user after the channel instead of keys.
+
+use crate::{ContentType, IrcMsg, command::CommandKind, is_identical, parameters::CommaList, source::Source};
+
+/// A view over a `JOIN` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct JoinMsg<'msg> {
+    source: Option<Source<'msg>>,
+    channels: ContentType<'msg>,
+    keys: Option<ContentType<'msg>>,
+    account: Option<ContentType<'msg>>,
+    realname: Option<ContentType<'msg>>,
+}
+
+impl<'msg> JoinMsg<'msg> {
+    /// Generates a [`JoinMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `JOIN` command with channels.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Join)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        let source = msg.source();
+        let (keys, account, realname) = match source {
+            None => (params.extract_specific(1), None, None),
+            Some(_) if params.count() == 3 => match params.extract_specific(1) {
+                Some(account) if is_identical(account.as_bytes(), b"*") => (None, None, params.extract_specific(2)),
+                account => (None, account, params.extract_specific(2)),
+            },
+            Some(_) => (None, None, None),
+        };
+        Some(Self{source, channels: params.extract_first(), keys, account, realname})
+    }
+    /// Returns the [`Source`] of the user who joined.
+    ///
+    /// Returns `None` for a [`JoinMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the user who joined.
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Returns the channels as they appear in the message.
+    #[must_use]
+    pub const fn channels(&self) -> ContentType<'msg> {
+        self.channels
+    }
+    /// Returns the keys as they appear in the message if present.
+    ///
+    /// Only a [`JoinMsg`] sent by an IRC client has keys.
+    #[must_use]
+    pub const fn keys(&self) -> Option<ContentType<'msg>> {
+        self.keys
+    }
+    /// Returns the account name of the user who joined from an `extended-join`.
+    ///
+    /// Returns `None` if the user is not logged into an account or the [`JoinMsg`] is not an `extended-join`.
+    #[must_use]
+    pub const fn account(&self) -> Option<ContentType<'msg>> {
+        self.account
+    }
+    /// Returns the realname of the user who joined from an `extended-join`.
+    #[must_use]
+    pub const fn realname(&self) -> Option<ContentType<'msg>> {
+        self.realname
+    }
+    /// Returns a [`JoinChannels`] pairing each channel with its key.
+    #[must_use]
+    pub const fn channels_and_keys(&self) -> JoinChannels<'msg> {
+        let keys = match self.keys {
+            Some(keys) => CommaList::new(keys),
+            None => CommaList::new(ContentType::new(b"")),
+        };
+        JoinChannels{channels: CommaList::new(self.channels), keys}
+    }
+    /// Check whether the [`JoinMsg`] is the special `JOIN 0` to leave all channels.
+    #[must_use]
+    pub const fn is_part_all(&self) -> bool {
+        is_identical(self.channels.as_bytes(), b"0")
+    }
+}
+
+/// A cursor over the channels of a [`JoinMsg`] paired with their keys.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct JoinChannels<'msg> {
+    channels: CommaList<'msg>,
+    keys: CommaList<'msg>,
+}
+
+impl<'msg> JoinChannels<'msg> {
+    /// Returns the next channel with its key and the advanced [`JoinChannels`].
+    ///
+    /// The key is `None` if the channel has no key or an empty key. Returns `None` once all channels have been read.
+    #[must_use]
+    pub const fn next_channel(self) -> Option<(ContentType<'msg>, Option<ContentType<'msg>>, Self)> {
+        let Some((channel, channels)) = self.channels.next_item() else {return None;};
+        let (key, keys) = match self.keys.next_item() {
+            Some((key, keys)) if !key.as_bytes().is_empty() => (Some(key), keys),
+            Some((_, keys)) => (None, keys),
+            None => (None, self.keys),
+        };
+        Some((channel, key, Self{channels, keys}))
+    }
+    /// Returns the amount of channels that have not been read yet.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.channels.count()
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::JoinMsg;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_join() {
+        let msg = IrcMsg::parse(b":dan!d@localhost JOIN #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let join = JoinMsg::from_msg(&msg);
+            assert!(join.is_some());
+            if let Some(join) = join {
+                let nick = join.nick();
+                assert!(nick.is_some());
+                if let Some(nick) = nick {
+                    assert!(is_identical(nick.as_bytes(), b"dan"));
+                    assert!(is_identical(join.channels().as_bytes(), b"#chan"));
+                }
+                assert!(join.keys().is_none());
+            }
+        }
+        let msg = IrcMsg::parse(b"JOIN #chan key");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let join = JoinMsg::from_msg(&msg);
+            assert!(join.is_some());
+            if let Some(join) = join {
+                let keys = join.keys();
+                assert!(keys.is_some());
+                if let Some(keys) = keys {assert!(join.source().is_none() && is_identical(keys.as_bytes(), b"key"));}
+            }
+        }
+        let msg = IrcMsg::parse(b"PART #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(JoinMsg::from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn reading_extended_join() {
+        let msg = IrcMsg::parse(b":dan!d@localhost JOIN #chan dan :Dan Smith");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let join = JoinMsg::from_msg(&msg);
+            assert!(join.is_some());
+            if let Some(join) = join {
+                let pair = (join.account(), join.realname());
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(account), Some(realname)) = pair {
+                    assert!(is_identical(account.as_bytes(), b"dan") && is_identical(realname.as_bytes(), b"Dan Smith"));
+                }
+                assert!(join.keys().is_none());
+            }
+        }
+        let msg = IrcMsg::parse(b":dan!d@localhost JOIN #chan * :Dan Smith");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let join = JoinMsg::from_msg(&msg);
+            assert!(join.is_some());
+            if let Some(join) = join {
+                let realname = join.realname();
+                assert!(realname.is_some());
+                if let Some(realname) = realname {assert!(is_identical(realname.as_bytes(), b"Dan Smith"));}
+                assert!(join.account().is_none() && join.keys().is_none());
+            }
+        }
+        let msg = IrcMsg::parse(b"JOIN #chan key");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let join = JoinMsg::from_msg(&msg);
+            assert!(join.is_some());
+            if let Some(join) = join {assert!(join.account().is_none() && join.realname().is_none());}
+        }
+    }
+    #[test]
+    const fn pairing_channels_and_keys() {
+        let msg = IrcMsg::parse(b"JOIN #a,#b,&c key1,,key3");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let join = JoinMsg::from_msg(&msg);
+            assert!(join.is_some());
+            if let Some(join) = join {
+                let pairs = join.channels_and_keys();
+                assert!(pairs.count() == 3 && !join.is_part_all());
+                let pairs = pairs.next_channel();
+                assert!(matches!(pairs, Some((_, Some(_), _))));
+                if let Some((channel, Some(key), pairs)) = pairs {
+                    assert!(is_identical(channel.as_bytes(), b"#a") && is_identical(key.as_bytes(), b"key1"));
+                    let pairs = pairs.next_channel();
+                    assert!(matches!(pairs, Some((_, None, _))));
+                    if let Some((channel, None, pairs)) = pairs {
+                        assert!(is_identical(channel.as_bytes(), b"#b"));
+                        let pairs = pairs.next_channel();
+                        assert!(matches!(pairs, Some((_, Some(_), _))));
+                        if let Some((channel, Some(key), pairs)) = pairs {
+                            assert!(is_identical(channel.as_bytes(), b"&c") && is_identical(key.as_bytes(), b"key3"));
+                            assert!(pairs.next_channel().is_none());
+                        }
+                    }
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b"JOIN #a,#b key");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let join = JoinMsg::from_msg(&msg);
+            assert!(join.is_some());
+            if let Some(join) = join {
+                let pairs = join.channels_and_keys().next_channel();
+                assert!(matches!(pairs, Some((_, Some(_), _))));
+                if let Some((_, Some(_), pairs)) = pairs {
+                    let pairs = pairs.next_channel();
+                    assert!(matches!(pairs, Some((_, None, _))));
+                    if let Some((channel, None, pairs)) = pairs {
+                        assert!(is_identical(channel.as_bytes(), b"#b") && pairs.count() == 0);
+                    }
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b"JOIN 0");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let join = JoinMsg::from_msg(&msg);
+            assert!(join.is_some());
+            if let Some(join) = join {assert!(join.is_part_all());}
+        }
+    }
+}
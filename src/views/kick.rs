@@ -0,0 +1,123 @@
+//! Methods for reading `KICK` messages.
+//!
+//! ## Purpose
+//!
+//! A channel operator sends `KICK` to remove users from a channel. The IRC server sends it with the operator as the
+//! [`Source`] followed by the channel, the kicked users and an optional comment.
+//! The kicked users are a comma separated list although IRC servers send a separate `KICK` for each user.
+
+use crate::{ContentType, IrcMsg, casemapping::IrcCaseMapping, command::CommandKind, parameters::CommaList,
+    source::Source};
+
+/// A view over a `KICK` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KickMsg<'msg> {
+    source: Option<Source<'msg>>,
+    channel: ContentType<'msg>,
+    users: ContentType<'msg>,
+    comment: Option<ContentType<'msg>>,
+}
+
+impl<'msg> KickMsg<'msg> {
+    /// Generates a [`KickMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `KICK` command with a channel and user.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Kick)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        let Some(users) = params.extract_specific(1) else {return None;};
+        Some(Self{source: msg.source(), channel: params.extract_first(), users, comment: params.extract_specific(2)})
+    }
+    /// Returns the [`Source`] of the user who kicked.
+    ///
+    /// Returns `None` for a [`KickMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the user who kicked.
+    #[must_use]
+    pub const fn kicker(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Returns the channel.
+    #[must_use]
+    pub const fn channel(&self) -> ContentType<'msg> {
+        self.channel
+    }
+    /// Returns the kicked users as they appear in the message.
+    #[must_use]
+    pub const fn users(&self) -> ContentType<'msg> {
+        self.users
+    }
+    /// Returns a [`CommaList`] over the kicked users.
+    #[must_use]
+    pub const fn user_list(&self) -> CommaList<'msg> {
+        CommaList::new(self.users)
+    }
+    /// Check whether `nick` is one of the kicked users according to the [`IrcCaseMapping`].
+    #[must_use]
+    pub const fn is_kicked(&self, nick: &[u8], casemapping: IrcCaseMapping) -> bool {
+        let mut users = self.user_list();
+        while let Some((user, rest)) = users.next_item() {
+            if casemapping.is_equivalent(user.as_bytes(), nick) {return true;}
+            users = rest;
+        }
+        false
+    }
+    /// Returns the comment if present.
+    #[must_use]
+    pub const fn comment(&self) -> Option<ContentType<'msg>> {
+        self.comment
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::KickMsg;
+    use crate::{IrcMsg, casemapping::IrcCaseMapping, is_identical};
+    #[test]
+    const fn reading_kick() {
+        let msg = IrcMsg::parse(b":dan!d@localhost KICK #chan bob :Behave");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let kick = KickMsg::from_msg(&msg);
+            assert!(kick.is_some());
+            if let Some(kick) = kick {
+                let pair = (kick.kicker(), kick.comment());
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(kicker), Some(comment)) = pair {
+                    assert!(is_identical(kicker.as_bytes(), b"dan"));
+                    assert!(is_identical(kick.channel().as_bytes(), b"#chan"));
+                    assert!(is_identical(kick.users().as_bytes(), b"bob"));
+                    assert!(is_identical(comment.as_bytes(), b"Behave"));
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b"KICK #chan bob");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let kick = KickMsg::from_msg(&msg);
+            assert!(kick.is_some());
+            if let Some(kick) = kick {assert!(kick.source().is_none() && kick.comment().is_none());}
+        }
+        let msg = IrcMsg::parse(b"KICK #chan Bob,alice");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let kick = KickMsg::from_msg(&msg);
+            assert!(kick.is_some());
+            if let Some(kick) = kick {
+                assert!(kick.user_list().count() == 2 && kick.is_kicked(b"bob", IrcCaseMapping::Rfc1459));
+                assert!(kick.is_kicked(b"alice", IrcCaseMapping::Ascii));
+                assert!(!kick.is_kicked(b"dan", IrcCaseMapping::Ascii));
+            }
+        }
+        let msg = IrcMsg::parse(b"INVITE bob #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(KickMsg::from_msg(&msg).is_none());}
+    }
+}
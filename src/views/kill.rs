@@ -0,0 +1,133 @@
+//! Methods for reading `KILL` messages.
+//!
+//! ## Purpose
+//!
+//! An IRC operator or IRC server sends `KILL` to forcibly disconnect a user. The first parameter is the nick of the
+//! killed user and the second a comment. Traditionally the comment starts with the path of servers and the
+//! operator the `KILL` passed through separated by `!` followed by the reason in parentheses such as
+//! `irc.hub.net!irc.leaf.net!oper (Flooding)`.
+
+use crate::{ContentType, IrcMsg, command::CommandKind, source::Source};
+
+/// A view over a `KILL` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KillMsg<'msg> {
+    source: Option<Source<'msg>>,
+    target: ContentType<'msg>,
+    comment: ContentType<'msg>,
+}
+
+impl<'msg> KillMsg<'msg> {
+    /// Generates a [`KillMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `KILL` command with a nick and comment.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Kill)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        let Some(comment) = params.extract_specific(1) else {return None;};
+        Some(Self{source: msg.source(), target: params.extract_first(), comment})
+    }
+    /// Returns the [`Source`] of the IRC operator or IRC server who sent the `KILL`.
+    ///
+    /// Returns `None` for a [`KillMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the killed user.
+    #[must_use]
+    pub const fn target(&self) -> ContentType<'msg> {
+        self.target
+    }
+    /// Returns the full comment.
+    #[must_use]
+    pub const fn comment(&self) -> ContentType<'msg> {
+        self.comment
+    }
+    /// Returns the server path prefix of the comment if present.
+    ///
+    /// The path is the first word of the comment if it contains a `!`.
+    #[must_use]
+    pub const fn path(&self) -> Option<ContentType<'msg>> {
+        match self.split_path() {
+            Some((path, _)) => Some(ContentType::new(path)),
+            None => None,
+        }
+    }
+    /// Returns the reason of the comment without the server path prefix and surrounding parentheses.
+    #[must_use]
+    pub const fn reason(&self) -> ContentType<'msg> {
+        let reason = match self.split_path() {
+            Some((_, reason)) => reason,
+            None => self.comment.as_bytes(),
+        };
+        match reason {
+            [b'(', inner @ .., b')'] => ContentType::new(inner),
+            _ => ContentType::new(reason),
+        }
+    }
+    const fn split_path(&self) -> Option<(&'msg [u8], &'msg [u8])> {
+        let comment = self.comment.as_bytes();
+        let (mut index, mut bang) = (0, false);
+        while index < comment.len() {
+            match comment[index] {
+                b'!' => bang = true,
+                b' ' if bang => {
+                    let (path, rest) = comment.split_at(index);
+                    let (_, reason) = rest.split_at(1);
+                    return Some((path, reason));
+                },
+                b' ' => return None,
+                _ => {},
+            }
+            index += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::KillMsg;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_kill() {
+        let input = b":oper!o@localhost KILL dan :irc.hub.net!irc.leaf.net!oper (Flooding)";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let kill = KillMsg::from_msg(&msg);
+            assert!(kill.is_some());
+            if let Some(kill) = kill {
+                let path = kill.path();
+                assert!(path.is_some());
+                if let Some(path) = path {
+                    assert!(is_identical(kill.target().as_bytes(), b"dan") && kill.source().is_some());
+                    assert!(is_identical(path.as_bytes(), b"irc.hub.net!irc.leaf.net!oper"));
+                }
+                assert!(is_identical(kill.reason().as_bytes(), b"Flooding"));
+            }
+        }
+        let msg = IrcMsg::parse(b"KILL dan :Go away now");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let kill = KillMsg::from_msg(&msg);
+            assert!(kill.is_some());
+            if let Some(kill) = kill {
+                assert!(kill.path().is_none() && is_identical(kill.reason().as_bytes(), b"Go away now"));
+                assert!(is_identical(kill.comment().as_bytes(), b"Go away now"));
+            }
+        }
+        let msg = IrcMsg::parse(b"KILL dan :(Spam)");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let kill = KillMsg::from_msg(&msg);
+            assert!(kill.is_some());
+            if let Some(kill) = kill {assert!(is_identical(kill.reason().as_bytes(), b"Spam"));}
+        }
+        let msg = IrcMsg::parse(b"KICK #chan dan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(KillMsg::from_msg(&msg).is_none());}
+    }
+}
@@ -0,0 +1,117 @@
+//! Methods for reading `LIST` replies.
+//!
+//! ## Purpose
+//!
+//! An IRC server answers `LIST` with an `RPL_LIST` (`322`) for each visible channel naming its user count and topic.
+//! The entries may be preceded by `RPL_LISTSTART` (`321`) although it is deprecated and are always followed by
+//! `RPL_LISTEND` (`323`).
+
+use crate::{ContentType, IrcMsg, parse_u32};
+
+/// The start or end of the replies to `LIST`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ListMarker {
+    /// `RPL_LISTSTART` (`321`) precedes the entries.
+    Start,
+    /// `RPL_LISTEND` (`323`) follows the entries.
+    End,
+}
+
+impl ListMarker {
+    /// Generates a [`ListMarker`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_LISTSTART` (`321`) or `RPL_LISTEND` (`323`) numeric.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg) -> Option<Self> {
+        match msg.command().as_numeric_u16() {
+            Some(321) => Some(Self::Start),
+            Some(323) => Some(Self::End),
+            _ => None,
+        }
+    }
+}
+
+/// A view over an `RPL_LIST` (`322`) numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ListEntry<'msg> {
+    client: ContentType<'msg>,
+    channel: ContentType<'msg>,
+    users: ContentType<'msg>,
+    topic: ContentType<'msg>,
+}
+
+impl<'msg> ListEntry<'msg> {
+    /// Generates a [`ListEntry`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_LIST` (`322`) numeric with enough parameters.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(322) = msg.command().as_numeric_u16() else {return None;};
+        let Some(params) = msg.parameters() else {return None;};
+        let (Some(channel), Some(users)) = (params.extract_specific(1), params.extract_specific(2)) else {
+            return None;
+        };
+        if params.count() < 4 {return None;}
+        Some(Self{client: params.extract_first(), channel, users, topic: params.extract_last()})
+    }
+    /// Returns the nickname of the client receiving the [`ListEntry`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the channel.
+    #[must_use]
+    pub const fn channel(&self) -> ContentType<'msg> {
+        self.channel
+    }
+    /// Returns the amount of visible users in the channel.
+    ///
+    /// Returns `None` if the user count is not a number.
+    #[must_use]
+    pub const fn users(&self) -> Option<u32> {
+        parse_u32(self.users.as_bytes())
+    }
+    /// Returns the topic of the channel.
+    #[must_use]
+    pub const fn topic(&self) -> ContentType<'msg> {
+        self.topic
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{ListEntry, ListMarker};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_list() {
+        let msg = IrcMsg::parse(b":irc.host 321 dan Channel :Users  Name");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            assert!(matches!(ListMarker::from_msg(&msg), Some(ListMarker::Start)));
+            assert!(ListEntry::from_msg(&msg).is_none());
+        }
+        let msg = IrcMsg::parse(b":irc.host 322 dan #chan 42 :Welcome to #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let entry = ListEntry::from_msg(&msg);
+            assert!(entry.is_some());
+            if let Some(entry) = entry {
+                assert!(is_identical(entry.client().as_bytes(), b"dan"));
+                assert!(is_identical(entry.channel().as_bytes(), b"#chan"));
+                assert!(matches!(entry.users(), Some(42)));
+                assert!(is_identical(entry.topic().as_bytes(), b"Welcome to #chan"));
+            }
+            assert!(ListMarker::from_msg(&msg).is_none());
+        }
+        let msg = IrcMsg::parse(b":irc.host 322 dan #chan many :");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let entry = ListEntry::from_msg(&msg);
+            assert!(entry.is_some());
+            if let Some(entry) = entry {assert!(entry.users().is_none() && entry.topic().as_bytes().is_empty());}
+        }
+        let msg = IrcMsg::parse(b":irc.host 323 dan :End of /LIST");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(ListMarker::from_msg(&msg), Some(ListMarker::End)));}
+    }
+}
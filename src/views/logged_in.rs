@@ -0,0 +1,113 @@
+//! Methods for reading `RPL_LOGGEDIN` (`900`) and `RPL_LOGGEDOUT` (`901`) numerics.
+//!
+//! ## Purpose
+//!
+//! After [SASL](crate::sasl) authentication or a later change of account the IRC server sends `RPL_LOGGEDIN` with
+//! the account name the client is now logged into or `RPL_LOGGEDOUT` once it is logged out.
+
+use crate::{ContentType, IrcMsg};
+
+/// A view over an `RPL_LOGGEDIN` (`900`) or `RPL_LOGGEDOUT` (`901`) numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AccountLogin<'msg> {
+    client: ContentType<'msg>,
+    mask: ContentType<'msg>,
+    account: Option<ContentType<'msg>>,
+    text: ContentType<'msg>,
+}
+
+impl<'msg> AccountLogin<'msg> {
+    /// Generates an [`AccountLogin`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_LOGGEDIN` (`900`) or `RPL_LOGGEDOUT` (`901`) numeric with
+    /// enough parameters.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(params) = msg.parameters() else {return None;};
+        let (Some(client), Some(mask)) = (params.extract_specific(0), params.extract_specific(1)) else {return None;};
+        match msg.command().as_numeric_u16() {
+            Some(900) if params.count() >= 4 => {
+                let Some(account) = params.extract_specific(2) else {return None;};
+                Some(Self{client, mask, account: Some(account), text: params.extract_last()})
+            },
+            Some(901) if params.count() >= 3 => Some(Self{client, mask, account: None, text: params.extract_last()}),
+            _ => None,
+        }
+    }
+    /// Returns the nickname of the client.
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the full `nick!user@host` mask of the client.
+    #[must_use]
+    pub const fn mask(&self) -> ContentType<'msg> {
+        self.mask
+    }
+    /// Returns the nickname part of the mask.
+    #[must_use]
+    pub const fn nick(&self) -> &'msg [u8] {
+        let mask = self.mask.as_bytes();
+        let mut index = 0;
+        while index < mask.len() {
+            if mask[index] == b'!' {
+                let (nick, _) = mask.split_at(index);
+                return nick;
+            }
+            index += 1;
+        }
+        mask
+    }
+    /// Returns the account name logged into or `None` if logged out.
+    #[must_use]
+    pub const fn account(&self) -> Option<ContentType<'msg>> {
+        self.account
+    }
+    /// Check whether the client is now logged in.
+    #[must_use]
+    pub const fn is_logged_in(&self) -> bool {
+        self.account.is_some()
+    }
+    /// Returns the human readable text.
+    #[must_use]
+    pub const fn text(&self) -> ContentType<'msg> {
+        self.text
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::AccountLogin;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_account_login() {
+        let input = b":irc.example.com 900 dan dan!d@localhost dan_account :You are now logged in as dan_account";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let login = AccountLogin::from_msg(&msg);
+            assert!(login.is_some());
+            if let Some(login) = login {
+                assert!(login.is_logged_in() && is_identical(login.nick(), b"dan"));
+                assert!(is_identical(login.mask().as_bytes(), b"dan!d@localhost"));
+                let account = login.account();
+                assert!(account.is_some());
+                if let Some(account) = account {assert!(is_identical(account.as_bytes(), b"dan_account"));}
+            }
+        }
+        let input = b":irc.example.com 901 dan dan!d@localhost :You are now logged out";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let logout = AccountLogin::from_msg(&msg);
+            assert!(logout.is_some());
+            if let Some(logout) = logout {
+                assert!(!logout.is_logged_in() && is_identical(logout.client().as_bytes(), b"dan"));
+                assert!(is_identical(logout.text().as_bytes(), b"You are now logged out"));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com 903 dan :SASL authentication successful");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(AccountLogin::from_msg(&msg).is_none());}
+    }
+}
@@ -0,0 +1,237 @@
+//! Methods for reading `LUSERS` replies.
+//!
+//! ## Purpose
+//!
+//! An IRC server sends the `LUSERS` replies during registration and in reply to `LUSERS` to describe the size of
+//! the network. Some counts are parameters of their own while others are only embedded in the human readable text
+//! such as `There are 42 users and 7 invisible on 3 servers`. A [`LusersReply`] reads the counts from wherever the
+//! numeric places them.
+
+use crate::{ContentType, IrcMsg, parameters::Parameters, parse_u32};
+
+/// The kind of a [`LusersReply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LusersKind {
+    /// `RPL_LUSERCLIENT` (`251`) the amount of users, invisible users and servers.
+    Client,
+    /// `RPL_LUSEROP` (`252`) the amount of IRC operators online.
+    Operators,
+    /// `RPL_LUSERUNKNOWN` (`253`) the amount of connections that have not completed registration.
+    Unknown,
+    /// `RPL_LUSERCHANNELS` (`254`) the amount of channels.
+    Channels,
+    /// `RPL_LUSERME` (`255`) the amount of clients and servers connected to this IRC server.
+    Me,
+    /// `RPL_LOCALUSERS` (`265`) the current and maximum amount of users on this IRC server.
+    LocalUsers,
+    /// `RPL_GLOBALUSERS` (`266`) the current and maximum amount of users on the network.
+    GlobalUsers,
+}
+
+impl LusersKind {
+    /// Generates a [`LusersKind`] from a numeric.
+    #[must_use]
+    pub const fn from_numeric(numeric: u16) -> Option<Self> {
+        match numeric {
+            251 => Some(Self::Client),
+            252 => Some(Self::Operators),
+            253 => Some(Self::Unknown),
+            254 => Some(Self::Channels),
+            255 => Some(Self::Me),
+            265 => Some(Self::LocalUsers),
+            266 => Some(Self::GlobalUsers),
+            _ => None,
+        }
+    }
+}
+
+/// A view over one of the `LUSERS` numeric [`IrcMsg`]s.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LusersReply<'msg> {
+    kind: LusersKind,
+    params: Parameters<'msg>,
+}
+
+impl<'msg> LusersReply<'msg> {
+    /// Generates a [`LusersReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not one of the `LUSERS` numerics.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(numeric) = msg.command().as_numeric_u16() else {return None;};
+        let Some(kind) = LusersKind::from_numeric(numeric) else {return None;};
+        let Some(params) = msg.parameters() else {return None;};
+        Some(Self{kind, params})
+    }
+    /// Returns the [`LusersKind`].
+    #[must_use]
+    pub const fn kind(&self) -> LusersKind {
+        self.kind
+    }
+    /// Returns the nickname of the client receiving the [`LusersReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.params.extract_first()
+    }
+    /// Returns the human readable text.
+    #[must_use]
+    pub const fn text(&self) -> ContentType<'msg> {
+        self.params.extract_last()
+    }
+    /// Returns the count of an `RPL_LUSEROP` (`252`), `RPL_LUSERUNKNOWN` (`253`) or `RPL_LUSERCHANNELS` (`254`).
+    #[must_use]
+    pub const fn count(&self) -> Option<u32> {
+        match self.kind {
+            LusersKind::Operators | LusersKind::Unknown | LusersKind::Channels => self.param_number(1),
+            _ => None,
+        }
+    }
+    /// Returns the amount of visible users of an `RPL_LUSERCLIENT` (`251`).
+    #[must_use]
+    pub const fn users(&self) -> Option<u32> {
+        if matches!(self.kind, LusersKind::Client) {self.text_number(0)} else {None}
+    }
+    /// Returns the amount of invisible users of an `RPL_LUSERCLIENT` (`251`).
+    #[must_use]
+    pub const fn invisible(&self) -> Option<u32> {
+        if matches!(self.kind, LusersKind::Client) {self.text_number(1)} else {None}
+    }
+    /// Returns the amount of clients connected to this IRC server of an `RPL_LUSERME` (`255`).
+    #[must_use]
+    pub const fn clients(&self) -> Option<u32> {
+        if matches!(self.kind, LusersKind::Me) {self.text_number(0)} else {None}
+    }
+    /// Returns the amount of servers of an `RPL_LUSERCLIENT` (`251`) or `RPL_LUSERME` (`255`).
+    #[must_use]
+    pub const fn servers(&self) -> Option<u32> {
+        match self.kind {
+            LusersKind::Client => self.text_number(2),
+            LusersKind::Me => self.text_number(1),
+            _ => None,
+        }
+    }
+    /// Returns the current amount of users of an `RPL_LOCALUSERS` (`265`) or `RPL_GLOBALUSERS` (`266`).
+    ///
+    /// The count is read from the parameters if present and otherwise from the text.
+    #[must_use]
+    pub const fn current(&self) -> Option<u32> {
+        match self.kind {
+            LusersKind::LocalUsers | LusersKind::GlobalUsers if self.params.count() >= 4 => self.param_number(1),
+            LusersKind::LocalUsers | LusersKind::GlobalUsers => self.text_number(0),
+            _ => None,
+        }
+    }
+    /// Returns the maximum amount of users of an `RPL_LOCALUSERS` (`265`) or `RPL_GLOBALUSERS` (`266`).
+    ///
+    /// The count is read from the parameters if present and otherwise from the text.
+    #[must_use]
+    pub const fn max(&self) -> Option<u32> {
+        match self.kind {
+            LusersKind::LocalUsers | LusersKind::GlobalUsers if self.params.count() >= 4 => self.param_number(2),
+            LusersKind::LocalUsers | LusersKind::GlobalUsers => self.text_number(1),
+            _ => None,
+        }
+    }
+    const fn param_number(&self, index: usize) -> Option<u32> {
+        if index + 1 >= self.params.count() {return None;}
+        match self.params.extract_specific(index) {
+            Some(param) => parse_u32(param.as_bytes()),
+            None => None,
+        }
+    }
+    // Returns the number at the `position` among the runs of ascii digits in the text.
+    const fn text_number(&self, position: usize) -> Option<u32> {
+        let text = self.text().as_bytes();
+        let (mut index, mut found) = (0, 0);
+        while index < text.len() {
+            if text[index].is_ascii_digit() {
+                let start = index;
+                while index < text.len() && text[index].is_ascii_digit() {index += 1;}
+                if found == position {
+                    let (before, _) = text.split_at(index);
+                    let (_, digits) = before.split_at(start);
+                    return parse_u32(digits);
+                }
+                found += 1;
+            } else {
+                index += 1;
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{LusersKind, LusersReply};
+    use crate::IrcMsg;
+    #[test]
+    const fn reading_lusers() {
+        let input = b":irc.host 251 dan :There are 42 users and 7 invisible on 3 servers";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = LusersReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), LusersKind::Client) && matches!(reply.users(), Some(42)));
+                assert!(matches!(reply.invisible(), Some(7)) && matches!(reply.servers(), Some(3)));
+                assert!(reply.count().is_none() && reply.clients().is_none() && reply.current().is_none());
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 252 dan 5 :operator(s) online");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = LusersReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), LusersKind::Operators) && matches!(reply.count(), Some(5)));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 254 dan 1234 :channels formed");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = LusersReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {assert!(matches!(reply.count(), Some(1234)) && reply.users().is_none());}
+        }
+        let msg = IrcMsg::parse(b":irc.host 255 dan :I have 12 clients and 1 servers");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = LusersReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.clients(), Some(12)) && matches!(reply.servers(), Some(1)));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 265 dan 12 20 :Current local users 12, max 20");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = LusersReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.current(), Some(12)) && matches!(reply.max(), Some(20)));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 266 dan :Current global users: 345  Max: 678");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = LusersReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), LusersKind::GlobalUsers));
+                assert!(matches!(reply.current(), Some(345)) && matches!(reply.max(), Some(678)));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 266 dan :No users");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = LusersReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {assert!(reply.current().is_none() && reply.max().is_none());}
+        }
+        let msg = IrcMsg::parse(b":irc.host 250 dan :Highest connection count: 9");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(LusersReply::from_msg(&msg).is_none());}
+    }
+}
@@ -0,0 +1,208 @@
+//! Methods for reading the ban, exception and invite exception lists of a channel.
+//!
+//! ## Purpose
+//!
+//! Querying a list mode of a channel such as `MODE #chan b` is answered with an entry numeric for each mask on the
+//! list followed by an end numeric. Most IRC servers add who set the mask and the Unix timestamp of when.
+//!
+//! | Mode | Entry                    | End                           |
+//! |------|--------------------------|-------------------------------|
+//! | `b`  | `RPL_BANLIST` (`367`)    | `RPL_ENDOFBANLIST` (`368`)    |
+//! | `e`  | `RPL_EXCEPTLIST` (`348`) | `RPL_ENDOFEXCEPTLIST` (`349`) |
+//! | `I`  | `RPL_INVITELIST` (`346`) | `RPL_ENDOFINVITELIST` (`347`) |
+
+use crate::{ContentType, IrcMsg};
+
+/// The list a [`MaskListEntry`] or [`MaskListEnd`] belongs to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MaskListKind {
+    /// The ban list of mode `b`.
+    Ban,
+    /// The ban exception list of mode `e`.
+    Except,
+    /// The invite exception list of mode `I`.
+    Invex,
+}
+
+impl MaskListKind {
+    /// Returns the channel mode of the list.
+    #[must_use]
+    pub const fn mode(&self) -> u8 {
+        match self {
+            Self::Ban => b'b',
+            Self::Except => b'e',
+            Self::Invex => b'I',
+        }
+    }
+}
+
+/// A view over an `RPL_BANLIST` (`367`), `RPL_EXCEPTLIST` (`348`) or `RPL_INVITELIST` (`346`) numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MaskListEntry<'msg> {
+    kind: MaskListKind,
+    client: ContentType<'msg>,
+    channel: ContentType<'msg>,
+    mask: ContentType<'msg>,
+    setter: Option<ContentType<'msg>>,
+    set_at: Option<u64>,
+}
+
+impl<'msg> MaskListEntry<'msg> {
+    /// Generates a [`MaskListEntry`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_BANLIST` (`367`), `RPL_EXCEPTLIST` (`348`) or
+    /// `RPL_INVITELIST` (`346`) numeric with a channel and mask.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let kind = match msg.command().as_numeric_u16() {
+            Some(367) => MaskListKind::Ban,
+            Some(348) => MaskListKind::Except,
+            Some(346) => MaskListKind::Invex,
+            _ => return None,
+        };
+        let Some(params) = msg.parameters() else {return None;};
+        let (Some(channel), Some(mask)) = (params.extract_specific(1), params.extract_specific(2)) else {
+            return None;
+        };
+        let set_at = match params.extract_specific(4) {
+            Some(set_at) => set_at.to_u64(),
+            None => None,
+        };
+        Some(Self{kind, client: params.extract_first(), channel, mask, setter: params.extract_specific(3), set_at})
+    }
+    /// Returns the [`MaskListKind`].
+    #[must_use]
+    pub const fn kind(&self) -> MaskListKind {
+        self.kind
+    }
+    /// Returns the nickname of the client receiving the [`MaskListEntry`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the channel.
+    #[must_use]
+    pub const fn channel(&self) -> ContentType<'msg> {
+        self.channel
+    }
+    /// Returns the mask.
+    #[must_use]
+    pub const fn mask(&self) -> ContentType<'msg> {
+        self.mask
+    }
+    /// Returns who set the mask if present.
+    ///
+    /// Depending on the IRC server this is either a nickname, a full `nick!user@host` mask or a server name.
+    #[must_use]
+    pub const fn setter(&self) -> Option<ContentType<'msg>> {
+        self.setter
+    }
+    /// Returns the Unix timestamp of when the mask was set if present.
+    #[must_use]
+    pub const fn set_at(&self) -> Option<u64> {
+        self.set_at
+    }
+}
+
+/// A view over an `RPL_ENDOFBANLIST` (`368`), `RPL_ENDOFEXCEPTLIST` (`349`) or `RPL_ENDOFINVITELIST` (`347`)
+/// numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MaskListEnd<'msg> {
+    kind: MaskListKind,
+    channel: ContentType<'msg>,
+}
+
+impl<'msg> MaskListEnd<'msg> {
+    /// Generates a [`MaskListEnd`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_ENDOFBANLIST` (`368`), `RPL_ENDOFEXCEPTLIST` (`349`) or
+    /// `RPL_ENDOFINVITELIST` (`347`) numeric with a channel.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let kind = match msg.command().as_numeric_u16() {
+            Some(368) => MaskListKind::Ban,
+            Some(349) => MaskListKind::Except,
+            Some(347) => MaskListKind::Invex,
+            _ => return None,
+        };
+        let Some(params) = msg.parameters() else {return None;};
+        let Some(channel) = params.extract_specific(1) else {return None;};
+        Some(Self{kind, channel})
+    }
+    /// Returns the [`MaskListKind`].
+    #[must_use]
+    pub const fn kind(&self) -> MaskListKind {
+        self.kind
+    }
+    /// Returns the channel.
+    #[must_use]
+    pub const fn channel(&self) -> ContentType<'msg> {
+        self.channel
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{MaskListEnd, MaskListEntry, MaskListKind};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_mask_lists() {
+        let msg = IrcMsg::parse(b":irc.host 367 dan #chan *!*@spam.host bob!b@host 1700000000");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let entry = MaskListEntry::from_msg(&msg);
+            assert!(entry.is_some());
+            if let Some(entry) = entry {
+                let setter = entry.setter();
+                assert!(setter.is_some());
+                if let Some(setter) = setter {
+                    assert!(matches!(entry.kind(), MaskListKind::Ban) && entry.kind().mode() == b'b');
+                    assert!(is_identical(entry.client().as_bytes(), b"dan"));
+                    assert!(is_identical(entry.channel().as_bytes(), b"#chan"));
+                    assert!(is_identical(entry.mask().as_bytes(), b"*!*@spam.host"));
+                    assert!(is_identical(setter.as_bytes(), b"bob!b@host"));
+                    assert!(matches!(entry.set_at(), Some(1_700_000_000)));
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 348 dan #chan *!*@good.host");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let entry = MaskListEntry::from_msg(&msg);
+            assert!(entry.is_some());
+            if let Some(entry) = entry {
+                assert!(matches!(entry.kind(), MaskListKind::Except) && entry.kind().mode() == b'e');
+                assert!(entry.setter().is_none() && entry.set_at().is_none());
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 346 dan #chan *!*@friend.host");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let entry = MaskListEntry::from_msg(&msg);
+            assert!(entry.is_some());
+            if let Some(entry) = entry {
+                assert!(matches!(entry.kind(), MaskListKind::Invex) && entry.kind().mode() == b'I');
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 368 dan #chan :End of channel ban list");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let end = MaskListEnd::from_msg(&msg);
+            assert!(end.is_some());
+            if let Some(end) = end {
+                assert!(matches!(end.kind(), MaskListKind::Ban) && is_identical(end.channel().as_bytes(), b"#chan"));
+            }
+            assert!(MaskListEntry::from_msg(&msg).is_none());
+        }
+        let msg = IrcMsg::parse(b":irc.host 347 dan #chan :End of invite list");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            assert!(matches!(MaskListEnd::from_msg(&msg), Some(MaskListEnd{kind: MaskListKind::Invex, ..})));
+        }
+        let msg = IrcMsg::parse(b":irc.host 349 dan #chan :End of exception list");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            assert!(matches!(MaskListEnd::from_msg(&msg), Some(MaskListEnd{kind: MaskListKind::Except, ..})));
+        }
+    }
+}
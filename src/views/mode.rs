@@ -0,0 +1,142 @@
+//! Methods for reading `MODE` messages.
+//!
+//! ## Purpose
+//!
+//! `MODE` queries or changes the modes of a channel or user. The target is the first parameter followed by an
+//! optional modestring and the arguments of the modes that take one.
+//! [`ModeMsg::changes`] reads the channel mode changes through the [`mode`](crate::mode) module.
+
+use crate::{ContentType, IrcMsg, command::CommandKind, isupport::{ChanModes, Prefix}, mode::ModeChanges,
+    parameters::Parameters, source::Source};
+
+/// A view over a `MODE` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ModeMsg<'msg> {
+    source: Option<Source<'msg>>,
+    params: Parameters<'msg>,
+}
+
+impl<'msg> ModeMsg<'msg> {
+    /// Generates a [`ModeMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `MODE` command with a target.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Mode)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        Some(Self{source: msg.source(), params})
+    }
+    /// Returns the [`Source`] of the user or server who changed the modes.
+    ///
+    /// Returns `None` for a [`ModeMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the channel or nickname whose modes are queried or changed.
+    #[must_use]
+    pub const fn target(&self) -> ContentType<'msg> {
+        self.params.extract_first()
+    }
+    /// Returns the modestring such as `+o-v` if present.
+    ///
+    /// A [`ModeMsg`] without a modestring queries the current modes.
+    #[must_use]
+    pub const fn modestring(&self) -> Option<ContentType<'msg>> {
+        self.params.extract_specific(1)
+    }
+    /// Returns the amount of arguments following the modestring.
+    #[must_use]
+    pub const fn argument_count(&self) -> usize {
+        self.params.count().saturating_sub(2)
+    }
+    /// Returns the argument following the modestring at the specified index.
+    ///
+    /// Index starts at 0. If out of bounds it returns `None`.
+    #[must_use]
+    pub const fn argument(&self, index: usize) -> Option<ContentType<'msg>> {
+        if index >= self.argument_count() {return None;}
+        self.params.extract_specific(index + 2)
+    }
+    /// Generates [`ModeChanges`] for reading each channel mode change alongside its argument.
+    ///
+    /// Returns `None` if the [`ModeMsg`] has no modestring.
+    #[must_use]
+    pub const fn changes<'modes>(&self, chanmodes: ChanModes<'modes>, prefix: Prefix<'modes>)
+    -> Option<ModeChanges<'msg, 'modes>> {
+        let Some((_, arguments)) = self.params.cursor().next_param() else {return None;};
+        let Some((modestring, arguments)) = arguments.next_param() else {return None;};
+        Some(ModeChanges::new(modestring, arguments, chanmodes, prefix))
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::ModeMsg;
+    use crate::{IrcMsg, is_identical, isupport::{ChanModes, Prefix}};
+    #[test]
+    const fn reading_mode() {
+        let msg = IrcMsg::parse(b":dan!d@localhost MODE #chan +ov bob alice");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let mode = ModeMsg::from_msg(&msg);
+            assert!(mode.is_some());
+            if let Some(mode) = mode {
+                let pair = (mode.modestring(), mode.argument(1));
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(modestring), Some(second)) = pair {
+                    assert!(is_identical(mode.target().as_bytes(), b"#chan"));
+                    assert!(is_identical(modestring.as_bytes(), b"+ov"));
+                    assert!(mode.argument_count() == 2 && is_identical(second.as_bytes(), b"alice"));
+                    assert!(mode.argument(2).is_none());
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b"MODE #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let mode = ModeMsg::from_msg(&msg);
+            assert!(mode.is_some());
+            if let Some(mode) = mode {
+                assert!(mode.modestring().is_none() && mode.argument_count() == 0 && mode.source().is_none());
+            }
+        }
+        let msg = IrcMsg::parse(b"TOPIC #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(ModeMsg::from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn reading_mode_changes() {
+        let chanmodes = ChanModes::parse(b"beI,k,l,imnpst");
+        assert!(chanmodes.is_ok());
+        if let Ok(chanmodes) = chanmodes {
+            let msg = IrcMsg::parse(b"MODE #chan +k key");
+            assert!(msg.is_ok());
+            if let Ok(msg) = msg {
+                let mode = ModeMsg::from_msg(&msg);
+                assert!(mode.is_some());
+                if let Some(mode) = mode {
+                    let changes = mode.changes(chanmodes, Prefix::DEFAULT);
+                    assert!(changes.is_some());
+                    if let Some(changes) = changes {
+                        let changes = changes.next_change();
+                        assert!(matches!(changes, Ok(Some((_, _)))));
+                        if let Ok(Some((key, changes))) = changes {
+                            assert!(key.mode() == b'k' && matches!(changes.next_change(), Ok(None)));
+                            let argument = key.argument();
+                            assert!(argument.is_some());
+                            if let Some(argument) = argument {assert!(is_identical(argument.as_bytes(), b"key"));}
+                        }
+                    }
+                }
+            }
+            let msg = IrcMsg::parse(b"MODE #chan");
+            assert!(msg.is_ok());
+            if let Ok(msg) = msg {
+                let mode = ModeMsg::from_msg(&msg);
+                assert!(mode.is_some());
+                if let Some(mode) = mode {assert!(mode.changes(chanmodes, Prefix::DEFAULT).is_none());}
+            }
+        }
+    }
+}
@@ -0,0 +1,286 @@
+//! Methods for reading `MONITOR` messages and the related numeric replies.
+//!
+//! ## Purpose
+//!
+//! An IRC client sends [`MONITOR`] to be notified when users connect or disconnect. The subcommand `+` adds and
+//! `-` removes a comma separated list of targets, `C` clears the list, `L` lists it and `S` requests the status of
+//! every target. The IRC server replies with `RPL_MONONLINE` (`730`), `RPL_MONOFFLINE` (`731`), `RPL_MONLIST`
+//! (`732`), `RPL_ENDOFMONLIST` (`733`) and `ERR_MONLISTFULL` (`734`).
+//!
+//! [`MONITOR`]: <https://ircv3.net/specs/extensions/monitor>
+
+use crate::{ContentType, IrcMsg, command::CommandKind, parameters::CommaList, parse_u32, source::Nickname};
+
+/// The subcommand of a `MONITOR` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MonitorSubcommand {
+    /// `+` adds targets to the list.
+    Add,
+    /// `-` removes targets from the list.
+    Remove,
+    /// `C` clears the list.
+    Clear,
+    /// `L` lists the targets.
+    List,
+    /// `S` requests the status of every target.
+    Status,
+}
+
+impl MonitorSubcommand {
+    /// Generates a [`MonitorSubcommand`] from a slice of bytes.
+    #[must_use]
+    pub const fn from_bytes(input: &[u8]) -> Option<Self> {
+        match input {
+            b"+" => Some(Self::Add),
+            b"-" => Some(Self::Remove),
+            b"C" | b"c" => Some(Self::Clear),
+            b"L" | b"l" => Some(Self::List),
+            b"S" | b"s" => Some(Self::Status),
+            _ => None,
+        }
+    }
+}
+
+/// A view over a `MONITOR` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MonitorMsg<'msg> {
+    subcommand: MonitorSubcommand,
+    targets: Option<ContentType<'msg>>,
+}
+
+impl<'msg> MonitorMsg<'msg> {
+    /// Generates a [`MonitorMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `MONITOR` command with a known subcommand or `+` and `-` are
+    /// missing their targets.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Monitor)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        let Some(subcommand) = MonitorSubcommand::from_bytes(params.extract_first().as_bytes()) else {return None;};
+        let targets = params.extract_specific(1);
+        if targets.is_none() && matches!(subcommand, MonitorSubcommand::Add | MonitorSubcommand::Remove) {
+            return None;
+        }
+        Some(Self{subcommand, targets})
+    }
+    /// Returns the [`MonitorSubcommand`].
+    #[must_use]
+    pub const fn subcommand(&self) -> MonitorSubcommand {
+        self.subcommand
+    }
+    /// Returns a [`CommaList`] for reading each target.
+    ///
+    /// The [`CommaList`] is empty for subcommands other than `+` and `-`.
+    #[must_use]
+    pub const fn targets(&self) -> CommaList<'msg> {
+        match self.targets {
+            Some(targets) => CommaList::new(targets),
+            None => CommaList::new(ContentType::StringSlice("")),
+        }
+    }
+}
+
+/// The kind of a [`MonitorReply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MonitorReplyKind {
+    /// `RPL_MONONLINE` (`730`) lists targets that are online.
+    Online,
+    /// `RPL_MONOFFLINE` (`731`) lists targets that are offline.
+    Offline,
+    /// `RPL_MONLIST` (`732`) lists the monitored targets.
+    List,
+    /// `RPL_ENDOFMONLIST` (`733`) ends the list.
+    EndOfList,
+    /// `ERR_MONLISTFULL` (`734`) lists targets that could not be added.
+    ListFull,
+}
+
+/// A view over an `RPL_MONONLINE` (`730`), `RPL_MONOFFLINE` (`731`), `RPL_MONLIST` (`732`), `RPL_ENDOFMONLIST`
+/// (`733`) or `ERR_MONLISTFULL` (`734`) numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MonitorReply<'msg> {
+    kind: MonitorReplyKind,
+    client: ContentType<'msg>,
+    limit: Option<u32>,
+    targets: Option<ContentType<'msg>>,
+}
+
+impl<'msg> MonitorReply<'msg> {
+    /// Generates a [`MonitorReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not one of the `MONITOR` numerics with enough parameters or the limit of
+    /// an `ERR_MONLISTFULL` (`734`) is not a number.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(numeric) = msg.command().as_numeric_u16() else {return None;};
+        let Some(params) = msg.parameters() else {return None;};
+        let client = params.extract_first();
+        let kind = match numeric {
+            730 => MonitorReplyKind::Online,
+            731 => MonitorReplyKind::Offline,
+            732 => MonitorReplyKind::List,
+            733 => return Some(Self{kind: MonitorReplyKind::EndOfList, client, limit: None, targets: None}),
+            734 => {
+                let (Some(limit), Some(targets)) = (params.extract_specific(1), params.extract_specific(2)) else {
+                    return None;
+                };
+                let Some(limit) = parse_u32(limit.as_bytes()) else {return None;};
+                return Some(Self{kind: MonitorReplyKind::ListFull, client, limit: Some(limit), targets: Some(targets)});
+            },
+            _ => return None,
+        };
+        if params.count() < 2 {return None;}
+        Some(Self{kind, client, limit: None, targets: Some(params.extract_last())})
+    }
+    /// Returns the [`MonitorReplyKind`].
+    #[must_use]
+    pub const fn kind(&self) -> MonitorReplyKind {
+        self.kind
+    }
+    /// Returns the nickname of the client receiving the [`MonitorReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the maximum amount of targets from an `ERR_MONLISTFULL` (`734`).
+    #[must_use]
+    pub const fn limit(&self) -> Option<u32> {
+        self.limit
+    }
+    /// Returns [`MonitorTargets`] for reading each target.
+    ///
+    /// There are no targets in an `RPL_ENDOFMONLIST` (`733`).
+    #[must_use]
+    pub const fn targets(&self) -> MonitorTargets<'msg> {
+        match self.targets {
+            Some(targets) => MonitorTargets(CommaList::new(targets)),
+            None => MonitorTargets(CommaList::new(ContentType::StringSlice(""))),
+        }
+    }
+}
+
+/// A cursor for reading the targets of a [`MonitorReply`] one at a time.
+///
+/// `RPL_MONONLINE` (`730`) targets may be a full `nick!user@host` mask while other targets are just a nick.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MonitorTargets<'msg>(CommaList<'msg>);
+
+impl<'msg> MonitorTargets<'msg> {
+    /// Returns the next target as a [`Nickname`] and the advanced [`MonitorTargets`].
+    ///
+    /// Returns `None` once all targets have been read.
+    #[must_use]
+    pub const fn next_target(self) -> Option<(Nickname<'msg>, Self)> {
+        match self.0.next_item() {
+            Some((target, rest)) => Some((Nickname::from_mask(target.as_bytes()), Self(rest))),
+            None => None,
+        }
+    }
+    /// Returns the amount of targets that have not been read yet.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.0.count()
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{MonitorMsg, MonitorReply, MonitorReplyKind, MonitorSubcommand};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_monitor() {
+        let msg = IrcMsg::parse(b"MONITOR + dan,bob");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let monitor = MonitorMsg::from_msg(&msg);
+            assert!(monitor.is_some());
+            if let Some(monitor) = monitor {
+                assert!(matches!(monitor.subcommand(), MonitorSubcommand::Add) && monitor.targets().count() == 2);
+                let first = monitor.targets().next_item();
+                assert!(matches!(first, Some((_, _))));
+                if let Some((first, _)) = first {assert!(is_identical(first.as_bytes(), b"dan"));}
+            }
+        }
+        let msg = IrcMsg::parse(b"MONITOR S");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let monitor = MonitorMsg::from_msg(&msg);
+            assert!(monitor.is_some());
+            if let Some(monitor) = monitor {
+                assert!(matches!(monitor.subcommand(), MonitorSubcommand::Status) && monitor.targets().count() == 0);
+            }
+        }
+        let msg = IrcMsg::parse(b"MONITOR -");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(MonitorMsg::from_msg(&msg).is_none());}
+        let msg = IrcMsg::parse(b"MONITOR X dan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(MonitorMsg::from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn reading_monitor_replies() {
+        let msg = IrcMsg::parse(b":irc.example.com 730 me :dan!d@localhost,bob!b@example.com");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = MonitorReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), MonitorReplyKind::Online));
+                assert!(is_identical(reply.client().as_bytes(), b"me"));
+                let targets = reply.targets();
+                assert!(targets.count() == 2);
+                let targets = targets.next_target();
+                assert!(matches!(targets, Some((_, _))));
+                if let Some((first, targets)) = targets {
+                    let host = first.host();
+                    assert!(host.is_some());
+                    if let Some(host) = host {
+                        assert!(is_identical(first.nick().as_bytes(), b"dan"));
+                        assert!(is_identical(host.as_bytes(), b"localhost"));
+                    }
+                    let targets = targets.next_target();
+                    assert!(matches!(targets, Some((_, _))));
+                    if let Some((second, targets)) = targets {
+                        assert!(is_identical(second.nick().as_bytes(), b"bob") && targets.next_target().is_none());
+                    }
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com 731 me :dan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = MonitorReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                let first = reply.targets().next_target();
+                assert!(matches!(first, Some((_, _))));
+                if let Some((first, _)) = first {
+                    assert!(matches!(reply.kind(), MonitorReplyKind::Offline) && first.user().is_none());
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com 733 me :End of MONITOR list");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = MonitorReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), MonitorReplyKind::EndOfList) && reply.targets().count() == 0);
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com 734 me 100 dan,bob :Monitor list is full.");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = MonitorReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), MonitorReplyKind::ListFull) && matches!(reply.limit(), Some(100)));
+                assert!(reply.targets().count() == 2);
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com 734 me x dan :Monitor list is full.");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(MonitorReply::from_msg(&msg).is_none());}
+    }
+}
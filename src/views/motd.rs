@@ -0,0 +1,240 @@
+//! Methods for reading the message of the day.
+//!
+//! ## Purpose
+//!
+//! After registration or in reply to `MOTD` the IRC server sends its message of the day as an `RPL_MOTDSTART`
+//! (`375`) followed by an `RPL_MOTD` (`372`) for each line and an `RPL_ENDOFMOTD` (`376`). If it has no message of
+//! the day it sends `ERR_NOMOTD` (`422`) instead. By convention each line starts with `- ` which [`MotdReply::line`]
+//! strips. A [`MotdCollector`] tracks the replies and hands out each line so it can be stored elsewhere.
+
+use crate::{ContentType, IrcMsg};
+
+/// The kind of a [`MotdReply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MotdReplyKind {
+    /// `RPL_MOTDSTART` (`375`) precedes the lines.
+    Start,
+    /// `RPL_MOTD` (`372`) a line of the message of the day.
+    Line,
+    /// `RPL_ENDOFMOTD` (`376`) follows the lines.
+    End,
+    /// `ERR_NOMOTD` (`422`) there is no message of the day.
+    Missing,
+}
+
+/// A view over an `RPL_MOTDSTART` (`375`), `RPL_MOTD` (`372`), `RPL_ENDOFMOTD` (`376`) or `ERR_NOMOTD` (`422`)
+/// numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MotdReply<'msg> {
+    kind: MotdReplyKind,
+    client: ContentType<'msg>,
+    text: ContentType<'msg>,
+}
+
+impl<'msg> MotdReply<'msg> {
+    /// Generates a [`MotdReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not one of the message of the day numerics with text.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let kind = match msg.command().as_numeric_u16() {
+            Some(375) => MotdReplyKind::Start,
+            Some(372) => MotdReplyKind::Line,
+            Some(376) => MotdReplyKind::End,
+            Some(422) => MotdReplyKind::Missing,
+            _ => return None,
+        };
+        let Some(params) = msg.parameters() else {return None;};
+        if params.count() < 2 {return None;}
+        Some(Self{kind, client: params.extract_first(), text: params.extract_last()})
+    }
+    /// Returns the [`MotdReplyKind`].
+    #[must_use]
+    pub const fn kind(&self) -> MotdReplyKind {
+        self.kind
+    }
+    /// Returns the nickname of the client receiving the [`MotdReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the text as it appears in the message.
+    #[must_use]
+    pub const fn text(&self) -> ContentType<'msg> {
+        self.text
+    }
+    /// Returns the line of an `RPL_MOTD` (`372`) without the conventional `- ` or `-` prefix.
+    #[must_use]
+    pub const fn line(&self) -> Option<ContentType<'msg>> {
+        if !matches!(self.kind, MotdReplyKind::Line) {return None;}
+        match self.text.as_bytes() {
+            [b'-', b' ', rest @ ..] | [b'-', rest @ ..] => Some(ContentType::new(rest)),
+            _ => Some(self.text),
+        }
+    }
+}
+
+/// Tracks the replies making up the message of the day.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct MotdCollector {
+    started: bool,
+    lines: usize,
+    finished: Option<bool>,
+}
+
+impl MotdCollector {
+    /// Generates an empty [`MotdCollector`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self{started: false, lines: 0, finished: None}
+    }
+    /// Consumes a message of the day reply and returns the stripped line to store if it is an `RPL_MOTD` (`372`)
+    /// alongside the advanced [`MotdCollector`].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the [`IrcMsg`] is not a message of the day numeric or arrives after the end.
+    pub const fn apply<'msg>(mut self, msg: &IrcMsg<'msg>) -> Result<(Option<ContentType<'msg>>, Self), MotdError> {
+        if self.finished.is_some() {return Err(MotdError::AlreadyComplete);}
+        let Some(reply) = MotdReply::from_msg(msg) else {return Err(MotdError::NotMotd);};
+        match reply.kind {
+            MotdReplyKind::Start => self.started = true,
+            MotdReplyKind::Line => {
+                self.lines += 1;
+                return Ok((reply.line(), self));
+            },
+            MotdReplyKind::End => self.finished = Some(true),
+            MotdReplyKind::Missing => self.finished = Some(false),
+        }
+        Ok((None, self))
+    }
+    /// Check whether `RPL_MOTDSTART` (`375`) has been received.
+    #[must_use]
+    pub const fn is_started(&self) -> bool {
+        self.started
+    }
+    /// Returns the amount of lines received.
+    #[must_use]
+    pub const fn line_count(&self) -> usize {
+        self.lines
+    }
+    /// Check whether `RPL_ENDOFMOTD` (`376`) or `ERR_NOMOTD` (`422`) has been received.
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.finished.is_some()
+    }
+    /// Check whether the IRC server replied with `ERR_NOMOTD` (`422`).
+    #[must_use]
+    pub const fn is_missing(&self) -> bool {
+        matches!(self.finished, Some(false))
+    }
+}
+
+/// The possible types of errors when consuming a reply with a [`MotdCollector`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MotdError {
+    /// The [`IrcMsg`] is not a message of the day numeric with text.
+    NotMotd,
+    /// `RPL_ENDOFMOTD` (`376`) or `ERR_NOMOTD` (`422`) has already been received.
+    AlreadyComplete,
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{MotdCollector, MotdError, MotdReply, MotdReplyKind};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_motd() {
+        let msg = IrcMsg::parse(b":irc.host 372 dan :- Welcome!");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = MotdReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                let line = reply.line();
+                assert!(line.is_some());
+                if let Some(line) = line {
+                    assert!(matches!(reply.kind(), MotdReplyKind::Line) && is_identical(line.as_bytes(), b"Welcome!"));
+                }
+                assert!(is_identical(reply.client().as_bytes(), b"dan"));
+                assert!(is_identical(reply.text().as_bytes(), b"- Welcome!"));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 372 dan :-");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = MotdReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                let line = reply.line();
+                assert!(line.is_some());
+                if let Some(line) = line {assert!(line.as_bytes().is_empty());}
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 372 dan :plain");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = MotdReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                let line = reply.line();
+                assert!(line.is_some());
+                if let Some(line) = line {assert!(is_identical(line.as_bytes(), b"plain"));}
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 375 dan :- irc.host Message of the day - ");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = MotdReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), MotdReplyKind::Start) && reply.line().is_none());
+            }
+        }
+    }
+    #[test]
+    const fn collecting_motd() {
+        let collector = MotdCollector::new();
+        let msg = IrcMsg::parse(b":irc.host 375 dan :- irc.host Message of the day - ");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let collector = collector.apply(&msg);
+            assert!(matches!(collector, Ok((None, _))));
+            if let Ok((None, collector)) = collector {
+                let msg = IrcMsg::parse(b":irc.host 372 dan :- Be nice");
+                assert!(msg.is_ok());
+                if let Ok(msg) = msg {
+                    let collector = collector.apply(&msg);
+                    assert!(matches!(collector, Ok((Some(_), _))));
+                    if let Ok((Some(line), collector)) = collector {
+                        assert!(is_identical(line.as_bytes(), b"Be nice") && collector.is_started());
+                        assert!(!collector.is_complete());
+                        let msg = IrcMsg::parse(b":irc.host 376 dan :End of /MOTD command.");
+                        assert!(msg.is_ok());
+                        if let Ok(msg) = msg {
+                            let collector = collector.apply(&msg);
+                            assert!(matches!(collector, Ok((None, _))));
+                            if let Ok((None, collector)) = collector {
+                                assert!(collector.is_complete() && !collector.is_missing());
+                                assert!(collector.line_count() == 1);
+                                assert!(matches!(collector.apply(&msg), Err(MotdError::AlreadyComplete)));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 422 dan :MOTD File is missing");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let collector = MotdCollector::new().apply(&msg);
+            assert!(matches!(collector, Ok((None, _))));
+            if let Ok((None, collector)) = collector {
+                assert!(collector.is_complete() && collector.is_missing() && !collector.is_started());
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 001 dan :Welcome");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(MotdCollector::new().apply(&msg), Err(MotdError::NotMotd)));}
+    }
+}
@@ -0,0 +1,115 @@
+//! Methods for reading `RPL_MYINFO` (`004`) numerics.
+//!
+//! ## Purpose
+//!
+//! `RPL_MYINFO` is sent during registration and names the IRC server, its version and the user and channel modes
+//! it supports. Some IRC servers add the channel modes that take a parameter as an optional last parameter.
+//! The mode lists are superseded by [`ISupport`](crate::isupport::ISupport) but remain useful on older networks.
+
+use crate::{ContentType, IrcMsg, contains_byte, parameters::Parameters};
+
+/// A view over an `RPL_MYINFO` (`004`) numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MyInfoReply<'msg> {
+    params: Parameters<'msg>,
+}
+
+impl<'msg> MyInfoReply<'msg> {
+    /// Generates a [`MyInfoReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_MYINFO` (`004`) numeric with at least the client, server name,
+    /// version, user modes and channel modes.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(4) = msg.command().as_numeric_u16() else {return None;};
+        let Some(params) = msg.parameters() else {return None;};
+        if params.count() < 5 {return None;}
+        Some(Self{params})
+    }
+    /// Returns the nickname of the client receiving the [`MyInfoReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.params.extract_first()
+    }
+    /// Returns the name of the IRC server.
+    #[must_use]
+    pub const fn servername(&self) -> ContentType<'msg> {
+        self.param(1)
+    }
+    /// Returns the version of the IRC server software.
+    #[must_use]
+    pub const fn version(&self) -> ContentType<'msg> {
+        self.param(2)
+    }
+    /// Returns the available user modes.
+    #[must_use]
+    pub const fn user_modes(&self) -> ContentType<'msg> {
+        self.param(3)
+    }
+    /// Returns the available channel modes.
+    #[must_use]
+    pub const fn channel_modes(&self) -> ContentType<'msg> {
+        self.param(4)
+    }
+    /// Returns the channel modes that take a parameter if present.
+    #[must_use]
+    pub const fn channel_modes_with_param(&self) -> Option<ContentType<'msg>> {
+        self.params.extract_specific(5)
+    }
+    /// Check whether the user `mode` is available.
+    #[must_use]
+    pub const fn has_user_mode(&self, mode: u8) -> bool {
+        contains_byte(self.user_modes().as_bytes(), mode)
+    }
+    /// Check whether the channel `mode` is available.
+    #[must_use]
+    pub const fn has_channel_mode(&self, mode: u8) -> bool {
+        contains_byte(self.channel_modes().as_bytes(), mode)
+    }
+    const fn param(&self, index: usize) -> ContentType<'msg> {
+        match self.params.extract_specific(index) {
+            Some(param) => param,
+            None => ContentType::new(b""),
+        }
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::MyInfoReply;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_myinfo() {
+        let input = b":irc.host 004 dan irc.host ircd-1.0 iosw biklmnopstv bklov";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let info = MyInfoReply::from_msg(&msg);
+            assert!(info.is_some());
+            if let Some(info) = info {
+                let with_param = info.channel_modes_with_param();
+                assert!(with_param.is_some());
+                if let Some(with_param) = with_param {
+                    assert!(is_identical(info.client().as_bytes(), b"dan"));
+                    assert!(is_identical(info.servername().as_bytes(), b"irc.host"));
+                    assert!(is_identical(info.version().as_bytes(), b"ircd-1.0"));
+                    assert!(is_identical(info.user_modes().as_bytes(), b"iosw"));
+                    assert!(is_identical(info.channel_modes().as_bytes(), b"biklmnopstv"));
+                    assert!(is_identical(with_param.as_bytes(), b"bklov"));
+                }
+                assert!(info.has_user_mode(b'w') && !info.has_user_mode(b'x'));
+                assert!(info.has_channel_mode(b'k') && !info.has_channel_mode(b'q'));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 004 dan irc.host ircd-1.0 iosw biklmnopstv");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let info = MyInfoReply::from_msg(&msg);
+            assert!(info.is_some());
+            if let Some(info) = info {assert!(info.channel_modes_with_param().is_none());}
+        }
+        let msg = IrcMsg::parse(b":irc.host 003 dan :This server was created today");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(MyInfoReply::from_msg(&msg).is_none());}
+    }
+}
@@ -0,0 +1,243 @@
+//! Methods for reading `RPL_NAMREPLY` (`353`) numerics.
+//!
+//! ## Purpose
+//!
+//! `RPL_NAMREPLY` lists the members of a channel on joining it or in reply to `NAMES`. Each member is a nick
+//! preceded by its membership prefixes such as `@` for channel operators. Only the highest prefix is sent unless
+//! [multi-prefix] is enabled and with [userhost-in-names] the nick is a full `nick!user@host` mask.
+//! The membership prefixes are the ones of the [`Prefix`](crate::isupport::Prefix) of the IRC server.
+//!
+//! [multi-prefix]: <https://ircv3.net/specs/extensions/multi-prefix>
+//! [userhost-in-names]: <https://ircv3.net/specs/extensions/userhost-in-names>
+
+use crate::{ContentType, IrcMsg, contains_byte, parameters::SpaceList, source::Nickname};
+
+/// The status of a channel in a [`NamReply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChannelStatus {
+    /// `=` a public channel.
+    Public,
+    /// `@` a secret channel.
+    Secret,
+    /// `*` a private channel.
+    Private,
+}
+
+impl ChannelStatus {
+    /// Generates a [`ChannelStatus`] from its symbol.
+    #[must_use]
+    pub const fn from_symbol(symbol: &[u8]) -> Option<Self> {
+        match symbol {
+            b"=" => Some(Self::Public),
+            b"@" => Some(Self::Secret),
+            b"*" => Some(Self::Private),
+            _ => None,
+        }
+    }
+}
+
+/// A view over an `RPL_NAMREPLY` (`353`) numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NamReply<'msg> {
+    client: ContentType<'msg>,
+    status: ChannelStatus,
+    channel: ContentType<'msg>,
+    names: ContentType<'msg>,
+}
+
+impl<'msg> NamReply<'msg> {
+    /// Generates a [`NamReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_NAMREPLY` (`353`) numeric with a valid channel status,
+    /// a channel and names.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(353) = msg.command().as_numeric_u16() else {return None;};
+        let Some(params) = msg.parameters() else {return None;};
+        let (Some(symbol), Some(channel)) = (params.extract_specific(1), params.extract_specific(2)) else {
+            return None;
+        };
+        let Some(status) = ChannelStatus::from_symbol(symbol.as_bytes()) else {return None;};
+        Some(Self{client: params.extract_first(), status, channel, names: params.extract_last()})
+    }
+    /// Returns the nickname of the client receiving the [`NamReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the [`ChannelStatus`].
+    #[must_use]
+    pub const fn status(&self) -> ChannelStatus {
+        self.status
+    }
+    /// Returns the channel.
+    #[must_use]
+    pub const fn channel(&self) -> ContentType<'msg> {
+        self.channel
+    }
+    /// Returns the names as they appear in the message.
+    #[must_use]
+    pub const fn names(&self) -> ContentType<'msg> {
+        self.names
+    }
+    /// Returns a [`NamesMembers`] cursor separating the membership `prefixes` from each member.
+    ///
+    /// The `prefixes` are usually [`Prefix::prefixes`](crate::isupport::Prefix::prefixes).
+    #[must_use]
+    pub const fn members<'a>(&self, prefixes: &'a [u8]) -> NamesMembers<'a> where 'msg: 'a {
+        NamesMembers{names: SpaceList::new(self.names), prefixes}
+    }
+}
+
+/// A member of a channel in a [`NamReply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NamesMember<'msg> {
+    prefixes: ContentType<'msg>,
+    nickname: Nickname<'msg>,
+}
+
+impl<'msg> NamesMember<'msg> {
+    /// Returns the membership prefixes of the member in descending order of rank.
+    #[must_use]
+    pub const fn prefixes(&self) -> ContentType<'msg> {
+        self.prefixes
+    }
+    /// Returns the highest membership prefix of the member if present.
+    #[must_use]
+    pub const fn highest_prefix(&self) -> Option<u8> {
+        match self.prefixes.as_bytes() {
+            [first, ..] => Some(*first),
+            [] => None,
+        }
+    }
+    /// Check whether the member has the membership `prefix`.
+    #[must_use]
+    pub const fn has_prefix(&self, prefix: u8) -> bool {
+        contains_byte(self.prefixes.as_bytes(), prefix)
+    }
+    /// Returns the member as a [`Nickname`].
+    ///
+    /// The user and host are only present with [userhost-in-names].
+    ///
+    /// [userhost-in-names]: <https://ircv3.net/specs/extensions/userhost-in-names>
+    #[must_use]
+    pub const fn nickname(&self) -> Nickname<'msg> {
+        self.nickname
+    }
+    /// Returns the nick of the member.
+    #[must_use]
+    pub const fn nick(&self) -> ContentType<'msg> {
+        self.nickname.nick()
+    }
+}
+
+/// A cursor over the members of a [`NamReply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NamesMembers<'msg> {
+    names: SpaceList<'msg>,
+    prefixes: &'msg [u8],
+}
+
+impl<'msg> NamesMembers<'msg> {
+    /// Returns the next [`NamesMember`] and the advanced [`NamesMembers`].
+    ///
+    /// Returns `None` once all members have been read.
+    #[must_use]
+    pub const fn next_member(self) -> Option<(NamesMember<'msg>, Self)> {
+        let Some((name, names)) = self.names.next_item() else {return None;};
+        let name = name.as_bytes();
+        let mut index = 0;
+        while index < name.len() && contains_byte(self.prefixes, name[index]) {index += 1;}
+        let (prefixes, mask) = name.split_at(index);
+        let member = NamesMember{prefixes: ContentType::new(prefixes), nickname: Nickname::from_mask(mask)};
+        Some((member, Self{names, prefixes: self.prefixes}))
+    }
+    /// Returns the amount of members that have not been read yet.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.names.count()
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{ChannelStatus, NamReply};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_names() {
+        let msg = IrcMsg::parse(b":irc.host 353 dan = #chan :@+dan +bob alice");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let names = NamReply::from_msg(&msg);
+            assert!(names.is_some());
+            if let Some(names) = names {
+                assert!(matches!(names.status(), ChannelStatus::Public));
+                assert!(is_identical(names.channel().as_bytes(), b"#chan"));
+                assert!(is_identical(names.client().as_bytes(), b"dan"));
+                let members = names.members(b"~&@%+");
+                assert!(members.count() == 3);
+                let members = members.next_member();
+                assert!(matches!(members, Some((_, _))));
+                if let Some((dan, members)) = members {
+                    assert!(is_identical(dan.nick().as_bytes(), b"dan"));
+                    assert!(is_identical(dan.prefixes().as_bytes(), b"@+"));
+                    assert!(matches!(dan.highest_prefix(), Some(b'@')) && dan.has_prefix(b'+'));
+                    assert!(!dan.has_prefix(b'%'));
+                    let members = members.next_member();
+                    assert!(matches!(members, Some((_, _))));
+                    if let Some((bob, members)) = members {
+                        assert!(is_identical(bob.nick().as_bytes(), b"bob"));
+                        assert!(matches!(bob.highest_prefix(), Some(b'+')));
+                        let members = members.next_member();
+                        assert!(matches!(members, Some((_, _))));
+                        if let Some((alice, members)) = members {
+                            assert!(is_identical(alice.nick().as_bytes(), b"alice"));
+                            assert!(alice.highest_prefix().is_none());
+                            assert!(members.next_member().is_none());
+                        }
+                    }
+                }
+            }
+        }
+    }
+    #[test]
+    const fn reading_userhost_in_names() {
+        let msg = IrcMsg::parse(b":irc.host 353 dan @ #chan :@dan!d@localhost bob!b@host");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let names = NamReply::from_msg(&msg);
+            assert!(names.is_some());
+            if let Some(names) = names {
+                assert!(matches!(names.status(), ChannelStatus::Secret));
+                let members = names.members(b"@+").next_member();
+                assert!(matches!(members, Some((_, _))));
+                if let Some((dan, members)) = members {
+                    let pair = (dan.nickname().user(), dan.nickname().host());
+                    assert!(matches!(pair, (Some(_), Some(_))));
+                    if let (Some(user), Some(host)) = pair {
+                        assert!(is_identical(dan.nick().as_bytes(), b"dan") && is_identical(user.as_bytes(), b"d"));
+                        assert!(is_identical(host.as_bytes(), b"localhost") && dan.has_prefix(b'@'));
+                    }
+                    let bob = members.next_member();
+                    assert!(matches!(bob, Some((_, _))));
+                    if let Some((bob, _)) = bob {
+                        assert!(is_identical(bob.nick().as_bytes(), b"bob") && bob.prefixes().as_bytes().is_empty());
+                    }
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 353 dan * #chan :");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let names = NamReply::from_msg(&msg);
+            assert!(names.is_some());
+            if let Some(names) = names {
+                assert!(matches!(names.status(), ChannelStatus::Private));
+                assert!(names.members(b"@").next_member().is_none());
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 353 dan ! #chan :dan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(NamReply::from_msg(&msg).is_none());}
+    }
+}
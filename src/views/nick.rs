@@ -0,0 +1,108 @@
+//! Methods for reading `NICK` messages.
+//!
+//! ## Purpose
+//!
+//! An IRC client sends `NICK` to set or change its nickname. The IRC server sends it back with the old nickname in
+//! the [`Source`] and the new nickname as the only parameter. The IRC server sends a single `NICK` for each user so
+//! the rename applies to every channel the user shares with the IRC client.
+
+use crate::{ContentType, IrcMsg, casemapping::IrcCaseMapping, command::CommandKind, source::Source};
+
+/// A view over a `NICK` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NickMsg<'msg> {
+    source: Option<Source<'msg>>,
+    new_nick: ContentType<'msg>,
+}
+
+impl<'msg> NickMsg<'msg> {
+    /// Generates a [`NickMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `NICK` command with a nickname.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Nick)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        Some(Self{source: msg.source(), new_nick: params.extract_first()})
+    }
+    /// Returns the [`Source`] of the user who changed their nickname.
+    ///
+    /// Returns `None` for a [`NickMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the old nickname from the [`Source`].
+    #[must_use]
+    pub const fn old_nick(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Returns the new nickname.
+    #[must_use]
+    pub const fn new_nick(&self) -> ContentType<'msg> {
+        self.new_nick
+    }
+    /// Check whether the IRC client itself changed its nickname.
+    ///
+    /// The old nickname is compared to `my_nick` according to the [`IrcCaseMapping`].
+    #[must_use]
+    pub const fn is_self(&self, my_nick: &[u8], casemapping: IrcCaseMapping) -> bool {
+        match self.old_nick() {
+            Some(old) => casemapping.is_equivalent(old.as_bytes(), my_nick),
+            None => false,
+        }
+    }
+    /// Check whether only the case of the nickname changed according to the [`IrcCaseMapping`].
+    #[must_use]
+    pub const fn is_case_change(&self, casemapping: IrcCaseMapping) -> bool {
+        match self.old_nick() {
+            Some(old) => casemapping.is_equivalent(old.as_bytes(), self.new_nick.as_bytes()),
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::NickMsg;
+    use crate::{IrcMsg, casemapping::IrcCaseMapping, is_identical};
+    #[test]
+    const fn reading_nick() {
+        let msg = IrcMsg::parse(b":dan!d@localhost NICK dan_");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let nick = NickMsg::from_msg(&msg);
+            assert!(nick.is_some());
+            if let Some(nick) = nick {
+                let old = nick.old_nick();
+                assert!(old.is_some());
+                if let Some(old) = old {
+                    assert!(is_identical(old.as_bytes(), b"dan") && is_identical(nick.new_nick().as_bytes(), b"dan_"));
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b"NICK dan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let nick = NickMsg::from_msg(&msg);
+            assert!(nick.is_some());
+            if let Some(nick) = nick {assert!(nick.source().is_none() && nick.old_nick().is_none());}
+        }
+        let msg = IrcMsg::parse(b":Dan[1]!d@localhost NICK dan{1}");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let nick = NickMsg::from_msg(&msg);
+            assert!(nick.is_some());
+            if let Some(nick) = nick {
+                assert!(nick.is_self(b"dan[1]", IrcCaseMapping::Ascii) && !nick.is_self(b"bob", IrcCaseMapping::Ascii));
+                assert!(nick.is_case_change(IrcCaseMapping::Rfc1459) && !nick.is_case_change(IrcCaseMapping::Ascii));
+            }
+        }
+        let msg = IrcMsg::parse(b"USER d 0 * :Dan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(NickMsg::from_msg(&msg).is_none());}
+    }
+}
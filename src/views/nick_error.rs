@@ -0,0 +1,223 @@
+//! Methods for reading rejected nicknames and proposing a fallback.
+//!
+//! ## Purpose
+//!
+//! The IRC server rejects a nickname with `ERR_ERRONEUSNICKNAME` (`432`), `ERR_NICKNAMEINUSE` (`433`) or
+//! `ERR_NICKCOLLISION` (`436`). During registration the IRC client has to pick another nickname before the
+//! connection completes. [`fallback_nick`] proposes one by appending `_` or a number to the rejected nickname
+//! without exceeding the `NICKLEN` [`ISupportToken`](crate::isupport::ISupportToken).
+
+use crate::{ContentType, IrcMsg};
+
+/// The kind of a [`NickErrorReply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NickErrorKind {
+    /// `ERR_ERRONEUSNICKNAME` (`432`) the nickname contains invalid bytes.
+    Erroneous,
+    /// `ERR_NICKNAMEINUSE` (`433`) the nickname is used by another client.
+    InUse,
+    /// `ERR_NICKCOLLISION` (`436`) the nickname collided with one on another IRC server.
+    Collision,
+}
+
+/// A view over an `ERR_ERRONEUSNICKNAME` (`432`), `ERR_NICKNAMEINUSE` (`433`) or `ERR_NICKCOLLISION` (`436`)
+/// numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct NickErrorReply<'msg> {
+    kind: NickErrorKind,
+    client: ContentType<'msg>,
+    nick: ContentType<'msg>,
+    text: ContentType<'msg>,
+}
+
+impl<'msg> NickErrorReply<'msg> {
+    /// Generates a [`NickErrorReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not one of the nickname error numerics with the rejected nickname.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let kind = match msg.command().as_numeric_u16() {
+            Some(432) => NickErrorKind::Erroneous,
+            Some(433) => NickErrorKind::InUse,
+            Some(436) => NickErrorKind::Collision,
+            _ => return None,
+        };
+        let Some(params) = msg.parameters() else {return None;};
+        let Some(nick) = params.extract_specific(1) else {return None;};
+        if params.count() < 3 {return None;}
+        Some(Self{kind, client: params.extract_first(), nick, text: params.extract_last()})
+    }
+    /// Returns the [`NickErrorKind`].
+    #[must_use]
+    pub const fn kind(&self) -> NickErrorKind {
+        self.kind
+    }
+    /// Returns the current nickname of the IRC client.
+    ///
+    /// This is `*` during registration.
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Check whether the IRC client has not completed registration yet.
+    #[must_use]
+    pub const fn is_registering(&self) -> bool {
+        matches!(self.client.as_bytes(), [b'*'])
+    }
+    /// Returns the rejected nickname.
+    #[must_use]
+    pub const fn nick(&self) -> ContentType<'msg> {
+        self.nick
+    }
+    /// Returns the human readable text.
+    #[must_use]
+    pub const fn text(&self) -> ContentType<'msg> {
+        self.text
+    }
+    /// Proposes a fallback for the rejected nickname.
+    ///
+    /// See [`fallback_nick`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` under the same conditions as [`fallback_nick`].
+    pub const fn fallback<const N: usize>(&self, attempt: u32, nicklen: usize)
+    -> Result<FallbackNick<N>, FallbackNickError> {
+        fallback_nick(self.nick.as_bytes(), attempt, nicklen)
+    }
+}
+
+/// A buffer of `N` bytes holding a fallback nickname.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FallbackNick<const N: usize> {
+    buffer: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> FallbackNick<N> {
+    /// Returns the fallback nickname as a slice of bytes.
+    #[must_use]
+    pub const fn as_bytes(&self) -> &[u8] {
+        let (output, _) = self.buffer.split_at(self.len);
+        output
+    }
+    /// Returns the amount of bytes written.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Check if no bytes were written.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Proposes a fallback nickname for the rejected `nick`.
+///
+/// The first `attempt` (0) appends `_` and later attempts append the `attempt` number so each attempt yields a
+/// different nickname. The `nick` is truncated if needed so the fallback is no longer than `nicklen` bytes which
+/// should be the value of the `NICKLEN` [`ISupportToken`](crate::isupport::ISupportToken).
+///
+/// # Errors
+///
+/// Will return `Err` if the `nick` is empty, `nicklen` leaves no room for at least one byte of the `nick` before
+/// the suffix or the fallback is longer than `N` bytes.
+pub const fn fallback_nick<const N: usize>(nick: &[u8], attempt: u32, nicklen: usize)
+-> Result<FallbackNick<N>, FallbackNickError> {
+    if nick.is_empty() {return Err(FallbackNickError::EmptyNick);}
+    let mut digits = [0; 10];
+    let mut suffix_len = 0;
+    if attempt == 0 {
+        digits[0] = b'_';
+        suffix_len = 1;
+    } else {
+        let mut remaining = attempt;
+        while remaining > 0 {
+            digits[suffix_len] = b'0' + (remaining % 10) as u8;
+            remaining /= 10;
+            suffix_len += 1;
+        }
+    }
+    if nicklen <= suffix_len {return Err(FallbackNickError::NickLenTooShort);}
+    let base_len = if nick.len() + suffix_len > nicklen {nicklen - suffix_len} else {nick.len()};
+    if base_len + suffix_len > N {return Err(FallbackNickError::BufferFull);}
+    let mut buffer = [0; N];
+    let mut index = 0;
+    while index < base_len {
+        buffer[index] = nick[index];
+        index += 1;
+    }
+    while suffix_len > 0 {
+        suffix_len -= 1;
+        buffer[index] = digits[suffix_len];
+        index += 1;
+    }
+    Ok(FallbackNick{buffer, len: index})
+}
+
+/// The possible types of errors when proposing a fallback nickname.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FallbackNickError {
+    /// The rejected nickname is empty.
+    EmptyNick,
+    /// The maximum nickname length leaves no room for the nickname before the suffix.
+    NickLenTooShort,
+    /// The fallback nickname is longer than the buffer.
+    BufferFull,
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{FallbackNickError, NickErrorKind, NickErrorReply, fallback_nick};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_nick_error() {
+        let msg = IrcMsg::parse(b":irc.host 433 * dan :Nickname is already in use");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = NickErrorReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), NickErrorKind::InUse) && reply.is_registering());
+                assert!(is_identical(reply.nick().as_bytes(), b"dan") && is_identical(reply.client().as_bytes(), b"*"));
+                assert!(is_identical(reply.text().as_bytes(), b"Nickname is already in use"));
+                let fallback = reply.fallback::<16>(0, 30);
+                assert!(fallback.is_ok());
+                if let Ok(fallback) = fallback {assert!(is_identical(fallback.as_bytes(), b"dan_"));}
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 432 bob d@n :Erroneous nickname");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = NickErrorReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), NickErrorKind::Erroneous) && !reply.is_registering());
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 436 * :Nickname collision KILL");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(NickErrorReply::from_msg(&msg).is_none());}
+        let msg = IrcMsg::parse(b":irc.host 401 dan bob :No such nick/channel");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(NickErrorReply::from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn proposing_fallback_nick() {
+        let fallback = fallback_nick::<9>(b"danny", 12, 9);
+        assert!(fallback.is_ok());
+        if let Ok(fallback) = fallback {
+            assert!(is_identical(fallback.as_bytes(), b"danny12") && fallback.len() == 7 && !fallback.is_empty());
+        }
+        let fallback = fallback_nick::<9>(b"danielle_long", 0, 9);
+        assert!(fallback.is_ok());
+        if let Ok(fallback) = fallback {assert!(is_identical(fallback.as_bytes(), b"danielle_"));}
+        let fallback = fallback_nick::<9>(b"danielle_long", 3, 9);
+        assert!(fallback.is_ok());
+        if let Ok(fallback) = fallback {assert!(is_identical(fallback.as_bytes(), b"danielle3"));}
+        assert!(matches!(fallback_nick::<9>(b"", 0, 9), Err(FallbackNickError::EmptyNick)));
+        assert!(matches!(fallback_nick::<9>(b"dan", 10, 2), Err(FallbackNickError::NickLenTooShort)));
+        assert!(matches!(fallback_nick::<3>(b"dan", 0, 9), Err(FallbackNickError::BufferFull)));
+    }
+}
@@ -0,0 +1,110 @@
+//! Methods for reading `PART` messages.
+//!
+//! ## Purpose
+//!
+//! An IRC client sends `PART` to leave channels and the IRC server sends it back with the user as the [`Source`]
+//! for every channel left along with the optional reason.
+
+use crate::{ContentType, IrcMsg, command::CommandKind, parameters::CommaList, source::Source};
+
+/// A view over a `PART` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PartMsg<'msg> {
+    source: Option<Source<'msg>>,
+    channels: ContentType<'msg>,
+    reason: Option<ContentType<'msg>>,
+}
+
+impl<'msg> PartMsg<'msg> {
+    /// Generates a [`PartMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `PART` command with channels.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Part)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        Some(Self{source: msg.source(), channels: params.extract_first(), reason: params.extract_specific(1)})
+    }
+    /// Returns the [`Source`] of the user who left.
+    ///
+    /// Returns `None` for a [`PartMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the user who left.
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Returns the channels as they appear in the message.
+    #[must_use]
+    pub const fn channels(&self) -> ContentType<'msg> {
+        self.channels
+    }
+    /// Returns a [`CommaList`] over the channels.
+    #[must_use]
+    pub const fn channel_list(&self) -> CommaList<'msg> {
+        CommaList::new(self.channels)
+    }
+    /// Returns the reason for leaving if present.
+    #[must_use]
+    pub const fn reason(&self) -> Option<ContentType<'msg>> {
+        self.reason
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::PartMsg;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_part() {
+        let msg = IrcMsg::parse(b":dan!d@localhost PART #chan :Goodbye");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let part = PartMsg::from_msg(&msg);
+            assert!(part.is_some());
+            if let Some(part) = part {
+                let pair = (part.nick(), part.reason());
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(nick), Some(reason)) = pair {
+                    assert!(is_identical(nick.as_bytes(), b"dan"));
+                    assert!(is_identical(part.channels().as_bytes(), b"#chan"));
+                    assert!(is_identical(reason.as_bytes(), b"Goodbye"));
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b"PART #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let part = PartMsg::from_msg(&msg);
+            assert!(part.is_some());
+            if let Some(part) = part {assert!(part.source().is_none() && part.reason().is_none());}
+        }
+        let msg = IrcMsg::parse(b"PART #a,#b");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let part = PartMsg::from_msg(&msg);
+            assert!(part.is_some());
+            if let Some(part) = part {
+                let channels = part.channel_list().next_item();
+                assert!(matches!(channels, Some((_, _))));
+                if let Some((first, channels)) = channels {
+                    let channels = channels.next_item();
+                    assert!(matches!(channels, Some((_, _))));
+                    if let Some((second, channels)) = channels {
+                        assert!(is_identical(first.as_bytes(), b"#a") && is_identical(second.as_bytes(), b"#b"));
+                        assert!(channels.next_item().is_none());
+                    }
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b"JOIN #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(PartMsg::from_msg(&msg).is_none());}
+    }
+}
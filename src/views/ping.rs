@@ -0,0 +1,151 @@
+//! Methods for reading `PING` and `PONG` messages and writing the `PONG` reply.
+//!
+//! ## Purpose
+//!
+//! `PING` checks that the other side of a connection is still responsive and must be answered with a `PONG`
+//! carrying the same token. An IRC server may place its name before the token of a `PONG`.
+//! [`pong_reply`] writes the `PONG` answering a received `PING` in one call.
+
+use crate::{ContentType, IrcMsg, IrcMsgWriter, IrcMsgWriterError, command::CommandKind, source::Source};
+
+/// A view over a `PING` or `PONG` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PingMsg<'msg> {
+    pong: bool,
+    source: Option<Source<'msg>>,
+    server: Option<ContentType<'msg>>,
+    token: ContentType<'msg>,
+}
+
+impl<'msg> PingMsg<'msg> {
+    /// Generates a [`PingMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `PING` or `PONG` command with a token.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let pong = match msg.command().kind() {
+            Some(CommandKind::Ping) => false,
+            Some(CommandKind::Pong) => true,
+            _ => return None,
+        };
+        let Some(params) = msg.parameters() else {return None;};
+        let server = if params.count() > 1 {Some(params.extract_first())} else {None};
+        Some(Self{pong, source: msg.source(), server, token: params.extract_last()})
+    }
+    /// Check whether the [`PingMsg`] is a `PONG`.
+    #[must_use]
+    pub const fn is_pong(&self) -> bool {
+        self.pong
+    }
+    /// Returns the [`Source`] if present.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the server name preceding the token if present.
+    #[must_use]
+    pub const fn server(&self) -> Option<ContentType<'msg>> {
+        self.server
+    }
+    /// Returns the token.
+    #[must_use]
+    pub const fn token(&self) -> ContentType<'msg> {
+        self.token
+    }
+    /// Writes the `PONG` carrying the token of the [`PingMsg`].
+    ///
+    /// The token is always written as the trailing parameter.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the message is longer than `N` bytes.
+    pub const fn reply<const N: usize>(&self) -> Result<IrcMsgWriter<N>, IrcMsgWriterError> {
+        match IrcMsgWriter::new().command(b"PONG") {
+            Ok(writer) => writer.trailing_with_colon(self.token.as_bytes()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Writes the `PONG` answering a `PING` [`IrcMsg`].
+///
+/// Returns `None` if the [`IrcMsg`] is not a `PING` command with a token.
+///
+/// # Errors
+///
+/// Will return `Err` inside the `Some` if the message is longer than `N` bytes.
+#[must_use]
+pub const fn pong_reply<const N: usize>(msg: &IrcMsg) -> Option<Result<IrcMsgWriter<N>, IrcMsgWriterError>> {
+    match PingMsg::from_msg(msg) {
+        Some(ping) if !ping.pong => Some(ping.reply()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{PingMsg, pong_reply};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_ping() {
+        let msg = IrcMsg::parse(b"PING :irc.example.com");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let ping = PingMsg::from_msg(&msg);
+            assert!(ping.is_some());
+            if let Some(ping) = ping {
+                assert!(!ping.is_pong() && ping.server().is_none() && ping.source().is_none());
+                assert!(is_identical(ping.token().as_bytes(), b"irc.example.com"));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com PONG irc.example.com :token");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = PingMsg::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                let server = reply.server();
+                assert!(server.is_some());
+                if let Some(server) = server {
+                    assert!(reply.is_pong() && is_identical(server.as_bytes(), b"irc.example.com"));
+                }
+                assert!(is_identical(reply.token().as_bytes(), b"token"));
+            }
+        }
+        let msg = IrcMsg::parse(b"ERROR :Closing link");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(PingMsg::from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn replying_to_ping() {
+        let msg = IrcMsg::parse(b"PING :irc.example.com");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let writer = pong_reply::<32>(&msg);
+            assert!(matches!(writer, Some(Ok(_))));
+            if let Some(Ok(writer)) = writer {assert!(is_identical(writer.as_bytes(), b"PONG :irc.example.com"));}
+        }
+        let msg = IrcMsg::parse(b"@time=x :irc.host PING 1234");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let writer = pong_reply::<32>(&msg);
+            assert!(matches!(writer, Some(Ok(_))));
+            if let Some(Ok(writer)) = writer {
+                assert!(is_identical(writer.as_bytes(), b"PONG :1234"));
+                let msg = IrcMsg::parse(writer.as_bytes());
+                assert!(msg.is_ok());
+                if let Ok(msg) = msg {
+                    let reply = PingMsg::from_msg(&msg);
+                    assert!(reply.is_some());
+                    if let Some(reply) = reply {
+                        assert!(reply.is_pong() && is_identical(reply.token().as_bytes(), b"1234"));
+                    }
+                    assert!(pong_reply::<32>(&msg).is_none());
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b"PING :irc.example.com");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(pong_reply::<8>(&msg), Some(Err(_))));}
+    }
+}
@@ -0,0 +1,181 @@
+//! Methods for reading `PRIVMSG` and `NOTICE` messages.
+//!
+//! ## Purpose
+//!
+//! `PRIVMSG` and `NOTICE` carry the text sent between users and channels. Both name the target as the first
+//! parameter and the text as the last. A `NOTICE` must never be automatically replied to.
+//!
+//! The target may be a comma separated list. A channel target is recognised by its first byte being one of the
+//! `CHANTYPES` of the [`ISupport`](crate::isupport::ISupport). With `STATUSMSG` a channel target may be prefixed by
+//! a membership prefix such as `@` to only reach the members with at least that status.
+
+use crate::{ContentType, IrcMsg, command::CommandKind, contains_byte, ctcp::CTCP_DELIMITER, parameters::CommaList,
+    source::Source};
+
+/// A view over a `PRIVMSG` or `NOTICE` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PrivmsgView<'msg> {
+    notice: bool,
+    source: Option<Source<'msg>>,
+    target: ContentType<'msg>,
+    text: ContentType<'msg>,
+}
+
+impl<'msg> PrivmsgView<'msg> {
+    /// Generates a [`PrivmsgView`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `PRIVMSG` or `NOTICE` command with a target and text.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let notice = match msg.command().kind() {
+            Some(CommandKind::Privmsg) => false,
+            Some(CommandKind::Notice) => true,
+            _ => return None,
+        };
+        let Some(params) = msg.parameters() else {return None;};
+        if params.count() < 2 {return None;}
+        Some(Self{notice, source: msg.source(), target: params.extract_first(), text: params.extract_last()})
+    }
+    /// Check whether the [`PrivmsgView`] is a `NOTICE`.
+    #[must_use]
+    pub const fn is_notice(&self) -> bool {
+        self.notice
+    }
+    /// Returns the [`Source`] of the sender.
+    ///
+    /// Returns `None` for a [`PrivmsgView`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the sender.
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Returns the targets as they appear in the message.
+    #[must_use]
+    pub const fn target(&self) -> ContentType<'msg> {
+        self.target
+    }
+    /// Returns a [`CommaList`] over the targets.
+    #[must_use]
+    pub const fn targets(&self) -> CommaList<'msg> {
+        CommaList::new(self.target)
+    }
+    /// Returns the `STATUSMSG` prefix of the first target if present.
+    ///
+    /// The prefix is only recognised if it is one of the bytes in `statusmsg` and followed by a channel.
+    #[must_use]
+    pub const fn status_prefix(&self, statusmsg: &[u8], chantypes: &[u8]) -> Option<u8> {
+        match self.target.as_bytes() {
+            [prefix, first, ..] if contains_byte(statusmsg, *prefix) && contains_byte(chantypes, *first) => {
+                Some(*prefix)
+            },
+            _ => None,
+        }
+    }
+    /// Check whether the first target is a channel according to `chantypes`.
+    ///
+    /// A target with one of the `STATUSMSG` prefixes in `statusmsg` followed by a channel is a channel message as
+    /// well.
+    #[must_use]
+    pub const fn is_channel_message(&self, statusmsg: &[u8], chantypes: &[u8]) -> bool {
+        match self.target.as_bytes() {
+            [first, ..] if contains_byte(chantypes, *first) => true,
+            _ => self.status_prefix(statusmsg, chantypes).is_some(),
+        }
+    }
+    /// Returns the text.
+    #[must_use]
+    pub const fn text(&self) -> ContentType<'msg> {
+        self.text
+    }
+    /// Check whether the text is a CTCP message delimited by `\x01`.
+    ///
+    /// The closing delimiter is optional as some IRC clients omit it.
+    #[must_use]
+    pub const fn is_ctcp(&self) -> bool {
+        let text = self.text.as_bytes();
+        text.len() > 1 && text[0] == CTCP_DELIMITER && text[1] != CTCP_DELIMITER
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::PrivmsgView;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_privmsg() {
+        let msg = IrcMsg::parse(b":dan!d@localhost PRIVMSG #chan :Hey what's up!");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let view = PrivmsgView::from_msg(&msg);
+            assert!(view.is_some());
+            if let Some(view) = view {
+                let nick = view.nick();
+                assert!(nick.is_some());
+                if let Some(nick) = nick {assert!(!view.is_notice() && is_identical(nick.as_bytes(), b"dan"));}
+                assert!(is_identical(view.target().as_bytes(), b"#chan"));
+                assert!(is_identical(view.text().as_bytes(), b"Hey what's up!"));
+            }
+        }
+        let msg = IrcMsg::parse(b"NOTICE dan :hi");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let view = PrivmsgView::from_msg(&msg);
+            assert!(view.is_some());
+            if let Some(view) = view {assert!(view.is_notice() && view.source().is_none());}
+        }
+        let msg = IrcMsg::parse(b"TAGMSG #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(PrivmsgView::from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn routing_privmsg() {
+        let msg = IrcMsg::parse(b":dan!d@localhost PRIVMSG @#chan :\x01ACTION waves\x01");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let view = PrivmsgView::from_msg(&msg);
+            assert!(view.is_some());
+            if let Some(view) = view {
+                assert!(view.is_channel_message(b"@+", b"#&") && view.is_ctcp());
+                assert!(!view.is_channel_message(b"+", b"#&"));
+                assert!(matches!(view.status_prefix(b"@+", b"#&"), Some(b'@')));
+                assert!(view.status_prefix(b"+", b"#").is_none());
+            }
+        }
+        let msg = IrcMsg::parse(b"PRIVMSG bob,&local :\x01");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let view = PrivmsgView::from_msg(&msg);
+            assert!(view.is_some());
+            if let Some(view) = view {
+                assert!(!view.is_channel_message(b"@+", b"#&") && !view.is_ctcp());
+                assert!(view.status_prefix(b"@", b"#&").is_none());
+                let targets = view.targets();
+                assert!(targets.count() == 2);
+                let targets = targets.next_item();
+                assert!(matches!(targets, Some((_, _))));
+                if let Some((first, targets)) = targets {
+                    let targets = targets.next_item();
+                    assert!(matches!(targets, Some((_, _))));
+                    if let Some((second, targets)) = targets {
+                        assert!(is_identical(first.as_bytes(), b"bob") && is_identical(second.as_bytes(), b"&local"));
+                        assert!(targets.next_item().is_none());
+                    }
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b"PRIVMSG a#foo :hi");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let view = PrivmsgView::from_msg(&msg);
+            assert!(view.is_some());
+            if let Some(view) = view {assert!(!view.is_channel_message(b"@+", b"#"));}
+        }
+    }
+}
@@ -0,0 +1,159 @@
+//! Methods for reading `QUIT` messages.
+//!
+//! ## Purpose
+//!
+//! An IRC client sends `QUIT` to disconnect and the IRC server forwards it with the user as the [`Source`] to
+//! everyone sharing a channel with them along with the optional reason.
+//!
+//! When a netsplit separates two IRC servers the users behind the lost server quit with the names of both
+//! servers separated by a space as the reason such as `irc.hub.net irc.leaf.net`.
+
+use crate::{ContentType, IrcMsg, command::CommandKind, source::Source};
+
+/// A view over a `QUIT` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct QuitMsg<'msg> {
+    source: Option<Source<'msg>>,
+    reason: Option<ContentType<'msg>>,
+}
+
+impl<'msg> QuitMsg<'msg> {
+    /// Generates a [`QuitMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `QUIT` command.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Quit)) {return None;}
+        let reason = match msg.parameters() {
+            Some(params) => Some(params.extract_first()),
+            None => None,
+        };
+        Some(Self{source: msg.source(), reason})
+    }
+    /// Returns the [`Source`] of the user who quit.
+    ///
+    /// Returns `None` for a [`QuitMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the user who quit.
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Returns the reason for quitting if present.
+    #[must_use]
+    pub const fn reason(&self) -> Option<ContentType<'msg>> {
+        self.reason
+    }
+    /// Returns the two server names if the reason looks like a netsplit.
+    ///
+    /// The reason must consist of exactly two server names separated by a single space and each must contain a `.`.
+    #[must_use]
+    pub const fn netsplit_servers(&self) -> Option<(ContentType<'msg>, ContentType<'msg>)> {
+        let Some(reason) = self.reason else {return None;};
+        let bytes = reason.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] == b' ' {
+                let (first, rest) = bytes.split_at(index);
+                let (_, second) = rest.split_at(1);
+                if !is_servername(first) || !is_servername(second) {return None;}
+                return Some((ContentType::new(first), ContentType::new(second)));
+            }
+            index += 1;
+        }
+        None
+    }
+    /// Check whether the reason looks like a netsplit.
+    #[must_use]
+    pub const fn is_netsplit(&self) -> bool {
+        self.netsplit_servers().is_some()
+    }
+}
+
+const fn is_servername(input: &[u8]) -> bool {
+    if input.is_empty() || input[0] == b'.' || input[input.len() - 1] == b'.' {return false;}
+    let (mut index, mut dotted) = (0, false);
+    while index < input.len() {
+        match input[index] {
+            b'.' => dotted = true,
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'*' | b':' => {},
+            _ => return false,
+        }
+        index += 1;
+    }
+    dotted
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::QuitMsg;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_quit() {
+        let msg = IrcMsg::parse(b":dan!d@localhost QUIT :Quit: Bye for now!");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let quit = QuitMsg::from_msg(&msg);
+            assert!(quit.is_some());
+            if let Some(quit) = quit {
+                let pair = (quit.nick(), quit.reason());
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(nick), Some(reason)) = pair {
+                    assert!(is_identical(nick.as_bytes(), b"dan"));
+                    assert!(is_identical(reason.as_bytes(), b"Quit: Bye for now!"));
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b"QUIT");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let quit = QuitMsg::from_msg(&msg);
+            assert!(quit.is_some());
+            if let Some(quit) = quit {assert!(quit.source().is_none() && quit.reason().is_none());}
+        }
+        let msg = IrcMsg::parse(b":dan!d@localhost QUIT :irc.hub.net irc.leaf.net");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let quit = QuitMsg::from_msg(&msg);
+            assert!(quit.is_some());
+            if let Some(quit) = quit {
+                let leaf = quit.netsplit_servers();
+                assert!(matches!(leaf, Some((_, _))));
+                if let Some((hub, leaf)) = leaf {
+                    assert!(is_identical(hub.as_bytes(), b"irc.hub.net"));
+                    assert!(is_identical(leaf.as_bytes(), b"irc.leaf.net"));
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":dan!d@localhost QUIT :see you. later.");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let quit = QuitMsg::from_msg(&msg);
+            assert!(quit.is_some());
+            if let Some(quit) = quit {assert!(!quit.is_netsplit());}
+        }
+        let msg = IrcMsg::parse(b":dan!d@localhost QUIT :a.net b.net c.net");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let quit = QuitMsg::from_msg(&msg);
+            assert!(quit.is_some());
+            if let Some(quit) = quit {assert!(!quit.is_netsplit());}
+        }
+        let msg = IrcMsg::parse(b":dan!d@localhost QUIT :Quit: a.net");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let quit = QuitMsg::from_msg(&msg);
+            assert!(quit.is_some());
+            if let Some(quit) = quit {assert!(!quit.is_netsplit());}
+        }
+        let msg = IrcMsg::parse(b"PART #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(QuitMsg::from_msg(&msg).is_none());}
+    }
+}
@@ -0,0 +1,116 @@
+//! Methods for reading and writing `SETNAME` messages.
+//!
+//! ## Purpose
+//!
+//! With the [setname] capability enabled an IRC client sends `SETNAME` to change its realname and the IRC server
+//! forwards the change to the client and users in shared channels. The realname is always the trailing parameter.
+//!
+//! [setname]: <https://ircv3.net/specs/extensions/setname>
+
+use crate::{ContentType, IrcMsg, IrcMsgWriter, IrcMsgWriterError, command::CommandKind, source::Source};
+
+/// A view over a `SETNAME` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SetnameMsg<'msg> {
+    source: Option<Source<'msg>>,
+    realname: ContentType<'msg>,
+}
+
+impl<'msg> SetnameMsg<'msg> {
+    /// Generates a [`SetnameMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `SETNAME` command with a realname.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Setname)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        Some(Self{source: msg.source(), realname: params.extract_last()})
+    }
+    /// Returns the [`Source`] of the user whose realname changed.
+    ///
+    /// Returns `None` for a [`SetnameMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the user whose realname changed.
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Returns the new realname.
+    #[must_use]
+    pub const fn realname(&self) -> ContentType<'msg> {
+        self.realname
+    }
+}
+
+/// Writes a `SETNAME` changing the realname with the realname as the trailing parameter.
+///
+/// # Errors
+///
+/// Will return `Err` if the `realname` contains a null, carriage return or line feed or the message is longer
+/// than `N` bytes.
+pub const fn setname<const N: usize>(realname: &[u8]) -> Result<IrcMsgWriter<N>, IrcMsgWriterError> {
+    match IrcMsgWriter::new().command(b"SETNAME") {
+        Ok(writer) => writer.trailing_with_colon(realname),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{SetnameMsg, setname};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_setname() {
+        let msg = IrcMsg::parse(b":dan!d@localhost SETNAME :Dan Smith");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let setname = SetnameMsg::from_msg(&msg);
+            assert!(setname.is_some());
+            if let Some(setname) = setname {
+                let nick = setname.nick();
+                assert!(nick.is_some());
+                if let Some(nick) = nick {
+                    assert!(is_identical(nick.as_bytes(), b"dan"));
+                    assert!(is_identical(setname.realname().as_bytes(), b"Dan Smith"));
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b"SETNAME Dan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let setname = SetnameMsg::from_msg(&msg);
+            assert!(setname.is_some());
+            if let Some(setname) = setname {
+                assert!(setname.source().is_none() && is_identical(setname.realname().as_bytes(), b"Dan"));
+            }
+        }
+        let msg = IrcMsg::parse(b"CHGHOST dan localhost");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(SetnameMsg::from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn writing_setname() {
+        let writer = setname::<32>(b"Dan");
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {assert!(is_identical(writer.as_bytes(), b"SETNAME :Dan"));}
+        let writer = setname::<32>(b"Dan Smith");
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {
+            assert!(is_identical(writer.as_bytes(), b"SETNAME :Dan Smith"));
+            let msg = IrcMsg::parse(writer.as_bytes());
+            assert!(msg.is_ok());
+            if let Ok(msg) = msg {
+                let view = SetnameMsg::from_msg(&msg);
+                assert!(view.is_some());
+                if let Some(view) = view {assert!(is_identical(view.realname().as_bytes(), b"Dan Smith"));}
+            }
+        }
+        assert!(setname::<32>(b"Dan\r\n").is_err() && setname::<8>(b"Dan").is_err());
+    }
+}
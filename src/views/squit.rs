@@ -0,0 +1,77 @@
+//! Methods for reading `SQUIT` messages.
+//!
+//! ## Purpose
+//!
+//! An IRC operator sends `SQUIT` to disconnect a server link. The first parameter is the name of the server to
+//! disconnect and the second a comment explaining why.
+
+use crate::{ContentType, IrcMsg, command::CommandKind, source::Source};
+
+/// A view over a `SQUIT` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SquitMsg<'msg> {
+    source: Option<Source<'msg>>,
+    server: ContentType<'msg>,
+    comment: ContentType<'msg>,
+}
+
+impl<'msg> SquitMsg<'msg> {
+    /// Generates a [`SquitMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `SQUIT` command with a server and comment.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Squit)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        let Some(comment) = params.extract_specific(1) else {return None;};
+        Some(Self{source: msg.source(), server: params.extract_first(), comment})
+    }
+    /// Returns the [`Source`] of the IRC operator or IRC server who sent the `SQUIT`.
+    ///
+    /// Returns `None` for a [`SquitMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the name of the server to disconnect.
+    #[must_use]
+    pub const fn server(&self) -> ContentType<'msg> {
+        self.server
+    }
+    /// Returns the comment.
+    #[must_use]
+    pub const fn comment(&self) -> ContentType<'msg> {
+        self.comment
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::SquitMsg;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_squit() {
+        let msg = IrcMsg::parse(b":oper!o@localhost SQUIT irc.leaf.net :Bad link");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let squit = SquitMsg::from_msg(&msg);
+            assert!(squit.is_some());
+            if let Some(squit) = squit {
+                assert!(is_identical(squit.server().as_bytes(), b"irc.leaf.net") && squit.source().is_some());
+                assert!(is_identical(squit.comment().as_bytes(), b"Bad link"));
+            }
+        }
+        let msg = IrcMsg::parse(b"SQUIT irc.leaf.net :Maintenance");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let squit = SquitMsg::from_msg(&msg);
+            assert!(squit.is_some());
+            if let Some(squit) = squit {
+                assert!(squit.source().is_none() && is_identical(squit.comment().as_bytes(), b"Maintenance"));
+            }
+        }
+        let msg = IrcMsg::parse(b"KILL dan :bye");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(SquitMsg::from_msg(&msg).is_none());}
+    }
+}
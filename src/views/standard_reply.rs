@@ -0,0 +1,140 @@
+//! Methods for reading `FAIL`, `WARN` and `NOTE` messages.
+//!
+//! ## Purpose
+//!
+//! [Standard replies] let an IRC server report failures, warnings and notes in a machine readable way.
+//! Each one names the command it relates to (or `*`), a code, optional context parameters and a human readable
+//! description as the last parameter.
+//!
+//! [Standard replies]: <https://ircv3.net/specs/extensions/standard-replies>
+
+use crate::{ContentType, IrcMsg, command::CommandKind, is_identical, parameters::Parameters};
+
+/// The severity of a [`StandardReply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StandardReplyKind {
+    /// `FAIL` indicates a command failed.
+    Fail,
+    /// `WARN` indicates a command succeeded with something worth noting.
+    Warn,
+    /// `NOTE` gives information about a command.
+    Note,
+}
+
+/// A view over a `FAIL`, `WARN` or `NOTE` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct StandardReply<'msg> {
+    kind: StandardReplyKind,
+    params: Parameters<'msg>,
+}
+
+impl<'msg> StandardReply<'msg> {
+    /// Generates a [`StandardReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `FAIL`, `WARN` or `NOTE` command with at least a command, code
+    /// and description.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let kind = match msg.command().kind() {
+            Some(CommandKind::Fail) => StandardReplyKind::Fail,
+            Some(CommandKind::Warn) => StandardReplyKind::Warn,
+            Some(CommandKind::Note) => StandardReplyKind::Note,
+            _ => return None,
+        };
+        let Some(params) = msg.parameters() else {return None;};
+        if params.count() < 3 {return None;}
+        Some(Self{kind, params})
+    }
+    /// Returns the [`StandardReplyKind`].
+    #[must_use]
+    pub const fn kind(&self) -> StandardReplyKind {
+        self.kind
+    }
+    /// Returns the command the [`StandardReply`] relates to.
+    ///
+    /// Returns `None` if it doesn't relate to a specific command as indicated by `*`.
+    #[must_use]
+    pub const fn command(&self) -> Option<ContentType<'msg>> {
+        let command = self.params.extract_first();
+        if is_identical(command.as_bytes(), b"*") {None} else {Some(command)}
+    }
+    /// Returns the machine readable code such as `ACCOUNT_REQUIRED`.
+    #[must_use]
+    pub const fn code(&self) -> ContentType<'msg> {
+        match self.params.extract_specific(1) {
+            Some(code) => code,
+            None => unreachable!(),
+        }
+    }
+    /// Returns the amount of context parameters between the code and the description.
+    #[must_use]
+    pub const fn context_count(&self) -> usize {
+        self.params.count() - 3
+    }
+    /// Returns the context parameter at the specified index.
+    ///
+    /// Index starts at 0. If out of bounds it returns `None`.
+    #[must_use]
+    pub const fn context(&self, index: usize) -> Option<ContentType<'msg>> {
+        if index >= self.context_count() {return None;}
+        self.params.extract_specific(index + 2)
+    }
+    /// Returns the human readable description.
+    #[must_use]
+    pub const fn description(&self) -> ContentType<'msg> {
+        self.params.extract_last()
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{StandardReply, StandardReplyKind};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_standard_replies() {
+        let input = b":irc.example.com FAIL CHATHISTORY MESSAGE_ERROR the_given_command #chan :Messages unavailable";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = StandardReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                let command = reply.command();
+                assert!(command.is_some());
+                if let Some(command) = command {
+                    assert!(matches!(reply.kind(), StandardReplyKind::Fail));
+                    assert!(is_identical(command.as_bytes(), b"CHATHISTORY"));
+                }
+                assert!(is_identical(reply.code().as_bytes(), b"MESSAGE_ERROR") && reply.context_count() == 2);
+                let pair = (reply.context(0), reply.context(1));
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(first), Some(second)) = pair {
+                    assert!(is_identical(first.as_bytes(), b"the_given_command"));
+                    assert!(is_identical(second.as_bytes(), b"#chan"));
+                }
+                assert!(reply.context(2).is_none());
+                assert!(is_identical(reply.description().as_bytes(), b"Messages unavailable"));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com NOTE * OPER_MESSAGE :The server is restarting");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = StandardReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), StandardReplyKind::Note) && reply.command().is_none());
+                assert!(reply.context_count() == 0 && reply.context(0).is_none());
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com WARN REHASH CERTS_EXPIRED :Certificate expired");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = StandardReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {assert!(matches!(reply.kind(), StandardReplyKind::Warn));}
+        }
+        let msg = IrcMsg::parse(b":irc.example.com NOTICE * :hi");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(StandardReply::from_msg(&msg).is_none());}
+    }
+}
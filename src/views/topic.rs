@@ -0,0 +1,309 @@
+//! Methods for reading `TOPIC` messages.
+//!
+//! ## Purpose
+//!
+//! An IRC client sends `TOPIC` with only a channel to query its topic or with a new topic to change it.
+//! The IRC server sends it with the user who changed the topic as the [`Source`].
+//!
+//! On joining a channel or querying its topic the IRC server replies with `RPL_NOTOPIC` (`331`) or with
+//! `RPL_TOPIC` (`332`) followed by `RPL_TOPICWHOTIME` (`333`) naming who set the topic and when.
+//! A [`TopicRecord`] combines these replies into a single record.
+
+use crate::{ContentType, IrcMsg, command::CommandKind, is_identical, source::Source};
+
+/// A view over a `TOPIC` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TopicMsg<'msg> {
+    source: Option<Source<'msg>>,
+    channel: ContentType<'msg>,
+    topic: Option<ContentType<'msg>>,
+}
+
+impl<'msg> TopicMsg<'msg> {
+    /// Generates a [`TopicMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `TOPIC` command with a channel.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Topic)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        Some(Self{source: msg.source(), channel: params.extract_first(), topic: params.extract_specific(1)})
+    }
+    /// Returns the [`Source`] of the user who changed the topic.
+    ///
+    /// Returns `None` for a [`TopicMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the user who changed the topic.
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Returns the channel.
+    #[must_use]
+    pub const fn channel(&self) -> ContentType<'msg> {
+        self.channel
+    }
+    /// Returns the new topic or `None` if the topic is queried.
+    ///
+    /// An empty topic clears the topic of the channel.
+    #[must_use]
+    pub const fn topic(&self) -> Option<ContentType<'msg>> {
+        self.topic
+    }
+}
+
+/// The kind of a [`TopicReply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TopicReplyKind {
+    /// `RPL_NOTOPIC` (`331`) the channel has no topic.
+    NoTopic,
+    /// `RPL_TOPIC` (`332`) the topic of the channel.
+    Topic,
+    /// `RPL_TOPICWHOTIME` (`333`) who set the topic and when.
+    WhoTime,
+}
+
+/// A view over an `RPL_NOTOPIC` (`331`), `RPL_TOPIC` (`332`) or `RPL_TOPICWHOTIME` (`333`) numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TopicReply<'msg> {
+    kind: TopicReplyKind,
+    client: ContentType<'msg>,
+    channel: ContentType<'msg>,
+    last: ContentType<'msg>,
+    setter: Option<ContentType<'msg>>,
+}
+
+impl<'msg> TopicReply<'msg> {
+    /// Generates a [`TopicReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_NOTOPIC` (`331`), `RPL_TOPIC` (`332`) or
+    /// `RPL_TOPICWHOTIME` (`333`) numeric with enough parameters.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(numeric) = msg.command().as_numeric_u16() else {return None;};
+        let Some(params) = msg.parameters() else {return None;};
+        let (kind, setter) = match numeric {
+            331 => (TopicReplyKind::NoTopic, None),
+            332 => (TopicReplyKind::Topic, None),
+            333 if params.count() >= 4 => (TopicReplyKind::WhoTime, params.extract_specific(2)),
+            _ => return None,
+        };
+        let Some(channel) = params.extract_specific(1) else {return None;};
+        Some(Self{kind, client: params.extract_first(), channel, last: params.extract_last(), setter})
+    }
+    /// Returns the [`TopicReplyKind`].
+    #[must_use]
+    pub const fn kind(&self) -> TopicReplyKind {
+        self.kind
+    }
+    /// Returns the nickname of the client receiving the [`TopicReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the channel.
+    #[must_use]
+    pub const fn channel(&self) -> ContentType<'msg> {
+        self.channel
+    }
+    /// Returns the topic from an `RPL_TOPIC` (`332`).
+    #[must_use]
+    pub const fn topic(&self) -> Option<ContentType<'msg>> {
+        if matches!(self.kind, TopicReplyKind::Topic) {Some(self.last)} else {None}
+    }
+    /// Returns who set the topic from an `RPL_TOPICWHOTIME` (`333`).
+    ///
+    /// Depending on the IRC server this is either a nickname or a full `nick!user@host` mask.
+    #[must_use]
+    pub const fn setter(&self) -> Option<ContentType<'msg>> {
+        self.setter
+    }
+    /// Returns the Unix timestamp of when the topic was set from an `RPL_TOPICWHOTIME` (`333`).
+    #[must_use]
+    pub const fn set_at(&self) -> Option<u64> {
+        if matches!(self.kind, TopicReplyKind::WhoTime) {self.last.to_u64()} else {None}
+    }
+}
+
+/// The topic of a channel combined from its [`TopicReply`]s.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TopicRecord<'msg> {
+    channel: ContentType<'msg>,
+    text: Option<ContentType<'msg>>,
+    setter: Option<ContentType<'msg>>,
+    set_at: Option<u64>,
+}
+
+impl<'msg> TopicRecord<'msg> {
+    /// Generates an empty [`TopicRecord`] for the channel.
+    #[must_use]
+    pub const fn new(channel: ContentType<'msg>) -> Self {
+        Self{channel, text: None, setter: None, set_at: None}
+    }
+    /// Generates a [`TopicRecord`] from the first [`TopicReply`] received for a channel.
+    #[must_use]
+    pub const fn from_reply(reply: &TopicReply<'msg>) -> Self {
+        match Self::new(reply.channel).apply(reply) {
+            Ok(record) => record,
+            Err(_) => Self::new(reply.channel),
+        }
+    }
+    /// Combines a [`TopicReply`] into the [`TopicRecord`].
+    ///
+    /// An `RPL_NOTOPIC` (`331`) clears the record while the other replies fill in their part of it.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the [`TopicReply`] concerns a different channel.
+    pub const fn apply(mut self, reply: &TopicReply<'msg>) -> Result<Self, TopicRecordError> {
+        if !is_identical(self.channel.as_bytes(), reply.channel.as_bytes()) {
+            return Err(TopicRecordError::ChannelMismatch);
+        }
+        match reply.kind {
+            TopicReplyKind::NoTopic => return Ok(Self::new(self.channel)),
+            TopicReplyKind::Topic => self.text = Some(reply.last),
+            TopicReplyKind::WhoTime => {
+                self.setter = reply.setter;
+                self.set_at = reply.set_at();
+            },
+        }
+        Ok(self)
+    }
+    /// Returns the channel.
+    #[must_use]
+    pub const fn channel(&self) -> ContentType<'msg> {
+        self.channel
+    }
+    /// Returns the topic text if known.
+    #[must_use]
+    pub const fn text(&self) -> Option<ContentType<'msg>> {
+        self.text
+    }
+    /// Returns who set the topic if known.
+    #[must_use]
+    pub const fn setter(&self) -> Option<ContentType<'msg>> {
+        self.setter
+    }
+    /// Returns the Unix timestamp of when the topic was set if known.
+    #[must_use]
+    pub const fn set_at(&self) -> Option<u64> {
+        self.set_at
+    }
+    /// Check whether the text, setter and time of the topic are all known.
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.text.is_some() && self.setter.is_some() && self.set_at.is_some()
+    }
+}
+
+/// The possible types of errors when combining a [`TopicReply`] into a [`TopicRecord`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TopicRecordError {
+    /// The [`TopicReply`] concerns a different channel.
+    ChannelMismatch,
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{TopicMsg, TopicRecord, TopicRecordError, TopicReply, TopicReplyKind};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_topic() {
+        let msg = IrcMsg::parse(b":dan!d@localhost TOPIC #chan :New topic");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let topic = TopicMsg::from_msg(&msg);
+            assert!(topic.is_some());
+            if let Some(topic) = topic {
+                let pair = (topic.nick(), topic.topic());
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(nick), Some(text)) = pair {
+                    assert!(is_identical(nick.as_bytes(), b"dan"));
+                    assert!(is_identical(topic.channel().as_bytes(), b"#chan"));
+                    assert!(is_identical(text.as_bytes(), b"New topic"));
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b"TOPIC #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let topic = TopicMsg::from_msg(&msg);
+            assert!(topic.is_some());
+            if let Some(topic) = topic {assert!(topic.topic().is_none() && topic.source().is_none());}
+        }
+        let msg = IrcMsg::parse(b"TOPIC #chan :");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let topic = TopicMsg::from_msg(&msg);
+            assert!(topic.is_some());
+            if let Some(topic) = topic {
+                let text = topic.topic();
+                assert!(text.is_some());
+                if let Some(text) = text {assert!(text.as_bytes().is_empty());}
+            }
+        }
+        let msg = IrcMsg::parse(b"MODE #chan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(TopicMsg::from_msg(&msg).is_none());}
+    }
+    const fn reply_of(input: &[u8]) -> Option<TopicReply<'_>> {
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {return TopicReply::from_msg(&msg);}
+        None
+    }
+    #[test]
+    const fn combining_topic_replies() {
+        let topic = reply_of(b":irc.host 332 dan #chan :Welcome to #chan");
+        assert!(topic.is_some());
+        if let Some(topic) = topic {
+            let text = topic.topic();
+            assert!(text.is_some());
+            if let Some(text) = text {
+                assert!(matches!(topic.kind(), TopicReplyKind::Topic));
+                assert!(is_identical(text.as_bytes(), b"Welcome to #chan"));
+            }
+            assert!(is_identical(topic.client().as_bytes(), b"dan") && topic.set_at().is_none());
+            let record = TopicRecord::from_reply(&topic);
+            assert!(!record.is_complete() && record.setter().is_none());
+            let whotime = reply_of(b":irc.host 333 dan #chan bob!b@host 1700000000");
+            assert!(whotime.is_some());
+            if let Some(whotime) = whotime {
+                assert!(matches!(whotime.set_at(), Some(1_700_000_000)) && whotime.topic().is_none());
+                let record = record.apply(&whotime);
+                assert!(record.is_ok());
+                if let Ok(record) = record {
+                    let pair = (record.text(), record.setter());
+                    assert!(matches!(pair, (Some(_), Some(_))));
+                    if let (Some(text), Some(setter)) = pair {
+                        assert!(record.is_complete() && is_identical(text.as_bytes(), b"Welcome to #chan"));
+                        assert!(is_identical(setter.as_bytes(), b"bob!b@host"));
+                        assert!(matches!(record.set_at(), Some(1_700_000_000)));
+                    }
+                    let notopic = reply_of(b":irc.host 331 dan #chan :No topic is set");
+                    assert!(notopic.is_some());
+                    if let Some(notopic) = notopic {
+                        let record = record.apply(&notopic);
+                        assert!(record.is_ok());
+                        if let Ok(record) = record {
+                            assert!(record.text().is_none());
+                            assert!(is_identical(record.channel().as_bytes(), b"#chan"));
+                            let other = reply_of(b":irc.host 332 dan #other :Other");
+                            assert!(other.is_some());
+                            if let Some(other) = other {
+                                assert!(matches!(record.apply(&other), Err(TopicRecordError::ChannelMismatch)));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        assert!(reply_of(b":irc.host 333 dan #chan bob").is_none());
+    }
+}
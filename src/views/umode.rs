@@ -0,0 +1,175 @@
+//! Methods for reading the user modes of the IRC client.
+//!
+//! ## Purpose
+//!
+//! The IRC server replies to a `MODE` query for the nickname of the IRC client with `RPL_UMODEIS` (`221`) listing
+//! the current user modes. Changes are announced with a `MODE` message targeting the nickname. Both are decoded
+//! into [`UserModes`] which can be combined to track the current user modes.
+
+use crate::{ContentType, IrcMsg, casemapping::IrcCaseMapping, command::CommandKind};
+
+/// A set of user mode characters that are set or unset.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct UserModes {
+    set: u128,
+    unset: u128,
+}
+
+impl UserModes {
+    /// Generates an empty [`UserModes`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self{set: 0, unset: 0}
+    }
+    /// Decodes a modestring such as `+iw-x` into [`UserModes`].
+    ///
+    /// Modes before any `+` or `-` count as set. Non-ASCII bytes are ignored.
+    #[must_use]
+    pub const fn from_modestring(modestring: &[u8]) -> Self {
+        let mut modes = Self::new();
+        let mut adding = true;
+        let mut index = 0;
+        while index < modestring.len() {
+            match modestring[index] {
+                b'+' => adding = true,
+                b'-' => adding = false,
+                mode if mode.is_ascii() => {
+                    let bit = 1 << mode;
+                    if adding {
+                        modes.set |= bit;
+                        modes.unset &= !bit;
+                    } else {
+                        modes.unset |= bit;
+                        modes.set &= !bit;
+                    }
+                },
+                _ => {},
+            }
+            index += 1;
+        }
+        modes
+    }
+    /// Check whether the mode is set.
+    #[must_use]
+    pub const fn is_set(&self, mode: u8) -> bool {
+        mode.is_ascii() && self.set & (1 << mode) != 0
+    }
+    /// Check whether the mode is unset.
+    #[must_use]
+    pub const fn is_unset(&self, mode: u8) -> bool {
+        mode.is_ascii() && self.unset & (1 << mode) != 0
+    }
+    /// Returns the amount of modes that are set.
+    #[must_use]
+    pub const fn set_count(&self) -> u32 {
+        self.set.count_ones()
+    }
+    /// Applies the changes on top of the current [`UserModes`].
+    ///
+    /// Modes set or unset by the changes override the current state.
+    #[must_use]
+    pub const fn apply(self, changes: Self) -> Self {
+        Self{
+            set: (self.set & !changes.unset) | changes.set,
+            unset: (self.unset & !changes.set) | changes.unset,
+        }
+    }
+}
+
+/// A view over an `RPL_UMODEIS` (`221`) numeric [`IrcMsg`] or a `MODE` [`IrcMsg`] changing the user modes of the
+/// IRC client.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UserModeReply<'msg> {
+    client: ContentType<'msg>,
+    modestring: ContentType<'msg>,
+    is_change: bool,
+}
+
+impl<'msg> UserModeReply<'msg> {
+    /// Generates a [`UserModeReply`] from an `RPL_UMODEIS` (`221`) [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_UMODEIS` (`221`) numeric.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(221) = msg.command().as_numeric_u16() else {return None;};
+        let Some(params) = msg.parameters() else {return None;};
+        let Some(modestring) = params.extract_specific(1) else {return None;};
+        Some(Self{client: params.extract_first(), modestring, is_change: false})
+    }
+    /// Generates a [`UserModeReply`] from a `MODE` [`IrcMsg`] targeting `my_nick`.
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `MODE` command with a modestring targeting `my_nick` according to
+    /// the [`IrcCaseMapping`].
+    #[must_use]
+    pub const fn from_mode_msg(msg: &IrcMsg<'msg>, my_nick: &[u8], casemapping: IrcCaseMapping) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Mode)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        let Some(modestring) = params.extract_specific(1) else {return None;};
+        let client = params.extract_first();
+        if !casemapping.is_equivalent(client.as_bytes(), my_nick) {return None;}
+        Some(Self{client, modestring, is_change: true})
+    }
+    /// Returns the nickname of the IRC client.
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the modestring as it appears in the message.
+    #[must_use]
+    pub const fn modestring(&self) -> ContentType<'msg> {
+        self.modestring
+    }
+    /// Check whether the [`UserModeReply`] came from a `MODE` message changing the user modes.
+    ///
+    /// An `RPL_UMODEIS` (`221`) lists all current user modes instead.
+    #[must_use]
+    pub const fn is_change(&self) -> bool {
+        self.is_change
+    }
+    /// Returns the decoded [`UserModes`].
+    #[must_use]
+    pub const fn modes(&self) -> UserModes {
+        UserModes::from_modestring(self.modestring.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{UserModeReply, UserModes};
+    use crate::{IrcMsg, casemapping::IrcCaseMapping, is_identical};
+    #[test]
+    const fn decoding_user_modes() {
+        let modes = UserModes::from_modestring(b"+iw-x");
+        assert!(modes.is_set(b'i') && modes.is_set(b'w') && modes.is_unset(b'x') && !modes.is_set(b'x'));
+        assert!(modes.set_count() == 2 && !modes.is_set(b'o') && !modes.is_unset(b'o') && !modes.is_set(200));
+        let modes = modes.apply(UserModes::from_modestring(b"-i+xo"));
+        assert!(modes.is_unset(b'i') && modes.is_set(b'x') && modes.is_set(b'o') && modes.set_count() == 3);
+        assert!(UserModes::from_modestring(b"iw").is_set(b'w') && UserModes::new().set_count() == 0);
+    }
+    #[test]
+    const fn reading_user_modes() {
+        let msg = IrcMsg::parse(b":irc.host 221 dan +iwx");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = UserModeReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(is_identical(reply.client().as_bytes(), b"dan"));
+                assert!(is_identical(reply.modestring().as_bytes(), b"+iwx"));
+                assert!(!reply.is_change() && reply.modes().is_set(b'x') && reply.modes().set_count() == 3);
+            }
+        }
+        let msg = IrcMsg::parse(b":Dan MODE dan :-x");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = UserModeReply::from_mode_msg(&msg, b"DAN", IrcCaseMapping::Ascii);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {assert!(reply.is_change() && reply.modes().is_unset(b'x'));}
+            assert!(UserModeReply::from_mode_msg(&msg, b"bob", IrcCaseMapping::Ascii).is_none());
+            assert!(UserModeReply::from_msg(&msg).is_none());
+        }
+        let msg = IrcMsg::parse(b":irc.host MODE #chan +o dan");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(UserModeReply::from_mode_msg(&msg, b"dan", IrcCaseMapping::Ascii).is_none());}
+    }
+}
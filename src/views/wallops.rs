@@ -0,0 +1,95 @@
+//! Methods for reading `WALLOPS` messages.
+//!
+//! ## Purpose
+//!
+//! `WALLOPS` broadcasts a message to every user with the `w` user mode set. It is sent by IRC operators or by IRC
+//! servers themselves and is usually shown apart from the regular channel and private messages.
+
+use crate::{ContentType, IrcMsg, command::CommandKind, source::{Origin, Source}};
+
+/// A view over a `WALLOPS` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WallopsMsg<'msg> {
+    source: Option<Source<'msg>>,
+    text: ContentType<'msg>,
+}
+
+impl<'msg> WallopsMsg<'msg> {
+    /// Generates a [`WallopsMsg`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not a `WALLOPS` command with text.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Wallops)) {return None;}
+        let Some(params) = msg.parameters() else {return None;};
+        Some(Self{source: msg.source(), text: params.extract_last()})
+    }
+    /// Returns the [`Source`] of the sender.
+    ///
+    /// Returns `None` for a [`WallopsMsg`] sent by an IRC client.
+    #[must_use]
+    pub const fn source(&self) -> Option<Source<'msg>> {
+        self.source
+    }
+    /// Returns the nick of the IRC operator who sent the [`WallopsMsg`].
+    ///
+    /// Returns `None` if an IRC server sent the [`WallopsMsg`].
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        match self.source {
+            Some(source) => source.nick(),
+            None => None,
+        }
+    }
+    /// Check whether an IRC server sent the [`WallopsMsg`].
+    #[must_use]
+    pub const fn is_from_server(&self) -> bool {
+        match self.source {
+            Some(source) => matches!(source.origin(), Origin::Servername(_) | Origin::Sid(_)),
+            None => false,
+        }
+    }
+    /// Returns the text.
+    #[must_use]
+    pub const fn text(&self) -> ContentType<'msg> {
+        self.text
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::WallopsMsg;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_wallops() {
+        let msg = IrcMsg::parse(b":dan!d@localhost WALLOPS :Server restart in 5 minutes");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let wallops = WallopsMsg::from_msg(&msg);
+            assert!(wallops.is_some());
+            if let Some(wallops) = wallops {
+                let nick = wallops.nick();
+                assert!(nick.is_some());
+                if let Some(nick) = nick {assert!(is_identical(nick.as_bytes(), b"dan") && !wallops.is_from_server());}
+                assert!(is_identical(wallops.text().as_bytes(), b"Server restart in 5 minutes"));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host WALLOPS :Netsplit");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let wallops = WallopsMsg::from_msg(&msg);
+            assert!(wallops.is_some());
+            if let Some(wallops) = wallops {assert!(wallops.is_from_server() && wallops.nick().is_none());}
+        }
+        let msg = IrcMsg::parse(b"WALLOPS :hi");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let wallops = WallopsMsg::from_msg(&msg);
+            assert!(wallops.is_some());
+            if let Some(wallops) = wallops {assert!(wallops.source().is_none() && !wallops.is_from_server());}
+        }
+        let msg = IrcMsg::parse(b":irc.host NOTICE dan :hi");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(WallopsMsg::from_msg(&msg).is_none());}
+    }
+}
@@ -0,0 +1,262 @@
+//! Methods for reading the numeric replies of the `WATCH` command.
+//!
+//! ## Purpose
+//!
+//! Some IRC networks offer `WATCH` instead of [`MONITOR`](super::monitor) to be notified when users connect,
+//! disconnect or change their away status. Most replies carry the nick, user, host and a Unix timestamp of the
+//! watched user while the remaining replies describe the watch list itself.
+
+use crate::{ContentType, IrcMsg, parameters::SpaceList};
+
+/// The kind of a [`WatchReply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WatchReplyKind {
+    /// `RPL_GONEAWAY` (`598`) a watched user went away.
+    GoneAway,
+    /// `RPL_NOTAWAY` (`599`) a watched user is no longer away.
+    NotAway,
+    /// `RPL_LOGON` (`600`) a watched user connected.
+    Logon,
+    /// `RPL_LOGOFF` (`601`) a watched user disconnected.
+    Logoff,
+    /// `RPL_WATCHOFF` (`602`) a user was removed from the watch list.
+    WatchOff,
+    /// `RPL_NOWON` (`604`) a user added to the watch list is online.
+    NowOn,
+    /// `RPL_NOWOFF` (`605`) a user added to the watch list is offline.
+    NowOff,
+    /// `RPL_NOWISAWAY` (`609`) a user added to the watch list is away.
+    NowIsAway,
+}
+
+/// A view over a `WATCH` numeric [`IrcMsg`] concerning a single watched user.
+///
+/// Covers `RPL_GONEAWAY` (`598`), `RPL_NOTAWAY` (`599`), `RPL_LOGON` (`600`), `RPL_LOGOFF` (`601`),
+/// `RPL_WATCHOFF` (`602`), `RPL_NOWON` (`604`), `RPL_NOWOFF` (`605`) and `RPL_NOWISAWAY` (`609`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WatchReply<'msg> {
+    kind: WatchReplyKind,
+    client: ContentType<'msg>,
+    nick: ContentType<'msg>,
+    user: ContentType<'msg>,
+    host: ContentType<'msg>,
+    timestamp: ContentType<'msg>,
+    text: Option<ContentType<'msg>>,
+}
+
+impl<'msg> WatchReply<'msg> {
+    /// Generates a [`WatchReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not one of the covered numerics with enough parameters.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let kind = match msg.command().as_numeric_u16() {
+            Some(598) => WatchReplyKind::GoneAway,
+            Some(599) => WatchReplyKind::NotAway,
+            Some(600) => WatchReplyKind::Logon,
+            Some(601) => WatchReplyKind::Logoff,
+            Some(602) => WatchReplyKind::WatchOff,
+            Some(604) => WatchReplyKind::NowOn,
+            Some(605) => WatchReplyKind::NowOff,
+            Some(609) => WatchReplyKind::NowIsAway,
+            _ => return None,
+        };
+        let Some(params) = msg.parameters() else {return None;};
+        let (Some(nick), Some(user), Some(host), Some(timestamp)) = (params.extract_specific(1),
+            params.extract_specific(2), params.extract_specific(3), params.extract_specific(4)) else {return None;};
+        let text = params.extract_specific(5);
+        Some(Self{kind, client: params.extract_first(), nick, user, host, timestamp, text})
+    }
+    /// Returns the [`WatchReplyKind`].
+    #[must_use]
+    pub const fn kind(&self) -> WatchReplyKind {
+        self.kind
+    }
+    /// Returns the nickname of the client receiving the [`WatchReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the nick of the watched user.
+    #[must_use]
+    pub const fn nick(&self) -> ContentType<'msg> {
+        self.nick
+    }
+    /// Returns the user of the watched user.
+    ///
+    /// This is `*` if the watched user is offline.
+    #[must_use]
+    pub const fn user(&self) -> ContentType<'msg> {
+        self.user
+    }
+    /// Returns the host of the watched user.
+    ///
+    /// This is `*` if the watched user is offline.
+    #[must_use]
+    pub const fn host(&self) -> ContentType<'msg> {
+        self.host
+    }
+    /// Returns the Unix timestamp of the change such as when the watched user connected or went away.
+    ///
+    /// Returns `None` if it is not a number. It is `0` if unknown.
+    #[must_use]
+    pub const fn timestamp(&self) -> Option<u64> {
+        self.timestamp.to_u64()
+    }
+    /// Returns the human readable text if present.
+    ///
+    /// For an `RPL_GONEAWAY` (`598`) this is the away message of the watched user.
+    #[must_use]
+    pub const fn text(&self) -> Option<ContentType<'msg>> {
+        self.text
+    }
+    /// Check whether the watched user is online after this [`WatchReply`].
+    #[must_use]
+    pub const fn is_online(&self) -> bool {
+        !matches!(self.kind, WatchReplyKind::Logoff | WatchReplyKind::NowOff)
+    }
+}
+
+/// The kind of a [`WatchListReply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WatchListKind {
+    /// `RPL_WATCHSTAT` (`603`) the size of the watch list.
+    Stat,
+    /// `RPL_WATCHLIST` (`606`) the nicks on the watch list.
+    List,
+    /// `RPL_ENDOFWATCHLIST` (`607`) ends the list.
+    EndOfList,
+    /// `RPL_WATCHCLEAR` (`608`) the watch list was cleared.
+    Clear,
+}
+
+/// A view over a `WATCH` numeric [`IrcMsg`] concerning the watch list.
+///
+/// Covers `RPL_WATCHSTAT` (`603`), `RPL_WATCHLIST` (`606`), `RPL_ENDOFWATCHLIST` (`607`) and `RPL_WATCHCLEAR`
+/// (`608`).
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WatchListReply<'msg> {
+    kind: WatchListKind,
+    client: ContentType<'msg>,
+    text: Option<ContentType<'msg>>,
+}
+
+impl<'msg> WatchListReply<'msg> {
+    /// Generates a [`WatchListReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not one of the covered numerics.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let kind = match msg.command().as_numeric_u16() {
+            Some(603) => WatchListKind::Stat,
+            Some(606) => WatchListKind::List,
+            Some(607) => WatchListKind::EndOfList,
+            Some(608) => WatchListKind::Clear,
+            _ => return None,
+        };
+        let Some(params) = msg.parameters() else {return None;};
+        let text = if params.count() > 1 {Some(params.extract_last())} else {None};
+        Some(Self{kind, client: params.extract_first(), text})
+    }
+    /// Returns the [`WatchListKind`].
+    #[must_use]
+    pub const fn kind(&self) -> WatchListKind {
+        self.kind
+    }
+    /// Returns the nickname of the client receiving the [`WatchListReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.client
+    }
+    /// Returns the human readable text if present.
+    #[must_use]
+    pub const fn text(&self) -> Option<ContentType<'msg>> {
+        self.text
+    }
+    /// Returns a [`SpaceList`] for reading each nick of an `RPL_WATCHLIST` (`606`).
+    ///
+    /// The [`SpaceList`] is empty for the other kinds.
+    #[must_use]
+    pub const fn nicks(&self) -> SpaceList<'msg> {
+        match (self.kind, self.text) {
+            (WatchListKind::List, Some(text)) => SpaceList::new(text),
+            _ => SpaceList::new(ContentType::StringSlice("")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{WatchListKind, WatchListReply, WatchReply, WatchReplyKind};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_watch_replies() {
+        let msg = IrcMsg::parse(b":irc.host 600 me dan d localhost 1700000000 :logged online");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = WatchReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), WatchReplyKind::Logon) && reply.is_online());
+                assert!(is_identical(reply.client().as_bytes(), b"me"));
+                assert!(is_identical(reply.nick().as_bytes(), b"dan"));
+                assert!(is_identical(reply.user().as_bytes(), b"d"));
+                assert!(is_identical(reply.host().as_bytes(), b"localhost"));
+                assert!(matches!(reply.timestamp(), Some(1_700_000_000)));
+                let text = reply.text();
+                assert!(text.is_some());
+                if let Some(text) = text {assert!(is_identical(text.as_bytes(), b"logged online"));}
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com 605 me bob * * 0 :is offline");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = WatchReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), WatchReplyKind::NowOff) && !reply.is_online());
+                assert!(matches!(reply.timestamp(), Some(0)));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 598 me dan d localhost 1700000000 :Gone to lunch");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = WatchReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {assert!(matches!(reply.kind(), WatchReplyKind::GoneAway) && reply.is_online());}
+        }
+        let msg = IrcMsg::parse(b":irc.example.com 606 me :dan bob");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(WatchReply::from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn reading_watch_list_replies() {
+        let msg = IrcMsg::parse(b":irc.example.com 606 me :dan bob");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = WatchListReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), WatchListKind::List) && reply.nicks().count() == 2);
+                let first = reply.nicks().next_item();
+                assert!(matches!(first, Some((_, _))));
+                if let Some((first, _)) = first {
+                    assert!(is_identical(first.as_bytes(), b"dan") && is_identical(reply.client().as_bytes(), b"me"));
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com 607 me :End of WATCH l");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let reply = WatchListReply::from_msg(&msg);
+            assert!(reply.is_some());
+            if let Some(reply) = reply {
+                assert!(matches!(reply.kind(), WatchListKind::EndOfList) && reply.nicks().count() == 0);
+                assert!(reply.text().is_some());
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.example.com 600 me dan d localhost 0 :logged online");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(WatchListReply::from_msg(&msg).is_none());}
+    }
+}
@@ -0,0 +1,157 @@
+//! Methods for reading and validating `WEBIRC` messages.
+//!
+//! ## Purpose
+//!
+//! A gateway such as a web client connecting users to an IRC server sends [`WEBIRC`] before registration to pass
+//! on the real hostname and IP address of the user. IRC servers must only trust it from gateways authenticated by
+//! the password so the IP address is validated before being used.
+//!
+//! [`WEBIRC`]: <https://ircv3.net/specs/extensions/webirc>
+
+use crate::{ContentType, IrcMsg, command::CommandKind, is_identical, parse_ipv4_octets, parse_ipv6_segments,
+    parameters::SpaceList};
+
+/// The IP address of the user sent in a [`WebircMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WebircAddress {
+    /// An IPv4 address as its four octets.
+    Ipv4([u8; 4]),
+    /// An IPv6 address as its eight 16-bit segments.
+    Ipv6([u16; 8]),
+}
+
+/// A view over a `WEBIRC` [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WebircMsg<'msg> {
+    password: ContentType<'msg>,
+    gateway: ContentType<'msg>,
+    hostname: ContentType<'msg>,
+    ip: ContentType<'msg>,
+    address: WebircAddress,
+    options: Option<ContentType<'msg>>,
+}
+
+impl<'msg> WebircMsg<'msg> {
+    /// Generates a [`WebircMsg`] from an [`IrcMsg`].
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the [`IrcMsg`] is not a `WEBIRC` command, a parameter is missing or the IP address is
+    /// not a valid IPv4 or IPv6 address.
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Result<Self, WebircError> {
+        if !matches!(msg.command().kind(), Some(CommandKind::Webirc)) {return Err(WebircError::NotWebirc);}
+        let Some(params) = msg.parameters() else {return Err(WebircError::MissingParameters);};
+        let (Some(gateway), Some(hostname), Some(ip)) = (params.extract_specific(1), params.extract_specific(2),
+            params.extract_specific(3)) else {return Err(WebircError::MissingParameters);};
+        let address = if let Some(octets) = parse_ipv4_octets(ip.as_bytes()) {
+            WebircAddress::Ipv4(octets)
+        } else if let Some(segments) = parse_ipv6_segments(ip.as_bytes()) {
+            WebircAddress::Ipv6(segments)
+        } else {
+            return Err(WebircError::InvalidIp);
+        };
+        let options = params.extract_specific(4);
+        Ok(Self{password: params.extract_first(), gateway, hostname, ip, address, options})
+    }
+    /// Returns the password authenticating the gateway.
+    #[must_use]
+    pub const fn password(&self) -> ContentType<'msg> {
+        self.password
+    }
+    /// Returns the name of the gateway.
+    #[must_use]
+    pub const fn gateway(&self) -> ContentType<'msg> {
+        self.gateway
+    }
+    /// Returns the hostname of the user.
+    ///
+    /// This is the same as the IP address if it couldn't be resolved.
+    #[must_use]
+    pub const fn hostname(&self) -> ContentType<'msg> {
+        self.hostname
+    }
+    /// Returns the IP address of the user as it appears in the message.
+    #[must_use]
+    pub const fn ip(&self) -> ContentType<'msg> {
+        self.ip
+    }
+    /// Returns the validated [`WebircAddress`] of the user.
+    #[must_use]
+    pub const fn address(&self) -> WebircAddress {
+        self.address
+    }
+    /// Returns a [`SpaceList`] for reading each option such as `secure` or `remote-port=5132`.
+    ///
+    /// The [`SpaceList`] is empty if no options were sent.
+    #[must_use]
+    pub const fn options(&self) -> SpaceList<'msg> {
+        match self.options {
+            Some(options) => SpaceList::new(options),
+            None => SpaceList::new(ContentType::StringSlice("")),
+        }
+    }
+    /// Check whether the gateway indicated the connection of the user is secure.
+    #[must_use]
+    pub const fn is_secure(&self) -> bool {
+        let mut options = self.options();
+        while let Some((option, rest)) = options.next_item() {
+            if is_identical(option.as_bytes(), b"secure") {return true;}
+            options = rest;
+        }
+        false
+    }
+}
+
+/// The possible types of errors when reading a [`WebircMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WebircError {
+    /// The [`IrcMsg`] is not a `WEBIRC` command.
+    NotWebirc,
+    /// The password, gateway, hostname or IP address is missing.
+    MissingParameters,
+    /// The IP address is not a valid IPv4 or IPv6 address.
+    InvalidIp,
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{WebircAddress, WebircError, WebircMsg};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_webirc() {
+        let input = b"WEBIRC hunter2 kiwiirc client.example.com 192.0.2.1 :secure remote-port=5132";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let webirc = WebircMsg::from_msg(&msg);
+            assert!(webirc.is_ok());
+            if let Ok(webirc) = webirc {
+                assert!(is_identical(webirc.password().as_bytes(), b"hunter2"));
+                assert!(is_identical(webirc.gateway().as_bytes(), b"kiwiirc"));
+                assert!(is_identical(webirc.hostname().as_bytes(), b"client.example.com"));
+                assert!(is_identical(webirc.ip().as_bytes(), b"192.0.2.1"));
+                assert!(matches!(webirc.address(), WebircAddress::Ipv4([192, 0, 2, 1])));
+                assert!(webirc.is_secure() && webirc.options().count() == 2);
+            }
+        }
+        let msg = IrcMsg::parse(b"WEBIRC hunter2 kiwiirc 0::1 0::1");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let webirc = WebircMsg::from_msg(&msg);
+            assert!(webirc.is_ok());
+            if let Ok(webirc) = webirc {
+                assert!(matches!(webirc.address(), WebircAddress::Ipv6([0, 0, 0, 0, 0, 0, 0, 1])));
+                assert!(!webirc.is_secure() && webirc.options().count() == 0);
+            }
+        }
+        let msg = IrcMsg::parse(b"WEBIRC hunter2 kiwiirc host 192.0.2.256");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(WebircMsg::from_msg(&msg), Err(WebircError::InvalidIp)));}
+        let msg = IrcMsg::parse(b"WEBIRC hunter2 kiwiirc host not-an-ip");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(WebircMsg::from_msg(&msg), Err(WebircError::InvalidIp)));}
+        let msg = IrcMsg::parse(b"PASS hunter2");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(WebircMsg::from_msg(&msg), Err(WebircError::NotWebirc)));}
+    }
+}
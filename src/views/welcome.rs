@@ -0,0 +1,136 @@
+//! Methods for reading `RPL_WELCOME` (`001`) numerics.
+//!
+//! ## Purpose
+//!
+//! `RPL_WELCOME` is the first reply after a successful registration. Its first parameter is the nickname the IRC
+//! server confirmed for the IRC client which may differ from the one requested. The text is free form but usually
+//! follows `Welcome to the <network> ... <nick>!<user>@<host>` from which the network name and the full user mask
+//! can be read.
+
+use crate::{ContentType, IrcMsg, casemapping::IrcCaseMapping, source::Nickname};
+
+/// A view over an `RPL_WELCOME` (`001`) numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WelcomeReply<'msg> {
+    nick: ContentType<'msg>,
+    text: ContentType<'msg>,
+}
+
+impl<'msg> WelcomeReply<'msg> {
+    /// Generates a [`WelcomeReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_WELCOME` (`001`) numeric with a nickname and text.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(1) = msg.command().as_numeric_u16() else {return None;};
+        let Some(params) = msg.parameters() else {return None;};
+        if params.count() < 2 {return None;}
+        Some(Self{nick: params.extract_first(), text: params.extract_last()})
+    }
+    /// Returns the nickname confirmed by the IRC server.
+    #[must_use]
+    pub const fn nick(&self) -> ContentType<'msg> {
+        self.nick
+    }
+    /// Returns the human readable text.
+    #[must_use]
+    pub const fn text(&self) -> ContentType<'msg> {
+        self.text
+    }
+    /// Returns the network name following `Welcome to the` in the text if present.
+    ///
+    /// Returns `None` for the generic `Welcome to the Internet Relay Network` of [RFC 2812].
+    ///
+    /// [RFC 2812]: <https://datatracker.ietf.org/doc/html/rfc2812#section-5.1>
+    #[must_use]
+    pub const fn network(&self) -> Option<ContentType<'msg>> {
+        let (prefix, rest) = match self.text.as_bytes() {
+            text if text.len() > 15 => text.split_at(15),
+            _ => return None,
+        };
+        if !IrcCaseMapping::Ascii.is_equivalent(prefix, b"Welcome to the ") {return None;}
+        let mut index = 0;
+        while index < rest.len() && rest[index] != b' ' {index += 1;}
+        let (network, _) = rest.split_at(index);
+        if network.is_empty() || IrcCaseMapping::Ascii.is_equivalent(network, b"Internet") {return None;}
+        Some(ContentType::new(network))
+    }
+    /// Returns the full user mask at the end of the text as a [`Nickname`] if present.
+    ///
+    /// The last word of the text is only treated as a mask if it contains a `!` or `@`.
+    #[must_use]
+    pub const fn mask(&self) -> Option<Nickname<'msg>> {
+        let text = self.text.as_bytes();
+        let mut index = text.len();
+        while index > 0 && text[index - 1] != b' ' {index -= 1;}
+        let (_, last) = text.split_at(index);
+        let mut position = 0;
+        while position < last.len() {
+            if last[position] == b'!' || last[position] == b'@' {return Some(Nickname::from_mask(last));}
+            position += 1;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::WelcomeReply;
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_welcome() {
+        let input = b":irc.host 001 dan :Welcome to the ExampleNet IRC Network dan!d@localhost";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let welcome = WelcomeReply::from_msg(&msg);
+            assert!(welcome.is_some());
+            if let Some(welcome) = welcome {
+                let pair = (welcome.network(), welcome.mask());
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(network), Some(mask)) = pair {
+                    assert!(is_identical(welcome.nick().as_bytes(), b"dan"));
+                    assert!(is_identical(network.as_bytes(), b"ExampleNet"));
+                    let host = mask.host();
+                    assert!(host.is_some());
+                    if let Some(host) = host {
+                        assert!(is_identical(mask.nick().as_bytes(), b"dan"));
+                        assert!(is_identical(host.as_bytes(), b"localhost"));
+                    }
+                }
+            }
+        }
+        let input = b":irc.host 001 dan_ :Welcome to the Libera.Chat Internet Relay Chat Network dan_";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let welcome = WelcomeReply::from_msg(&msg);
+            assert!(welcome.is_some());
+            if let Some(welcome) = welcome {
+                let network = welcome.network();
+                assert!(network.is_some());
+                if let Some(network) = network {
+                    assert!(is_identical(network.as_bytes(), b"Libera.Chat") && welcome.mask().is_none());
+                }
+            }
+        }
+        let input = b":irc.host 001 dan :Welcome to the Internet Relay Network dan!d@localhost";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let welcome = WelcomeReply::from_msg(&msg);
+            assert!(welcome.is_some());
+            if let Some(welcome) = welcome {assert!(welcome.network().is_none() && welcome.mask().is_some());}
+        }
+        let msg = IrcMsg::parse(b":irc.host 001 dan :Hi");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let welcome = WelcomeReply::from_msg(&msg);
+            assert!(welcome.is_some());
+            if let Some(welcome) = welcome {assert!(welcome.network().is_none() && welcome.mask().is_none());}
+        }
+        let msg = IrcMsg::parse(b":irc.host 002 dan :Your host is irc.host");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(WelcomeReply::from_msg(&msg).is_none());}
+    }
+}
@@ -0,0 +1,458 @@
+//! Methods for reading `WHO` replies and writing `WHOX` queries.
+//!
+//! ## Purpose
+//!
+//! `WHO` lists the users matching a mask or the members of a channel. Each user is sent as an `RPL_WHOREPLY`
+//! (`352`) followed by a single `RPL_ENDOFWHO` (`315`). The flags of a [`WhoReply`] start with `H` for here or `G`
+//! for gone, optionally followed by `*` for IRC operators and the membership prefixes of the user in the channel.
+//! The last parameter holds the hop count and the realname separated by a space.
+//!
+//! With [WHOX] the IRC client chooses the fields it needs by appending `%<fields>` to the query along with an
+//! optional token of up to 3 digits to tell queries apart. Each user is then sent as an `RPL_WHOSPCRPL` (`354`)
+//! holding only the requested fields in the fixed order `tcuihsnfdlaor` regardless of the order requested.
+//! As the reply doesn't name its fields a [`WhoxReply`] must be read with the same fields as the query.
+//!
+//! [WHOX]: <https://github.com/ircv3/ircv3-specifications/blob/master/extensions/whox.md>
+
+use crate::{ContentType, IrcMsg, IrcMsgWriter, IrcMsgWriterError, contains_byte, parameters::Parameters,
+    parse_u32};
+
+/// The `WHOX` fields in the order they appear in an `RPL_WHOSPCRPL` (`354`).
+pub const WHOX_FIELDS: &[u8; 13] = b"tcuihsnfdlaor";
+
+/// A view over an `RPL_WHOREPLY` (`352`) numeric [`IrcMsg`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WhoReply<'msg> {
+    params: Parameters<'msg>,
+}
+
+impl<'msg> WhoReply<'msg> {
+    /// Generates a [`WhoReply`] from an [`IrcMsg`].
+    ///
+    /// Returns `None` if the [`IrcMsg`] is not an `RPL_WHOREPLY` (`352`) numeric with enough parameters.
+    #[must_use]
+    pub const fn from_msg(msg: &IrcMsg<'msg>) -> Option<Self> {
+        let Some(352) = msg.command().as_numeric_u16() else {return None;};
+        let Some(params) = msg.parameters() else {return None;};
+        if params.count() < 8 {return None;}
+        Some(Self{params})
+    }
+    /// Returns the nickname of the client receiving the [`WhoReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.params.extract_first()
+    }
+    /// Returns the channel or `*` if the user shares no visible channel.
+    #[must_use]
+    pub const fn channel(&self) -> ContentType<'msg> {
+        self.param(1)
+    }
+    /// Returns the username of the user.
+    #[must_use]
+    pub const fn user(&self) -> ContentType<'msg> {
+        self.param(2)
+    }
+    /// Returns the host of the user.
+    #[must_use]
+    pub const fn host(&self) -> ContentType<'msg> {
+        self.param(3)
+    }
+    /// Returns the server the user is connected to.
+    #[must_use]
+    pub const fn server(&self) -> ContentType<'msg> {
+        self.param(4)
+    }
+    /// Returns the nick of the user.
+    #[must_use]
+    pub const fn nick(&self) -> ContentType<'msg> {
+        self.param(5)
+    }
+    /// Returns the flags as they appear in the message.
+    #[must_use]
+    pub const fn flags(&self) -> ContentType<'msg> {
+        self.param(6)
+    }
+    /// Check whether the user is away as indicated by the `G` flag.
+    #[must_use]
+    pub const fn is_away(&self) -> bool {
+        matches!(self.flags().as_bytes(), [b'G', ..])
+    }
+    /// Check whether the user is an IRC operator as indicated by the `*` flag.
+    #[must_use]
+    pub const fn is_oper(&self) -> bool {
+        matches!(self.flags().as_bytes(), [_, b'*', ..])
+    }
+    /// Returns the membership prefixes of the user in the channel.
+    ///
+    /// The `prefixes` are usually [`Prefix::prefixes`](crate::isupport::Prefix::prefixes).
+    #[must_use]
+    pub const fn membership_prefixes(&self, prefixes: &[u8]) -> ContentType<'msg> {
+        let flags = self.flags().as_bytes();
+        let mut start = if flags.is_empty() {0} else {1};
+        if start < flags.len() && flags[start] == b'*' && !contains_byte(prefixes, b'*') {start += 1;}
+        let mut end = start;
+        while end < flags.len() && contains_byte(prefixes, flags[end]) {end += 1;}
+        let (before, _) = flags.split_at(end);
+        let (_, membership) = before.split_at(start);
+        ContentType::new(membership)
+    }
+    /// Returns the hop count between the IRC server and the server of the user.
+    ///
+    /// Returns `None` if the hop count is not a number.
+    #[must_use]
+    pub const fn hopcount(&self) -> Option<u32> {
+        let (hopcount, _) = self.split_trailing();
+        parse_u32(hopcount)
+    }
+    /// Returns the realname of the user.
+    #[must_use]
+    pub const fn realname(&self) -> ContentType<'msg> {
+        let (_, realname) = self.split_trailing();
+        ContentType::new(realname)
+    }
+    const fn param(&self, index: usize) -> ContentType<'msg> {
+        match self.params.extract_specific(index) {
+            Some(param) => param,
+            None => ContentType::new(b""),
+        }
+    }
+    const fn split_trailing(&self) -> (&'msg [u8], &'msg [u8]) {
+        let trailing = self.param(7).as_bytes();
+        let mut index = 0;
+        while index < trailing.len() {
+            if trailing[index] == b' ' {
+                let (hopcount, rest) = trailing.split_at(index);
+                let (_, realname) = rest.split_at(1);
+                return (hopcount, realname);
+            }
+            index += 1;
+        }
+        (trailing, b"")
+    }
+}
+
+/// Writes a `WHOX` query of the form `WHO <mask> %<fields>[,<token>]`.
+///
+/// The `fields` are letters of [`WHOX_FIELDS`] in any order. A `token` of 1 to 3 digits requires the `t` field.
+///
+/// # Errors
+///
+/// Will return `Err` if a field is unknown or repeated, the `token` is invalid, the `token` and `t` field don't
+/// match up, the `mask` is not a valid middle parameter or the message is longer than `N` bytes.
+pub const fn whox_query<const N: usize>(mask: &[u8], fields: &[u8], token: Option<&[u8]>)
+-> Result<IrcMsgWriter<N>, WhoxError> {
+    let present = match field_mask(fields) {
+        Ok(present) => present,
+        Err(e) => return Err(e),
+    };
+    let mut query = [0; 18];
+    query[0] = b'%';
+    let mut len = 1;
+    while len <= fields.len() {
+        query[len] = fields[len - 1];
+        len += 1;
+    }
+    match token {
+        Some(token) => {
+            if token.is_empty() || token.len() > 3 {return Err(WhoxError::InvalidToken);}
+            if present & 1 == 0 {return Err(WhoxError::TokenMismatch);}
+            query[len] = b',';
+            len += 1;
+            let mut index = 0;
+            while index < token.len() {
+                if !token[index].is_ascii_digit() {return Err(WhoxError::InvalidToken);}
+                query[len] = token[index];
+                len += 1;
+                index += 1;
+            }
+        },
+        None if present & 1 == 1 => return Err(WhoxError::TokenMismatch),
+        None => {},
+    }
+    let (query, _) = query.split_at(len);
+    let writer = match IrcMsgWriter::new().command(b"WHO") {
+        Ok(writer) => writer,
+        Err(e) => return Err(WhoxError::Writer(e)),
+    };
+    let writer = match writer.middle(mask) {
+        Ok(writer) => writer,
+        Err(e) => return Err(WhoxError::Writer(e)),
+    };
+    match writer.middle(query) {
+        Ok(writer) => Ok(writer),
+        Err(e) => Err(WhoxError::Writer(e)),
+    }
+}
+
+// Returns a bit for each requested field in the order of `WHOX_FIELDS`.
+const fn field_mask(fields: &[u8]) -> Result<u16, WhoxError> {
+    if fields.is_empty() {return Err(WhoxError::MissingFields);}
+    let (mut present, mut index) = (0_u16, 0);
+    while index < fields.len() {
+        let Some(position) = field_position(fields[index]) else {return Err(WhoxError::InvalidField(fields[index]));};
+        if present & (1 << position) != 0 {return Err(WhoxError::RepeatedField(fields[index]));}
+        present |= 1 << position;
+        index += 1;
+    }
+    Ok(present)
+}
+
+const fn field_position(field: u8) -> Option<usize> {
+    let mut index = 0;
+    while index < WHOX_FIELDS.len() {
+        if WHOX_FIELDS[index] == field {return Some(index);}
+        index += 1;
+    }
+    None
+}
+
+/// The possible types of errors when writing a `WHOX` query or reading a [`WhoxReply`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WhoxError {
+    /// No fields were requested.
+    MissingFields,
+    /// The field is not one of [`WHOX_FIELDS`].
+    InvalidField(u8),
+    /// The field was requested more than once.
+    RepeatedField(u8),
+    /// The token is not 1 to 3 digits.
+    InvalidToken,
+    /// A token was given without the `t` field or the other way around.
+    TokenMismatch,
+    /// Writing the query failed.
+    Writer(IrcMsgWriterError),
+}
+
+/// A view over an `RPL_WHOSPCRPL` (`354`) numeric [`IrcMsg`] read according to the requested `WHOX` fields.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WhoxReply<'msg> {
+    params: Parameters<'msg>,
+    present: u16,
+}
+
+impl<'msg> WhoxReply<'msg> {
+    /// Generates a [`WhoxReply`] from an [`IrcMsg`] using the `fields` of the query.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if a field is unknown or repeated.
+    /// Returns `Ok(None)` if the [`IrcMsg`] is not an `RPL_WHOSPCRPL` (`354`) numeric with one parameter per field.
+    pub const fn from_msg(msg: &IrcMsg<'msg>, fields: &[u8]) -> Result<Option<Self>, WhoxError> {
+        let present = match field_mask(fields) {
+            Ok(present) => present,
+            Err(e) => return Err(e),
+        };
+        let Some(354) = msg.command().as_numeric_u16() else {return Ok(None);};
+        let Some(params) = msg.parameters() else {return Ok(None);};
+        if params.count() != 1 + present.count_ones() as usize {return Ok(None);}
+        Ok(Some(Self{params, present}))
+    }
+    /// Returns the nickname of the client receiving the [`WhoxReply`].
+    #[must_use]
+    pub const fn client(&self) -> ContentType<'msg> {
+        self.params.extract_first()
+    }
+    /// Returns the value of the `field` if it was requested.
+    #[must_use]
+    pub const fn field(&self, field: u8) -> Option<ContentType<'msg>> {
+        let Some(position) = field_position(field) else {return None;};
+        if self.present & (1 << position) == 0 {return None;}
+        let preceding = (self.present & ((1 << position) - 1)).count_ones() as usize;
+        self.params.extract_specific(1 + preceding)
+    }
+    /// Returns the token of the `t` field.
+    #[must_use]
+    pub const fn token(&self) -> Option<ContentType<'msg>> {
+        self.field(b't')
+    }
+    /// Returns the channel of the `c` field.
+    #[must_use]
+    pub const fn channel(&self) -> Option<ContentType<'msg>> {
+        self.field(b'c')
+    }
+    /// Returns the username of the `u` field.
+    #[must_use]
+    pub const fn user(&self) -> Option<ContentType<'msg>> {
+        self.field(b'u')
+    }
+    /// Returns the IP address of the `i` field.
+    #[must_use]
+    pub const fn ip(&self) -> Option<ContentType<'msg>> {
+        self.field(b'i')
+    }
+    /// Returns the host of the `h` field.
+    #[must_use]
+    pub const fn host(&self) -> Option<ContentType<'msg>> {
+        self.field(b'h')
+    }
+    /// Returns the server of the `s` field.
+    #[must_use]
+    pub const fn server(&self) -> Option<ContentType<'msg>> {
+        self.field(b's')
+    }
+    /// Returns the nick of the `n` field.
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        self.field(b'n')
+    }
+    /// Returns the flags of the `f` field.
+    #[must_use]
+    pub const fn flags(&self) -> Option<ContentType<'msg>> {
+        self.field(b'f')
+    }
+    /// Returns the hop count of the `d` field.
+    #[must_use]
+    pub const fn hopcount(&self) -> Option<u32> {
+        match self.field(b'd') {
+            Some(hopcount) => parse_u32(hopcount.as_bytes()),
+            None => None,
+        }
+    }
+    /// Returns the idle seconds of the `l` field.
+    #[must_use]
+    pub const fn idle(&self) -> Option<u32> {
+        match self.field(b'l') {
+            Some(idle) => parse_u32(idle.as_bytes()),
+            None => None,
+        }
+    }
+    /// Returns the account name of the `a` field.
+    ///
+    /// Returns `None` if the user is not logged in which is sent as `0`.
+    #[must_use]
+    pub const fn account(&self) -> Option<ContentType<'msg>> {
+        match self.field(b'a') {
+            Some(account) if matches!(account.as_bytes(), b"0") => None,
+            account => account,
+        }
+    }
+    /// Returns the oplevel of the `o` field.
+    #[must_use]
+    pub const fn oplevel(&self) -> Option<ContentType<'msg>> {
+        self.field(b'o')
+    }
+    /// Returns the realname of the `r` field.
+    #[must_use]
+    pub const fn realname(&self) -> Option<ContentType<'msg>> {
+        self.field(b'r')
+    }
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{WhoReply, WhoxError, WhoxReply, whox_query};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn reading_who() {
+        let input = b":irc.host 352 me #chan d localhost irc.host dan H*@+ :2 Dan Smith";
+        let msg = IrcMsg::parse(input);
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let who = WhoReply::from_msg(&msg);
+            assert!(who.is_some());
+            if let Some(who) = who {
+                assert!(is_identical(who.client().as_bytes(), b"me"));
+                assert!(is_identical(who.channel().as_bytes(), b"#chan"));
+                assert!(is_identical(who.user().as_bytes(), b"d") && is_identical(who.host().as_bytes(), b"localhost"));
+                assert!(is_identical(who.server().as_bytes(), b"irc.host"));
+                assert!(is_identical(who.nick().as_bytes(), b"dan"));
+                assert!(is_identical(who.flags().as_bytes(), b"H*@+") && !who.is_away() && who.is_oper());
+                assert!(is_identical(who.membership_prefixes(b"~&@%+").as_bytes(), b"@+"));
+                assert!(matches!(who.hopcount(), Some(2)) && is_identical(who.realname().as_bytes(), b"Dan Smith"));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 352 me * b host irc.host bob G :0 Bob");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let who = WhoReply::from_msg(&msg);
+            assert!(who.is_some());
+            if let Some(who) = who {
+                assert!(who.is_away() && !who.is_oper() && who.membership_prefixes(b"@+").as_bytes().is_empty());
+                assert!(matches!(who.hopcount(), Some(0)) && is_identical(who.realname().as_bytes(), b"Bob"));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 352 me * b host irc.host bob H@ :x");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let who = WhoReply::from_msg(&msg);
+            assert!(who.is_some());
+            if let Some(who) = who {
+                assert!(who.hopcount().is_none() && who.realname().as_bytes().is_empty());
+                assert!(is_identical(who.membership_prefixes(b"@+").as_bytes(), b"@"));
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 315 me #chan :End of WHO list");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(WhoReply::from_msg(&msg).is_none());}
+    }
+    #[test]
+    const fn writing_whox() {
+        let writer = whox_query::<32>(b"#chan", b"nuhat", Some(b"42"));
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {assert!(is_identical(writer.as_bytes(), b"WHO #chan %nuhat,42"));}
+        let writer = whox_query::<32>(b"dan", b"na", None);
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {assert!(is_identical(writer.as_bytes(), b"WHO dan %na"));}
+        assert!(matches!(whox_query::<32>(b"dan", b"", None), Err(WhoxError::MissingFields)));
+        assert!(matches!(whox_query::<32>(b"dan", b"nx", None), Err(WhoxError::InvalidField(b'x'))));
+        assert!(matches!(whox_query::<32>(b"dan", b"nan", None), Err(WhoxError::RepeatedField(b'n'))));
+        assert!(matches!(whox_query::<32>(b"dan", b"nt", Some(b"1234")), Err(WhoxError::InvalidToken)));
+        assert!(matches!(whox_query::<32>(b"dan", b"nt", Some(b"1a")), Err(WhoxError::InvalidToken)));
+        assert!(matches!(whox_query::<32>(b"dan", b"n", Some(b"1")), Err(WhoxError::TokenMismatch)));
+        assert!(matches!(whox_query::<32>(b"dan", b"nt", None), Err(WhoxError::TokenMismatch)));
+        assert!(matches!(whox_query::<8>(b"dan", b"n", None), Err(WhoxError::Writer(_))));
+        let writer = whox_query::<32>(b"dan", b"tcuihsnfdlaor", Some(b"999"));
+        assert!(writer.is_ok());
+        if let Ok(writer) = writer {assert!(is_identical(writer.as_bytes(), b"WHO dan %tcuihsnfdlaor,999"));}
+    }
+    #[test]
+    const fn reading_whox() {
+        let msg = IrcMsg::parse(b":irc.host 354 me 42 d localhost dan dan :Dan Smith");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let whox = WhoxReply::from_msg(&msg, b"nuhatr");
+            assert!(matches!(whox, Ok(Some(_))));
+            if let Ok(Some(whox)) = whox {
+                let values = (whox.token(), whox.user(), whox.host());
+                assert!(matches!(values, (Some(_), Some(_), Some(_))));
+                if let (Some(token), Some(user), Some(host)) = values {
+                    let pair = (whox.nick(), whox.account());
+                    assert!(matches!(pair, (Some(_), Some(_))));
+                    if let (Some(nick), Some(account)) = pair {
+                        let realname = whox.realname();
+                        assert!(realname.is_some());
+                        if let Some(realname) = realname {
+                            assert!(is_identical(whox.client().as_bytes(), b"me"));
+                            assert!(is_identical(token.as_bytes(), b"42"));
+                            assert!(is_identical(user.as_bytes(), b"d") && is_identical(host.as_bytes(), b"localhost"));
+                            assert!(is_identical(nick.as_bytes(), b"dan") && is_identical(account.as_bytes(), b"dan"));
+                            assert!(is_identical(realname.as_bytes(), b"Dan Smith") && whox.channel().is_none());
+                            assert!(whox.ip().is_none());
+                        }
+                    }
+                }
+            }
+        }
+        let msg = IrcMsg::parse(b":irc.host 354 me #chan bob H@ 0 5 0");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let whox = WhoxReply::from_msg(&msg, b"acnfdl");
+            assert!(matches!(whox, Ok(Some(_))));
+            if let Ok(Some(whox)) = whox {
+                let pair = (whox.channel(), whox.flags());
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(channel), Some(flags)) = pair {
+                    assert!(is_identical(channel.as_bytes(), b"#chan") && is_identical(flags.as_bytes(), b"H@"));
+                }
+                assert!(whox.account().is_none() && matches!(whox.hopcount(), Some(0)));
+                assert!(matches!(whox.idle(), Some(5)));
+                assert!(whox.server().is_none() && whox.oplevel().is_none() && whox.field(b'x').is_none());
+            }
+            assert!(matches!(WhoxReply::from_msg(&msg, b"acnf"), Ok(None)));
+            assert!(matches!(WhoxReply::from_msg(&msg, b"acnfdq"), Err(WhoxError::InvalidField(b'q'))));
+        }
+        let msg = IrcMsg::parse(b":irc.host 315 me #chan :End of WHO list");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {assert!(matches!(WhoxReply::from_msg(&msg, b"n"), Ok(None)));}
+    }
+}
@@ -0,0 +1,342 @@
+//! Methods for combining `WHOIS` replies.
+//!
+//! ## Purpose
+//!
+//! An IRC server answers `WHOIS` with a series of numerics each describing a part of the user followed by
+//! `RPL_ENDOFWHOIS` (`318`). A [`WhoisAccumulator`] consumes these replies and exposes the combined record.
+//! The user may be in so many channels that `RPL_WHOISCHANNELS` (`319`) is split across several replies so up to
+//! `N` of them are kept.
+//!
+//! The following numerics are consumed:
+//!
+//! | Numeric | Name                | Information                        |
+//! |---------|---------------------|------------------------------------|
+//! | `301`   | `RPL_AWAY`          | away message                       |
+//! | `311`   | `RPL_WHOISUSER`     | username, host and realname        |
+//! | `312`   | `RPL_WHOISSERVER`   | server and server info             |
+//! | `313`   | `RPL_WHOISOPERATOR` | IRC operator status                |
+//! | `317`   | `RPL_WHOISIDLE`     | idle seconds and sign on time      |
+//! | `318`   | `RPL_ENDOFWHOIS`    | end of the replies                 |
+//! | `319`   | `RPL_WHOISCHANNELS` | channels with membership prefixes  |
+//! | `330`   | `RPL_WHOISACCOUNT`  | account name                       |
+//! | `338`   | `RPL_WHOISACTUALLY` | actual host or IP address          |
+//! | `401`   | `ERR_NOSUCHNICK`    | no such user                       |
+//! | `671`   | `RPL_WHOISSECURE`   | secure connection                  |
+
+use crate::{ContentType, IrcMsg, is_identical, parameters::SpaceList};
+
+/// A record of a user combined from `WHOIS` replies keeping up to `N` `RPL_WHOISCHANNELS` (`319`) replies.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct WhoisAccumulator<'msg, const N: usize> {
+    nick: Option<ContentType<'msg>>,
+    user: Option<ContentType<'msg>>,
+    host: Option<ContentType<'msg>>,
+    realname: Option<ContentType<'msg>>,
+    server: Option<ContentType<'msg>>,
+    server_info: Option<ContentType<'msg>>,
+    away: Option<ContentType<'msg>>,
+    account: Option<ContentType<'msg>>,
+    actual_host: Option<ContentType<'msg>>,
+    idle: Option<u64>,
+    signon: Option<u64>,
+    channels: [Option<ContentType<'msg>>; N],
+    channel_replies: usize,
+    oper: bool,
+    secure: bool,
+    not_found: bool,
+    complete: bool,
+}
+
+impl<'msg, const N: usize> WhoisAccumulator<'msg, N> {
+    /// Generates an empty [`WhoisAccumulator`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self{nick: None, user: None, host: None, realname: None, server: None, server_info: None, away: None,
+            account: None, actual_host: None, idle: None, signon: None, channels: [None; N], channel_replies: 0,
+            oper: false, secure: false, not_found: false, complete: false}
+    }
+    /// Combines a `WHOIS` reply into the [`WhoisAccumulator`].
+    ///
+    /// The nick of the first reply determines the user the [`WhoisAccumulator`] describes.
+    ///
+    /// # Errors
+    ///
+    /// Will return `Err` if the [`IrcMsg`] is not a consumed numeric with enough parameters, concerns another nick,
+    /// arrives after `RPL_ENDOFWHOIS` (`318`) or is an `RPL_WHOISCHANNELS` (`319`) beyond the first `N`.
+    pub const fn apply(mut self, msg: &IrcMsg<'msg>) -> Result<Self, WhoisError> {
+        if self.complete {return Err(WhoisError::AlreadyComplete);}
+        let Some(numeric) = msg.command().as_numeric_u16() else {return Err(WhoisError::NotWhoisReply);};
+        let Some(params) = msg.parameters() else {return Err(WhoisError::NotWhoisReply);};
+        let Some(nick) = params.extract_specific(1) else {return Err(WhoisError::NotWhoisReply);};
+        if !matches!(numeric, 301 | 311 | 312 | 313 | 317 | 318 | 319 | 330 | 338 | 401 | 671) {
+            return Err(WhoisError::NotWhoisReply);
+        }
+        match self.nick {
+            Some(known) if !is_identical(known.as_bytes(), nick.as_bytes()) => return Err(WhoisError::NickMismatch),
+            Some(_) => {},
+            None => self.nick = Some(nick),
+        }
+        let count = params.count();
+        let last = params.extract_last();
+        match numeric {
+            301 => self.away = Some(last),
+            311 if count >= 6 => {
+                self.user = params.extract_specific(2);
+                self.host = params.extract_specific(3);
+                self.realname = Some(last);
+            },
+            312 if count >= 4 => {
+                self.server = params.extract_specific(2);
+                self.server_info = Some(last);
+            },
+            313 => self.oper = true,
+            317 if count >= 4 => {
+                self.idle = match params.extract_specific(2) {
+                    Some(idle) => idle.to_u64(),
+                    None => None,
+                };
+                self.signon = if count >= 5 {
+                    match params.extract_specific(3) {
+                        Some(signon) => signon.to_u64(),
+                        None => None,
+                    }
+                } else {None};
+            },
+            318 => self.complete = true,
+            319 => {
+                if self.channel_replies >= N {return Err(WhoisError::ChannelsFull);}
+                self.channels[self.channel_replies] = Some(last);
+                self.channel_replies += 1;
+            },
+            330 if count >= 4 => self.account = params.extract_specific(2),
+            338 if count >= 4 => self.actual_host = params.extract_specific(count - 2),
+            401 => self.not_found = true,
+            671 => self.secure = true,
+            _ => return Err(WhoisError::NotWhoisReply),
+        }
+        Ok(self)
+    }
+    /// Check whether `RPL_ENDOFWHOIS` (`318`) has been received.
+    #[must_use]
+    pub const fn is_complete(&self) -> bool {
+        self.complete
+    }
+    /// Check whether the IRC server replied with `ERR_NOSUCHNICK` (`401`).
+    #[must_use]
+    pub const fn is_not_found(&self) -> bool {
+        self.not_found
+    }
+    /// Returns the nick of the user.
+    #[must_use]
+    pub const fn nick(&self) -> Option<ContentType<'msg>> {
+        self.nick
+    }
+    /// Returns the username of the user.
+    #[must_use]
+    pub const fn user(&self) -> Option<ContentType<'msg>> {
+        self.user
+    }
+    /// Returns the host of the user.
+    #[must_use]
+    pub const fn host(&self) -> Option<ContentType<'msg>> {
+        self.host
+    }
+    /// Returns the realname of the user.
+    #[must_use]
+    pub const fn realname(&self) -> Option<ContentType<'msg>> {
+        self.realname
+    }
+    /// Returns the server the user is connected to.
+    #[must_use]
+    pub const fn server(&self) -> Option<ContentType<'msg>> {
+        self.server
+    }
+    /// Returns the description of the server the user is connected to.
+    #[must_use]
+    pub const fn server_info(&self) -> Option<ContentType<'msg>> {
+        self.server_info
+    }
+    /// Returns the away message of the user.
+    #[must_use]
+    pub const fn away_message(&self) -> Option<ContentType<'msg>> {
+        self.away
+    }
+    /// Returns the account name of the user.
+    #[must_use]
+    pub const fn account(&self) -> Option<ContentType<'msg>> {
+        self.account
+    }
+    /// Returns the actual host or IP address of the user.
+    #[must_use]
+    pub const fn actual_host(&self) -> Option<ContentType<'msg>> {
+        self.actual_host
+    }
+    /// Returns the amount of seconds the user has been idle.
+    #[must_use]
+    pub const fn idle_seconds(&self) -> Option<u64> {
+        self.idle
+    }
+    /// Returns the Unix timestamp of when the user signed on.
+    #[must_use]
+    pub const fn signon(&self) -> Option<u64> {
+        self.signon
+    }
+    /// Check whether the user is an IRC operator.
+    #[must_use]
+    pub const fn is_oper(&self) -> bool {
+        self.oper
+    }
+    /// Check whether the user is using a secure connection.
+    #[must_use]
+    pub const fn is_secure(&self) -> bool {
+        self.secure
+    }
+    /// Returns the amount of `RPL_WHOISCHANNELS` (`319`) replies received.
+    #[must_use]
+    pub const fn channel_reply_count(&self) -> usize {
+        self.channel_replies
+    }
+    /// Returns a [`SpaceList`] over the channels of the `RPL_WHOISCHANNELS` (`319`) reply at the specified index.
+    ///
+    /// Each channel may be preceded by membership prefixes. Index starts at 0. If out of bounds it returns `None`.
+    #[must_use]
+    pub const fn channels(&self, index: usize) -> Option<SpaceList<'msg>> {
+        if index >= self.channel_replies {return None;}
+        match self.channels[index] {
+            Some(channels) => Some(SpaceList::new(channels)),
+            None => None,
+        }
+    }
+}
+
+impl<const N: usize> Default for WhoisAccumulator<'_, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The possible types of errors when combining a reply into a [`WhoisAccumulator`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WhoisError {
+    /// The [`IrcMsg`] is not a consumed `WHOIS` numeric with enough parameters.
+    NotWhoisReply,
+    /// The reply concerns a different nick than the previous replies.
+    NickMismatch,
+    /// `RPL_ENDOFWHOIS` (`318`) has already been received.
+    AlreadyComplete,
+    /// More `RPL_WHOISCHANNELS` (`319`) replies were received than can be kept.
+    ChannelsFull,
+}
+
+#[cfg(test)]
+mod const_tests {
+    use super::{WhoisAccumulator, WhoisError};
+    use crate::{IrcMsg, is_identical};
+    #[test]
+    const fn accumulating_whois() {
+        let inputs: [&[u8]; 8] = [
+            b":irc.host 311 me dan d localhost * :Dan Smith",
+            b":irc.host 312 me dan irc.host :Example server",
+            b":irc.host 313 me dan :is an IRC operator",
+            b":irc.host 317 me dan 42 1700000000 :seconds idle, signon time",
+            b":irc.host 319 me dan :@#chan +#other",
+            b":irc.host 330 me dan dansmith :is logged in as",
+            b":irc.host 338 me dan 192.0.2.1 :actually using host",
+            b":irc.host 671 me dan :is using a secure connection",
+        ];
+        let mut whois = WhoisAccumulator::<2>::new();
+        let mut index = 0;
+        while index < inputs.len() {
+            let msg = IrcMsg::parse(inputs[index]);
+            assert!(msg.is_ok());
+            if let Ok(msg) = msg {
+                let applied = whois.apply(&msg);
+                assert!(applied.is_ok());
+                if let Ok(applied) = applied {whois = applied;}
+            }
+            index += 1;
+        }
+        assert!(!whois.is_complete());
+        let msg = IrcMsg::parse(b":irc.host 318 me dan :End of /WHOIS list");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let whois = whois.apply(&msg);
+            assert!(whois.is_ok());
+            if let Ok(whois) = whois {
+                assert!(whois.is_complete());
+                assert!(whois.is_oper());
+                assert!(whois.is_secure());
+                assert!(!whois.is_not_found());
+                let values = (whois.nick(), whois.user(), whois.host());
+                assert!(matches!(values, (Some(_), Some(_), Some(_))));
+                if let (Some(nick), Some(user), Some(host)) = values {
+                    assert!(is_identical(nick.as_bytes(), b"dan"));
+                    assert!(is_identical(user.as_bytes(), b"d"));
+                    assert!(is_identical(host.as_bytes(), b"localhost"));
+                }
+                let values = (whois.realname(), whois.server(), whois.server_info());
+                assert!(matches!(values, (Some(_), Some(_), Some(_))));
+                if let (Some(realname), Some(server), Some(info)) = values {
+                    assert!(is_identical(realname.as_bytes(), b"Dan Smith"));
+                    assert!(is_identical(server.as_bytes(), b"irc.host"));
+                    assert!(is_identical(info.as_bytes(), b"Example server"));
+                }
+                assert!(whois.away_message().is_none());
+                let pair = (whois.account(), whois.actual_host());
+                assert!(matches!(pair, (Some(_), Some(_))));
+                if let (Some(account), Some(actual)) = pair {
+                    assert!(is_identical(account.as_bytes(), b"dansmith"));
+                    assert!(is_identical(actual.as_bytes(), b"192.0.2.1"));
+                }
+                assert!(matches!(whois.idle_seconds(), Some(42)));
+                assert!(matches!(whois.signon(), Some(1_700_000_000)));
+                let channels = whois.channels(0);
+                assert!(channels.is_some());
+                if let Some(channels) = channels {assert!(channels.count() == 2);}
+                assert!(whois.channel_reply_count() == 1 && whois.channels(1).is_none());
+                assert!(matches!(whois.apply(&msg), Err(WhoisError::AlreadyComplete)));
+            }
+        }
+    }
+    #[test]
+    const fn rejecting_whois_replies() {
+        let whois = WhoisAccumulator::<1>::new();
+        let msg = IrcMsg::parse(b":irc.host 401 me nobody :No such nick/channel");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let whois = whois.apply(&msg);
+            assert!(whois.is_ok());
+            if let Ok(whois) = whois {
+                assert!(whois.is_not_found());
+                let msg = IrcMsg::parse(b":irc.host 301 me dan :Gone");
+                assert!(msg.is_ok());
+                if let Ok(msg) = msg {assert!(matches!(whois.apply(&msg), Err(WhoisError::NickMismatch)));}
+                let msg = IrcMsg::parse(b":irc.host 332 me #chan :Topic");
+                assert!(msg.is_ok());
+                if let Ok(msg) = msg {assert!(matches!(whois.apply(&msg), Err(WhoisError::NotWhoisReply)));}
+            }
+        }
+        let whois = WhoisAccumulator::<1>::new();
+        let msg = IrcMsg::parse(b":irc.host 319 me dan :#a");
+        assert!(msg.is_ok());
+        if let Ok(msg) = msg {
+            let whois = whois.apply(&msg);
+            assert!(whois.is_ok());
+            if let Ok(whois) = whois {
+                assert!(matches!(whois.apply(&msg), Err(WhoisError::ChannelsFull)));
+                let msg = IrcMsg::parse(b":irc.host 301 me dan :Gone");
+                assert!(msg.is_ok());
+                if let Ok(msg) = msg {
+                    let whois = whois.apply(&msg);
+                    assert!(whois.is_ok());
+                    if let Ok(whois) = whois {
+                        let away = whois.away_message();
+                        assert!(away.is_some());
+                        if let Some(away) = away {assert!(is_identical(away.as_bytes(), b"Gone"));}
+                    }
+                }
+            }
+        }
+    }
+}